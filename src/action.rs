@@ -1,3 +1,7 @@
+use crate::app::StatusFilter;
+use crate::components::confirm_modal::ConfirmAction;
+use crate::export::ExportFormat;
+
 /// Actions that can be performed in the application
 #[derive(Debug, Clone, PartialEq)]
 pub enum Action {
@@ -8,6 +12,12 @@ pub enum Action {
     Bottom,
     Left,
     Right,
+    MoveBy(isize), // Count-prefixed move (5j), stops at the ends instead of wrapping
+    HalfPageUp,
+    HalfPageDown,
+    ScreenTop, // First row on screen (`(` in the list, H in logs)
+    ScreenMiddle,
+    ScreenBottom,
 
     // Container operations
     StartContainer(String),
@@ -17,29 +27,93 @@ pub enum Action {
     PauseContainer(String),
     UnpauseContainer(String),
     RenameContainer(String, String), // (old_name, new_name)
+    ToggleKeepAlive(String), // Restart the container whenever it exits
+    ToggleMark(String), // Mark/unmark for the compare view
+    TogglePin(String), // Keep at the top of the list (persisted)
+    ScaleService(String, u64), // (service, replicas)
     CopyFromContainer(String, String, String), // (container, container_path, host_path)
     CopyToContainer(String, String, String), // (container, host_path, container_path)
+    PullImage(String, String), // (image, tag), runs in the background
 
     // Views
     ViewLogs(String),
+    ViewProjectLogs(String), // Every container of a compose project, interleaved
+    ViewCharts(String),
+    ViewCompare, // Side-by-side sparklines of marked containers
+    ViewServices, // Swarm services (managers only)
+    ViewPods, // Kubernetes pods tab (kubernetes feature)
+    ViewPodLogs(String, String), // (namespace, pod)
+    ViewHostProcesses,
+    ViewGpus, // GPUs with the containers allowed on and using each
+    ViewEvents, // Docker events received since start
+    StartEventFilter, // Open the container filter input in the events view
+    StartGoToTime, // Open the go-to-time input in the logs view
+    LoadMoreLogs, // Refetch the logs view with twice the history
+    StartLogFilter, // Open the include/exclude filter input in the logs view
+    StartStdin, // Open the stdin input for the container in the logs view
+    ToggleProcessSort, // CPU <-> memory in the host process view
+    CycleProjectSort, // Order compose projects by name/CPU/memory/network in Groups mode
+    ToggleProcessGrouping, // Flat host processes <-> grouped by container
+    NextNamespace, // Show pods of the next namespace
     ViewDetails,
     BackToList,
 
     // Modals
     ShowHelp,
-    ShowConfirmDelete(String),
-    ShowConfirmStop(String),
+    ShowYank, // Copy-to-clipboard menu for the selected container
+    ShowActionMenu, // Popup of the operations available for the selected container
+    ShowPalette, // Fuzzy search over every command, the current view's first
+    ShowConfirm(ConfirmAction), // Prompt (unless disabled in config), then run
     ShowRename(String),
     ShowProcesses(String),
+    ShowImageHistory(String), // Layers of an image
+    ShowVulns(String), // trivy report of this container's image, scanning it first if needed
+    ShowDiff(String), // Filesystem changes of a container (docker diff)
+    ShowCompose(String), // Compose files of the container's project, at its service
+    ScaffoldProject(String), // Write a project.yaml skeleton from the container's inspect
+    ShowCheckpoints(String), // Checkpoint manager (CRIU)
+    ShowGroups(String), // Group membership of a container
+    ToggleGroup(String), // Add/remove the group modal's container to/from a group
+    ShowLabels(Vec<String>), // Label editor for these containers
+    ApplyLabels, // Recreate the containers the reviewed label edits change
+    CreateCheckpoint(bool), // Checkpoint the managed container; true = leave it running
+    RestoreCheckpoint(String), // Start the managed container from a checkpoint
+    DeleteCheckpoint(String),
+    ShowCleanup, // Scan for unused resources and open the cleanup wizard
+    ShowPull, // Pull dialog with registry tag browser
+    FetchTags, // Look up registry tags for the repository typed in the pull dialog
+    ShowLogin, // Registry login modal
+    Login, // Check the login modal's credentials and save them to the keyring
     ShowCopyFiles(String),
     CloseModal,
     ConfirmAction,
+    ConfirmAlternative, // Secondary choice in the confirm modal (e.g. stop instead of delete)
 
     // App control
     Refresh,
+    Export(ExportFormat), // Write filtered list + stats to a file
     Quit,
     Tick, // Timer tick for stats refresh
-    CycleStatusFilter, // Cycle through All/Running/Stopped
+    CycleStatusFilter, // Cycle through All/Groups/Running/Stopped
+    SetStatusFilter(StatusFilter), // Jump directly to a status filter
+    CycleGroupFilter, // Show only the next user-defined group's members
+    ToggleAlerts, // Show/hide the recent alerts panel
+    NewTab, // Open a tab on the container list
+    CloseTab,
+    SwitchTab(usize), // Index of the tab (Alt+1 is 0)
+    ToggleExpandedHeader, // Show/hide per-core CPU, load and temperature
+    ToggleSplitView, // Show/hide the details pane beside the list
+    FocusExecPane, // Send keys to the embedded shell
+    ChangeRefreshSpeed(bool), // true = halve every refresh interval, false = double
+    ToggleRecycleBin, // Show/hide the recently deleted panel
+    ToggleAuditLog, // Show/hide the audit log panel
+    ToggleDaemon, // Show/hide the Docker daemon status panel
+    ToggleSchedule, // Show/hide the scheduled actions panel
+    ToggleExitWatch(String), // Notify when this container exits, with its exit code
+    ShowForward(String), // Port forward dialog for this container
+    RecreateDeleted, // Recreate the most recently deleted container
+    CheckImageUpdates, // Compare image digests with their registries
+    ReadOnlyBlocked(&'static str), // A mutating command was pressed in read-only mode
 
     // No action
     None,
@@ -0,0 +1,310 @@
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::{stdout, Write};
+use std::time::{Duration, Instant};
+
+use crate::models::{ContainerInfo, ContainerStatus};
+
+/// Number of fired alerts kept for the alerts panel
+const MAX_RECENT: usize = 50;
+
+/// Alert thresholds, the `[alerts]` section of config.toml
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AlertConfig {
+    /// CPU percent that counts as high
+    pub cpu_percent: f64,
+    /// How long CPU must stay high before alerting
+    pub cpu_duration_secs: u64,
+    /// Memory percent (of limit) that triggers an alert
+    pub memory_percent: f64,
+    /// PIDs as a percent of the container's pids limit that trigger an alert (0 = off)
+    pub pids_percent: f64,
+    /// Alert when a running container exits with a non-zero code or dies
+    pub on_exit: bool,
+    /// Alert when a healthcheck reports unhealthy
+    pub on_unhealthy: bool,
+    /// Restarts by the restart policy that count as a crash loop (0 = off)...
+    pub crash_loop_restarts: usize,
+    /// ...within this many seconds
+    pub crash_loop_window_secs: u64,
+    /// Ring the terminal bell and send an OSC 9 notification
+    pub bell: bool,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            cpu_percent: 90.0,
+            cpu_duration_secs: 30,
+            memory_percent: 95.0,
+            pids_percent: 90.0,
+            on_exit: true,
+            on_unhealthy: true,
+            crash_loop_restarts: 3,
+            crash_loop_window_secs: 300,
+            bell: true,
+        }
+    }
+}
+
+/// Kind of alert condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertKind {
+    HighCpu,
+    HighMemory,
+    PidLimit,
+    Exited,
+    Unhealthy,
+    CrashLoop,
+}
+
+/// A fired alert
+#[derive(Debug, Clone)]
+pub struct Alert {
+    pub container: String,
+    pub kind: AlertKind,
+    pub message: String,
+    pub at: chrono::DateTime<chrono::Local>,
+}
+
+/// Evaluates alert rules against each refresh and tracks active alerts
+#[derive(Debug, Default)]
+pub struct AlertManager {
+    pub config: AlertConfig,
+    /// Conditions currently firing (container, kind)
+    active: HashSet<(String, AlertKind)>,
+    /// When each container's CPU first went above the threshold
+    high_cpu_since: HashMap<String, Instant>,
+    /// Status seen on the previous evaluation
+    prev_status: HashMap<String, ContainerStatus>,
+    /// Recent policy restarts of each container
+    restarts: HashMap<String, RestartHistory>,
+    /// Most recent alerts first
+    pub recent: VecDeque<Alert>,
+}
+
+impl AlertManager {
+    pub fn new(config: AlertConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Check all rules, returning alerts that started firing on this evaluation
+    pub fn evaluate(&mut self, containers: &[ContainerInfo]) -> Vec<Alert> {
+        let mut fired = Vec::new();
+        let cpu_duration = Duration::from_secs(self.config.cpu_duration_secs);
+
+        for c in containers {
+            let stats = c.stats.as_ref().filter(|_| c.status.is_running());
+
+            // CPU must stay above the threshold for the configured duration
+            let cpu_high = match stats {
+                Some(s) if s.cpu_percent > self.config.cpu_percent => {
+                    let since = *self.high_cpu_since.entry(c.name.clone()).or_insert_with(Instant::now);
+                    since.elapsed() >= cpu_duration
+                }
+                _ => {
+                    self.high_cpu_since.remove(&c.name);
+                    false
+                }
+            };
+            let cpu_message = stats.map(|s| format!("CPU {:.0}% for over {}s", s.cpu_percent, cpu_duration.as_secs()));
+            self.update(c, AlertKind::HighCpu, cpu_high, cpu_message, &mut fired);
+
+            let mem_high = stats.is_some_and(|s| s.memory_percent > self.config.memory_percent);
+            let mem_message = stats.map(|s| format!("memory at {:.0}% of limit", s.memory_percent));
+            self.update(c, AlertKind::HighMemory, mem_high, mem_message, &mut fired);
+
+            // Containers without a pids limit never fire
+            let pids_percent = stats.and_then(|s| s.pids_percent());
+            let pids_high = self.config.pids_percent > 0.0 && pids_percent.is_some_and(|p| p >= self.config.pids_percent);
+            let pids_message = stats.map(|s| {
+                format!("{} of {} PIDs in use", s.pids.unwrap_or(0), s.pids_limit.unwrap_or(0))
+            });
+            self.update(c, AlertKind::PidLimit, pids_high, pids_message, &mut fired);
+
+            // Only a running -> exited/dead transition counts as an unexpected exit
+            let was_running = self.prev_status.get(&c.name).is_some_and(|s| s.is_running());
+            let crashed = match c.status {
+                ContainerStatus::Exited => c.exit_code.is_some_and(|code| code != 0),
+                ContainerStatus::Dead => true,
+                _ => false,
+            };
+            let exited = self.config.on_exit
+                && crashed
+                && (was_running || self.active.contains(&(c.name.clone(), AlertKind::Exited)));
+            let exit_message = Some(match c.exit_code {
+                Some(code) => format!("exited unexpectedly (code {})", code),
+                None => format!("is {}", c.status.as_str()),
+            });
+            self.update(c, AlertKind::Exited, exited, exit_message, &mut fired);
+
+            let unhealthy = self.config.on_unhealthy && c.health.as_deref() == Some("unhealthy");
+            self.update(c, AlertKind::Unhealthy, unhealthy, Some("healthcheck is unhealthy".to_string()), &mut fired);
+
+            let window = Duration::from_secs(self.config.crash_loop_window_secs);
+            let recent = self.restarts.entry(c.name.clone()).or_default().observe(c.restart_count, Instant::now(), window);
+            let looping = self.config.crash_loop_restarts > 0 && recent >= self.config.crash_loop_restarts;
+            let loop_message = Some(format!("is crash looping ({} restarts in {}s)", recent, window.as_secs()));
+            self.update(c, AlertKind::CrashLoop, looping, loop_message, &mut fired);
+
+            self.prev_status.insert(c.name.clone(), c.status.clone());
+        }
+
+        // Forget containers that no longer exist
+        let names: HashSet<&str> = containers.iter().map(|c| c.name.as_str()).collect();
+        self.active.retain(|(name, _)| names.contains(name.as_str()));
+        self.high_cpu_since.retain(|name, _| names.contains(name.as_str()));
+        self.prev_status.retain(|name, _| names.contains(name.as_str()));
+        self.restarts.retain(|name, _| names.contains(name.as_str()));
+
+        for alert in &fired {
+            self.recent.push_front(alert.clone());
+        }
+        self.recent.truncate(MAX_RECENT);
+
+        fired
+    }
+
+    /// Names of containers with an active alert
+    pub fn alerting_names(&self) -> HashSet<String> {
+        self.active.iter().map(|(n, _)| n.clone()).collect()
+    }
+
+    /// Names of containers currently flagged as crash looping
+    pub fn crash_looping(&self) -> HashSet<String> {
+        self.active
+            .iter()
+            .filter(|(_, kind)| *kind == AlertKind::CrashLoop)
+            .map(|(n, _)| n.clone())
+            .collect()
+    }
+
+    /// Track a condition's state, recording an alert when it starts firing
+    fn update(&mut self, c: &ContainerInfo, kind: AlertKind, firing: bool, message: Option<String>, fired: &mut Vec<Alert>) {
+        let key = (c.name.clone(), kind);
+        if !firing {
+            self.active.remove(&key);
+            return;
+        }
+        if self.active.insert(key) {
+            fired.push(Alert {
+                container: c.name.clone(),
+                kind,
+                message: message.unwrap_or_default(),
+                at: chrono::Local::now(),
+            });
+        }
+    }
+}
+
+/// Policy restarts of one container, from its inspect RestartCount
+#[derive(Debug, Default)]
+struct RestartHistory {
+    /// RestartCount at the previous evaluation
+    last_count: Option<i64>,
+    /// When each increase was seen, oldest first
+    seen: VecDeque<Instant>,
+}
+
+impl RestartHistory {
+    /// Note the current RestartCount; returns the restarts seen within the window
+    fn observe(&mut self, count: i64, now: Instant, window: Duration) -> usize {
+        // The first sample is a baseline, and a recreated container starts over
+        if let Some(last) = self.last_count {
+            for _ in 0..(count - last).clamp(0, 10) {
+                self.seen.push_back(now);
+            }
+        }
+        self.last_count = Some(count);
+        while self.seen.front().is_some_and(|&at| now.duration_since(at) > window) {
+            self.seen.pop_front();
+        }
+        self.seen.len()
+    }
+}
+
+/// Ring the terminal bell and post a desktop notification (OSC 9)
+pub fn notify(alert: &Alert) {
+    let mut out = stdout();
+    // Strip control characters so the message can't end the escape sequence early
+    let message: String = format!("{} {}", alert.container, alert.message)
+        .chars()
+        .filter(|c| !c.is_control())
+        .collect();
+    let _ = write!(out, "\x07\x1b]9;backplane: {}\x07", message);
+    let _ = out.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ContainerStats;
+
+    fn running(name: &str, cpu_percent: f64, memory_percent: f64) -> ContainerInfo {
+        let mut c = ContainerInfo::new(name.to_string());
+        c.status = ContainerStatus::Running;
+        c.stats = Some(ContainerStats { cpu_percent, memory_percent, ..Default::default() });
+        c
+    }
+
+    fn kinds(alerts: &[Alert]) -> Vec<AlertKind> {
+        alerts.iter().map(|a| a.kind).collect()
+    }
+
+    #[test]
+    fn test_evaluate_fires_once_until_cleared() {
+        let config = AlertConfig { cpu_duration_secs: 0, bell: false, ..Default::default() };
+        let mut alerts = AlertManager::new(config);
+
+        let fired = alerts.evaluate(&[running("web", 95.0, 97.0), running("db", 10.0, 20.0)]);
+        assert_eq!(kinds(&fired), [AlertKind::HighCpu, AlertKind::HighMemory]);
+        assert_eq!(fired[0].container, "web");
+        assert_eq!(alerts.alerting_names(), HashSet::from(["web".to_string()]));
+
+        // Still high: already firing, so nothing new
+        assert!(alerts.evaluate(&[running("web", 96.0, 98.0)]).is_empty());
+
+        // Memory recovers and clears; going high again fires again
+        assert!(alerts.evaluate(&[running("web", 96.0, 50.0)]).is_empty());
+        assert_eq!(kinds(&alerts.evaluate(&[running("web", 96.0, 99.0)])), [AlertKind::HighMemory]);
+        assert!(alerts.evaluate(&[running("web", 5.0, 5.0)]).is_empty());
+        assert!(alerts.alerting_names().is_empty());
+        assert_eq!(alerts.recent.len(), 3);
+    }
+
+    #[test]
+    fn test_evaluate_exit_only_after_running() {
+        let mut alerts = AlertManager::new(AlertConfig::default());
+        let mut web = ContainerInfo::new("web".to_string());
+        web.status = ContainerStatus::Exited;
+        web.exit_code = Some(1);
+        // Already exited when first seen: not an alert
+        assert!(alerts.evaluate(std::slice::from_ref(&web)).is_empty());
+
+        assert!(alerts.evaluate(&[running("web", 0.0, 0.0)]).is_empty());
+        let fired = alerts.evaluate(std::slice::from_ref(&web));
+        assert_eq!(kinds(&fired), [AlertKind::Exited]);
+        assert_eq!(fired[0].message, "exited unexpectedly (code 1)");
+        assert!(alerts.evaluate(std::slice::from_ref(&web)).is_empty());
+    }
+
+    #[test]
+    fn restart_history_counts_within_window() {
+        let window = Duration::from_secs(60);
+        let start = Instant::now();
+        let mut history = RestartHistory::default();
+
+        // First sample is only a baseline
+        assert_eq!(history.observe(5, start, window), 0);
+        assert_eq!(history.observe(7, start + Duration::from_secs(10), window), 2);
+        assert_eq!(history.observe(8, start + Duration::from_secs(20), window), 3);
+        // The first two fall out of the window
+        assert_eq!(history.observe(8, start + Duration::from_secs(75), window), 1);
+        // A recreated container starts again from zero
+        assert_eq!(history.observe(0, start + Duration::from_secs(76), window), 1);
+    }
+}
@@ -1,27 +1,61 @@
 use anyhow::Result;
-use sysinfo::{Disks, System};
-use std::collections::HashMap;
+use sysinfo::{Components, Disks, System};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use crate::action::Action;
+use crate::alerts::AlertManager;
+use crate::audit::{self, AuditLog};
 use crate::components::{
-    ConfirmModal, ContainerList, CopyFilesModal, CreateContainerForm, CreateModal,
-    CreateMode, ExecModal, FilterBar, Header, HelpModal, InfoModal, LogsView,
-    ProcessesModal, RenameModal, StatsHistory, StatusBar,
+    ActionMenu, AlertsPanel, CommandPalette, ContainerDetail, AuditPanel, CompareView, DaemonPanel, HostView, PodsView, ServicesView, ConfirmModal, RecyclePanel, StartupPanel, StatsFooter, YankMenu, ContainerList, CopyFilesModal, ForwardModal, ForwardsPanel, SchedulePanel, CreateContainerForm, CreateModal,
+    ExecModal, ExecPane, FilterBar, Header, HelpModal, HistoryChart, InfoModal, EventsView, LogsStatsStrip, LogsView,
+    CheckpointModal, CleanupModal, ComposeModal, ConfigErrorsModal, GpuModal, GroupModal, LabelModal, DiffModal, ImageHistoryModal, ProcessesModal, PullModal, LoginModal, RenameModal, StatsHistory, StatusBar, TabBar, Toast, VulnModal,
 };
-use crate::components::confirm_modal::ConfirmAction;
-use crate::docker::client::DockerClient;
-use crate::docker::gpu::get_container_gpu_usage;
-use crate::docker::logs::get_container_logs;
-use crate::docker::stats::get_container_stats;
+use crate::components::confirm_modal::{BatchOp, ConfirmAction};
+use crate::components::cleanup_modal::CleanupPhase;
+use crate::components::compose_modal::ComposeFile;
+use crate::components::container_list::StatusCounts;
+use crate::components::create_modal::parse_env_file;
+use crate::components::yank_menu::YankTarget;
+use crate::config::{AppConfig, CleanupConfig, ConfigWatch, ConfirmConfig, LogsConfig, RegistryAuth, StopConfig};
+use crate::forwards::PortForwards;
+use crate::groups::ContainerGroups;
+use crate::keepalive::KeepAlive;
+use crate::image_sizes::ImageSizeCache;
+use crate::pins::Pins;
+use crate::recording;
+use crate::recycle::RecycleBin;
+use crate::schedule::{ScheduleTarget, ScheduledAction, Scheduler};
+use crate::template::ContainerTemplate;
+use crate::trivy::{self, ScanReport};
+use crate::docker::client::{ContainerSpec, DockerClient};
+use crate::docker::events::EventLog;
+use crate::docker::exec_session::ExecSession;
+use crate::docker::startup::{Startup, StepState};
+use crate::docker::gpu::{GpuDevice, GpuMonitor};
+use crate::docker::pids::PidMapper;
+use crate::docker::registry::{self, RepoRef};
+use crate::docker::logs::{get_container_logs, get_project_logs, LogBuffer, LogFilter};
+use crate::docker::stats::get_containers_stats;
 use crate::effects::EffectManager;
-use crate::models::{ContainerInfo, SystemStats};
+use crate::history::{HistoryStore, TimeRange};
+use crate::keymap::{Command, KeyContext};
+use crate::models::{CleanupKind, ContainerInfo, ContainerStats, ContainerStatus, ContainerTotals, DaemonInfo, GpuSensors, PodInfo, ProjectSort, RemovalPreview, RunState, RuntimeInfo, ServiceInfo, SystemStats, HostProcess, Transition};
+use crate::models::label::{label_changes, parse_label_edits};
+use crate::ui::ThemeName;
 
 /// Current view mode
 #[derive(Debug, Clone, PartialEq)]
 pub enum ViewMode {
     List,
     Logs,
+    Charts,
+    Compare,
+    Services,
+    Pods,
+    Host,
     Create,
     Filter,
     Exec,
@@ -29,6 +63,20 @@ pub enum ViewMode {
     Rename,
     Processes,
     CopyFiles,
+    ImageHistory,
+    Cleanup,
+    Pull,
+    Login,
+    Diff,
+    Checkpoints,
+    Groups,
+    Labels,
+    Forward,
+    Vulns,
+    Gpus,
+    Events,
+    Compose,
+    ConfigErrors,
 }
 
 /// Container list view modes (horizontal scroll)
@@ -72,12 +120,161 @@ impl StatusFilter {
     }
 }
 
+/// How often config.toml's watcher events are collected, so the several
+/// events of one save reload it once
+const CONFIG_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Longest a stats refresh waits for slow containers
+const STATS_BUDGET: Duration = Duration::from_secs(2);
+
+/// Wait for input between frames while animations run (~30 FPS)
+const ANIMATION_TICK: Duration = Duration::from_millis(32);
+
+/// Wait for input between ticks with reduced motion: enough to pick up refreshes
+const REDUCED_MOTION_TICK: Duration = Duration::from_millis(250);
+
+/// Image sizes looked up in the background: (id and size, IDs that failed)
+type ImageSizeLookup = (Vec<(String, u64)>, Vec<String>);
+
+/// Outcome of a scheduled run per container
+type ScheduleOutcomes = Vec<(String, Result<()>)>;
+
+/// A container listing with what it takes to show it: fresh stats by name
+/// and run states inspected for containers whose state changed
+struct ContainerFetch {
+    containers: Vec<ContainerInfo>,
+    stats: HashMap<String, ContainerStats>,
+    run_states: Vec<(String, RunState)>,
+}
+
+/// List the containers and fetch their stats, inspecting the ones whose
+/// state is new or changed since `known`
+async fn fetch_containers(docker: DockerClient, known: HashMap<String, RunState>) -> Result<ContainerFetch> {
+    let containers = docker.list_containers().await?;
+    let stats = fetch_stats(&docker, &containers).await;
+
+    let now = chrono::Utc::now().timestamp();
+    let mut run_states = Vec::new();
+    for container in containers.iter().filter(|c| c.status != ContainerStatus::NotDeployed) {
+        // Inspect lazily, once per state change: listings only carry the
+        // human-readable status, not start/finish times, the OOM flag or
+        // restart count
+        let stale = known.get(&container.id).is_none_or(|s| s.outdated_by(container, now));
+        if stale {
+            if let Ok(state) = docker.inspect_state(&container.id).await {
+                run_states.push((container.id.clone(), state));
+            }
+        }
+    }
+    Ok(ContainerFetch { containers, stats, run_states })
+}
+
+/// Stats of the active containers by name. Paused ones count: they still hold GPU memory
+async fn fetch_stats(docker: &DockerClient, containers: &[ContainerInfo]) -> HashMap<String, ContainerStats> {
+    let active: Vec<String> = containers.iter().filter(|c| c.status.is_active()).map(|c| c.name.clone()).collect();
+    get_containers_stats(docker.inner(), &active, STATS_BUDGET).await
+}
+
+/// Startup options (from the command line)
+#[derive(Debug, Clone, Default)]
+pub struct AppOptions {
+    /// Docker host URL or context name (None = local socket)
+    pub host: Option<String>,
+    /// Container list refresh interval override
+    pub refresh_interval: Option<Duration>,
+    /// --theme, which wins over the config file
+    pub theme: Option<ThemeName>,
+    /// Initial name filter
+    pub filter: Option<String>,
+    /// Persist long-range stats history to this file
+    pub history_file: Option<PathBuf>,
+    /// Disable mutating actions (also settable in config.toml)
+    pub read_only: bool,
+    /// ASCII glyphs and the high-contrast palette (also settable in config.toml)
+    pub accessible: bool,
+    /// Open the create form filled from this template
+    pub template: Option<ContainerTemplate>,
+    /// Container to start with selected
+    pub select: Option<String>,
+    /// Container to start in the logs view of
+    pub logs: Option<String>,
+    /// Where to serve the Prometheus metrics endpoint
+    #[cfg(feature = "metrics")]
+    pub metrics_addr: Option<std::net::SocketAddr>,
+}
+
+/// Log lines fetched for the details pane
+const DETAIL_LOG_TAIL: usize = 100;
+
+/// Most tabs open at once (Alt+1..9 reaches them all)
+const MAX_TABS: usize = 9;
+
+/// A tab's own view, selection and scroll. The active tab's live in the
+/// app's fields; the others are parked here
+struct TabState {
+    view_mode: ViewMode,
+    list_state: ratatui::widgets::TableState,
+    logs: LogBuffer,
+    logs_view: LogsView,
+    logs_container: String,
+    logs_pod: Option<(String, String)>,
+    logs_project: Option<String>,
+    logs_tail: usize,
+    chart_container: String,
+    services_view: ServicesView,
+    pods_view: PodsView,
+    host_view: HostView,
+    events_view: EventsView,
+}
+
+impl TabState {
+    /// A container list at the given selection
+    fn new(list_state: ratatui::widgets::TableState, logs_config: &LogsConfig) -> Self {
+        Self {
+            view_mode: ViewMode::List,
+            list_state,
+            logs: LogBuffer::new(logs_config.max_lines, logs_config.max_bytes()),
+            logs_view: LogsView::new(),
+            logs_container: String::new(),
+            logs_pod: None,
+            logs_project: None,
+            logs_tail: logs_config.tail,
+            chart_container: String::new(),
+            services_view: ServicesView::new(),
+            pods_view: PodsView::new(),
+            host_view: HostView::new(),
+            events_view: EventsView::new(),
+        }
+    }
+}
+
+/// Name of a tab in the tab bar, after its view
+fn tab_label(view_mode: &ViewMode, logs_container: &str, chart_container: &str) -> String {
+    match view_mode {
+        ViewMode::Logs => format!("Logs: {}", logs_container),
+        ViewMode::Charts => format!("Charts: {}", chart_container),
+        ViewMode::Compare => "Compare".to_string(),
+        ViewMode::Services => "Services".to_string(),
+        ViewMode::Pods => "Pods".to_string(),
+        ViewMode::Host => "Host".to_string(),
+        ViewMode::Events => "Events".to_string(),
+        // Modals open over the list
+        _ => "Containers".to_string(),
+    }
+}
+
 /// Active modal state
 #[derive(Debug, Clone)]
 pub enum ModalState {
     None,
     Help,
     Confirm(ConfirmAction),
+    /// Copy-to-clipboard menu
+    Yank,
+    /// Per-container actions popup
+    Actions,
+    /// Command palette (Ctrl+P)
+    Palette,
 }
 
 /// Main application state
@@ -89,19 +286,54 @@ pub struct App {
     pub view_mode: ViewMode,
     pub list_view_mode: ListViewMode,
     pub modal: ModalState,
+    /// What the confirm prompt's compose down or prune would remove
+    pub confirm_preview: Option<RemovalPreview>,
     pub should_quit: bool,
     pub loading: bool,
+    /// Something changed since the last frame; the main loop redraws
+    pub needs_redraw: bool,
+    pub theme: ThemeName,
+    /// Render plain ASCII without animations, always high-contrast
+    pub accessible: bool,
 
     // Status filter (quick toggle with 'f')
     pub status_filter: StatusFilter,
+    /// Order of compose projects in Groups mode ('$')
+    pub project_sort: ProjectSort,
 
     // Container data (auto-discovered)
     pub containers: Vec<ContainerInfo>,
     pub filtered_indices: Vec<usize>,
 
+    // Swarm services (when connected to a manager)
+    pub services: Vec<ServiceInfo>,
+    pub services_view: ServicesView,
+
+    // Kubernetes pods tab
+    pub pods: Vec<PodInfo>,
+    pub pods_view: PodsView,
+    pub namespace: String,
+    namespaces: Vec<String>,
+
+    // Host processes view
+    pub host_processes: Vec<HostProcess>,
+    pid_mapper: PidMapper,
+    pub host_view: HostView,
+
+    // Docker events view
+    events: Arc<Mutex<EventLog>>,
+    pub events_view: EventsView,
+
     // Logs data
-    pub logs: Vec<String>,
+    pub logs: LogBuffer,
     pub logs_container: String,
+    /// (namespace, pod) when the logs view shows a Kubernetes pod
+    logs_pod: Option<(String, String)>,
+    /// Compose project when the logs view merges all of its containers
+    logs_project: Option<String>,
+    logs_config: LogsConfig,
+    /// Lines of history fetched for the open logs view (grows with `m`)
+    logs_tail: usize,
 
     // Create container form
     pub create_form: CreateContainerForm,
@@ -111,6 +343,23 @@ pub struct App {
 
     // Exec modal
     pub exec_modal: Option<ExecModal>,
+    /// Embedded shell beside the container list
+    pub exec_pane: Option<ExecSession>,
+    /// Keys go to the embedded shell instead of the list
+    pub exec_focused: bool,
+    /// Inner size of the pane at the last render (rows, cols)
+    exec_pane_size: (u16, u16),
+    /// Where exec sessions are recorded, when recording is enabled
+    pub recording_dir: Option<PathBuf>,
+    /// Where project.yaml skeletons are written
+    pub projects_dir: Option<PathBuf>,
+    /// Browse-only guardrail: mutating commands are refused
+    pub read_only: bool,
+    /// Count typed before a navigation key (the 5 of 5j)
+    pub pending_count: Option<usize>,
+    /// A count begun with a digit the view also binds (1-4 in the list) and
+    /// when: the digit gets its binding unless a move or digit follows soon
+    pub count_key: Option<(crossterm::event::KeyEvent, Instant)>,
 
     // Rename modal
     pub rename_modal: Option<RenameModal>,
@@ -118,12 +367,124 @@ pub struct App {
     // Processes modal
     pub processes_modal: Option<ProcessesModal>,
 
+    // Image history (layers) modal
+    pub image_history_modal: Option<ImageHistoryModal>,
+
+    // Vulnerability report (trivy) modal
+    pub vuln_modal: Option<VulnModal>,
+
+    // GPU view modal
+    pub gpu_modal: Option<GpuModal>,
+
+    // Filesystem diff modal
+    pub diff_modal: Option<DiffModal>,
+    pub compose_modal: Option<ComposeModal>,
+    pub config_errors: Option<ConfigErrorsModal>,
+
+    // Checkpoint manager
+    pub checkpoint_modal: Option<CheckpointModal>,
+
+    // User-defined groups, the active group filter and the assignment modal
+    pub groups: ContainerGroups,
+    pub group_filter: Option<String>,
+    pub group_modal: Option<GroupModal>,
+    pub label_modal: Option<LabelModal>,
+
+    /// Port forwards and their dialog; forwards end with the TUI
+    pub forwards: PortForwards,
+    pub forward_modal: Option<ForwardModal>,
+
+    /// Timezone, OS and command of the container in the info view
+    pub runtime_info: Option<RuntimeInfo>,
+
+    /// Containers kept at the top of the list
+    pub pins: Pins,
+
+    /// Operations popup for the selected container
+    pub action_menu: ActionMenu,
+    pub palette: Option<CommandPalette>,
+
+    // Cleanup wizard
+    pub cleanup_modal: Option<CleanupModal>,
+    cleanup_config: CleanupConfig,
+
+    // Pull dialog and background jobs (pulls, image updates) still running
+    pub pull_modal: Option<PullModal>,
+    registries: HashMap<String, RegistryAuth>,
+    /// Keyring lookups so far (None when nothing is stored), so each host is asked once
+    keyring_auth: HashMap<String, Option<RegistryAuth>>,
+    jobs: Vec<BackgroundJob>,
+
+    /// Images with a newer digest in their registry
+    image_updates: HashSet<String>,
+    /// Registry check in progress: (images with updates, images that couldn't be checked)
+    update_check: Option<tokio::task::JoinHandle<(Vec<String>, usize)>>,
+    last_update_check: Instant,
+    /// Periodic check (None = on demand only)
+    update_check_interval: Option<Duration>,
+
+    /// Image sizes for the Details list, fetched lazily and cached on disk
+    image_sizes: ImageSizeCache,
+    /// Lookup in progress: (fetched sizes, image IDs that couldn't be inspected)
+    image_size_fetch: Option<tokio::task::JoinHandle<ImageSizeLookup>>,
+    /// Container list refresh running in the background
+    container_fetch: Option<tokio::task::JoinHandle<Result<ContainerFetch>>>,
+    /// Containers waited on for their exit code, by name
+    exit_watches: HashMap<String, tokio::task::JoinHandle<Result<i64>>>,
+    /// Not retried until the next start
+    image_size_failed: HashSet<String>,
+    /// Lifecycle operations in flight, by container name
+    transitions: HashMap<String, PendingOp>,
+    /// trivy reports by image ID, kept until the TUI exits
+    scans: HashMap<String, ScanReport>,
+    /// trivy scans running in the background, by image ID
+    scan_jobs: HashMap<String, tokio::task::JoinHandle<Result<ScanReport>>>,
+
+    /// Dependency-ordered start in progress
+    startup: Option<Arc<Mutex<Startup>>>,
+    pub login_modal: Option<LoginModal>,
+
     // Copy files modal
     pub copy_modal: Option<CopyFilesModal>,
 
     // Stats history for sparklines
     pub stats_history: StatsHistory,
 
+    // Long-range stats history for charts
+    pub history: HistoryStore,
+    pub chart_container: String,
+    pub chart_range: TimeRange,
+
+    // Help modal (scroll/scope state)
+    pub help: HelpModal,
+
+    // Threshold alerts
+    pub alerts: AlertManager,
+    pub show_alerts: bool,
+    /// Recently deleted containers that can be recreated
+    pub recycle: RecycleBin,
+    pub show_recycle: bool,
+    /// Mutating actions, appended to audit.log in the config dir
+    pub audit_log: AuditLog,
+    pub show_audit: bool,
+    /// `[[schedule]]` rules and the runs in flight, by description
+    pub scheduler: Scheduler,
+    pub show_schedule: bool,
+    /// Rule description and its action, for the audit log
+    schedule_jobs: Vec<(String, ScheduledAction, tokio::task::JoinHandle<ScheduleOutcomes>)>,
+    /// Engine details and journal errors, fetched when the panel is opened
+    pub daemon_info: Option<DaemonInfo>,
+    pub show_daemon: bool,
+    /// Containers restarted by the TUI when they exit
+    pub keep_alive: KeepAlive,
+    /// Which actions prompt before running
+    pub confirm: ConfirmConfig,
+    /// Stop and restart grace periods
+    stop_config: StopConfig,
+
+    // Transient notification
+    pub toast: Option<Toast>,
+
     // System stats
     pub system_stats: SystemStats,
 
@@ -131,89 +492,315 @@ pub struct App {
     pub container_list: ContainerList,
     pub logs_view: LogsView,
 
+    /// Open tabs, parked; None at the active one, whose state is live above
+    tabs: Vec<Option<TabState>>,
+    active_tab: usize,
+
     // System info
     sys: System,
     disks: Disks,
+    components: Components,
+    /// Second header line with per-core CPU, load and temperature
+    pub expanded_header: bool,
+    /// Details pane beside the list, following the selection
+    pub split_view: bool,
+    /// Log tail of the container in the details pane
+    detail_logs: Option<(String, Vec<String>)>,
 
     // Refresh timing
     last_container_refresh: Instant,
     last_stats_refresh: Instant,
     last_vram_refresh: Instant,
     last_logs_refresh: Instant,
+    last_detail_logs_refresh: Instant,
     container_refresh_interval: Duration,
     stats_refresh_interval: Duration,
     vram_refresh_interval: Duration,
     logs_refresh_interval: Duration,
+    /// --refresh-ms, which wins over the config file
+    cli_refresh_interval: Option<Duration>,
+    /// --theme, which wins over the config file
+    cli_theme: Option<ThemeName>,
+    /// Multiplier on every interval, changed at runtime with +/-
+    refresh_scale: f64,
+    /// config.toml, re-read when it changes
+    config_watch: Option<ConfigWatch>,
+    last_config_check: Instant,
+    /// Last key press, for the idle backoff
+    last_input: Instant,
+    /// No input for this long slows polling down (None = never)
+    idle_after: Option<Duration>,
+    idle_slowdown: f64,
+    /// NVIDIA, AMD or Intel GPU polling
+    gpu: GpuMonitor,
     cached_vram: Option<f32>,
+    cached_gpu_util: Option<f32>,
+    cached_gpu_sensors: GpuSensors,
     /// Cached per-container GPU usage (container_id -> VRAM MB)
     cached_container_gpu: std::collections::HashMap<String, f64>,
+    /// Cards from the last GPU poll, for the GPU view
+    gpu_devices: Vec<GpuDevice>,
+    /// container_id -> card index -> VRAM MB
+    container_gpus: HashMap<String, BTreeMap<u32, f64>>,
+    /// Last network counters per container for rate calculation
+    io_counters: HashMap<String, IoCounter>,
+    /// Cached inspect state per container (container_id -> state)
+    run_states: HashMap<String, RunState>,
 
     // Visual effects
     pub effects: EffectManager,
+
+    // Prometheus metrics publisher
+    #[cfg(feature = "metrics")]
+    metrics: Option<crate::metrics::MetricsHandle>,
 }
 
 impl App {
-    pub async fn new() -> Result<Self> {
-        let docker = DockerClient::connect()?;
+    pub async fn new(options: AppOptions) -> Result<Self> {
+        let docker = DockerClient::connect_with_host(options.host.as_deref())?;
         let mut sys = System::new_all();
         sys.refresh_all();
         let disks = Disks::new_with_refreshed_list();
 
+        let history = match options.history_file {
+            Some(path) => HistoryStore::with_file(path)?,
+            None => HistoryStore::new(),
+        };
+
+        // A config that doesn't load is reported in the errors view
+        let (config, config_issues) = AppConfig::load_checked();
+        let events = EventLog::spawn(docker.clone());
+
+        #[cfg(feature = "metrics")]
+        let metrics = match options.metrics_addr {
+            Some(addr) => Some(crate::metrics::serve(addr).await?),
+            None => None,
+        };
+
+        let mut container_list = ContainerList::new();
+        container_list.columns = config.columns.clone();
+
         let mut app = Self {
             docker,
             view_mode: ViewMode::List,
             list_view_mode: ListViewMode::Stats,
             modal: ModalState::None,
+            confirm_preview: None,
             should_quit: false,
             loading: false,
+            needs_redraw: true,
+            theme: match options.accessible || config.accessible {
+                true => ThemeName::HighContrast,
+                false => options.theme.or(config.theme).unwrap_or_default(),
+            },
+            accessible: options.accessible || config.accessible,
             status_filter: StatusFilter::All,
+            project_sort: ProjectSort::default(),
             containers: Vec::new(),
             filtered_indices: Vec::new(),
-            logs: Vec::new(),
+            services: Vec::new(),
+            services_view: ServicesView::new(),
+            pods: Vec::new(),
+            pods_view: PodsView::new(),
+            namespace: String::new(),
+            namespaces: Vec::new(),
+            host_processes: Vec::new(),
+            events,
+            events_view: EventsView::new(),
+            pid_mapper: PidMapper::default(),
+            host_view: HostView::new(),
+            logs: LogBuffer::new(config.logs.max_lines, config.logs.max_bytes()),
             logs_container: String::new(),
+            logs_pod: None,
+            logs_project: None,
+            logs_config: config.logs.clone(),
+            logs_tail: config.logs.tail,
             create_form: CreateContainerForm::new(),
             filter: FilterBar::new(),
             exec_modal: None,
+            exec_pane: None,
+            exec_focused: false,
+            exec_pane_size: (24, 80),
+            recording_dir: config.recording.target_dir(),
+            projects_dir: config.projects_dir.clone(),
+            read_only: options.read_only || config.read_only,
+            pending_count: None,
+            count_key: None,
             rename_modal: None,
             processes_modal: None,
+            image_history_modal: None,
+            vuln_modal: None,
+            gpu_modal: None,
+            diff_modal: None,
+            compose_modal: None,
+            config_errors: None,
+            checkpoint_modal: None,
+            groups: ContainerGroups::load_default()?,
+            group_filter: None,
+            group_modal: None,
+            label_modal: None,
+            forwards: PortForwards::default(),
+            forward_modal: None,
+            runtime_info: None,
+            pins: Pins::load_default()?,
+            action_menu: ActionMenu::default(),
+            palette: None,
+            cleanup_modal: None,
+            cleanup_config: config.cleanup,
+            pull_modal: None,
+            registries: config.registries,
+            keyring_auth: HashMap::new(),
+            jobs: Vec::new(),
+            image_updates: HashSet::new(),
+            update_check: None,
+            last_update_check: Instant::now(),
+            update_check_interval: (config.refresh.image_updates_hours > 0)
+                .then(|| Duration::from_secs(config.refresh.image_updates_hours * 3600)),
+            // A broken cache only costs some lookups
+            image_sizes: ImageSizeCache::load_default().unwrap_or_default(),
+            image_size_fetch: None,
+            container_fetch: None,
+            exit_watches: HashMap::new(),
+            image_size_failed: HashSet::new(),
+            transitions: HashMap::new(),
+            scans: HashMap::new(),
+            scan_jobs: HashMap::new(),
+            startup: None,
+            login_modal: None,
             copy_modal: None,
             stats_history: StatsHistory::new(30), // Keep 30 samples
+            history,
+            chart_container: String::new(),
+            chart_range: TimeRange::default(),
+            help: HelpModal::new(),
+            alerts: AlertManager::new(config.alerts),
+            show_alerts: false,
+            recycle: RecycleBin::new(),
+            show_recycle: false,
+            audit_log: AuditLog::open(AuditLog::default_path()),
+            show_audit: false,
+            scheduler: Scheduler::new(&config.schedule, chrono::Local::now())?,
+            show_schedule: false,
+            schedule_jobs: Vec::new(),
+            daemon_info: None,
+            show_daemon: false,
+            keep_alive: KeepAlive::new(),
+            confirm: config.confirm,
+            stop_config: config.stop,
+            toast: None,
             system_stats: SystemStats::default(),
-            container_list: ContainerList::new(),
+            container_list,
             logs_view: LogsView::new(),
+            tabs: vec![None],
+            active_tab: 0,
             sys,
             disks,
+            components: Components::new_with_refreshed_list(),
+            expanded_header: false,
+            split_view: false,
+            detail_logs: None,
             last_container_refresh: Instant::now() - Duration::from_secs(10),
             last_stats_refresh: Instant::now() - Duration::from_secs(10),
             last_vram_refresh: Instant::now() - Duration::from_secs(10),
             last_logs_refresh: Instant::now() - Duration::from_secs(10),
-            container_refresh_interval: Duration::from_secs(3),
-            stats_refresh_interval: Duration::from_secs(2),
-            vram_refresh_interval: Duration::from_secs(5),
-            logs_refresh_interval: Duration::from_secs(2),
+            last_detail_logs_refresh: Instant::now(),
+            container_refresh_interval: options
+                .refresh_interval
+                .unwrap_or(Duration::from_millis(config.refresh.containers_ms)),
+            stats_refresh_interval: Duration::from_millis(config.refresh.stats_ms),
+            vram_refresh_interval: Duration::from_millis(config.refresh.vram_ms),
+            logs_refresh_interval: Duration::from_millis(config.refresh.logs_ms),
+            cli_refresh_interval: options.refresh_interval,
+            cli_theme: options.theme,
+            refresh_scale: 1.0,
+            config_watch: crate::config::config_path().map(ConfigWatch::new),
+            last_config_check: Instant::now(),
+            last_input: Instant::now(),
+            idle_after: (config.refresh.idle_minutes > 0).then(|| Duration::from_secs(config.refresh.idle_minutes * 60)),
+            idle_slowdown: config.refresh.idle_slowdown.max(1.0),
+            gpu: GpuMonitor::new(),
             cached_vram: None,
+            cached_gpu_util: None,
+            cached_gpu_sensors: GpuSensors::default(),
             cached_container_gpu: HashMap::new(),
-            effects: EffectManager::new(),
+            gpu_devices: Vec::new(),
+            container_gpus: HashMap::new(),
+            io_counters: HashMap::new(),
+            run_states: HashMap::new(),
+            effects: EffectManager::new(!(config.reduced_motion || options.accessible || config.accessible)),
+            #[cfg(feature = "metrics")]
+            metrics,
         };
 
+        if let Some(template) = options.template {
+            if app.read_only {
+                app.toast = Some(Toast::error("Read-only: not opening the create form for the template"));
+            } else {
+                app.open_create_form().await?;
+                template.apply(&mut app.create_form);
+            }
+        }
+
+        if !config_issues.is_empty() {
+            app.config_errors = Some(ConfigErrorsModal::new(config_issues));
+            app.view_mode = ViewMode::ConfigErrors;
+        }
+
+        // Pre-fill the name filter if requested
+        if let Some(query) = options.filter {
+            app.filter.activate();
+            app.filter.query = query;
+        }
+
         // Refresh system stats FIRST to populate GPU cache
         app.refresh_system_stats();
         app.refresh_containers().await?;
         app.update_filtered_indices();
+        app.container_list.image_sizes = app.image_sizes.sizes().clone();
+
+        // Deep link from the command line, by name or ID prefix
+        if let Some(target) = options.logs.as_deref().or(options.select.as_deref()) {
+            match app.containers.iter().find(|c| c.name == target || c.id.starts_with(target)) {
+                Some(container) => {
+                    let name = container.name.clone();
+                    app.container_list.reselect(Some((String::new(), name.clone())), app.nav_item_count());
+                    if app.selected_container_name().as_deref() != Some(&name) {
+                        app.toast = Some(Toast::error(format!("{} is hidden by the filters", name)));
+                    } else if options.logs.is_some() && app.view_mode == ViewMode::List {
+                        if let Err(e) = app.load_logs(&name).await {
+                            app.toast = Some(Toast::error(format!("{:#}", e)));
+                        }
+                    }
+                }
+                None => app.toast = Some(Toast::error(format!("No container named {}", target))),
+            }
+        }
 
         Ok(app)
     }
 
-    /// Update filtered indices based on current filter and status filter
+    /// Update filtered indices based on current filter and status filter.
+    /// The selection follows its container to its new row
     pub fn update_filtered_indices(&mut self) {
+        let selected = self.container_list.selected_key();
         self.filtered_indices = self.containers
             .iter()
             .enumerate()
             .filter(|(_, c)| {
+                // Pinned containers stay visible whatever the filters
+                if self.pins.contains(&c.name) {
+                    return true;
+                }
                 // Text filter
-                if !self.filter.matches(&c.name) {
+                if !self.filter.matches(c) {
                     return false;
                 }
+                // Group filter
+                if let Some(ref group) = self.group_filter {
+                    if !self.groups.contains(group, &c.name) {
+                        return false;
+                    }
+                }
                 // Status filter
                 match self.status_filter {
                     StatusFilter::All | StatusFilter::Groups => true,
@@ -224,18 +811,37 @@ impl App {
             .map(|(i, _)| i)
             .collect();
 
-        // Adjust selection if needed
-        if !self.filtered_indices.is_empty() {
-            if let Some(selected) = self.container_list.selected() {
-                if selected >= self.filtered_indices.len() {
-                    self.container_list.state.select(Some(self.filtered_indices.len() - 1));
-                }
-            } else {
-                self.container_list.state.select(Some(0));
-            }
+        // Rank by fuzzy score (stable, so ties keep list order); groups keep
+        // their project order so headers stay contiguous
+        if !self.filter.is_empty() && self.status_filter != StatusFilter::Groups {
+            let containers = &self.containers;
+            let filter = &self.filter;
+            self.filtered_indices
+                .sort_by_key(|&i| std::cmp::Reverse(filter.score(&containers[i]).unwrap_or(0)));
+        }
+        // Pinned ones go first (stable, keeping their relative order); groups
+        // keep their project order so headers stay contiguous
+        if self.status_filter != StatusFilter::Groups {
+            let containers = &self.containers;
+            let pins = &self.pins;
+            self.filtered_indices.sort_by_key(|&i| !pins.contains(&containers[i].name));
         } else {
-            self.container_list.state.select(None);
+            self.project_sort.order(&mut self.filtered_indices, &self.containers);
         }
+        self.container_list.project_sort = self.project_sort;
+        self.container_list.match_query = self.filter.query.clone();
+        self.container_list.group = self.group_filter.clone();
+        self.container_list.pinned = self.pins.names().iter().cloned().collect();
+        self.container_list.counts = StatusCounts::of(&self.containers);
+
+        // Keep the grouped row mapping in sync with the filtered containers
+        let grouped = self.status_filter == StatusFilter::Groups;
+        let filtered: Vec<&ContainerInfo> = self.filtered_indices
+            .iter()
+            .filter_map(|&i| self.containers.get(i))
+            .collect();
+        self.container_list.set_rows(&filtered, grouped);
+        self.container_list.reselect(selected, self.nav_item_count());
     }
 
     /// Get filtered containers
@@ -246,57 +852,154 @@ impl App {
             .collect()
     }
 
+    /// Refresh the container list, waiting for it (after actions, at startup)
     pub async fn refresh_containers(&mut self) -> Result<()> {
+        // This one is newer than a refresh still running in the background
+        if let Some(handle) = self.container_fetch.take() {
+            handle.abort();
+        }
+        self.loading = true;
+        self.last_container_refresh = Instant::now();
+        let fetched = fetch_containers(self.docker.clone(), self.run_states.clone()).await?;
+        self.apply_containers(fetched);
+        Ok(())
+    }
+
+    /// Refresh the container list on a background task, so the loop keeps
+    /// drawing (and the header spinner turning) while Docker answers
+    fn start_container_refresh(&mut self) {
         self.loading = true;
         self.last_container_refresh = Instant::now();
+        let docker = self.docker.clone();
+        let run_states = self.run_states.clone();
+        self.container_fetch = Some(tokio::spawn(fetch_containers(docker, run_states)));
+    }
+
+    /// Apply a background refresh once it's done, or toast why it failed;
+    /// whether it finished. An inline refresh takes the handle before
+    /// aborting it, so aborted fetches never get here.
+    async fn finish_container_refresh(&mut self) -> bool {
+        if !self.container_fetch.as_ref().is_some_and(|h| h.is_finished()) {
+            return false;
+        }
+        let Some(handle) = self.container_fetch.take() else {
+            return false;
+        };
+        match handle.await {
+            Ok(Ok(fetched)) => self.apply_containers(fetched),
+            Ok(Err(e)) => {
+                self.loading = false;
+                self.toast = Some(Toast::error(format!("{:#}", e)));
+            }
+            Err(e) => {
+                self.loading = false;
+                self.toast = Some(Toast::error(format!("Background task failed: {}", e)));
+            }
+        }
+        true
+    }
 
-        let mut containers = self.docker.list_containers().await?;
+    /// Take in a fresh listing: stats, run states and everything derived from them
+    fn apply_containers(&mut self, fetched: ContainerFetch) {
+        let ContainerFetch { mut containers, stats, run_states } = fetched;
+        self.run_states.extend(run_states);
 
-        // Clone GPU cache to avoid borrow conflict
-        let gpu_cache = self.cached_container_gpu.clone();
+        // Until a fresh sample arrives, active containers show their last one
+        for container in containers.iter_mut().filter(|c| c.status.is_active()) {
+            container.stats = self.containers.iter().find(|c| c.id == container.id).and_then(|c| c.stats.clone());
+        }
+        self.apply_stats(&mut containers, stats);
 
         for container in &mut containers {
-            // Use is_active() to include paused containers (they still hold GPU memory)
-            if container.status.is_active() {
-                if let Ok(mut stats) = get_container_stats(self.docker.inner(), &container.name).await {
-                    // Record history for sparklines
-                    self.stats_history.record_cpu(&container.name, stats.cpu_percent);
-                    self.stats_history.record_mem(&container.name, stats.memory_percent);
-                    // Apply GPU usage if available
-                    stats.vram_usage_mb = lookup_container_vram(&gpu_cache, &container.id);
-                    container.stats = Some(stats);
+            if let Some(state) = self.run_states.get(&container.id) {
+                container.started_at = state.started_at;
+                container.restart_count = state.restart_count;
+                container.gpus = state.gpus.clone();
+                for network in &mut container.networks {
+                    network.aliases = state.aliases.get(&network.network).cloned().unwrap_or_default();
+                }
+                if matches!(container.status, ContainerStatus::Exited | ContainerStatus::Dead) {
+                    container.exit_code = state.exit_code.or(container.exit_code);
+                    container.oom_killed = state.oom_killed;
+                    container.finished_at = state.finished_at;
                 }
             }
         }
 
+        // Drop cached state and marks for removed containers
+        self.run_states.retain(|id, _| containers.iter().any(|c| &c.id == id));
+        self.stats_history.retain(|id| containers.iter().any(|c| c.id == id));
+        self.io_counters.retain(|name, _| containers.iter().any(|c| &c.name == name));
+        self.container_list.marked.retain(|name| containers.iter().any(|c| &c.name == name));
+
         self.containers = containers;
         self.update_filtered_indices();
+        self.evaluate_alerts();
+        self.publish_metrics();
+        self.container_list.keep_alive = self.keep_alive.restarts();
+        self.flag_image_updates();
         self.loading = false;
-
-        Ok(())
     }
 
     pub async fn refresh_container_stats(&mut self) -> Result<()> {
         self.last_stats_refresh = Instant::now();
 
-        // Clone GPU cache to avoid borrow conflict
-        let gpu_cache = self.cached_container_gpu.clone();
+        let mut containers = std::mem::take(&mut self.containers);
+        self.collect_stats(&mut containers).await;
+        self.containers = containers;
 
-        for container in &mut self.containers {
-            // Use is_active() to include paused containers (they still hold GPU memory)
-            if container.status.is_active() {
-                if let Ok(mut stats) = get_container_stats(self.docker.inner(), &container.name).await {
-                    // Record history for sparklines
-                    self.stats_history.record_cpu(&container.name, stats.cpu_percent);
-                    self.stats_history.record_mem(&container.name, stats.memory_percent);
-                    // Apply GPU usage if available
-                    stats.vram_usage_mb = lookup_container_vram(&gpu_cache, &container.id);
-                    container.stats = Some(stats);
-                }
+        self.evaluate_alerts();
+        self.publish_metrics();
+        Ok(())
+    }
+
+    /// Fetch stats for the active containers concurrently and record them
+    /// in the histories. Containers that didn't answer in time keep what
+    /// they had
+    async fn collect_stats(&mut self, containers: &mut [ContainerInfo]) {
+        let fresh = fetch_stats(&self.docker, containers).await;
+        self.apply_stats(containers, fresh);
+    }
+
+    /// Fill in fresh samples, recording them for the sparklines and history
+    fn apply_stats(&mut self, containers: &mut [ContainerInfo], mut fresh: HashMap<String, ContainerStats>) {
+        for container in containers.iter_mut() {
+            let Some(mut stats) = fresh.remove(&container.name) else {
+                continue;
+            };
+            update_io_rates(&mut self.io_counters, &container.name, &mut stats);
+            // Record history for sparklines
+            self.stats_history.record_cpu(&container.id, stats.cpu_percent);
+            self.stats_history.record_mem(&container.id, stats.memory_percent);
+            self.history.record(&container.name, stats.cpu_percent, stats.memory_percent, stats.net_rx_rate, stats.net_tx_rate);
+            // Apply GPU usage if available
+            stats.vram_usage_mb = lookup_container_vram(&self.cached_container_gpu, &container.id);
+            container.stats = Some(stats);
+        }
+    }
+
+    /// Check alert rules against the latest stats and notify on new alerts
+    fn evaluate_alerts(&mut self) {
+        let fired = self.alerts.evaluate(&self.containers);
+        if let Some(alert) = fired.first() {
+            let more = if fired.len() > 1 { format!(" (+{} more)", fired.len() - 1) } else { String::new() };
+            self.toast = Some(Toast::error(format!("{} {}{}", alert.container, alert.message, more)));
+        }
+        if self.alerts.config.bell {
+            for alert in &fired {
+                crate::alerts::notify(alert);
             }
         }
+        self.container_list.alerting = self.alerts.alerting_names();
+        self.container_list.crash_looping = self.alerts.crash_looping();
+    }
 
-        Ok(())
+    /// Publish the latest samples to the metrics endpoint (if enabled)
+    fn publish_metrics(&self) {
+        #[cfg(feature = "metrics")]
+        if let Some(ref metrics) = self.metrics {
+            metrics.publish(&self.containers, &self.system_stats);
+        }
     }
 
     pub fn refresh_system_stats(&mut self) {
@@ -338,15 +1041,27 @@ impl App {
             0.0
         };
 
-        // Throttle nvidia-smi calls - only refresh every 2 seconds
-        let vram_percent = if self.last_vram_refresh.elapsed() >= self.vram_refresh_interval {
+        // Throttle GPU tool calls to the VRAM interval
+        if self.last_vram_refresh.elapsed() >= self.scaled(self.vram_refresh_interval) {
             self.last_vram_refresh = Instant::now();
-            self.cached_vram = SystemStats::get_vram_percent();
+            let sample = self.gpu.sample();
+            self.cached_vram = sample.vram_percent;
+            self.cached_gpu_util = sample.util_percent;
+            self.cached_gpu_sensors = sample.sensors;
             // Also refresh per-container GPU usage
-            self.cached_container_gpu = get_container_gpu_usage();
-            self.cached_vram
+            self.cached_container_gpu = sample.containers;
+            self.gpu_devices = sample.devices;
+            self.container_gpus = sample.container_gpus;
+        }
+
+        // Per-core usage, load and temperature for the expanded header
+        let cpu_cores = self.sys.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+        let load = System::load_average();
+        let temperature = if self.expanded_header {
+            self.components.refresh();
+            SystemStats::cpu_temperature(self.components.iter().map(|c| (c.label(), c.temperature())))
         } else {
-            self.cached_vram
+            None
         };
 
         self.system_stats = SystemStats {
@@ -357,30 +1072,153 @@ impl App {
             disk_percent,
             disk_used_gb: disk_used / 1024.0 / 1024.0 / 1024.0,
             disk_total_gb: disk_total / 1024.0 / 1024.0 / 1024.0,
-            vram_percent,
+            vram_percent: self.cached_vram,
+            gpu_util_percent: self.cached_gpu_util,
+            cpu_count: self.sys.cpus().len(),
+            cpu_cores,
+            load_average: [load.one, load.five, load.fifteen],
+            temperature,
+            gpu_sensors: self.cached_gpu_sensors,
         };
     }
 
     pub async fn load_logs(&mut self, container_name: &str) -> Result<()> {
         self.logs_container = container_name.to_string();
-        self.logs = get_container_logs(self.docker.inner(), container_name, 500).await?;
+        self.logs_project = None;
+        self.logs_tail = self.logs_config.tail;
+        let lines = get_container_logs(self.docker.inner(), container_name, self.logs_tail, None).await?;
+        self.logs.reset(self.logs_config.max_lines, self.logs_config.max_bytes());
+        self.logs.replace(lines);
         self.logs_view = LogsView::new();
         self.view_mode = ViewMode::Logs;
         Ok(())
     }
 
     pub async fn open_create_form(&mut self) -> Result<()> {
+        if let Some(desc) = Command::NewContainer.read_only_refusal(self.read_only) {
+            self.toast = Some(Toast::error(format!("Read-only mode: {} is disabled", desc)));
+            return Ok(());
+        }
         self.create_form = CreateContainerForm::new();
         self.create_form.available_images = self.docker.list_images().await.unwrap_or_default();
+        self.create_form.available_networks = self.docker.list_networks().await.unwrap_or_default();
         self.view_mode = ViewMode::Create;
         Ok(())
     }
 
-    pub fn open_exec_modal(&mut self, container_name: String) {
-        self.exec_modal = Some(ExecModal::new(container_name));
+    pub async fn open_exec_modal(&mut self, container_name: String) {
+        if let Some(desc) = Command::Exec.read_only_refusal(self.read_only) {
+            self.toast = Some(Toast::error(format!("Read-only mode: {} is disabled", desc)));
+            return;
+        }
+        let mut modal = ExecModal::new(container_name);
+        // Without a probe result every shell stays selectable
+        if let Ok(found) = self.docker.available_shells(&modal.container_name, crate::components::exec_modal::SHELLS).await {
+            modal.set_available(&found);
+        }
+        self.exec_modal = Some(modal);
         self.view_mode = ViewMode::Exec;
     }
 
+    /// Start the exec modal's shell in the embedded pane and focus it
+    pub async fn open_exec_pane(&mut self, modal: &ExecModal) {
+        let user = Some(modal.user.trim()).filter(|u| !u.is_empty());
+        let workdir = Some(modal.workdir.trim()).filter(|w| !w.is_empty());
+        let result = ExecSession::start(&self.docker, &modal.container_name, modal.selected_shell(), user, workdir, self.exec_pane_size, self.recording_dir.as_deref()).await;
+        self.audit("exec", &modal.container_name, audit::outcome(&result));
+        match result {
+            Ok(session) => {
+                self.exec_pane = Some(session);
+                self.exec_focused = true;
+            }
+            Err(e) => self.toast = Some(Toast::error(format!("{:#}", e))),
+        }
+    }
+
+    /// Cast file for a full-screen exec, which records through asciinema
+    pub fn full_screen_cast(&mut self, container: &str) -> Option<PathBuf> {
+        let dir = self.recording_dir.as_deref()?;
+        if !recording::asciinema_available() {
+            self.toast = Some(Toast::error("Install asciinema to record full-screen exec (F2 pane records without it)"));
+            return None;
+        }
+        match recording::cast_path(dir, container) {
+            Ok(path) => Some(path),
+            Err(e) => {
+                self.toast = Some(Toast::error(format!("{:#}", e)));
+                None
+            }
+        }
+    }
+
+    /// Report where a full-screen exec was recorded
+    pub fn recorded(&mut self, path: &std::path::Path) {
+        self.toast = Some(Toast::info(format!("Recorded session to {}", path.display())));
+    }
+
+    /// Drop the pane once its shell exits, otherwise follow the pane's size
+    async fn update_exec_pane(&mut self) {
+        let Some(ref mut session) = self.exec_pane else {
+            return;
+        };
+        if session.has_exited() {
+            self.toast = Some(Toast::info(match session.recording {
+                Some(ref path) => format!("Shell in {} exited, recorded to {}", session.container, path.display()),
+                None => format!("Shell in {} exited", session.container),
+            }));
+            self.exec_pane = None;
+            self.exec_focused = false;
+        } else {
+            session.resize(self.exec_pane_size).await;
+        }
+    }
+
+    /// Save the create form as a template file to share
+    pub fn export_template(&mut self) {
+        if self.create_form.name.trim().is_empty() {
+            self.toast = Some(Toast::error("Name the container before exporting a template"));
+            return;
+        }
+        self.toast = Some(match ContainerTemplate::from_form(&self.create_form) {
+            Ok(template) => match template.write_file() {
+                Ok(path) => Toast::info(format!("Exported template to {}", path.display())),
+                Err(e) => Toast::error(format!("{:#}", e)),
+            },
+            Err(e) => Toast::error(e),
+        });
+    }
+
+    /// Fill the create form from a template file or project.yaml
+    pub fn import_template(&mut self, path: &str) {
+        // The terminal is stdin here
+        if path.trim() == "-" {
+            self.toast = Some(Toast::error("Templates are read from stdin with --template - only"));
+            return;
+        }
+        self.toast = Some(match ContainerTemplate::read(path) {
+            Ok(template) => {
+                template.apply(&mut self.create_form);
+                Toast::info(format!("Loaded template {}", template.name))
+            }
+            Err(e) => Toast::error(format!("{:#}", e)),
+        });
+    }
+
+    /// Merge a host .env file into the create form's env rows
+    pub fn load_env_file(&mut self, path: &str) {
+        let path = match path.trim().strip_prefix("~/") {
+            Some(rest) => std::env::var_os("HOME").map(PathBuf::from).unwrap_or_default().join(rest),
+            None => PathBuf::from(path.trim()),
+        };
+        self.toast = Some(match std::fs::read_to_string(&path) {
+            Ok(content) => {
+                let count = self.create_form.merge_env(parse_env_file(&content));
+                Toast::info(format!("Loaded {} variables from {}", count, path.display()))
+            }
+            Err(e) => Toast::error(format!("Failed to read {}: {}", path.display(), e)),
+        });
+    }
+
     pub async fn create_container_from_form(&mut self) -> Result<()> {
         let form = &self.create_form;
 
@@ -391,10 +1229,12 @@ impl App {
         let port_host = form.port_host.parse::<u16>().ok();
         let port_container = form.port_container.parse::<u16>().ok();
 
-        let env_vars: Vec<String> = if form.env_vars.is_empty() {
-            Vec::new()
-        } else {
-            form.env_vars.split(',').map(|s| s.trim().to_string()).collect()
+        let env_vars = match form.env_vars() {
+            Ok(env_vars) => env_vars,
+            Err(e) => {
+                self.toast = Some(Toast::error(e));
+                return Ok(());
+            }
         };
 
         let volumes: Vec<String> = if form.volumes.is_empty() {
@@ -409,17 +1249,43 @@ impl App {
             Some(form.command.clone())
         };
 
-        self.docker
-            .create_container(
-                &form.name,
-                &form.image,
+        let (memory, nano_cpus) = match (form.memory_bytes(), form.nano_cpus()) {
+            (Ok(memory), Ok(nano_cpus)) => (memory, nano_cpus),
+            (Err(e), _) | (_, Err(e)) => {
+                self.toast = Some(Toast::error(e));
+                return Ok(());
+            }
+        };
+
+        let devices: Vec<String> = form
+            .devices
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        let target = format!("{} ({})", form.name, form.image);
+        let result = self
+            .docker
+            .create_container(ContainerSpec {
+                name: form.name.clone(),
+                image: form.image.clone(),
                 port_host,
                 port_container,
                 env_vars,
                 volumes,
                 command,
-            )
-            .await?;
+                gpu: form.gpu,
+                devices,
+                memory,
+                nano_cpus,
+                network: Some(form.network.trim().to_string()).filter(|n| !n.is_empty()),
+                hostname: Some(form.hostname.trim().to_string()).filter(|h| !h.is_empty()),
+                dns: form.dns_servers(),
+            })
+            .await;
+        self.audit("create", &target, audit::outcome(&result));
+        result?;
 
         self.view_mode = ViewMode::List;
         self.refresh_containers().await?;
@@ -427,6 +1293,107 @@ impl App {
         Ok(())
     }
 
+    /// Append to the audit log; a failed write is reported but never blocks the action
+    pub fn audit(&mut self, action: &str, target: &str, result: String) {
+        if let Err(e) = self.audit_log.record(action, target, result) {
+            self.toast = Some(Toast::error(format!("{:#}", e)));
+        }
+    }
+
+    /// Move the list selection or scroll the logs by `delta` rows
+    fn move_by(&mut self, delta: isize) {
+        match self.view_mode {
+            ViewMode::List => self.container_list.move_by(delta, self.nav_item_count()),
+            ViewMode::Logs if delta < 0 => self.logs_view.scroll_up(delta.unsigned_abs()),
+            ViewMode::Logs => self.logs_view.scroll_down(delta as usize, self.logs.len()),
+            _ => {}
+        }
+    }
+
+    /// Open the info view, probing the selected container's runtime details
+    pub async fn open_info(&mut self) {
+        let Some(name) = self.selected_container().map(|c| c.name.clone()) else {
+            return;
+        };
+        self.runtime_info = match self.docker.runtime_info(&name).await {
+            Ok(info) => Some(info),
+            Err(e) => {
+                self.toast = Some(Toast::error(format!("{:#}", e)));
+                None
+            }
+        };
+        self.view_mode = ViewMode::Info;
+    }
+
+    /// Check the label editor's input and compute the dry run for its containers
+    pub async fn preview_labels(&mut self) {
+        let Some(ref mut modal) = self.label_modal else {
+            return;
+        };
+        let edits = match parse_label_edits(&modal.input) {
+            Ok(edits) => edits,
+            Err(e) => {
+                modal.error = Some(format!("{:#}", e));
+                return;
+            }
+        };
+        let mut review = Vec::new();
+        for name in &modal.targets {
+            match self.docker.container_labels(name).await {
+                Ok(labels) => review.push((name.clone(), label_changes(&labels, &edits))),
+                Err(e) => {
+                    modal.error = Some(format!("{:#}", e));
+                    return;
+                }
+            }
+        }
+        modal.edits = edits;
+        modal.review = Some(review);
+        modal.scroll = 0;
+    }
+
+    /// Start the forward set up in the port forward dialog
+    pub fn start_forward(&mut self) {
+        let Some(ref mut modal) = self.forward_modal else {
+            return;
+        };
+        let (port, local_port) = match modal.ports() {
+            Ok(ports) => ports,
+            Err(e) => {
+                modal.error = Some(e);
+                return;
+            }
+        };
+        match self.forwards.start(&modal.container, &modal.ip, port, local_port, self.docker.host()) {
+            Ok(()) => {
+                self.toast = Some(Toast::info(format!("Forwarding 127.0.0.1:{} to {}:{}", local_port, modal.container, port)));
+                modal.selected = self.forwards.entries.len() - 1;
+            }
+            Err(e) => modal.error = Some(format!("{:#}", e)),
+        }
+    }
+
+    /// Stop a port forward and kill its process
+    pub fn stop_forward(&mut self, index: usize) {
+        if let Some(forward) = self.forwards.stop(index) {
+            self.toast = Some(Toast::info(format!("Stopped forwarding port {}", forward.local_port)));
+        }
+    }
+
+    /// Keymap context of the current view (modals count as the list)
+    pub fn key_context(&self) -> KeyContext {
+        match self.view_mode {
+            ViewMode::Logs => KeyContext::Logs,
+            ViewMode::Charts => KeyContext::Charts,
+            ViewMode::Compare => KeyContext::Compare,
+            ViewMode::Services => KeyContext::Services,
+            ViewMode::Pods => KeyContext::Pods,
+            ViewMode::Host => KeyContext::Host,
+            ViewMode::Events => KeyContext::Events,
+            _ => KeyContext::List,
+        }
+    }
+
     /// Get the currently selected container from filtered list
     pub fn selected_container(&self) -> Option<&ContainerInfo> {
         if self.status_filter == StatusFilter::Groups {
@@ -447,8 +1414,13 @@ impl App {
         self.selected_container().map(|c| c.name.clone())
     }
 
+    /// Events shown in the events view with its filter
+    fn event_count(&self) -> usize {
+        self.events.lock().map_or(0, |log| self.events_view.visible(&log).len())
+    }
+
     /// Get the item count for navigation (includes headers in groups mode)
-    fn nav_item_count(&self) -> usize {
+    pub fn nav_item_count(&self) -> usize {
         if self.status_filter == StatusFilter::Groups {
             let list_count = self.container_list.item_count();
             if list_count > 0 {
@@ -462,79 +1434,1198 @@ impl App {
     }
 
     pub fn should_refresh_containers(&self) -> bool {
-        self.last_container_refresh.elapsed() >= self.container_refresh_interval
+        self.last_container_refresh.elapsed() >= self.scaled(self.container_refresh_interval)
     }
 
     pub fn should_refresh_stats(&self) -> bool {
-        self.last_stats_refresh.elapsed() >= self.stats_refresh_interval
+        self.last_stats_refresh.elapsed() >= self.scaled(self.stats_refresh_interval)
+    }
+
+    /// A configured interval with the runtime speed (and idle backoff) applied
+    fn scaled(&self, interval: Duration) -> Duration {
+        let idle = if self.is_idle() { self.idle_slowdown } else { 1.0 };
+        interval.mul_f64(self.refresh_scale * idle)
+    }
+
+    /// How long the main loop waits for input before the next tick and
+    /// redraw. The embedded shell keeps the fast rate so its output isn't laggy
+    pub fn tick_rate(&self) -> Duration {
+        match self.effects.enabled() || self.exec_pane.is_some() {
+            true => ANIMATION_TICK,
+            false => REDUCED_MOTION_TICK,
+        }
+    }
+
+    /// Something on screen moves on its own: an effect, the loading spinner,
+    /// a row in transition or the embedded shell's output
+    pub fn animating(&self) -> bool {
+        self.exec_pane.is_some()
+            || self.effects.enabled() && (self.effects.is_active() || self.loading || !self.transitions.is_empty())
+    }
+
+    /// No key pressed for the configured idle time
+    pub fn is_idle(&self) -> bool {
+        self.idle_after.is_some_and(|after| self.last_input.elapsed() >= after)
+    }
+
+    /// Record a key press; ends the idle backoff
+    pub fn note_input(&mut self) {
+        self.last_input = Instant::now();
+    }
+
+    /// Effective polling interval per data source (shown in the help)
+    pub fn refresh_intervals(&self) -> [(&'static str, Duration); 4] {
+        [
+            ("containers", self.scaled(self.container_refresh_interval)),
+            ("stats", self.scaled(self.stats_refresh_interval)),
+            ("vram", self.scaled(self.vram_refresh_interval)),
+            ("logs", self.scaled(self.logs_refresh_interval)),
+        ]
     }
 
     pub async fn tick(&mut self) -> Result<()> {
+        if self.last_config_check.elapsed() >= CONFIG_CHECK_INTERVAL {
+            self.last_config_check = Instant::now();
+            if self.config_watch.as_mut().is_some_and(|w| w.changed()) {
+                self.reload_config();
+                self.needs_redraw = true;
+            }
+        }
+        self.finish_jobs().await;
+        self.finish_transitions().await?;
+        self.finish_exit_watches().await;
+        self.finish_scans().await;
+        self.run_schedule();
+        self.finish_schedule_jobs().await;
+        self.finish_startup();
+        self.finish_update_check().await;
+        if self.update_check.is_none() && self.update_check_interval.is_some_and(|i| self.last_update_check.elapsed() >= i) {
+            self.start_update_check().await;
+        }
+        self.finish_image_sizes().await;
+        if self.image_size_fetch.is_none() && self.list_view_mode == ListViewMode::Details {
+            self.start_image_sizes();
+        }
+        self.update_exec_pane().await;
+        for message in self.forwards.poll() {
+            self.toast = Some(Toast::error(message));
+            self.needs_redraw = true;
+        }
+
         if self.view_mode == ViewMode::Create || self.view_mode == ViewMode::Exec {
             return Ok(());
         }
 
+        // Remove one cleanup item per tick so progress is drawn in between
+        if self.view_mode == ViewMode::Cleanup && self.cleanup_modal.as_ref().is_some_and(|m| m.phase == CleanupPhase::Running) {
+            self.needs_redraw = true;
+            return self.step_cleanup().await;
+        }
+
         // Refresh system stats FIRST so GPU cache is populated before container stats
         self.refresh_system_stats();
 
-        if self.should_refresh_containers() {
-            self.refresh_containers().await?;
-        } else if self.should_refresh_stats() {
+        if self.container_fetch.is_none() && self.should_refresh_containers() {
+            self.needs_redraw = true;
+            self.start_container_refresh();
+        } else if self.finish_container_refresh().await {
+            self.needs_redraw = true;
+            if self.view_mode == ViewMode::Services {
+                self.refresh_services().await;
+            }
+            if self.view_mode == ViewMode::Pods {
+                self.refresh_pods().await;
+            }
+            if self.view_mode == ViewMode::Host {
+                self.refresh_host_processes();
+            }
+        } else if self.container_fetch.is_none() && self.should_refresh_stats() {
+            self.needs_redraw = true;
             self.refresh_container_stats().await?;
         }
 
-        // Throttle log refreshes to every 2 seconds
+        self.restart_kept_alive().await?;
+
+        // Throttle log refreshes to the logs interval
         if self.view_mode == ViewMode::Logs && !self.logs_container.is_empty()
-            && self.last_logs_refresh.elapsed() >= self.logs_refresh_interval {
+            && self.last_logs_refresh.elapsed() >= self.scaled(self.logs_refresh_interval) {
             self.last_logs_refresh = Instant::now();
-            if let Ok(logs) = get_container_logs(self.docker.inner(), &self.logs_container, 500).await {
-                self.logs = logs;
+            // Everything since the newest line held, so fast loggers don't
+            // outrun a fixed tail between refreshes
+            let since = self.logs.last_timestamp().map(|at| at.timestamp());
+            if let Ok(lines) = self.fetch_logs(self.logs_tail, since).await {
+                self.logs.extend_new(lines);
+                self.needs_redraw = true;
+            }
+        }
+
+        // The details pane's log tail, right away for a newly selected container
+        if self.split_view && self.view_mode == ViewMode::List && self.exec_pane.is_none() {
+            if let Some(name) = self.selected_container_name() {
+                let stale = self.detail_logs.as_ref().is_none_or(|(shown, _)| *shown != name);
+                if stale || self.last_detail_logs_refresh.elapsed() >= self.scaled(self.logs_refresh_interval) {
+                    self.last_detail_logs_refresh = Instant::now();
+                    if let Ok(lines) = get_container_logs(self.docker.inner(), &name, DETAIL_LOG_TAIL, None).await {
+                        self.detail_logs = Some((name, lines));
+                        self.needs_redraw = true;
+                    }
+                }
             }
         }
 
         Ok(())
     }
 
-    pub async fn handle_action(&mut self, action: Action) -> Result<()> {
-        match action {
-            Action::Quit => {
-                match self.view_mode {
-                    ViewMode::Create => self.view_mode = ViewMode::List,
-                    ViewMode::Filter => {
-                        self.filter.deactivate();
-                        self.update_filtered_indices();
-                        self.view_mode = ViewMode::List;
-                    }
-                    ViewMode::Exec => {
-                        self.exec_modal = None;
-                        self.view_mode = ViewMode::List;
-                    }
-                    _ => self.should_quit = true,
+    /// Restart keep-alive containers that exited, once their backoff has passed
+    async fn restart_kept_alive(&mut self) -> Result<()> {
+        let due = self.keep_alive.due(&self.containers, Instant::now());
+        if due.is_empty() {
+            return Ok(());
+        }
+        self.needs_redraw = true;
+        for name in &due {
+            self.toast = Some(match self.docker.start_container(name).await {
+                Ok(()) => {
+                    let restarts = self.keep_alive.restarts().get(name).copied().unwrap_or(0);
+                    Toast::info(format!("Keep-alive: restarted {} (#{})", name, restarts))
                 }
+                Err(e) => Toast::error(format!("Keep-alive: {}", e)),
+            });
+        }
+        self.refresh_containers().await
+    }
+
+    /// Lines for the logs view: the pod's or the project's when showing
+    /// one, else the container's. Pods always get the last `tail` lines
+    async fn fetch_logs(&self, tail: usize, since: Option<i64>) -> Result<Vec<String>> {
+        #[cfg(feature = "kubernetes")]
+        if let Some((ref namespace, ref pod)) = self.logs_pod {
+            let _ = since;
+            return crate::kube::pod_logs(namespace, pod, tail).await;
+        }
+        if let Some(ref project) = self.logs_project {
+            // Containers that join the project show up from the next refresh
+            let names: Vec<String> = self
+                .containers
+                .iter()
+                .filter(|c| c.compose_project.as_ref() == Some(project))
+                .map(|c| c.name.clone())
+                .collect();
+            return get_project_logs(self.docker.inner(), &names, tail, since).await;
+        }
+        get_container_logs(self.docker.inner(), &self.logs_container, tail, since).await
+    }
+
+    /// Add a filter typed in the logs view (kept for other containers'
+    /// logs until removed)
+    pub fn add_log_filter(&mut self, input: &str) {
+        match LogFilter::parse(input.trim()) {
+            Ok(filter) => {
+                self.logs.add_filter(filter);
+                self.logs_view.filter_selected = self.logs.filters().len() - 1;
             }
+            Err(e) => self.toast = Some(Toast::error(format!("{:#}", e))),
+        }
+    }
 
-            Action::Up => match self.view_mode {
-                ViewMode::List | ViewMode::Filter => {
-                    self.container_list.previous(self.nav_item_count())
-                }
-                ViewMode::Logs => self.logs_view.scroll_up(1),
-                ViewMode::Create => {
-                    if self.create_form.mode == CreateMode::ImageSelect {
-                        self.create_form.prev_image();
-                    } else {
-                        self.create_form.prev_field();
-                    }
-                }
-                ViewMode::Exec => {
-                    if let Some(ref mut modal) = self.exec_modal {
-                        modal.previous();
-                    }
+    /// Refetch the logs with twice the history and keep the view on the
+    /// line that was at the top of the buffer
+    async fn load_more_logs(&mut self) {
+        let first = self.logs.first().cloned();
+        let tail = self.logs_tail.max(self.logs.len()) * 2;
+        match self.fetch_logs(tail, None).await {
+            Ok(lines) => {
+                self.logs_tail = tail;
+                self.logs.reserve_lines(tail);
+                self.logs.replace(lines);
+                let older = first.and_then(|first| self.logs.iter().position(|line| *line == first)).unwrap_or(0);
+                if older > 0 {
+                    self.logs_view.jump_to_line(older);
+                    self.toast = Some(Toast::info(format!("Loaded {} older lines", older)));
+                } else {
+                    self.toast = Some(Toast::info("No older logs".to_string()));
                 }
-                ViewMode::Info | ViewMode::Rename | ViewMode::CopyFiles => {} // No scrolling
-                ViewMode::Processes => {
-                    if let Some(ref mut modal) = self.processes_modal {
-                        modal.scroll_up();
-                    }
+            }
+            Err(e) => self.toast = Some(Toast::error(format!("{:#}", e))),
+        }
+    }
+
+    /// Switch to the Kubernetes pods tab
+    async fn open_pods(&mut self) {
+        #[cfg(feature = "kubernetes")]
+        {
+            if crate::kube::kubeconfig().is_none() {
+                self.toast = Some(Toast::error("No kubeconfig found".to_string()));
+                return;
+            }
+            if self.namespace.is_empty() {
+                self.namespace = crate::kube::current_namespace().await;
+                self.namespaces = crate::kube::list_namespaces().await.unwrap_or_default();
+            }
+            self.refresh_pods().await;
+            self.view_mode = ViewMode::Pods;
+        }
+        #[cfg(not(feature = "kubernetes"))]
+        {
+            self.toast = Some(Toast::error("Built without the kubernetes feature".to_string()));
+        }
+    }
+
+    /// Reload pods of the current namespace
+    async fn refresh_pods(&mut self) {
+        #[cfg(feature = "kubernetes")]
+        match crate::kube::list_pods(&self.namespace).await {
+            Ok(pods) => self.pods = pods,
+            Err(e) => self.toast = Some(Toast::error(format!("{:#}", e))),
+        }
+        self.pods_view.clamp_selection(self.pods.len());
+    }
+
+    /// Reload the Swarm services list
+    async fn refresh_services(&mut self) {
+        match self.docker.list_services().await {
+            Ok(services) => self.services = services,
+            Err(e) => self.toast = Some(Toast::error(format!("{}", e))),
+        }
+        self.services_view.clamp_selection(self.services.len());
+    }
+
+    /// Whether Alt+1..9 may switch away: a full view with nothing open over it
+    pub fn can_switch_tabs(&self) -> bool {
+        matches!(
+            self.view_mode,
+            ViewMode::List | ViewMode::Logs | ViewMode::Charts | ViewMode::Compare
+                | ViewMode::Services | ViewMode::Pods | ViewMode::Host | ViewMode::Events
+        ) && matches!(self.modal, ModalState::None)
+    }
+
+    /// Exchange the live view state with a parked tab's
+    fn swap_tab_state(&mut self, tab: &mut TabState) {
+        std::mem::swap(&mut self.view_mode, &mut tab.view_mode);
+        std::mem::swap(&mut self.container_list.state, &mut tab.list_state);
+        std::mem::swap(&mut self.logs, &mut tab.logs);
+        std::mem::swap(&mut self.logs_view, &mut tab.logs_view);
+        std::mem::swap(&mut self.logs_container, &mut tab.logs_container);
+        std::mem::swap(&mut self.logs_pod, &mut tab.logs_pod);
+        std::mem::swap(&mut self.logs_project, &mut tab.logs_project);
+        std::mem::swap(&mut self.logs_tail, &mut tab.logs_tail);
+        std::mem::swap(&mut self.chart_container, &mut tab.chart_container);
+        std::mem::swap(&mut self.services_view, &mut tab.services_view);
+        std::mem::swap(&mut self.pods_view, &mut tab.pods_view);
+        std::mem::swap(&mut self.host_view, &mut tab.host_view);
+        std::mem::swap(&mut self.events_view, &mut tab.events_view);
+    }
+
+    fn switch_tab(&mut self, index: usize) {
+        let Some(mut tab) = self.tabs.get_mut(index).and_then(Option::take) else {
+            return;
+        };
+        self.swap_tab_state(&mut tab);
+        self.tabs[self.active_tab] = Some(tab);
+        self.active_tab = index;
+        // Rows may have come and gone while it was parked
+        self.container_list.clamp_selection(self.nav_item_count());
+    }
+
+    /// Open a tab on the container list and switch to it
+    fn open_tab(&mut self) {
+        if self.tabs.len() >= MAX_TABS {
+            self.toast = Some(Toast::error(format!("At most {} tabs", MAX_TABS)));
+            return;
+        }
+        self.tabs.push(Some(TabState::new(self.container_list.state, &self.logs_config)));
+        self.switch_tab(self.tabs.len() - 1);
+    }
+
+    /// Close the active tab, moving to the one after it (or before, for the last)
+    fn close_tab(&mut self) {
+        if self.tabs.len() == 1 {
+            return;
+        }
+        let closing = self.active_tab;
+        self.switch_tab(if closing + 1 < self.tabs.len() { closing + 1 } else { closing - 1 });
+        self.tabs.remove(closing);
+        if self.active_tab > closing {
+            self.active_tab -= 1;
+        }
+    }
+
+    /// Labels for the tab bar, in order
+    fn tab_labels(&self) -> Vec<String> {
+        self.tabs
+            .iter()
+            .map(|tab| match tab {
+                Some(tab) => tab_label(&tab.view_mode, &tab.logs_container, &tab.chart_container),
+                None => tab_label(&self.view_mode, &self.logs_container, &self.chart_container),
+            })
+            .collect()
+    }
+
+    /// Pod shown in the logs view, if it is showing one
+    pub fn logs_pod(&self) -> Option<&(String, String)> {
+        self.logs_pod.as_ref()
+    }
+
+    /// Compose project shown in the logs view, if it is showing one
+    pub fn logs_project(&self) -> Option<&str> {
+        self.logs_project.as_deref()
+    }
+
+    /// Send a line to the logged container's stdin and refresh the logs soon
+    pub async fn send_stdin(&mut self, line: &str) {
+        let result = self.docker.send_stdin(&self.logs_container, line).await;
+        let container = self.logs_container.clone();
+        self.audit("stdin", &container, audit::outcome(&result));
+        match result {
+            Ok(()) => {
+                // Show the response on the next tick rather than a full interval later
+                self.last_logs_refresh = Instant::now() - self.scaled(self.logs_refresh_interval);
+                self.logs_view.follow = true;
+            }
+            Err(e) => self.toast = Some(Toast::error(format!("{:#}", e))),
+        }
+    }
+
+    /// Scroll the logs to the first line at or after a typed time
+    pub fn go_to_time(&mut self, input: &str) {
+        use crate::docker::logs::{closest_line, line_timestamp, parse_goto_time};
+
+        let Some(latest) = self.logs.iter().rev().find_map(|line| line_timestamp(line)) else {
+            self.toast = Some(Toast::error("Logs have no timestamps".to_string()));
+            return;
+        };
+        let Some(target) = parse_goto_time(input, latest) else {
+            self.toast = Some(Toast::error(format!("Unrecognized time: {}", input)));
+            return;
+        };
+        if let Some(line) = closest_line(&self.logs, target) {
+            self.logs_view.jump_to_line(line);
+        }
+    }
+
+    /// Reload host processes, heaviest first, annotated with their container.
+    /// Grouped by container, every process in a container is kept. CPU% is
+    /// usage since the previous refresh, so the first one only sets a baseline
+    fn refresh_host_processes(&mut self) {
+        /// Rows kept (and cgroup lookups done) per refresh when not grouped
+        const LIMIT: usize = 200;
+
+        self.sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        let mut processes: Vec<HostProcess> = self
+            .sys
+            .processes()
+            .values()
+            // Threads show up as processes on Linux; their usage is in the parent
+            .filter(|p| p.thread_kind().is_none())
+            .map(|p| HostProcess {
+                pid: p.pid().as_u32(),
+                name: p.name().to_string_lossy().into_owned(),
+                command: p.cmd().iter().map(|arg| arg.to_string_lossy()).collect::<Vec<_>>().join(" "),
+                cpu_percent: p.cpu_usage(),
+                memory_mb: p.memory() as f64 / 1024.0 / 1024.0,
+                container: None,
+            })
+            .collect();
+        self.host_view.sort.sort(&mut processes);
+        if !self.host_view.grouped {
+            processes.truncate(LIMIT);
+        }
+
+        let sys = &self.sys;
+        self.pid_mapper.retain(|pid| sys.process(sysinfo::Pid::from_u32(pid)).is_some());
+        for process in &mut processes {
+            process.container = self.pid_mapper.container_id(process.pid).and_then(|id| {
+                self.containers
+                    .iter()
+                    .find(|c| !c.id.is_empty() && (id.starts_with(&c.id) || c.id.starts_with(id)))
+                    .map(|c| c.name.clone())
+            });
+        }
+        if self.host_view.grouped {
+            processes.retain(|p| p.container.is_some());
+        }
+
+        self.host_processes = processes;
+        self.host_view.cpu_pending = false;
+        self.host_view.clamp_selection(self.host_view.row_count(&self.host_processes));
+    }
+
+    /// Report background jobs that finished since the last tick
+    async fn finish_jobs(&mut self) {
+        let (done, running): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.jobs).into_iter().partition(|job| job.handle.is_finished());
+        self.jobs = running;
+        for job in done {
+            let result = match job.handle.await {
+                Ok(result) => result,
+                Err(e) => Err(anyhow::anyhow!("Background task failed: {}", e)),
+            };
+            if let Some((verb, target)) = job.audit {
+                self.audit(&verb, &target, audit::outcome(&result));
+            }
+            self.toast = Some(match result {
+                Ok(()) => Toast::info(job.message),
+                Err(e) => Toast::error(format!("{:#}", e)),
+            });
+        }
+    }
+
+    /// `docker cp` on a background task, recorded once it finishes
+    fn start_copy(&mut self, from: String, to: String) {
+        let docker = self.docker.clone();
+        let (job_from, job_to) = (from.clone(), to.clone());
+        let handle = tokio::spawn(async move { docker.copy_files(&job_from, &job_to).await });
+        self.toast = Some(Toast::info(format!("Copying {}...", from)));
+        self.jobs.push(BackgroundJob {
+            message: format!("Copied {} to {}", from, to),
+            audit: Some(("copy".to_string(), format!("{} -> {}", from, to))),
+            handle,
+        });
+    }
+
+    /// Start the scheduled rules that are due, each on a background task
+    fn run_schedule(&mut self) {
+        for rule in self.scheduler.take_due(chrono::Local::now()) {
+            let description = rule.describe();
+            let names = match rule.target {
+                ScheduleTarget::Container(ref name) => vec![name.clone()],
+                ScheduleTarget::Group(ref group) => self.groups.members(group),
+            };
+            if self.read_only {
+                self.scheduler.record(description, Err("skipped (read-only)".to_string()));
+                continue;
+            }
+            if names.is_empty() {
+                self.scheduler.record(description, Err("group has no containers".to_string()));
+                continue;
+            }
+            let restart = rule.action == ScheduledAction::Restart;
+            let targets: Vec<(String, i64)> = names.iter().map(|n| (n.clone(), self.stop_config.timeout(n, restart))).collect();
+            let docker = self.docker.clone();
+            let action = rule.action;
+            let handle = tokio::spawn(async move {
+                let mut outcomes = Vec::new();
+                for (name, timeout) in targets {
+                    let result = match action {
+                        ScheduledAction::Start => docker.start_container(&name).await,
+                        ScheduledAction::Stop => docker.stop_container(&name, timeout).await,
+                        ScheduledAction::Restart => docker.restart_container(&name, timeout).await,
+                    };
+                    outcomes.push((name, result));
+                }
+                outcomes
+            });
+            self.schedule_jobs.push((description, action, handle));
+        }
+    }
+
+    /// Record finished scheduled runs in the audit log and the panel's history
+    async fn finish_schedule_jobs(&mut self) {
+        let (done, running): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.schedule_jobs).into_iter().partition(|(_, _, handle)| handle.is_finished());
+        self.schedule_jobs = running;
+        for (description, action, handle) in done {
+            let result = match handle.await {
+                Ok(outcomes) => {
+                    let verb = format!("scheduled {}", action.as_str());
+                    let mut failed = Vec::new();
+                    for (name, result) in outcomes {
+                        self.audit(&verb, &name, audit::outcome(&result));
+                        if let Err(e) = result {
+                            failed.push(format!("{:#}", e));
+                        }
+                    }
+                    if failed.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(failed.join("; "))
+                    }
+                }
+                Err(e) => Err(format!("Background task failed: {}", e)),
+            };
+            self.toast = Some(match result {
+                Ok(()) => Toast::info(format!("Scheduled: {}", description)),
+                Err(ref e) => Toast::error(format!("Scheduled {} failed: {}", description, e)),
+            });
+            self.scheduler.record(description, result);
+        }
+    }
+
+    /// Apply an edited config.toml. Nothing changes if it doesn't parse;
+    /// read_only only takes effect on the next start
+    fn reload_config(&mut self) {
+        let Some(ref watch) = self.config_watch else {
+            return;
+        };
+        let config = match AppConfig::check_file(&watch.path) {
+            Ok(config) => config,
+            Err(issue) => {
+                self.toast = Some(Toast::error(format!("Config not reloaded: {}", issue)));
+                // Details in the errors view, unless something else is open
+                if self.view_mode == ViewMode::List {
+                    self.config_errors = Some(ConfigErrorsModal::new(vec![issue]));
+                    self.view_mode = ViewMode::ConfigErrors;
+                }
+                return;
+            }
+        };
+        // Schedules were checked with the rest
+        let mut scheduler = Scheduler::new(&config.schedule, chrono::Local::now()).unwrap_or_default();
+
+        self.confirm = config.confirm;
+        self.stop_config = config.stop;
+        self.alerts.config = config.alerts;
+        self.cleanup_config = config.cleanup;
+        // Caps apply from the next logs view opened
+        self.logs_config = config.logs;
+        self.registries = config.registries;
+        self.recording_dir = config.recording.target_dir();
+        self.container_list.columns = config.columns;
+        self.projects_dir = config.projects_dir;
+        scheduler.history = std::mem::take(&mut self.scheduler.history);
+        self.scheduler = scheduler;
+
+        let refresh = config.refresh;
+        self.container_refresh_interval = self.cli_refresh_interval.unwrap_or(Duration::from_millis(refresh.containers_ms));
+        self.stats_refresh_interval = Duration::from_millis(refresh.stats_ms);
+        self.vram_refresh_interval = Duration::from_millis(refresh.vram_ms);
+        self.logs_refresh_interval = Duration::from_millis(refresh.logs_ms);
+        self.idle_after = (refresh.idle_minutes > 0).then(|| Duration::from_secs(refresh.idle_minutes * 60));
+        self.idle_slowdown = refresh.idle_slowdown.max(1.0);
+        self.update_check_interval = (refresh.image_updates_hours > 0).then(|| Duration::from_secs(refresh.image_updates_hours * 3600));
+
+        self.effects.set_enabled(!(config.reduced_motion || self.accessible));
+        if !self.accessible {
+            self.theme = self.cli_theme.or(config.theme).unwrap_or_default();
+        }
+
+        let note = if config.read_only != self.read_only { " (read_only applies on restart)" } else { "" };
+        self.toast = Some(Toast::info(format!("Reloaded {}{}", watch.path.display(), note)));
+    }
+
+    /// Run a start/stop/restart/pause/unpause in the background, showing
+    /// the transition on the container's row until it's done. `timeout` is
+    /// the stop grace period (stop and restart only)
+    fn begin_transition(&mut self, name: String, transition: Transition, verb: &'static str, timeout: i64) {
+        if let Some(pending) = self.transitions.get(&name) {
+            self.toast = Some(Toast::error(format!("{} is already {}", name, pending.transition.label())));
+            return;
+        }
+        let docker = self.docker.clone();
+        let target = name.clone();
+        let handle = tokio::spawn(async move {
+            match transition {
+                Transition::Starting => docker.start_container(&target).await,
+                Transition::Stopping => docker.stop_container(&target, timeout).await,
+                Transition::Restarting => docker.restart_container(&target, timeout).await,
+                Transition::Pausing => docker.pause_container(&target).await,
+                Transition::Unpausing => docker.unpause_container(&target).await,
+            }
+        });
+        self.transitions.insert(name, PendingOp { transition, verb, handle });
+    }
+
+    /// Record finished lifecycle operations and refresh to show the real state
+    async fn finish_transitions(&mut self) -> Result<()> {
+        let done: Vec<String> = self.transitions.iter().filter(|(_, p)| p.handle.is_finished()).map(|(n, _)| n.clone()).collect();
+        if done.is_empty() {
+            return Ok(());
+        }
+        self.needs_redraw = true;
+        for name in done {
+            let Some(pending) = self.transitions.remove(&name) else {
+                continue;
+            };
+            let result = match pending.handle.await {
+                Ok(result) => result,
+                Err(e) => Err(anyhow::anyhow!("{} of {} failed: {}", pending.verb, name, e)),
+            };
+            self.audit(pending.verb, &name, audit::outcome(&result));
+            match result {
+                Ok(()) => match pending.transition {
+                    Transition::Starting => self.effects.trigger_status_change(true),
+                    Transition::Stopping => self.effects.trigger_status_change(false),
+                    // Status stays "running", so force a fresh StartedAt
+                    Transition::Restarting => {
+                        if let Some(c) = self.containers.iter().find(|c| c.name == name) {
+                            self.run_states.remove(&c.id);
+                        }
+                    }
+                    Transition::Pausing | Transition::Unpausing => {}
+                },
+                Err(e) => self.toast = Some(Toast::error(format!("{:#}", e))),
+            }
+        }
+        self.refresh_containers().await
+    }
+
+    /// Scan an image with trivy in the background; the result shows as a
+    /// badge in the Details list and opens as a report
+    pub fn start_scan(&mut self, image_id: String, image: String) {
+        if self.scan_jobs.contains_key(&image_id) {
+            return;
+        }
+        let host = self.docker.host().map(str::to_string);
+        self.toast = Some(Toast::info(format!("Scanning {} with trivy...", image)));
+        self.scan_jobs.insert(image_id, tokio::spawn(async move { trivy::scan(&image, host.as_deref()).await }));
+        self.container_list.scanning = self.scan_jobs.keys().cloned().collect();
+    }
+
+    /// Keep the reports of finished scans
+    async fn finish_scans(&mut self) {
+        let done: Vec<String> = self.scan_jobs.iter().filter(|(_, h)| h.is_finished()).map(|(id, _)| id.clone()).collect();
+        for id in done {
+            let Some(handle) = self.scan_jobs.remove(&id) else {
+                continue;
+            };
+            match handle.await {
+                Ok(Ok(report)) => {
+                    let counts = report.counts();
+                    self.toast = Some(Toast::info(format!(
+                        "{}: {} critical, {} high, {} medium",
+                        report.image, counts.critical, counts.high, counts.medium
+                    )));
+                    self.container_list.vulns.insert(id.clone(), counts);
+                    // Refresh an open report after a rescan
+                    if let Some(ref mut modal) = self.vuln_modal {
+                        if modal.report.image == report.image {
+                            *modal = VulnModal::new(report.clone());
+                        }
+                    }
+                    self.scans.insert(id, report);
+                }
+                Ok(Err(e)) => self.toast = Some(Toast::error(format!("{:#}", e))),
+                Err(e) => self.toast = Some(Toast::error(format!("trivy scan failed: {}", e))),
+            }
+        }
+        self.container_list.scanning = self.scan_jobs.keys().cloned().collect();
+    }
+
+    /// Report watched containers that exited, with their exit code
+    async fn finish_exit_watches(&mut self) {
+        let done: Vec<String> = self.exit_watches.iter().filter(|(_, h)| h.is_finished()).map(|(n, _)| n.clone()).collect();
+        for name in done {
+            let Some(handle) = self.exit_watches.remove(&name) else {
+                continue;
+            };
+            let (clean, message) = match handle.await {
+                Ok(Ok(code)) => (code == 0, format!("exited with code {}", code)),
+                Ok(Err(e)) => (false, format!("can't be watched: {:#}", e)),
+                Err(e) => (false, format!("watch failed: {}", e)),
+            };
+            let text = format!("{} {}", name, message);
+            self.toast = Some(if clean { Toast::info(text) } else { Toast::error(text) });
+            if self.alerts.config.bell {
+                crate::alerts::notify(&crate::alerts::Alert {
+                    container: name.clone(),
+                    kind: crate::alerts::AlertKind::Exited,
+                    message,
+                    at: chrono::Local::now(),
+                });
+            }
+        }
+        self.container_list.waiting = self.exit_watches.keys().cloned().collect();
+    }
+
+    /// Look up the sizes of images not in the cache yet on a background task,
+    /// one inspect per image
+    fn start_image_sizes(&mut self) {
+        let ids: Vec<String> = self
+            .image_sizes
+            .missing(self.containers.iter().map(|c| c.image_id.as_str()))
+            .into_iter()
+            .filter(|id| !self.image_size_failed.contains(id))
+            .collect();
+        if ids.is_empty() {
+            return;
+        }
+        let docker = self.docker.clone();
+        self.image_size_fetch = Some(tokio::spawn(async move {
+            let mut fetched = Vec::new();
+            let mut failed = Vec::new();
+            for id in ids {
+                match docker.image_size(&id).await {
+                    Ok(size) => fetched.push((id, size)),
+                    Err(_) => failed.push(id),
+                }
+            }
+            (fetched, failed)
+        }));
+    }
+
+    /// Store the looked-up sizes once the task is done
+    async fn finish_image_sizes(&mut self) {
+        if !self.image_size_fetch.as_ref().is_some_and(|h| h.is_finished()) {
+            return;
+        }
+        let Some(handle) = self.image_size_fetch.take() else {
+            return;
+        };
+        let Ok((fetched, failed)) = handle.await else {
+            return;
+        };
+        self.image_size_failed.extend(failed);
+        let in_use: HashSet<&str> = self.containers.iter().map(|c| c.image_id.as_str()).collect();
+        if let Err(e) = self.image_sizes.insert(fetched, &in_use) {
+            self.toast = Some(Toast::error(format!("{:#}", e)));
+        }
+        self.container_list.image_sizes = self.image_sizes.sizes().clone();
+    }
+
+    /// Compare the digests of the containers' images with their registries
+    /// on a background task
+    async fn start_update_check(&mut self) {
+        self.last_update_check = Instant::now();
+        let mut images: Vec<String> = self
+            .containers
+            .iter()
+            .map(|c| c.image.clone())
+            .filter(|image| registry::image_tag(image).is_some() && !image.starts_with("sha256:"))
+            .collect();
+        images.sort();
+        images.dedup();
+        let mut auth: HashMap<String, RegistryAuth> = HashMap::new();
+        for repo in images.iter().filter_map(|image| RepoRef::parse(image)) {
+            if let Some(credentials) = self.registry_auth(&repo.registry).await {
+                auth.insert(repo.registry, credentials);
+            }
+        }
+
+        let docker = self.docker.clone();
+        self.toast = Some(Toast::info(format!("Checking {} images for updates...", images.len())));
+        self.update_check = Some(tokio::spawn(async move {
+            let mut updated = Vec::new();
+            let mut failed = 0;
+            for image in images {
+                let (Some(repo), Some(tag)) = (RepoRef::parse(&image), registry::image_tag(&image)) else {
+                    continue;
+                };
+                // Locally built images have no registry digest to compare
+                let local = docker.image_repo_digests(&image).await.unwrap_or_default();
+                if local.is_empty() {
+                    continue;
+                }
+                match registry::manifest_digest(&repo, tag, auth.get(&repo.registry)).await {
+                    Ok(remote) if !local.iter().any(|d| d.ends_with(&format!("@{}", remote))) => updated.push(image),
+                    Ok(_) => {}
+                    Err(_) => failed += 1,
+                }
+            }
+            (updated, failed)
+        }));
+    }
+
+    /// Flag containers whose images have updates once the check is done
+    async fn finish_update_check(&mut self) {
+        if !self.update_check.as_ref().is_some_and(|h| h.is_finished()) {
+            return;
+        }
+        let Some(handle) = self.update_check.take() else {
+            return;
+        };
+        let Ok((updated, failed)) = handle.await else {
+            self.toast = Some(Toast::error("Image update check failed"));
+            return;
+        };
+        self.image_updates = updated.into_iter().collect();
+        self.flag_image_updates();
+        let count = self.container_list.updates.len();
+        let unchecked = if failed > 0 { format!(" ({} images couldn't be checked)", failed) } else { String::new() };
+        self.toast = Some(if count == 0 {
+            Toast::info(format!("All images are up to date{}", unchecked))
+        } else {
+            Toast::info(format!("{} containers have newer images (B to update){}", count, unchecked))
+        });
+    }
+
+    /// Mark containers running an image that has an update
+    fn flag_image_updates(&mut self) {
+        self.container_list.updates = self
+            .containers
+            .iter()
+            .filter(|c| self.image_updates.contains(&c.image))
+            .map(|c| c.name.clone())
+            .collect();
+    }
+
+    /// Report an ordered start once its last step is done
+    fn finish_startup(&mut self) {
+        let Some(startup) = self.startup.as_ref().and_then(|s| s.lock().ok().filter(|s| s.finished).map(|s| s.clone())) else {
+            return;
+        };
+        self.startup = None;
+        let failed: Vec<&str> = startup
+            .steps
+            .iter()
+            .filter(|s| matches!(s.state, StepState::Failed(_) | StepState::Skipped))
+            .map(|s| s.name.as_str())
+            .collect();
+        self.toast = Some(if failed.is_empty() {
+            Toast::info(format!("Started {} containers in order", startup.steps.len()))
+        } else {
+            Toast::error(format!("Ordered start: failed or skipped {}", failed.join(", ")))
+        });
+    }
+
+    /// Look up registry tags for the repository typed in the pull dialog
+    async fn fetch_tags(&mut self) {
+        let Some(modal) = self.pull_modal.as_mut() else {
+            return;
+        };
+        let Some(repo) = RepoRef::parse(&modal.repository) else {
+            modal.error = Some("Enter a repository, e.g. nginx or ghcr.io/org/app".to_string());
+            return;
+        };
+        let auth = self.registry_auth(&repo.registry).await;
+        let result = registry::fetch_tags(&repo, auth.as_ref()).await;
+        if let Some(modal) = self.pull_modal.as_mut() {
+            modal.set_tags(repo, result);
+        }
+    }
+
+    /// Credentials for a registry: config.toml first, then the system keyring
+    /// (looked up off the async runtime, once per host)
+    async fn registry_auth(&mut self, registry: &str) -> Option<RegistryAuth> {
+        if let Some(auth) = self.registries.get(registry) {
+            return Some(auth.clone());
+        }
+        if let Some(auth) = self.keyring_auth.get(registry) {
+            return auth.clone();
+        }
+        let host = registry.to_string();
+        let auth = tokio::task::spawn_blocking(move || crate::keyring::load(&host)).await.ok().flatten();
+        self.keyring_auth.insert(registry.to_string(), auth.clone());
+        auth
+    }
+
+    /// Verify the login modal's credentials and remember them
+    async fn login(&mut self) {
+        let Some(modal) = self.login_modal.as_mut() else {
+            return;
+        };
+        let registry = modal.registry.clone();
+        let auth = modal.auth();
+        if let Err(e) = registry::check_login(&registry, &auth).await {
+            modal.error = Some(format!("{:#}", e));
+            return;
+        }
+
+        self.audit("login", &format!("{} as {}", registry, auth.username), "ok".to_string());
+        let (host, credentials) = (registry.clone(), auth.clone());
+        let stored = tokio::task::spawn_blocking(move || crate::keyring::store(&host, &credentials))
+            .await
+            .unwrap_or_else(|e| Err(e.into()));
+        self.toast = Some(match stored {
+            Ok(()) => Toast::info(format!("Logged in to {}", registry)),
+            Err(e) => Toast::error(format!("Logged in to {} for this session only: {}", registry, e)),
+        });
+        self.registries.insert(registry, auth);
+        self.login_modal = None;
+        self.view_mode = ViewMode::List;
+    }
+
+    /// Every group with whether the container belongs to it
+    fn group_choices(&self, container: &str) -> Vec<(String, bool)> {
+        self.groups
+            .names()
+            .into_iter()
+            .map(|group| {
+                let member = self.groups.contains(&group, container);
+                (group, member)
+            })
+            .collect()
+    }
+
+    /// Add the group modal's container to a group or take it out
+    fn toggle_group(&mut self, group: &str) {
+        let Some(container) = self.group_modal.as_ref().map(|m| m.container_name.clone()) else {
+            return;
+        };
+        self.toast = Some(match self.groups.toggle(group, &container) {
+            Ok(true) => Toast::info(format!("Added {} to {}", container, group)),
+            Ok(false) => Toast::info(format!("Removed {} from {}", container, group)),
+            Err(e) => Toast::error(format!("{:#}", e)),
+        });
+        let choices = self.group_choices(&container);
+        if let Some(modal) = self.group_modal.as_mut() {
+            modal.set_groups(choices);
+        }
+        // The filtered group may have lost its last member
+        if self.group_filter.as_deref().is_some_and(|g| self.groups.members(g).is_empty()) {
+            self.group_filter = None;
+        }
+        self.update_filtered_indices();
+    }
+
+    /// Run a checkpoint operation for the managed container, then reload its checkpoints
+    async fn checkpoint_op(&mut self, op: CheckpointOp) -> Result<()> {
+        let Some(name) = self.checkpoint_modal.as_ref().map(|m| m.container_name.clone()) else {
+            return Ok(());
+        };
+        let verb = op.verb();
+        let result = match op {
+            CheckpointOp::Create(leave_running) => {
+                let checkpoint = format!("cp-{}", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+                self.docker.create_checkpoint(&name, &checkpoint, leave_running).await.map(|()| format!("Created {}", checkpoint))
+            }
+            CheckpointOp::Restore(checkpoint) => {
+                self.docker.restore_checkpoint(&name, &checkpoint).await.map(|()| format!("Restored {} from {}", name, checkpoint))
+            }
+            CheckpointOp::Delete(checkpoint) => {
+                self.docker.remove_checkpoint(&name, &checkpoint).await.map(|()| format!("Removed {}", checkpoint))
+            }
+        };
+        self.audit(verb, &name, audit::outcome(&result));
+        let checkpoints = self.docker.list_checkpoints(&name).await;
+
+        if let Some(modal) = self.checkpoint_modal.as_mut() {
+            modal.error = None;
+            match result {
+                Ok(message) => self.toast = Some(Toast::info(message)),
+                Err(e) => modal.error = Some(format!("{:#}", e)),
+            }
+            if let Ok(checkpoints) = checkpoints {
+                modal.set_checkpoints(checkpoints);
+            }
+        }
+        self.refresh_containers().await
+    }
+
+    /// Remove the next queued item of a running cleanup
+    async fn step_cleanup(&mut self) -> Result<()> {
+        let Some(modal) = self.cleanup_modal.as_mut() else {
+            return Ok(());
+        };
+        if modal.phase != CleanupPhase::Running {
+            return Ok(());
+        }
+        let item = modal.next_item();
+        if let Some(ref item) = item {
+            // Containers go to the recycle bin like deleted ones; without a
+            // snapshot to recreate one from, it's kept
+            let result = match item.kind {
+                CleanupKind::Container => match self.docker.snapshot_container(&item.id).await {
+                    Ok(snapshot) => {
+                        let removed = self.docker.remove_cleanup_item(item).await;
+                        if removed.is_ok() {
+                            self.recycle.push(snapshot);
+                        }
+                        removed
+                    }
+                    Err(e) => Err(e.context(format!("Kept container {}: no snapshot to recreate it from", item.name))),
+                },
+                _ => self.docker.remove_cleanup_item(item).await,
+            };
+            let outcome = audit::outcome(&result);
+            modal.record(item, result);
+            let done = modal.phase == CleanupPhase::Done;
+            self.audit(&format!("remove {}", item.kind.as_str()), &item.name, outcome);
+            if done {
+                self.refresh_containers().await?;
+            }
+        } else if modal.phase == CleanupPhase::Done {
+            self.refresh_containers().await?;
+        }
+        Ok(())
+    }
+
+    /// Copy a value of the selected container to the clipboard
+    pub fn yank(&mut self, target: YankTarget) {
+        let Some(value) = self.selected_container().and_then(|c| target.value(c)) else {
+            self.toast = Some(Toast::error(format!("No {} to copy", target.label().to_lowercase())));
+            return;
+        };
+        self.toast = Some(match crate::clipboard::copy(&value) {
+            Ok(()) => Toast::info(format!("Copied {}: {}", target.label().to_lowercase(), value)),
+            Err(e) => Toast::error(format!("Copy failed: {}", e)),
+        });
+    }
+
+    /// Look up what a compose down (or, without a project, a prune) would
+    /// remove; the prompt falls back to a plain yes/no if this fails
+    async fn preview_removal(&mut self, project: Option<&str>) -> Option<RemovalPreview> {
+        match self.docker.removal_preview(project).await {
+            Ok(preview) => Some(preview),
+            Err(e) => {
+                self.toast = Some(Toast::error(format!("No preview: {:#}", e)));
+                None
+            }
+        }
+    }
+
+    /// Run a (confirmed or unprompted) destructive action and record it
+    async fn run_confirmed(&mut self, action: ConfirmAction) -> Result<()> {
+        let (verb, target) = audit::describe_confirmed(&action);
+        // Stops, restarts and image updates finish in the background and are recorded then
+        let background = matches!(
+            action,
+            ConfirmAction::Stop(_)
+                | ConfirmAction::StopNow(_)
+                | ConfirmAction::Restart(_)
+                | ConfirmAction::RestartNow(_)
+                | ConfirmAction::UpdateImage(_)
+        );
+        // Remember the config so the delete can be undone; without it the
+        // container is kept
+        if let ConfirmAction::Delete(ref name) = action {
+            match self.docker.snapshot_container(name).await {
+                Ok(snapshot) => self.recycle.push(snapshot),
+                Err(e) => {
+                    let message = format!("Kept {}: its config couldn't be saved to recreate it: {:#}", name, e);
+                    self.audit(verb, &target, message.clone());
+                    self.toast = Some(Toast::error(message));
+                    return Ok(());
+                }
+            }
+        }
+        let result = self.execute_confirmed(action).await;
+        if !background {
+            self.audit(verb, &target, audit::outcome(&result));
+        }
+        result
+    }
+
+    /// Run a destructive action, then refresh
+    async fn execute_confirmed(&mut self, action: ConfirmAction) -> Result<()> {
+        match action {
+            ConfirmAction::Delete(name) => {
+                self.docker.remove_container(&name).await?;
+            }
+            ConfirmAction::Stop(ref name) | ConfirmAction::StopNow(ref name) => {
+                // A deliberate stop shouldn't be undone by keep-alive
+                self.keep_alive.unwatch(name);
+                let timeout = if matches!(action, ConfirmAction::StopNow(_)) { 0 } else { self.stop_config.timeout(name, false) };
+                let verb = audit::describe_confirmed(&action).0;
+                self.begin_transition(name.clone(), Transition::Stopping, verb, timeout);
+                return Ok(());
+            }
+            ConfirmAction::Restart(ref name) | ConfirmAction::RestartNow(ref name) => {
+                let timeout = if matches!(action, ConfirmAction::RestartNow(_)) { 0 } else { self.stop_config.timeout(name, true) };
+                let verb = audit::describe_confirmed(&action).0;
+                self.begin_transition(name.clone(), Transition::Restarting, verb, timeout);
+                return Ok(());
+            }
+            ConfirmAction::ComposeDown(project) => {
+                let removed = self.docker.compose_down(&project).await?;
+                self.toast = Some(Toast::info(format!("{}: removed {} containers", project, removed)));
+            }
+            ConfirmAction::PruneContainers => {
+                let (removed, reclaimed) = self.docker.prune_containers().await?;
+                self.toast = Some(Toast::info(format!(
+                    "Pruned {} containers, reclaimed {:.1} MB",
+                    removed,
+                    reclaimed as f64 / (1024.0 * 1024.0)
+                )));
+            }
+            ConfirmAction::UpdateImage(name) => {
+                let image = self.containers.iter().find(|c| c.name == name).map(|c| c.image.clone()).unwrap_or_default();
+                let auth = match RepoRef::parse(&image) {
+                    Some(repo) => self.registry_auth(&repo.registry).await.map(|auth| (repo.registry, auth)),
+                    None => None,
+                };
+                let docker = self.docker.clone();
+                let job_name = name.clone();
+                let handle = tokio::spawn(async move { docker.update_container(&job_name, auth).await });
+                self.toast = Some(Toast::info(format!("Updating {}...", name)));
+                self.image_updates.remove(&image);
+                self.jobs.push(BackgroundJob {
+                    message: format!("Updated {} to the latest {}", name, image),
+                    audit: Some(("update image".to_string(), name)),
+                    handle,
+                });
+            }
+            ConfirmAction::RestartService(name) => {
+                self.docker.force_update_service(&name).await?;
+                self.toast = Some(Toast::info(format!("Rolling restart of {} started", name)));
+                self.refresh_services().await;
+            }
+            ConfirmAction::Batch(op, names) => {
+                // Dependencies among them: start in order on a background task
+                if matches!(op, BatchOp::Start) && self.startup.is_none() {
+                    if let Some(plan) = Startup::plan("Starting in dependency order".to_string(), &names, &self.containers) {
+                        self.startup = Some(plan.spawn(self.docker.clone()));
+                        return self.refresh_containers().await;
+                    }
+                }
+                let mut failed = Vec::new();
+                for name in &names {
+                    let result = match op {
+                        BatchOp::Start => self.docker.start_container(name).await,
+                        BatchOp::Stop => {
+                            self.keep_alive.unwatch(name);
+                            self.docker.stop_container(name, self.stop_config.timeout(name, false)).await
+                        }
+                    };
+                    if result.is_err() {
+                        failed.push(name.as_str());
+                    }
+                }
+                self.toast = Some(if failed.is_empty() {
+                    Toast::info(format!("{} {} containers", op.past_tense(), names.len()))
+                } else {
+                    Toast::error(format!("Failed to {} {}", op.as_str(), failed.join(", ")))
+                });
+            }
+        }
+        self.refresh_containers().await
+    }
+
+    /// Run an action, recording mutating ones in the audit log
+    pub async fn handle_action(&mut self, action: Action) -> Result<()> {
+        let audited = audit::describe_action(&action);
+        let result = self.dispatch(action).await;
+        if let Some((verb, target)) = audited {
+            self.audit(verb, &target, audit::outcome(&result));
+        }
+        result
+    }
+
+    async fn dispatch(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::Quit => {
+                match self.view_mode {
+                    ViewMode::Create => self.view_mode = ViewMode::List,
+                    ViewMode::Filter => {
+                        self.filter.deactivate();
+                        self.update_filtered_indices();
+                        self.view_mode = ViewMode::List;
+                    }
+                    ViewMode::Exec => {
+                        self.exec_modal = None;
+                        self.view_mode = ViewMode::List;
+                    }
+                    _ => self.should_quit = true,
+                }
+            }
+
+            Action::Up => match self.view_mode {
+                ViewMode::List | ViewMode::Filter => {
+                    self.container_list.previous(self.nav_item_count())
+                }
+                ViewMode::Logs => self.logs_view.scroll_up(1),
+                ViewMode::Services => self.services_view.previous(self.services.len()),
+                ViewMode::Pods => self.pods_view.previous(self.pods.len()),
+                ViewMode::Host => self.host_view.previous(self.host_view.row_count(&self.host_processes)),
+                ViewMode::Events => self.events_view.previous(self.event_count()),
+                ViewMode::Charts | ViewMode::Compare => {}
+                ViewMode::Create => self.create_form.up(),
+                ViewMode::Exec => {
+                    if let Some(ref mut modal) = self.exec_modal {
+                        modal.previous();
+                    }
+                }
+                ViewMode::Info | ViewMode::Rename | ViewMode::CopyFiles | ViewMode::ImageHistory
+                | ViewMode::Cleanup | ViewMode::Pull | ViewMode::Login | ViewMode::Diff
+                | ViewMode::Checkpoints | ViewMode::Labels | ViewMode::Forward | ViewMode::Vulns | ViewMode::Gpus | ViewMode::Compose | ViewMode::ConfigErrors => {} // No scrolling
+                ViewMode::Groups => {
+                    if let Some(ref mut modal) = self.group_modal {
+                        modal.previous();
+                    }
+                }
+                ViewMode::Processes => {
+                    if let Some(ref mut modal) = self.processes_modal {
+                        modal.scroll_up();
+                    }
                 }
             },
 
@@ -543,19 +2634,25 @@ impl App {
                     self.container_list.next(self.nav_item_count())
                 }
                 ViewMode::Logs => self.logs_view.scroll_down(1, self.logs.len()),
-                ViewMode::Create => {
-                    if self.create_form.mode == CreateMode::ImageSelect {
-                        self.create_form.next_image();
-                    } else {
-                        self.create_form.next_field();
-                    }
-                }
+                ViewMode::Services => self.services_view.next(self.services.len()),
+                ViewMode::Pods => self.pods_view.next(self.pods.len()),
+                ViewMode::Host => self.host_view.next(self.host_view.row_count(&self.host_processes)),
+                ViewMode::Events => self.events_view.next(self.event_count()),
+                ViewMode::Charts | ViewMode::Compare => {}
+                ViewMode::Create => self.create_form.down(),
                 ViewMode::Exec => {
                     if let Some(ref mut modal) = self.exec_modal {
                         modal.next();
                     }
                 }
-                ViewMode::Info | ViewMode::Rename | ViewMode::CopyFiles => {} // No scrolling
+                ViewMode::Info | ViewMode::Rename | ViewMode::CopyFiles | ViewMode::ImageHistory
+                | ViewMode::Cleanup | ViewMode::Pull | ViewMode::Login | ViewMode::Diff
+                | ViewMode::Checkpoints | ViewMode::Labels | ViewMode::Forward | ViewMode::Vulns | ViewMode::Gpus | ViewMode::Compose | ViewMode::ConfigErrors => {} // No scrolling
+                ViewMode::Groups => {
+                    if let Some(ref mut modal) = self.group_modal {
+                        modal.next();
+                    }
+                }
                 ViewMode::Processes => {
                     if let Some(ref mut modal) = self.processes_modal {
                         modal.scroll_down();
@@ -566,6 +2663,7 @@ impl App {
             Action::Top => match self.view_mode {
                 ViewMode::List | ViewMode::Filter => self.container_list.top(),
                 ViewMode::Logs => self.logs_view.top(),
+                ViewMode::Events => self.events_view.top(),
                 _ => {}
             },
 
@@ -574,6 +2672,35 @@ impl App {
                     self.container_list.bottom(self.nav_item_count())
                 }
                 ViewMode::Logs => self.logs_view.bottom(self.logs.len()),
+                ViewMode::Events => self.events_view.bottom(self.event_count()),
+                _ => {}
+            },
+
+            Action::MoveBy(delta) => self.move_by(delta),
+
+            Action::HalfPageUp | Action::HalfPageDown => {
+                let page = match self.view_mode {
+                    ViewMode::Logs => self.logs_view.page_height,
+                    _ => self.container_list.page_height,
+                };
+                let half = (page / 2).max(1) as isize;
+                self.move_by(if action == Action::HalfPageUp { -half } else { half });
+            }
+
+            Action::ScreenTop | Action::ScreenMiddle | Action::ScreenBottom => match self.view_mode {
+                ViewMode::List => {
+                    let at = match action {
+                        Action::ScreenTop => 0.0,
+                        Action::ScreenMiddle => 0.5,
+                        _ => 1.0,
+                    };
+                    self.container_list.select_on_screen(at, self.nav_item_count());
+                }
+                ViewMode::Logs => match action {
+                    Action::ScreenTop => self.logs_view.top(),
+                    Action::ScreenMiddle => self.logs_view.middle(self.logs.len()),
+                    _ => self.logs_view.bottom(self.logs.len()),
+                },
                 _ => {}
             },
 
@@ -581,22 +2708,181 @@ impl App {
                 self.load_logs(&name).await?;
             }
 
+            Action::ViewProjectLogs(project) => {
+                self.logs_container = format!("project {}", project);
+                self.logs_project = Some(project);
+                self.logs_tail = self.logs_config.tail;
+                match self.fetch_logs(self.logs_tail, None).await {
+                    Ok(lines) => {
+                        self.logs.reset(self.logs_config.max_lines, self.logs_config.max_bytes());
+                        self.logs.replace(lines);
+                        self.logs_view = LogsView::new();
+                        self.logs_view.prefixed = true;
+                        self.view_mode = ViewMode::Logs;
+                    }
+                    Err(e) => {
+                        self.logs_project = None;
+                        self.toast = Some(Toast::error(format!("{:#}", e)));
+                    }
+                }
+            }
+
+            Action::ViewCharts(name) => {
+                self.chart_container = name;
+                self.view_mode = ViewMode::Charts;
+            }
+
+            Action::ViewCompare => {
+                self.view_mode = ViewMode::Compare;
+            }
+
+            Action::ViewServices => {
+                match self.docker.is_swarm_manager().await {
+                    Ok(true) => {
+                        self.refresh_services().await;
+                        self.view_mode = ViewMode::Services;
+                    }
+                    Ok(false) => self.toast = Some(Toast::error("Not connected to a Swarm manager".to_string())),
+                    Err(e) => self.toast = Some(Toast::error(format!("{}", e))),
+                }
+            }
+
+            Action::ViewPods => {
+                self.open_pods().await;
+            }
+
+            Action::ViewHostProcesses => {
+                self.refresh_host_processes();
+                self.host_view.cpu_pending = true;
+                self.view_mode = ViewMode::Host;
+            }
+
+            Action::ViewEvents => {
+                self.events_view.top();
+                self.view_mode = ViewMode::Events;
+            }
+
+            Action::StartEventFilter => {
+                self.events_view.input = Some(self.events_view.filter.clone());
+            }
+
+            Action::ViewGpus => {
+                self.gpu_modal = Some(GpuModal::default());
+                self.view_mode = ViewMode::Gpus;
+            }
+
+            Action::ToggleProcessGrouping => {
+                self.host_view.grouped = !self.host_view.grouped;
+                self.refresh_host_processes();
+            }
+
+            Action::CycleProjectSort => {
+                self.project_sort = self.project_sort.cycle();
+                self.update_filtered_indices();
+                self.toast = Some(Toast::info(format!("Projects sorted by {}", self.project_sort.as_str())));
+            }
+
+            Action::ToggleProcessSort => {
+                self.host_view.sort = self.host_view.sort.toggle();
+                // Re-read rather than re-sort: the rows kept depend on the sort
+                self.refresh_host_processes();
+            }
+
+            Action::NextNamespace if !self.namespaces.is_empty() => {
+                let i = self.namespaces.iter().position(|ns| *ns == self.namespace).map_or(0, |i| i + 1);
+                self.namespace = self.namespaces[i % self.namespaces.len()].clone();
+                self.pods_view.state.select(Some(0));
+                self.refresh_pods().await;
+            }
+
+            Action::ViewPodLogs(namespace, pod) => {
+                self.logs_container = format!("{}/{}", namespace, pod);
+                self.logs_pod = Some((namespace, pod));
+                self.logs_tail = self.logs_config.tail;
+                match self.fetch_logs(self.logs_tail, None).await {
+                    Ok(lines) => {
+                        self.logs.reset(self.logs_config.max_lines, self.logs_config.max_bytes());
+                        self.logs.replace(lines);
+                        self.logs_view = LogsView::new();
+                        self.view_mode = ViewMode::Logs;
+                    }
+                    Err(e) => {
+                        self.logs_pod = None;
+                        self.toast = Some(Toast::error(format!("{:#}", e)));
+                    }
+                }
+            }
+
+            Action::ScaleService(name, replicas) => {
+                let result = self.docker.scale_service(&name, replicas).await;
+                self.audit("scale", &format!("{} to {}", name, replicas), audit::outcome(&result));
+                self.toast = Some(match result {
+                    Ok(()) => Toast::info(format!("Scaling {} to {} replicas", name, replicas)),
+                    Err(e) => Toast::error(format!("{:#}", e)),
+                });
+                self.refresh_services().await;
+            }
+
+            Action::ToggleMark(name) => {
+                if !self.container_list.marked.remove(&name) {
+                    self.container_list.marked.insert(name);
+                }
+                self.container_list.next(self.nav_item_count());
+            }
+
+            Action::TogglePin(name) => {
+                self.toast = Some(match self.pins.toggle(&name) {
+                    Ok(true) => Toast::info(format!("Pinned {}", name)),
+                    Ok(false) => Toast::info(format!("Unpinned {}", name)),
+                    Err(e) => Toast::error(format!("{:#}", e)),
+                });
+                self.update_filtered_indices();
+            }
+
             Action::BackToList => {
-                self.view_mode = ViewMode::List;
+                // Pod logs go back to the pods tab
+                self.view_mode = if self.logs_pod.take().is_some() { ViewMode::Pods } else { ViewMode::List };
+                self.logs_project = None;
                 self.logs.clear();
                 self.logs_container.clear();
             }
 
+            Action::ShowYank => {
+                self.modal = ModalState::Yank;
+            }
+
+            Action::ShowPalette => {
+                self.palette = Some(CommandPalette::new(self.key_context()));
+                self.modal = ModalState::Palette;
+            }
+
+            Action::ShowActionMenu => {
+                if let Some(container) = self.selected_container() {
+                    self.action_menu = ActionMenu::new(container, self.read_only);
+                    self.modal = ModalState::Actions;
+                }
+            }
+
             Action::ShowHelp => {
+                self.help.scroll = 0;
                 self.modal = ModalState::Help;
             }
 
-            Action::ShowConfirmDelete(name) => {
-                self.modal = ModalState::Confirm(ConfirmAction::Delete(name));
+            Action::ReadOnlyBlocked(desc) => {
+                self.toast = Some(Toast::error(format!("Read-only mode: {} is disabled", desc)));
             }
 
-            Action::ShowConfirmStop(name) => {
-                self.modal = ModalState::Confirm(ConfirmAction::Stop(name));
+            Action::ShowConfirm(action) => {
+                if action.needs_confirmation(&self.confirm) {
+                    self.confirm_preview = match action {
+                        ConfirmAction::ComposeDown(ref project) => self.preview_removal(Some(project)).await,
+                        ConfirmAction::PruneContainers => self.preview_removal(None).await,
+                        _ => None,
+                    };
+                    self.modal = ModalState::Confirm(action);
+                } else {
+                    self.run_confirmed(action).await?;
+                }
             }
 
             Action::CloseModal => {
@@ -604,35 +2890,35 @@ impl App {
             }
 
             Action::ConfirmAction => {
-                if let ModalState::Confirm(ref confirm) = self.modal.clone() {
-                    match confirm {
-                        ConfirmAction::Delete(name) => {
-                            self.docker.remove_container(name).await?;
-                        }
-                        ConfirmAction::Stop(name) => {
-                            self.docker.stop_container(name).await?;
-                        }
-                    }
+                if let ModalState::Confirm(confirm) = self.modal.clone() {
                     self.modal = ModalState::None;
-                    self.refresh_containers().await?;
+                    self.run_confirmed(confirm).await?;
+                }
+            }
+
+            Action::ConfirmAlternative => {
+                if let ModalState::Confirm(confirm) = self.modal.clone() {
+                    if let Some((_, _, alternative)) = confirm.alternative() {
+                        self.modal = ModalState::None;
+                        self.run_confirmed(alternative).await?;
+                    }
                 }
             }
 
             Action::StartContainer(name) => {
-                self.docker.start_container(&name).await?;
-                self.effects.trigger_status_change(true);
-                self.refresh_containers().await?;
+                self.begin_transition(name, Transition::Starting, "start", 0);
             }
 
             Action::StopContainer(name) => {
-                self.docker.stop_container(&name).await?;
-                self.effects.trigger_status_change(false);
-                self.refresh_containers().await?;
+                // A deliberate stop shouldn't be undone by keep-alive
+                self.keep_alive.unwatch(&name);
+                let timeout = self.stop_config.timeout(&name, false);
+                self.begin_transition(name, Transition::Stopping, "stop", timeout);
             }
 
             Action::RestartContainer(name) => {
-                self.docker.restart_container(&name).await?;
-                self.refresh_containers().await?;
+                let timeout = self.stop_config.timeout(&name, true);
+                self.begin_transition(name, Transition::Restarting, "restart", timeout);
             }
 
             Action::DeleteContainer(name) => {
@@ -641,13 +2927,11 @@ impl App {
             }
 
             Action::PauseContainer(name) => {
-                self.docker.pause_container(&name).await?;
-                self.refresh_containers().await?;
+                self.begin_transition(name, Transition::Pausing, "pause", 0);
             }
 
             Action::UnpauseContainer(name) => {
-                self.docker.unpause_container(&name).await?;
-                self.refresh_containers().await?;
+                self.begin_transition(name, Transition::Unpausing, "unpause", 0);
             }
 
             Action::RenameContainer(old_name, new_name) => {
@@ -660,6 +2944,204 @@ impl App {
                 self.view_mode = ViewMode::Rename;
             }
 
+            Action::ShowCleanup => {
+                match self.docker.cleanup_candidates(self.cleanup_config.container_age_days).await {
+                    Ok(items) => {
+                        self.cleanup_modal = Some(CleanupModal::new(items));
+                        self.view_mode = ViewMode::Cleanup;
+                    }
+                    Err(e) => self.toast = Some(Toast::error(format!("{}", e))),
+                }
+            }
+
+            Action::ShowPull => {
+                // Start from the selected container's repository, handy for finding newer tags
+                let repository = self
+                    .selected_container()
+                    .and_then(|c| RepoRef::parse(&c.image))
+                    .map(|r| r.image())
+                    .unwrap_or_default();
+                self.pull_modal = Some(PullModal::new(repository));
+                self.view_mode = ViewMode::Pull;
+            }
+
+            Action::FetchTags => {
+                self.fetch_tags().await;
+            }
+
+            Action::ShowLogin => {
+                let registry = self
+                    .selected_container()
+                    .and_then(|c| RepoRef::parse(&c.image))
+                    .map(|r| r.registry)
+                    .unwrap_or_default();
+                self.login_modal = Some(LoginModal::new(registry));
+                self.view_mode = ViewMode::Login;
+            }
+
+            Action::Login => {
+                self.login().await;
+            }
+
+            Action::PullImage(image, tag) => {
+                let reference = format!("{}:{}", image, tag);
+                let auth = match RepoRef::parse(&image) {
+                    Some(repo) => self.registry_auth(&repo.registry).await.map(|auth| (repo.registry, auth)),
+                    None => None,
+                };
+                let docker = self.docker.clone();
+                let handle = tokio::spawn(async move { docker.pull_image(&image, &tag, auth).await });
+                self.toast = Some(Toast::info(format!("Pulling {}...", reference)));
+                self.jobs.push(BackgroundJob {
+                    message: format!("Pulled {}", reference),
+                    audit: Some(("pull".to_string(), reference)),
+                    handle,
+                });
+            }
+
+            Action::ShowDiff(name) => {
+                match self.docker.container_changes(&name).await {
+                    Ok(changes) => {
+                        self.diff_modal = Some(DiffModal::new(name, changes));
+                        self.view_mode = ViewMode::Diff;
+                    }
+                    Err(e) => self.toast = Some(Toast::error(format!("{}", e))),
+                }
+            }
+
+            Action::ShowCompose(name) => {
+                let Some(container) = self.containers.iter().find(|c| c.name == name) else {
+                    return Ok(());
+                };
+                match (&container.compose_project, container.compose_files.is_empty()) {
+                    (Some(project), false) => {
+                        let files = container
+                            .compose_files
+                            .iter()
+                            .map(|path| ComposeFile {
+                                path: path.clone(),
+                                // The path is on the Docker host, which may not be this machine
+                                content: std::fs::read_to_string(path).map_err(|e| e.to_string()),
+                            })
+                            .collect();
+                        self.compose_modal = Some(ComposeModal::new(project.clone(), container.compose_service.clone(), files));
+                        self.view_mode = ViewMode::Compose;
+                    }
+                    _ => self.toast = Some(Toast::error(format!("{} is not managed by compose", name))),
+                }
+            }
+
+            Action::ScaffoldProject(name) => {
+                let Some(ref dir) = self.projects_dir else {
+                    self.toast = Some(Toast::error("Set projects_dir in config.toml to write project.yaml files".to_string()));
+                    return Ok(());
+                };
+                let written = match self.docker.project_source(&name).await {
+                    Ok(source) => crate::scaffold::write_file(dir, &source),
+                    Err(e) => Err(e),
+                };
+                self.toast = Some(match written {
+                    Ok(path) => Toast::info(format!("Wrote {}", path.display())),
+                    Err(e) => Toast::error(format!("Scaffold failed: {}", e)),
+                });
+            }
+
+            Action::ShowGroups(name) => {
+                let choices = self.group_choices(&name);
+                self.group_modal = Some(GroupModal::new(name, choices));
+                self.view_mode = ViewMode::Groups;
+            }
+
+            Action::ShowLabels(targets) => {
+                self.label_modal = Some(LabelModal::new(targets));
+                self.view_mode = ViewMode::Labels;
+            }
+
+            Action::ApplyLabels => {
+                let Some(modal) = self.label_modal.take() else {
+                    return Ok(());
+                };
+                self.view_mode = ViewMode::List;
+                let changed = modal.changed();
+                if changed.is_empty() {
+                    self.toast = Some(Toast::info("No labels to change"));
+                    return Ok(());
+                }
+                for name in &changed {
+                    let docker = self.docker.clone();
+                    let (job_name, edits) = (name.clone(), modal.edits.clone());
+                    let handle = tokio::spawn(async move { docker.relabel_container(&job_name, &edits).await });
+                    self.jobs.push(BackgroundJob {
+                        message: format!("Relabeled {}", name),
+                        audit: Some(("relabel".to_string(), format!("{}: {}", name, modal.input.trim()))),
+                        handle,
+                    });
+                }
+                self.toast = Some(Toast::info(format!("Recreating {} containers with new labels...", changed.len())));
+            }
+
+            Action::ToggleGroup(group) => {
+                self.toggle_group(&group);
+            }
+
+            Action::CycleGroupFilter => {
+                self.group_filter = self.groups.next_after(self.group_filter.as_deref());
+                self.update_filtered_indices();
+                self.toast = Some(Toast::info(match self.group_filter {
+                    Some(ref group) => format!("Group {} ({} containers)", group, self.groups.members(group).len()),
+                    None => "Showing all groups".to_string(),
+                }));
+            }
+
+            Action::ShowCheckpoints(name) => {
+                let mut modal = CheckpointModal::new(name.clone());
+                match self.docker.list_checkpoints(&name).await {
+                    Ok(checkpoints) => modal.set_checkpoints(checkpoints),
+                    Err(e) => modal.error = Some(format!("{:#}", e)),
+                }
+                self.checkpoint_modal = Some(modal);
+                self.view_mode = ViewMode::Checkpoints;
+            }
+
+            Action::CreateCheckpoint(leave_running) => {
+                self.checkpoint_op(CheckpointOp::Create(leave_running)).await?;
+            }
+
+            Action::RestoreCheckpoint(checkpoint) => {
+                self.checkpoint_op(CheckpointOp::Restore(checkpoint)).await?;
+            }
+
+            Action::DeleteCheckpoint(checkpoint) => {
+                self.checkpoint_op(CheckpointOp::Delete(checkpoint)).await?;
+            }
+
+            Action::ShowImageHistory(image) => {
+                match self.docker.image_history(&image).await {
+                    Ok(layers) => {
+                        self.image_history_modal = Some(ImageHistoryModal::new(image, layers));
+                        self.view_mode = ViewMode::ImageHistory;
+                    }
+                    Err(e) => self.toast = Some(Toast::error(format!("{}", e))),
+                }
+            }
+
+            Action::ShowVulns(name) => {
+                let Some(c) = self.containers.iter().find(|c| c.name == name) else {
+                    return Ok(());
+                };
+                let (id, image) = (c.image_id.clone(), c.image.clone());
+                match self.scans.get(&id) {
+                    Some(report) => {
+                        self.vuln_modal = Some(VulnModal::new(report.clone()));
+                        self.view_mode = ViewMode::Vulns;
+                    }
+                    None if self.scan_jobs.contains_key(&id) => {
+                        self.toast = Some(Toast::info(format!("Still scanning {}", image)));
+                    }
+                    None => self.start_scan(id, image),
+                }
+            }
+
             Action::ShowProcesses(name) => {
                 if let Ok(processes) = self.docker.top_container(&name).await {
                     self.processes_modal = Some(ProcessesModal::new(name, processes));
@@ -673,21 +3155,33 @@ impl App {
             }
 
             Action::CopyFromContainer(container, container_path, host_path) => {
-                // Use docker cp command
-                let _ = std::process::Command::new("docker")
-                    .args(["cp", &format!("{}:{}", container, container_path), &host_path])
-                    .status();
+                self.start_copy(format!("{}:{}", container, container_path), host_path);
             }
 
             Action::CopyToContainer(container, host_path, container_path) => {
-                // Use docker cp command
-                let _ = std::process::Command::new("docker")
-                    .args(["cp", &host_path, &format!("{}:{}", container, container_path)])
-                    .status();
+                self.start_copy(host_path, format!("{}:{}", container, container_path));
             }
 
             Action::Refresh => {
+                if self.view_mode == ViewMode::Services {
+                    self.refresh_services().await;
+                }
+                if self.view_mode == ViewMode::Pods {
+                    self.refresh_pods().await;
+                }
                 self.refresh_containers().await?;
+                if self.view_mode == ViewMode::Host {
+                    self.refresh_host_processes();
+                }
+            }
+
+            Action::Export(format) => {
+                let filtered = self.filtered_containers();
+                let count = filtered.len();
+                self.toast = Some(match crate::export::write_file(&filtered, format) {
+                    Ok(path) => Toast::info(format!("Exported {} containers to {}", count, path.display())),
+                    Err(e) => Toast::error(format!("Export failed: {}", e)),
+                });
             }
 
             Action::CycleStatusFilter => {
@@ -695,13 +3189,154 @@ impl App {
                 self.update_filtered_indices();
             }
 
+            Action::NewTab => self.open_tab(),
+            Action::CloseTab => self.close_tab(),
+            Action::SwitchTab(index) => self.switch_tab(index),
+
+            Action::ToggleAlerts => {
+                self.show_alerts = !self.show_alerts;
+            }
+
+            Action::ChangeRefreshSpeed(faster) => {
+                // Halve or double every interval, between 4x faster and 8x slower
+                self.refresh_scale = if faster { self.refresh_scale / 2.0 } else { self.refresh_scale * 2.0 }.clamp(0.25, 8.0);
+                let [containers, stats, ..] = self.refresh_intervals();
+                self.toast = Some(Toast::info(format!(
+                    "Refresh x{}: containers {:.1}s, stats {:.1}s",
+                    self.refresh_scale,
+                    containers.1.as_secs_f64(),
+                    stats.1.as_secs_f64()
+                )));
+            }
+
+            Action::FocusExecPane => {
+                self.exec_focused = self.exec_pane.is_some();
+            }
+
+            Action::LoadMoreLogs if self.view_mode == ViewMode::Logs => self.load_more_logs().await,
+
+            Action::StartGoToTime => {
+                self.logs_view.goto = Some(String::new());
+            }
+
+            Action::StartLogFilter => {
+                self.logs_view.filter_input = Some(String::new());
+                self.logs_view.filter_selected = self.logs.filters().len().saturating_sub(1);
+            }
+
+            Action::StartStdin => match self.docker.stdin_open(&self.logs_container).await {
+                Ok(true) => self.logs_view.stdin = Some(String::new()),
+                Ok(false) => {
+                    self.toast = Some(Toast::error(format!("{} was not started with stdin open (-i)", self.logs_container)));
+                }
+                Err(e) => self.toast = Some(Toast::error(format!("{}", e))),
+            },
+
+            Action::ToggleSplitView => {
+                self.split_view = !self.split_view;
+                self.detail_logs = None;
+            }
+
+            Action::ToggleExpandedHeader => {
+                self.expanded_header = !self.expanded_header;
+                self.refresh_system_stats();
+            }
+
+            Action::ToggleKeepAlive(name) => {
+                let watched = self.keep_alive.toggle(&name);
+                self.container_list.keep_alive = self.keep_alive.restarts();
+                let state = if watched { "on" } else { "off" };
+                self.toast = Some(Toast::info(format!("Keep-alive {} for {}", state, name)));
+            }
+
+            Action::ToggleExitWatch(name) => {
+                if let Some(handle) = self.exit_watches.remove(&name) {
+                    handle.abort();
+                    self.toast = Some(Toast::info(format!("No longer waiting for {} to exit", name)));
+                } else if self.containers.iter().any(|c| c.name == name && c.status.is_active()) {
+                    let docker = self.docker.clone();
+                    let job_name = name.clone();
+                    let handle = tokio::spawn(async move { docker.wait_for_exit(&job_name).await });
+                    self.exit_watches.insert(name.clone(), handle);
+                    self.toast = Some(Toast::info(format!("Will notify when {} exits", name)));
+                } else {
+                    self.toast = Some(Toast::error(format!("{} isn't running", name)));
+                }
+                self.container_list.waiting = self.exit_watches.keys().cloned().collect();
+            }
+
+            Action::CheckImageUpdates if self.update_check.is_none() => {
+                self.start_update_check().await;
+            }
+
+            Action::ToggleRecycleBin => {
+                self.show_recycle = !self.show_recycle;
+            }
+
+            Action::ToggleAuditLog => {
+                self.show_audit = !self.show_audit;
+            }
+
+            Action::ToggleSchedule => {
+                self.show_schedule = !self.show_schedule;
+            }
+
+            Action::ToggleDaemon => {
+                self.show_daemon = !self.show_daemon;
+                if self.show_daemon {
+                    self.daemon_info = match self.docker.daemon_info().await {
+                        Ok(info) => Some(info),
+                        Err(e) => {
+                            self.toast = Some(Toast::error(format!("{:#}", e)));
+                            None
+                        }
+                    };
+                }
+            }
+
+            Action::ShowForward(name) => {
+                let Some(container) = self.containers.iter().find(|c| c.name == name) else {
+                    return Ok(());
+                };
+                match container.ip() {
+                    Some(ip) => {
+                        self.forward_modal = Some(ForwardModal::new(container, ip));
+                        self.view_mode = ViewMode::Forward;
+                    }
+                    None => self.toast = Some(Toast::error(format!("{} has no IP address (is it running?)", name))),
+                }
+            }
+
+            Action::RecreateDeleted => {
+                if let Some(deleted) = self.recycle.pop() {
+                    let result = self.docker.recreate_container(&deleted).await;
+                    self.audit("recreate", &deleted.name, audit::outcome(&result));
+                    match result {
+                        Ok(()) => self.toast = Some(Toast::info(format!("Recreated {}", deleted.name))),
+                        Err(e) => {
+                            self.toast = Some(Toast::error(format!("Recreate failed: {}", e)));
+                            // Keep it around so the user can retry
+                            self.recycle.push(deleted);
+                        }
+                    }
+                    self.refresh_containers().await?;
+                }
+            }
+
+            Action::SetStatusFilter(filter) => {
+                self.status_filter = filter;
+                self.update_filtered_indices();
+            }
+
             Action::Tick => {
                 self.tick().await?;
             }
 
             Action::Left => {
                 // Cycle list view mode backwards
-                if self.view_mode == ViewMode::List {
+                if matches!(self.view_mode, ViewMode::Charts | ViewMode::Compare) {
+                    self.chart_range = self.chart_range.previous();
+                } else if self.view_mode == ViewMode::List {
                     self.list_view_mode = match self.list_view_mode {
                         ListViewMode::Stats => ListViewMode::Details,
                         ListViewMode::Network => ListViewMode::Stats,
@@ -712,7 +3347,9 @@ impl App {
 
             Action::Right => {
                 // Cycle list view mode forwards
-                if self.view_mode == ViewMode::List {
+                if matches!(self.view_mode, ViewMode::Charts | ViewMode::Compare) {
+                    self.chart_range = self.chart_range.next();
+                } else if self.view_mode == ViewMode::List {
                     self.list_view_mode = match self.list_view_mode {
                         ListViewMode::Stats => ListViewMode::Network,
                         ListViewMode::Network => ListViewMode::Details,
@@ -736,15 +3373,70 @@ impl App {
             .style(ratatui::prelude::Style::default().bg(Theme::BG));
         frame.render_widget(bg_block, frame.area());
 
-        let (header_area, body, footer) = main_layout(frame.area());
+        if crate::ui::layout::is_too_small(frame.area()) {
+            render_too_small(frame);
+            return;
+        }
+
+        let header_height = if self.expanded_header { 2 } else { 1 };
+        let (header_area, body, footer) = main_layout(frame.area(), header_height);
+        let body = match self.tabs.len() {
+            1 => body,
+            _ => {
+                let chunks = ratatui::prelude::Layout::default()
+                    .direction(ratatui::prelude::Direction::Vertical)
+                    .constraints([ratatui::prelude::Constraint::Length(1), ratatui::prelude::Constraint::Min(0)])
+                    .split(body);
+                TabBar::render(frame, chunks[0], &self.tab_labels(), self.active_tab);
+                chunks[1]
+            }
+        };
 
-        // Header with system stats
-        Header::render(frame, header_area, &self.system_stats, self.system_stats.vram_percent, self.loading);
+        // Header with system stats (plus per-core line when expanded)
+        Header::render(
+            frame,
+            ratatui::prelude::Rect { height: 1, ..header_area },
+            &self.system_stats,
+            self.system_stats.vram_percent,
+            self.loading,
+            self.effects.spinner_frame(),
+            self.last_container_refresh.elapsed(),
+        );
+        if self.expanded_header {
+            let cores_area = ratatui::prelude::Rect { y: header_area.y + 1, height: 1, ..header_area };
+            Header::render_cores(frame, cores_area, &self.system_stats);
+        }
 
         // Main content area based on view mode
         match self.view_mode {
             ViewMode::List | ViewMode::Filter | ViewMode::Create | ViewMode::Exec | ViewMode::Info
-            | ViewMode::Rename | ViewMode::Processes | ViewMode::CopyFiles => {
+            | ViewMode::Rename | ViewMode::Processes | ViewMode::CopyFiles | ViewMode::ImageHistory
+            | ViewMode::Cleanup | ViewMode::Pull | ViewMode::Login | ViewMode::Diff | ViewMode::Checkpoints
+            | ViewMode::Groups | ViewMode::Labels | ViewMode::Forward | ViewMode::Vulns | ViewMode::Gpus
+            | ViewMode::Compose | ViewMode::ConfigErrors => {
+                // Embedded shell takes the right side when open, else the details pane
+                let body = match self.exec_pane {
+                    Some(ref session) => {
+                        let (list_side, pane_area) = crate::ui::layout::split_pane(body);
+                        self.exec_pane_size = ExecPane::render(frame, pane_area, session, self.exec_focused);
+                        list_side
+                    }
+                    None if self.split_view => {
+                        let (list_side, pane_area) = crate::ui::layout::split_pane(body);
+                        let (detail_area, logs_area) = crate::ui::layout::details_layout(pane_area);
+                        let selected = self.selected_container();
+                        ContainerDetail::render(frame, detail_area, selected, &self.stats_history);
+                        let tail = self
+                            .detail_logs
+                            .as_ref()
+                            .filter(|(name, _)| selected.is_some_and(|c| c.name == *name))
+                            .map(|(_, lines)| lines.as_slice());
+                        ContainerDetail::render_log_tail(frame, logs_area, tail);
+                        list_side
+                    }
+                    None => body,
+                };
+
                 // Full-width container list (with optional filter bar at bottom)
                 let (list_area, filter_area) = if self.filter.active || self.view_mode == ViewMode::Filter {
                     let chunks = ratatui::prelude::Layout::default()
@@ -759,11 +3451,80 @@ impl App {
                     (body, None)
                 };
 
-                // Container list (filtered) - full width with inline stats
-                let filtered: Vec<ContainerInfo> = self.filtered_containers().into_iter().cloned().collect();
+                // Recent alerts and recently deleted panels below the list
+                let startup = self.startup.as_ref().and_then(|s| s.lock().ok().map(|s| s.clone()));
+                let show_forwards = !self.forwards.entries.is_empty();
+                let panels = [show_forwards, self.show_alerts, self.show_recycle, self.show_audit, self.show_schedule, self.show_daemon];
+                let mut constraints = vec![ratatui::prelude::Constraint::Min(0), ratatui::prelude::Constraint::Length(1)];
+                if let Some(ref startup) = startup {
+                    constraints.push(ratatui::prelude::Constraint::Length(startup.steps.len().min(8) as u16 + 2));
+                }
+                constraints.extend(panels.iter().filter(|&&shown| shown).map(|_| ratatui::prelude::Constraint::Length(8)));
+                let chunks = ratatui::prelude::Layout::default()
+                    .direction(ratatui::prelude::Direction::Vertical)
+                    .constraints(constraints)
+                    .split(list_area);
+                let list_area = chunks[0];
+                let totals_area = chunks[1];
+                let mut panel_areas = chunks.iter().skip(2).copied();
+
+                // Container list (filtered) - full width with inline stats. Borrowed
+                // field by field, so the list can render without copying rows
+                let filtered: Vec<&ContainerInfo> = self.filtered_indices
+                    .iter()
+                    .filter_map(|&i| self.containers.get(i))
+                    .collect();
                 let total_count = self.containers.len();
+                // Operations in flight, until a refresh shows where they lead
+                self.container_list.transitions = self
+                    .transitions
+                    .iter()
+                    .filter(|(name, p)| !self.containers.iter().any(|c| c.name == **name && p.transition.reached(&c.status)))
+                    .map(|(name, p)| (name.clone(), p.transition))
+                    .collect();
+                self.container_list.spinner = self.effects.spinner_frame();
                 self.container_list.render(frame, list_area, &filtered, self.list_view_mode, self.status_filter, total_count);
 
+                // Totals across all containers, next to the host figures
+                let totals = ContainerTotals::sum(self.containers.iter().filter_map(|c| c.stats.as_ref()));
+                StatsFooter::render(frame, totals_area, &totals, &self.system_stats);
+
+                if let Some(ref startup) = startup {
+                    if let Some(area) = panel_areas.next() {
+                        StartupPanel::render(frame, area, startup);
+                    }
+                }
+                if show_forwards {
+                    if let Some(area) = panel_areas.next() {
+                        ForwardsPanel::render(frame, area, &self.forwards.entries);
+                    }
+                }
+                if self.show_alerts {
+                    if let Some(area) = panel_areas.next() {
+                        AlertsPanel::render(frame, area, &self.alerts.recent);
+                    }
+                }
+                if self.show_recycle {
+                    if let Some(area) = panel_areas.next() {
+                        RecyclePanel::render(frame, area, &self.recycle.entries);
+                    }
+                }
+                if self.show_audit {
+                    if let Some(area) = panel_areas.next() {
+                        AuditPanel::render(frame, area, &self.audit_log.recent);
+                    }
+                }
+                if self.show_schedule {
+                    if let Some(area) = panel_areas.next() {
+                        SchedulePanel::render(frame, area, &self.scheduler);
+                    }
+                }
+                if self.show_daemon {
+                    if let Some(area) = panel_areas.next() {
+                        DaemonPanel::render(frame, area, self.daemon_info.as_ref());
+                    }
+                }
+
                 // Filter bar
                 if let Some(filter_rect) = filter_area {
                     self.filter.render(frame, filter_rect, self.filtered_indices.len(), self.containers.len());
@@ -772,7 +3533,51 @@ impl App {
             ViewMode::Logs => {
                 // Full-screen logs view
                 self.logs_view.focused = true;
-                self.logs_view.render(frame, body, &self.logs, &self.logs_container);
+                // Stats strip on top for containers (pods have no stats here)
+                let container = match self.logs_pod {
+                    Some(_) => None,
+                    None => self.containers.iter().find(|c| c.name == self.logs_container),
+                };
+                let logs_area = match container {
+                    Some(container) => {
+                        let chunks = ratatui::prelude::Layout::default()
+                            .direction(ratatui::prelude::Direction::Vertical)
+                            .constraints([ratatui::prelude::Constraint::Length(1), ratatui::prelude::Constraint::Min(0)])
+                            .split(body);
+                        LogsStatsStrip::render(frame, chunks[0], container, &self.stats_history);
+                        chunks[1]
+                    }
+                    None => body,
+                };
+                self.logs_view.render(frame, logs_area, &self.logs, &self.logs_container);
+            }
+            ViewMode::Compare => {
+                // Marked containers in list order
+                let series: Vec<(String, Vec<crate::history::Sample>)> = self
+                    .containers
+                    .iter()
+                    .filter(|c| self.container_list.marked.contains(&c.name))
+                    .map(|c| (c.name.clone(), self.history.range(&c.name, self.chart_range)))
+                    .collect();
+                CompareView::render(frame, body, &series, self.chart_range);
+            }
+            ViewMode::Services => {
+                self.services_view.render(frame, body, &self.services);
+            }
+            ViewMode::Pods => {
+                self.pods_view.render(frame, body, &self.pods, &self.namespace);
+            }
+            ViewMode::Host => {
+                self.host_view.render(frame, body, &self.host_processes);
+            }
+            ViewMode::Events => {
+                if let Ok(events) = self.events.lock() {
+                    self.events_view.render(frame, body, &events);
+                }
+            }
+            ViewMode::Charts => {
+                let samples = self.history.range(&self.chart_container, self.chart_range);
+                HistoryChart::render(frame, body, &self.chart_container, &samples, self.chart_range);
             }
         }
 
@@ -780,6 +3585,12 @@ impl App {
         let view_str = match self.view_mode {
             ViewMode::List => "list",
             ViewMode::Logs => "logs",
+            ViewMode::Charts => "charts",
+            ViewMode::Compare => "compare",
+            ViewMode::Services => "services",
+            ViewMode::Pods => "pods",
+            ViewMode::Host => "host",
+            ViewMode::Events => "events",
             ViewMode::Create => "create",
             ViewMode::Filter => "filter",
             ViewMode::Exec => "exec",
@@ -787,13 +3598,36 @@ impl App {
             ViewMode::Rename => "rename",
             ViewMode::Processes => "processes",
             ViewMode::CopyFiles => "copy",
+            ViewMode::ImageHistory => "image_history",
+            ViewMode::Cleanup => "cleanup",
+            ViewMode::Pull => "pull",
+            ViewMode::Login => "login",
+            ViewMode::Diff => "diff",
+            ViewMode::Compose => "compose",
+            ViewMode::ConfigErrors => "config_errors",
+            ViewMode::Checkpoints => "checkpoints",
+            ViewMode::Groups => "groups",
+            ViewMode::Labels => "labels",
+            ViewMode::Forward => "forward",
+            ViewMode::Vulns => "vulns",
+            ViewMode::Gpus => "gpus",
         };
-        StatusBar::render(frame, footer, view_str);
+        StatusBar::render(frame, footer, view_str, self.read_only);
 
         // Modals (rendered last, on top)
         match &self.modal {
-            ModalState::Help => HelpModal::render(frame, frame.area()),
-            ModalState::Confirm(action) => ConfirmModal::render(frame, frame.area(), action),
+            ModalState::Help => {
+                let context = self.key_context();
+                self.help.render(frame, frame.area(), context, &self.refresh_intervals(), self.read_only);
+            }
+            ModalState::Confirm(action) => ConfirmModal::render(frame, frame.area(), action, self.confirm_preview.as_ref()),
+            ModalState::Yank => YankMenu::render(frame, frame.area(), self.selected_container()),
+            ModalState::Actions => self.action_menu.render(frame, frame.area()),
+            ModalState::Palette => {
+                if let Some(ref mut palette) = self.palette {
+                    palette.render(frame, frame.area());
+                }
+            }
             ModalState::None => {}
         }
 
@@ -811,7 +3645,8 @@ impl App {
 
         // Info modal (network I/O)
         if self.view_mode == ViewMode::Info {
-            InfoModal::render(frame, frame.area(), self.selected_container(), &self.stats_history);
+            let gpu_usage = self.selected_container().and_then(|c| self.container_gpus.get(&c.id));
+            InfoModal::render(frame, frame.area(), self.selected_container(), self.runtime_info.as_ref(), gpu_usage, &self.stats_history);
         }
 
         // Rename modal
@@ -828,12 +3663,111 @@ impl App {
             }
         }
 
+        // Image history modal
+        if self.view_mode == ViewMode::ImageHistory {
+            if let Some(ref modal) = self.image_history_modal {
+                modal.render(frame, frame.area());
+            }
+        }
+
+        // Vulnerability report
+        if self.view_mode == ViewMode::Vulns {
+            if let Some(ref modal) = self.vuln_modal {
+                modal.render(frame, frame.area());
+            }
+        }
+
+        // GPU view
+        if self.view_mode == ViewMode::Gpus {
+            if let Some(ref mut modal) = self.gpu_modal {
+                modal.render(frame, frame.area(), &self.gpu_devices, &self.containers, &self.container_gpus);
+            }
+        }
+
+        // Config problems
+        if self.view_mode == ViewMode::ConfigErrors {
+            if let Some(ref mut modal) = self.config_errors {
+                modal.render(frame, frame.area());
+            }
+        }
+
+        // Compose file viewer
+        if self.view_mode == ViewMode::Compose {
+            if let Some(ref mut modal) = self.compose_modal {
+                modal.render(frame, frame.area());
+            }
+        }
+
+        // Diff modal
+        if self.view_mode == ViewMode::Diff {
+            if let Some(ref modal) = self.diff_modal {
+                modal.render(frame, frame.area());
+            }
+        }
+
+        // Checkpoint manager
+        if self.view_mode == ViewMode::Checkpoints {
+            if let Some(ref mut modal) = self.checkpoint_modal {
+                modal.render(frame, frame.area());
+            }
+        }
+
+        // Group assignment
+        if self.view_mode == ViewMode::Groups {
+            if let Some(ref mut modal) = self.group_modal {
+                modal.render(frame, frame.area());
+            }
+        }
+
+        // Label editor
+        if self.view_mode == ViewMode::Labels {
+            if let Some(ref mut modal) = self.label_modal {
+                modal.render(frame, frame.area());
+            }
+        }
+
+        // Port forwards
+        if self.view_mode == ViewMode::Forward {
+            if let Some(ref mut modal) = self.forward_modal {
+                modal.render(frame, frame.area(), &self.forwards.entries);
+            }
+        }
+
+        // Cleanup wizard
+        if self.view_mode == ViewMode::Cleanup {
+            if let Some(ref mut modal) = self.cleanup_modal {
+                modal.render(frame, frame.area());
+            }
+        }
+
+        // Pull dialog
+        if self.view_mode == ViewMode::Pull {
+            if let Some(ref mut modal) = self.pull_modal {
+                modal.render(frame, frame.area());
+            }
+        }
+
+        // Registry login
+        if self.view_mode == ViewMode::Login {
+            if let Some(ref modal) = self.login_modal {
+                modal.render(frame, frame.area());
+            }
+        }
+
         // Copy files modal
         if self.view_mode == ViewMode::CopyFiles {
             if let Some(ref modal) = self.copy_modal {
                 modal.render(frame, frame.area());
             }
         }
+
+        // Toast notification (topmost)
+        if self.toast.as_ref().is_some_and(|t| t.is_expired()) {
+            self.toast = None;
+        }
+        if let Some(ref toast) = self.toast {
+            toast.render(frame, frame.area());
+        }
     }
 
     /// Render with visual effects
@@ -843,6 +3777,15 @@ impl App {
 
         let area = frame.area();
 
+        // Remap colors for non-default themes
+        crate::ui::apply_theme(frame.buffer_mut(), area, self.theme);
+
+        // Accessible mode: plain glyphs and steady colors, no fades or dimming
+        if self.accessible {
+            crate::ui::apply_ascii(frame.buffer_mut(), area);
+            return;
+        }
+
         // Process startup fade-in effect (affects whole screen)
         self.effects.process(elapsed, frame.buffer_mut(), area);
 
@@ -866,11 +3809,85 @@ impl App {
             .split(area)[1];
 
         self.effects.process_status(elapsed, frame.buffer_mut(), body_area);
+
+        // Dimmed while idle, as a hint that polling has slowed down
+        if self.is_idle() {
+            crate::ui::dim(frame.buffer_mut(), area);
+        }
+    }
+}
+
+/// Operation from the checkpoint manager
+enum CheckpointOp {
+    /// true = leave the container running
+    Create(bool),
+    Restore(String),
+    Delete(String),
+}
+
+impl CheckpointOp {
+    /// Audit log verb
+    fn verb(&self) -> &'static str {
+        match self {
+            Self::Create(_) => "checkpoint",
+            Self::Restore(_) => "restore checkpoint",
+            Self::Delete(_) => "delete checkpoint",
+        }
+    }
+}
+
+/// A start/stop/... running in the background, shown on its row meanwhile
+struct PendingOp {
+    transition: Transition,
+    /// Audit log verb, recorded with the outcome once it finishes
+    verb: &'static str,
+    handle: tokio::task::JoinHandle<Result<()>>,
+}
+
+/// A pull, image update, relabel or copy running in the background
+struct BackgroundJob {
+    /// Shown once it succeeds
+    message: String,
+    /// Audit log verb and target, recorded with the outcome once it finishes
+    audit: Option<(String, String)>,
+    handle: tokio::task::JoinHandle<Result<()>>,
+}
+
+/// Network and block I/O byte counters at the time of the previous sample
+struct IoCounter {
+    rx_bytes: u64,
+    tx_bytes: u64,
+    read_bytes: u64,
+    write_bytes: u64,
+    at: Instant,
+}
+
+/// Fill in network and disk rates from the delta against the previous sample
+fn update_io_rates(counters: &mut HashMap<String, IoCounter>, name: &str, stats: &mut ContainerStats) {
+    let now = Instant::now();
+    if let Some(prev) = counters.get(name) {
+        let secs = now.duration_since(prev.at).as_secs_f64();
+        // Counters reset when a container restarts
+        if secs > 0.0 && stats.net_rx_bytes >= prev.rx_bytes && stats.net_tx_bytes >= prev.tx_bytes {
+            stats.net_rx_rate = (stats.net_rx_bytes - prev.rx_bytes) as f64 / secs;
+            stats.net_tx_rate = (stats.net_tx_bytes - prev.tx_bytes) as f64 / secs;
+        }
+        if secs > 0.0 && stats.blk_read_bytes >= prev.read_bytes && stats.blk_write_bytes >= prev.write_bytes {
+            stats.blk_read_rate = (stats.blk_read_bytes - prev.read_bytes) as f64 / secs;
+            stats.blk_write_rate = (stats.blk_write_bytes - prev.write_bytes) as f64 / secs;
+        }
     }
+    counters.insert(name.to_string(), IoCounter {
+        rx_bytes: stats.net_rx_bytes,
+        tx_bytes: stats.net_tx_bytes,
+        read_bytes: stats.blk_read_bytes,
+        write_bytes: stats.blk_write_bytes,
+        at: now,
+    });
 }
 
 /// Lookup VRAM usage for a container from cached GPU metrics
-fn lookup_container_vram(gpu_cache: &HashMap<String, f64>, container_id: &str) -> Option<f64> {
+pub fn lookup_container_vram(gpu_cache: &HashMap<String, f64>, container_id: &str) -> Option<f64> {
     // Try exact match first
     if let Some(&vram) = gpu_cache.get(container_id) {
         return Some(vram);
@@ -885,3 +3902,24 @@ fn lookup_container_vram(gpu_cache: &HashMap<String, f64>, container_id: &str) -
 
     None
 }
+
+/// Placeholder shown instead of the UI when the terminal is too small for it
+fn render_too_small(frame: &mut ratatui::Frame) {
+    use crate::ui::layout::{MIN_HEIGHT, MIN_WIDTH};
+    use crate::ui::Theme;
+    use ratatui::prelude::*;
+    use ratatui::widgets::Paragraph;
+
+    let area = frame.area();
+    let lines = vec![
+        Line::styled("Terminal too small", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
+        Line::styled(
+            format!("{}x{} (need {}x{})", area.width, area.height, MIN_WIDTH, MIN_HEIGHT),
+            Style::default().fg(Theme::FG_DARK),
+        ),
+    ];
+    // Vertically center the message
+    let top = area.height.saturating_sub(lines.len() as u16) / 2;
+    let text_area = Rect { y: area.y + top, height: area.height - top, ..area };
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), text_area);
+}
@@ -0,0 +1,164 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::action::Action;
+use crate::components::confirm_modal::ConfirmAction;
+
+/// Number of entries kept in memory for the audit panel
+const MAX_RECENT: usize = 50;
+
+/// One mutating action performed from the TUI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// RFC 3339 local time
+    pub time: String,
+    pub user: String,
+    /// Short verb, e.g. "stop" or "pull"
+    pub action: String,
+    /// Container, image, project or service the action applied to
+    pub target: String,
+    /// "ok" or the error
+    pub result: String,
+}
+
+impl AuditEntry {
+    pub fn succeeded(&self) -> bool {
+        self.result == "ok"
+    }
+}
+
+/// Append-only JSON lines log of mutating actions, plus the latest entries
+#[derive(Debug, Default)]
+pub struct AuditLog {
+    path: Option<PathBuf>,
+    user: String,
+    /// Newest first, including entries written by earlier sessions
+    pub recent: VecDeque<AuditEntry>,
+}
+
+impl AuditLog {
+    /// Open the log, loading the tail of an existing file for the panel
+    pub fn open(path: Option<PathBuf>) -> Self {
+        let recent = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|content| parse_recent(&content))
+            .unwrap_or_default();
+        Self {
+            path,
+            user: current_user(),
+            recent,
+        }
+    }
+
+    /// Log file in the config directory
+    pub fn default_path() -> Option<PathBuf> {
+        crate::config::config_dir().map(|dir| dir.join("audit.log"))
+    }
+
+    /// Record an action and its outcome
+    pub fn record(&mut self, action: &str, target: &str, result: String) -> Result<()> {
+        let entry = AuditEntry {
+            time: chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            user: self.user.clone(),
+            action: action.to_string(),
+            target: target.to_string(),
+            result,
+        };
+        self.recent.push_front(entry.clone());
+        self.recent.truncate(MAX_RECENT);
+
+        let Some(ref path) = self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).context(format!("Failed to create {:?}", dir))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .context(format!("Failed to open audit log {:?}", path))?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?).context(format!("Failed to write audit log {:?}", path))
+    }
+}
+
+/// Latest entries of a log file, newest first; unreadable lines are skipped
+fn parse_recent(content: &str) -> VecDeque<AuditEntry> {
+    content
+        .lines()
+        .rev()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .take(MAX_RECENT)
+        .collect()
+}
+
+/// Outcome of an action as written to the log
+pub fn outcome<T>(result: &Result<T>) -> String {
+    match result {
+        Ok(_) => "ok".to_string(),
+        Err(e) => format!("{:#}", e),
+    }
+}
+
+/// Login name of whoever runs the TUI; under sudo, the invoking user
+fn current_user() -> String {
+    ["SUDO_USER", "USER", "LOGNAME", "USERNAME"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Verb and target for actions that change containers, images or services
+pub fn describe_action(action: &Action) -> Option<(&'static str, String)> {
+    Some(match action {
+        // Start/stop/restart/pause/unpause, pulls and copies run in the
+        // background and are recorded when they finish; scaling reports its
+        // own errors and records itself
+        Action::DeleteContainer(name) => ("delete", name.clone()),
+        Action::RenameContainer(old, new) => ("rename", format!("{} -> {}", old, new)),
+        Action::ToggleKeepAlive(name) => ("keep-alive", name.clone()),
+        _ => return None,
+    })
+}
+
+/// Verb and target for a confirmed action
+pub fn describe_confirmed(action: &ConfirmAction) -> (&'static str, String) {
+    match action {
+        ConfirmAction::Delete(name) => ("delete", name.clone()),
+        ConfirmAction::Stop(name) => ("stop", name.clone()),
+        ConfirmAction::StopNow(name) => ("stop now", name.clone()),
+        ConfirmAction::Restart(name) => ("restart", name.clone()),
+        ConfirmAction::RestartNow(name) => ("restart now", name.clone()),
+        ConfirmAction::ComposeDown(project) => ("compose down", project.clone()),
+        ConfirmAction::PruneContainers => ("prune", "stopped containers".to_string()),
+        ConfirmAction::Batch(op, names) => (op.as_str(), names.join(", ")),
+        ConfirmAction::UpdateImage(name) => ("update image", name.clone()),
+        ConfirmAction::RestartService(name) => ("restart service", name.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_recent_is_newest_first() {
+        let content = concat!(
+            r#"{"time":"2024-05-01T10:00:00+02:00","user":"ana","action":"stop","target":"web","result":"ok"}"#,
+            "\n",
+            "not json\n",
+            r#"{"time":"2024-05-01T10:01:00+02:00","user":"ben","action":"delete","target":"db","result":"No such container"}"#,
+            "\n",
+        );
+        let recent = parse_recent(content);
+        let actions: Vec<&str> = recent.iter().map(|e| e.action.as_str()).collect();
+        assert_eq!(actions, ["delete", "stop"]);
+        assert!(!recent[0].succeeded());
+        assert!(recent[1].succeeded());
+    }
+}
@@ -0,0 +1,89 @@
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+/// Docker container management TUI
+#[derive(Debug, Parser)]
+#[command(name = "backplane-tui", version, about)]
+pub struct Cli {
+    /// Docker host URL (unix://, tcp://, http://) or docker context name
+    #[arg(long, value_name = "CTX")]
+    pub host: Option<String>,
+
+    /// Container list refresh interval in milliseconds
+    #[arg(long, value_name = "MS")]
+    pub refresh_ms: Option<u64>,
+
+    /// Color theme (mocha, macchiato, frappe, latte, high-contrast)
+    #[arg(long, value_name = "NAME")]
+    pub theme: Option<String>,
+
+    /// Start with the name filter pre-filled
+    #[arg(long, value_name = "NAME")]
+    pub filter: Option<String>,
+
+    /// Start with this container (name or ID prefix) selected
+    #[arg(long, value_name = "NAME")]
+    pub select: Option<String>,
+
+    /// Start in this container's logs view
+    #[arg(long, value_name = "NAME", conflicts_with = "select")]
+    pub logs: Option<String>,
+
+    /// Serve Prometheus metrics on this port (ADDR:PORT/metrics)
+    #[cfg(feature = "metrics")]
+    #[arg(long, value_name = "PORT")]
+    pub metrics_port: Option<u16>,
+
+    /// Address the metrics endpoint listens on (0.0.0.0 for every interface)
+    #[cfg(feature = "metrics")]
+    #[arg(long, value_name = "ADDR", default_value = "127.0.0.1")]
+    pub metrics_addr: std::net::IpAddr,
+
+    /// Persist 24h of stats history to disk for the history charts
+    #[arg(long)]
+    pub history: bool,
+
+    /// Browse only: disable start/stop/delete/create/exec/copy and other changes
+    #[arg(long)]
+    pub read_only: bool,
+
+    /// ASCII instead of box drawing, bars and icons, with a high-contrast palette
+    #[arg(long)]
+    pub accessible: bool,
+
+    /// Open the create form filled from a template or project.yaml (- reads stdin)
+    #[arg(long, value_name = "PATH")]
+    pub template: Option<String>,
+
+    /// Print containers with stats (json or csv) and exit
+    #[arg(long, value_name = "FORMAT", num_args = 0..=1, default_missing_value = "json")]
+    pub snapshot: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+}
+
+/// Non-interactive subcommands
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    /// Print a summary of Claude sessions found under ~/.claude/projects
+    Sessions,
+    /// List containers without entering the TUI
+    Ps {
+        /// Output as JSON for scripting
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check config.toml and the project.yaml manifests, printing problems
+    /// with their file and line
+    CheckConfig {
+        /// Directory of project manifests (default: projects_dir from config.toml)
+        #[arg(long, value_name = "DIR")]
+        projects: Option<PathBuf>,
+    },
+    /// Print a project.yaml (or template) as a create-form template to share
+    ExportTemplate {
+        /// Manifest to convert (- reads stdin)
+        manifest: String,
+    },
+}
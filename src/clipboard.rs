@@ -0,0 +1,44 @@
+use std::io::{stdout, Write};
+
+use anyhow::{Context, Result};
+
+/// Copy text to the system clipboard via OSC 52 (works over SSH and in tmux with set-clipboard on)
+pub fn copy(text: &str) -> Result<()> {
+    let mut out = stdout();
+    write!(out, "\x1b]52;c;{}\x07", base64_encode(text.as_bytes()))
+        .and_then(|_| out.flush())
+        .context("Failed to write clipboard escape sequence")
+}
+
+/// Standard base64 with padding
+pub fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"docker exec -it api sh"), "ZG9ja2VyIGV4ZWMgLWl0IGFwaSBzaA==");
+    }
+}
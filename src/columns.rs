@@ -0,0 +1,243 @@
+use serde::Deserialize;
+
+use crate::app::ListViewMode;
+use crate::ui::display_width;
+
+/// A column of the container list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Name,
+    Project,
+    Port,
+    Cpu,
+    Mem,
+    Gpu,
+    Ip,
+    NetRx,
+    NetTx,
+    RxTotal,
+    TxTotal,
+    DiskRead,
+    DiskWrite,
+    Image,
+    Size,
+    Vulns,
+    Uptime,
+    Created,
+    Exit,
+    Restarts,
+}
+
+impl Column {
+    pub const ALL: [Column; 20] = [
+        Self::Name, Self::Project, Self::Port, Self::Cpu, Self::Mem, Self::Gpu, Self::Ip, Self::NetRx,
+        Self::NetTx, Self::RxTotal, Self::TxTotal, Self::DiskRead, Self::DiskWrite, Self::Image, Self::Size,
+        Self::Vulns, Self::Uptime, Self::Created, Self::Exit, Self::Restarts,
+    ];
+
+    /// Name in config.toml
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Project => "project",
+            Self::Port => "port",
+            Self::Cpu => "cpu",
+            Self::Mem => "mem",
+            Self::Gpu => "gpu",
+            Self::Ip => "ip",
+            Self::NetRx => "rx",
+            Self::NetTx => "tx",
+            Self::RxTotal => "rx_total",
+            Self::TxTotal => "tx_total",
+            Self::DiskRead => "disk_read",
+            Self::DiskWrite => "disk_write",
+            Self::Image => "image",
+            Self::Size => "size",
+            Self::Vulns => "vulns",
+            Self::Uptime => "uptime",
+            Self::Created => "created",
+            Self::Exit => "exit",
+            Self::Restarts => "restarts",
+        }
+    }
+
+    pub fn parse(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|column| column.key() == key)
+    }
+
+    /// Label in the list's header row
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Name => "NAME",
+            Self::Project => "PROJECT",
+            Self::Port => "PORT",
+            Self::Cpu => "CPU",
+            Self::Mem => "MEM",
+            Self::Gpu => "GPU",
+            Self::Ip => "IP",
+            Self::NetRx => "↓ RX",
+            Self::NetTx => "↑ TX",
+            Self::RxTotal => "TOTAL ↓",
+            Self::TxTotal => "TOTAL ↑",
+            Self::DiskRead => "DISK R",
+            Self::DiskWrite => "DISK W",
+            Self::Image => "IMAGE",
+            Self::Size => "SIZE",
+            Self::Vulns => "VULNS",
+            Self::Uptime => "UPTIME",
+            Self::Created => "CREATED",
+            Self::Exit => "EXIT",
+            Self::Restarts => "RESTARTS",
+        }
+    }
+
+    /// Figures lined up on the right, header included
+    pub fn right_aligned(&self) -> bool {
+        matches!(
+            self,
+            Self::Gpu | Self::NetRx | Self::NetTx | Self::RxTotal | Self::TxTotal | Self::DiskRead | Self::DiskWrite | Self::Size | Self::Uptime
+        )
+    }
+
+    /// Columns the value gets unless the config sets a width. Bars take
+    /// what's left of the CPU and memory columns after the percentage
+    pub fn default_width(&self) -> u16 {
+        match self {
+            Self::Name | Self::Image => 20,
+            Self::Project => 10,
+            Self::Port | Self::Vulns | Self::Uptime => 12,
+            Self::Cpu | Self::Mem => 14,
+            Self::Gpu => 5,
+            Self::Ip => 18,
+            Self::NetRx | Self::NetTx | Self::DiskRead | Self::DiskWrite | Self::Created => 10,
+            Self::RxTotal | Self::TxTotal | Self::Size | Self::Exit => 8,
+            Self::Restarts => 4,
+        }
+    }
+}
+
+/// One column of a view mode: `"name"`, or `"name:30"` for a width
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct ColumnSpec {
+    pub column: Column,
+    pub width: Option<u16>,
+}
+
+impl ColumnSpec {
+    const fn new(column: Column) -> Self {
+        Self { column, width: None }
+    }
+
+    /// Width of the value (the configured one, else the column's default)
+    pub fn value_width(&self) -> u16 {
+        self.width.unwrap_or_else(|| self.column.default_width())
+    }
+
+    /// Width on screen: the value's, or the label's with room for a sort
+    /// arrow when that's wider
+    pub fn width(&self) -> u16 {
+        self.value_width().max(display_width(self.column.label()) as u16 + 2)
+    }
+}
+
+impl TryFrom<String> for ColumnSpec {
+    type Error = String;
+
+    fn try_from(spec: String) -> Result<Self, Self::Error> {
+        let (key, width) = match spec.split_once(':') {
+            Some((key, width)) => (key.trim(), Some(width.trim())),
+            None => (spec.trim(), None),
+        };
+        let column = Column::parse(key).ok_or_else(|| {
+            let keys: Vec<&str> = Column::ALL.iter().map(Column::key).collect();
+            format!("unknown column '{}' (one of {})", key, keys.join(", "))
+        })?;
+        let width = match width {
+            Some(width) => match width.parse::<u16>() {
+                Ok(width) if width > 0 => Some(width),
+                _ => return Err(format!("bad width '{}' for column {}", width, key)),
+            },
+            None => None,
+        };
+        Ok(Self { column, width })
+    }
+}
+
+const STATS_COLUMNS: [ColumnSpec; 6] = [
+    ColumnSpec::new(Column::Name),
+    ColumnSpec::new(Column::Project),
+    ColumnSpec::new(Column::Port),
+    ColumnSpec::new(Column::Cpu),
+    ColumnSpec::new(Column::Mem),
+    ColumnSpec::new(Column::Gpu),
+];
+
+const NETWORK_COLUMNS: [ColumnSpec; 8] = [
+    ColumnSpec::new(Column::Name),
+    ColumnSpec::new(Column::Ip),
+    ColumnSpec::new(Column::NetRx),
+    ColumnSpec::new(Column::NetTx),
+    ColumnSpec::new(Column::RxTotal),
+    ColumnSpec::new(Column::TxTotal),
+    ColumnSpec::new(Column::DiskRead),
+    ColumnSpec::new(Column::DiskWrite),
+];
+
+const DETAILS_COLUMNS: [ColumnSpec; 9] = [
+    ColumnSpec::new(Column::Name),
+    ColumnSpec::new(Column::Image),
+    ColumnSpec::new(Column::Size),
+    ColumnSpec::new(Column::Vulns),
+    ColumnSpec::new(Column::Project),
+    ColumnSpec::new(Column::Uptime),
+    ColumnSpec::new(Column::Created),
+    ColumnSpec::new(Column::Exit),
+    ColumnSpec::new(Column::Restarts),
+];
+
+/// Columns of each list view mode, in order, from `[columns]`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ColumnsConfig {
+    pub stats: Vec<ColumnSpec>,
+    pub network: Vec<ColumnSpec>,
+    pub details: Vec<ColumnSpec>,
+}
+
+impl Default for ColumnsConfig {
+    fn default() -> Self {
+        Self {
+            stats: STATS_COLUMNS.to_vec(),
+            network: NETWORK_COLUMNS.to_vec(),
+            details: DETAILS_COLUMNS.to_vec(),
+        }
+    }
+}
+
+impl ColumnsConfig {
+    pub fn for_mode(&self, mode: ListViewMode) -> &[ColumnSpec] {
+        match mode {
+            ListViewMode::Stats => &self.stats,
+            ListViewMode::Network => &self.network,
+            ListViewMode::Details => &self.details,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_config() {
+        let config: ColumnsConfig = toml::from_str("stats = [\"name:30\", \"cpu\", \"ip\"]\n").unwrap();
+        assert_eq!(config.stats[0], ColumnSpec { column: Column::Name, width: Some(30) });
+        assert_eq!(config.stats[2].value_width(), 18);
+        assert_eq!(ColumnSpec::new(Column::Restarts).width(), 10);
+        assert_eq!(config.details, DETAILS_COLUMNS.to_vec());
+
+        assert!(toml::from_str::<ColumnsConfig>("stats = [\"health\"]\n").is_err());
+        assert!(toml::from_str::<ColumnsConfig>("stats = [\"name:0\"]\n").is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::app::lookup_container_vram;
+use crate::components::FilterBar;
+use crate::config::{config_path, scan_projects, AppConfig};
+use crate::docker::client::DockerClient;
+use crate::docker::gpu::get_container_gpu_usage;
+use crate::docker::stats::get_containers_stats;
+use crate::export::{self, ExportFormat};
+use crate::template::ContainerTemplate;
+use crate::ui::pad_width;
+
+/// Longest `--snapshot` waits for slow containers' stats
+const SNAPSHOT_STATS_BUDGET: Duration = Duration::from_secs(5);
+
+/// Container entry for `ps` output
+#[derive(Debug, Serialize)]
+struct PsEntry {
+    id: String,
+    name: String,
+    image: String,
+    status: String,
+    compose_project: Option<String>,
+    ports: Vec<String>,
+    created: Option<i64>,
+}
+
+/// Print the container list (`backplane-tui ps`)
+pub async fn ps(host: Option<&str>, json: bool) -> Result<()> {
+    let docker = DockerClient::connect_with_host(host)?;
+    let containers = docker.list_containers().await?;
+
+    let entries: Vec<PsEntry> = containers
+        .into_iter()
+        .map(|c| PsEntry {
+            id: c.id.chars().take(12).collect(),
+            name: c.name,
+            image: c.image,
+            status: c.status.as_str().to_string(),
+            compose_project: c.compose_project,
+            ports: c.ports.iter().map(|p| p.display()).collect(),
+            created: c.created,
+        })
+        .collect();
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    println!("{:<12}  {:<24}  {:<10}  {:<14}  {:<30}  PORTS", "ID", "NAME", "STATUS", "PROJECT", "IMAGE");
+    for e in &entries {
+        println!(
+            "{:<12}  {}  {:<10}  {}  {}  {}",
+            e.id,
+            pad_width(&e.name, 24),
+            e.status,
+            pad_width(e.compose_project.as_deref().unwrap_or("-"), 14),
+            pad_width(&e.image, 30),
+            e.ports.join(", "),
+        );
+    }
+    Ok(())
+}
+
+/// Check config.toml and the project manifests (`backplane-tui check-config`).
+/// Fails when anything is wrong, for use in scripts.
+pub fn check_config(projects: Option<&Path>) -> Result<()> {
+    let mut issues = Vec::new();
+    let mut config = AppConfig::default();
+    if let Some(path) = config_path() {
+        match AppConfig::check_file(&path) {
+            Ok(_) if !path.exists() => println!("{}: not found, using defaults", path.display()),
+            Ok(loaded) => {
+                println!("{}: ok", path.display());
+                config = loaded;
+            }
+            Err(issue) => issues.push(issue),
+        }
+    }
+
+    if let Some(dir) = projects.map(Path::to_path_buf).or(config.projects_dir) {
+        let (manifests, manifest_issues) = scan_projects(&dir)?;
+        println!("{}: {} manifests ok", dir.display(), manifests.len());
+        issues.extend(manifest_issues);
+    }
+
+    for issue in &issues {
+        println!("{}", issue);
+    }
+    if !issues.is_empty() {
+        anyhow::bail!("{} problems found", issues.len());
+    }
+    Ok(())
+}
+
+/// Print a manifest as a create-form template (`backplane-tui export-template`)
+pub fn export_template(manifest: &str) -> Result<()> {
+    print!("{}", ContainerTemplate::read(manifest)?.to_yaml()?);
+    Ok(())
+}
+
+/// Print containers with a single stats sample (`--snapshot`)
+pub async fn snapshot(host: Option<&str>, filter: Option<&str>, format: ExportFormat) -> Result<()> {
+    let docker = DockerClient::connect_with_host(host)?;
+    let mut containers = docker.list_containers().await?;
+    let gpu_usage = get_container_gpu_usage();
+
+    let mut name_filter = FilterBar::new();
+    if let Some(query) = filter {
+        name_filter.query = query.to_string();
+    }
+    containers.retain(|c| name_filter.matches(c));
+
+    let active: Vec<String> = containers.iter().filter(|c| c.status.is_active()).map(|c| c.name.clone()).collect();
+    let mut stats = get_containers_stats(docker.inner(), &active, SNAPSHOT_STATS_BUDGET).await;
+    for container in &mut containers {
+        if let Some(mut stats) = stats.remove(&container.name) {
+            stats.vram_usage_mb = lookup_container_vram(&gpu_usage, &container.id);
+            container.stats = Some(stats);
+        }
+    }
+
+    let refs: Vec<_> = containers.iter().collect();
+    println!("{}", export::render(&refs, format)?);
+    Ok(())
+}
+
+/// Per-project Claude session summary
+struct SessionSummary {
+    project: String,
+    sessions: usize,
+    total_bytes: u64,
+    last_active: Option<SystemTime>,
+}
+
+/// Print a summary of Claude sessions (`backplane-tui sessions`)
+pub fn sessions() -> Result<()> {
+    let dir = claude_projects_dir().context("HOME is not set")?;
+    let entries = fs::read_dir(&dir)
+        .context(format!("Failed to read directory: {:?}", dir))?;
+
+    let mut summaries = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let mut summary = SessionSummary {
+            project: entry.file_name().to_string_lossy().to_string(),
+            sessions: 0,
+            total_bytes: 0,
+            last_active: None,
+        };
+
+        // Each session is stored as a .jsonl transcript
+        for file in fs::read_dir(&path)?.flatten() {
+            let file_path = file.path();
+            if file_path.extension().is_some_and(|ext| ext == "jsonl") {
+                if let Ok(meta) = file.metadata() {
+                    summary.sessions += 1;
+                    summary.total_bytes += meta.len();
+                    if let Ok(modified) = meta.modified() {
+                        summary.last_active = summary.last_active.max(Some(modified));
+                    }
+                }
+            }
+        }
+
+        if summary.sessions > 0 {
+            summaries.push(summary);
+        }
+    }
+
+    // Most recently active first
+    summaries.sort_by_key(|s| std::cmp::Reverse(s.last_active));
+
+    if summaries.is_empty() {
+        println!("No Claude sessions found in {:?}", dir);
+        return Ok(());
+    }
+
+    println!("{:<40}  {:>8}  {:>10}  LAST ACTIVE", "PROJECT", "SESSIONS", "SIZE");
+    for s in &summaries {
+        let last_active = s
+            .last_active
+            .map(|t| chrono::DateTime::<chrono::Local>::from(t).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "-".to_string());
+        println!(
+            "{}  {:>8}  {:>9.1}M  {}",
+            pad_width(&s.project, 40),
+            s.sessions,
+            s.total_bytes as f64 / 1024.0 / 1024.0,
+            last_active,
+        );
+    }
+    Ok(())
+}
+
+/// Location of Claude's per-project session transcripts
+fn claude_projects_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".claude").join("projects"))
+}
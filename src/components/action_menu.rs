@@ -0,0 +1,145 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+use crate::keymap::{self, Binding, Command, KeyContext};
+use crate::models::{ContainerInfo, ContainerStatus};
+use crate::ui::{centered_modal, Theme};
+
+/// Container commands the menu offers, in display order
+const COMMANDS: &[Command] = &[
+    Command::ViewLogs,
+    Command::Exec,
+    Command::Info,
+    Command::Start,
+    Command::Stop,
+    Command::Restart,
+    Command::Pause,
+    Command::Unpause,
+    Command::Processes,
+    Command::ViewCharts,
+    Command::Diff,
+    Command::ComposeFile,
+    Command::ScaffoldProject,
+    Command::ImageHistory,
+    Command::ScanImage,
+    Command::CopyFiles,
+    Command::Rename,
+    Command::Labels,
+    Command::ForwardPort,
+    Command::Yank,
+    Command::Pin,
+    Command::Groups,
+    Command::KeepAlive,
+    Command::Checkpoints,
+    Command::UpdateImage,
+    Command::Delete,
+];
+
+/// Popup listing the operations available for the selected container (opened with .)
+#[derive(Debug, Default)]
+pub struct ActionMenu {
+    pub container: String,
+    /// List bindings of the applicable commands
+    pub items: Vec<&'static Binding>,
+    pub state: ListState,
+}
+
+impl ActionMenu {
+    pub fn new(container: &ContainerInfo, read_only: bool) -> Self {
+        let items = COMMANDS
+            .iter()
+            .filter(|command| applies(**command, &container.status) && !(read_only && command.is_mutating()))
+            .filter_map(|command| keymap::bindings_for(KeyContext::List).find(|b| b.command == *command))
+            .collect();
+        Self {
+            container: container.name.clone(),
+            items,
+            state: ListState::default().with_selected(Some(0)),
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.items.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + 1) % self.items.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.items.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + self.items.len() - 1) % self.items.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    /// Key that runs the highlighted entry in the list
+    pub fn selected_key(&self) -> Option<KeyCode> {
+        self.state.selected().and_then(|i| self.items.get(i)).map(|b| b.keys[0])
+    }
+
+    /// The key itself, if it belongs to one of the entries
+    pub fn shortcut(&self, code: KeyCode) -> Option<KeyCode> {
+        self.items.iter().any(|b| b.keys.contains(&code)).then_some(code)
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 50, self.items.len() as u16 + 2);
+
+        // Clear the background
+        frame.render_widget(Clear, modal_area);
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .map(|b| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!(" {:<7}", b.label()), Style::default().fg(Theme::YELLOW)),
+                    Span::styled(b.desc, Style::default().fg(Theme::FG)),
+                ]))
+            })
+            .collect();
+
+        let block = Block::default()
+            .title(format!(" {} ", self.container))
+            .title_bottom(Line::styled(" ↑↓ select  Enter run  Esc close ", Style::default().fg(Theme::FG_DARK)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MODAL_BORDER))
+            .style(Style::default().bg(Theme::MODAL_BG));
+
+        let list = List::new(items).block(block).highlight_style(Style::default().bg(Theme::BG_HIGHLIGHT));
+        frame.render_stateful_widget(list, modal_area, &mut self.state);
+    }
+}
+
+/// Whether a command makes sense for a container in this state
+fn applies(command: Command, status: &ContainerStatus) -> bool {
+    match command {
+        Command::Start => !status.is_active(),
+        Command::Stop | Command::Restart | Command::Pause | Command::Exec | Command::Processes => status.is_running(),
+        Command::Unpause => *status == ContainerStatus::Paused,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_follow_status() {
+        let mut container = ContainerInfo::new("api".to_string());
+        container.status = ContainerStatus::Exited;
+        let commands: Vec<Command> = ActionMenu::new(&container, false).items.iter().map(|b| b.command).collect();
+        assert!(commands.contains(&Command::Start));
+        assert!(!commands.contains(&Command::Stop));
+
+        // Read-only mode leaves only the non-mutating ones
+        container.status = ContainerStatus::Running;
+        let menu = ActionMenu::new(&container, true);
+        assert!(menu.items.iter().all(|b| !b.command.is_mutating()));
+        assert!(menu.items.iter().any(|b| b.command == Command::ViewLogs));
+    }
+}
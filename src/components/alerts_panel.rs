@@ -0,0 +1,55 @@
+use std::collections::VecDeque;
+
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use crate::alerts::{Alert, AlertKind};
+use crate::ui::Theme;
+
+/// Panel listing recently fired alerts (newest first)
+pub struct AlertsPanel;
+
+impl AlertsPanel {
+    pub fn render(frame: &mut Frame, area: Rect, alerts: &VecDeque<Alert>) {
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Alerts ({}) ", alerts.len()),
+                Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::BORDER));
+
+        let items: Vec<ListItem> = if alerts.is_empty() {
+            vec![ListItem::new(Line::styled(" No alerts", Style::default().fg(Theme::FG_DARK)))]
+        } else {
+            alerts
+                .iter()
+                .map(|a| {
+                    let (icon, color) = kind_style(a.kind);
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!(" {} ", a.at.format("%H:%M:%S")), Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(format!("{} ", icon), Style::default().fg(color)),
+                        Span::styled(format!("{} ", a.container), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+                        Span::styled(a.message.clone(), Style::default().fg(Theme::FG)),
+                    ]))
+                })
+                .collect()
+        };
+
+        frame.render_widget(List::new(items).block(block), area);
+    }
+}
+
+/// Icon and color for an alert kind
+fn kind_style(kind: AlertKind) -> (&'static str, Color) {
+    match kind {
+        AlertKind::HighCpu => ("▲", Theme::PEACH),
+        AlertKind::HighMemory => ("▲", Theme::MAUVE),
+        AlertKind::PidLimit => ("▲", Theme::YELLOW),
+        AlertKind::Exited => ("✗", Theme::RED),
+        AlertKind::Unhealthy => ("♥", Theme::YELLOW),
+        AlertKind::CrashLoop => ("⚠", Theme::RED),
+    }
+}
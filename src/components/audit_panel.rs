@@ -0,0 +1,50 @@
+use std::collections::VecDeque;
+
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use crate::audit::AuditEntry;
+use crate::ui::Theme;
+
+/// Panel listing recent audit log entries (newest first, across sessions)
+pub struct AuditPanel;
+
+impl AuditPanel {
+    pub fn render(frame: &mut Frame, area: Rect, entries: &VecDeque<AuditEntry>) {
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Audit Log ({}) ", entries.len()),
+                Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::BORDER));
+
+        let items: Vec<ListItem> = if entries.is_empty() {
+            vec![ListItem::new(Line::styled(" No actions recorded", Style::default().fg(Theme::FG_DARK)))]
+        } else {
+            entries
+                .iter()
+                .map(|e| {
+                    // "2024-05-01T10:00:00+02:00" -> "05-01 10:00:00"
+                    let time = e.time.get(5..19).unwrap_or(&e.time).replace('T', " ");
+                    let (icon, color) = if e.succeeded() { ("✓", Theme::GREEN) } else { ("✗", Theme::RED) };
+                    let mut spans = vec![
+                        Span::styled(format!(" {} ", time), Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(format!("{} ", icon), Style::default().fg(color)),
+                        Span::styled(format!("{} ", e.user), Style::default().fg(Theme::LAVENDER)),
+                        Span::styled(format!("{} ", e.action), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+                        Span::styled(e.target.clone(), Style::default().fg(Theme::FG)),
+                    ];
+                    if !e.succeeded() {
+                        spans.push(Span::styled(format!("  {}", e.result), Style::default().fg(Theme::RED)));
+                    }
+                    ListItem::new(Line::from(spans))
+                })
+                .collect()
+        };
+
+        frame.render_widget(List::new(items).block(block), area);
+    }
+}
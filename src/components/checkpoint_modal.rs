@@ -0,0 +1,117 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::ui::{centered_modal, Theme};
+
+/// Checkpoint manager for a container (CRIU, experimental daemons only)
+#[derive(Debug, Clone)]
+pub struct CheckpointModal {
+    pub container_name: String,
+    pub checkpoints: Vec<String>,
+    pub state: ListState,
+    /// Last failure, e.g. the daemon not having experimental features on
+    pub error: Option<String>,
+}
+
+impl CheckpointModal {
+    pub fn new(container_name: String) -> Self {
+        Self {
+            container_name,
+            checkpoints: Vec::new(),
+            state: ListState::default(),
+            error: None,
+        }
+    }
+
+    /// Replace the list, keeping the selection in range
+    pub fn set_checkpoints(&mut self, checkpoints: Vec<String>) {
+        self.checkpoints = checkpoints;
+        let selected = self.state.selected().unwrap_or(0).min(self.checkpoints.len().saturating_sub(1));
+        self.state.select((!self.checkpoints.is_empty()).then_some(selected));
+    }
+
+    pub fn next(&mut self) {
+        if !self.checkpoints.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + 1) % self.checkpoints.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.checkpoints.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + self.checkpoints.len() - 1) % self.checkpoints.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn selected(&self) -> Option<&String> {
+        self.state.selected().and_then(|i| self.checkpoints.get(i))
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 64, 18);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(format!(" Checkpoints: {} ", self.container_name))
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3), Constraint::Length(2)])
+            .split(inner);
+
+        if self.checkpoints.is_empty() {
+            let msg = Paragraph::new("No checkpoints")
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center);
+            frame.render_widget(msg, chunks[0]);
+        } else {
+            let items: Vec<ListItem> = self
+                .checkpoints
+                .iter()
+                .map(|name| ListItem::new(Span::styled(format!(" {}", name), Style::default().fg(Theme::FG))))
+                .collect();
+            let list = List::new(items).highlight_style(Style::default().bg(Theme::BG_HIGHLIGHT));
+            frame.render_stateful_widget(list, chunks[0], &mut self.state);
+        }
+
+        if let Some(ref error) = self.error {
+            let msg = Paragraph::new(format!(" {}", error))
+                .style(Style::default().fg(Theme::RED))
+                .wrap(ratatui::widgets::Wrap { trim: false });
+            frame.render_widget(msg, chunks[1]);
+        }
+
+        // Instructions
+        let key = |k: &'static str, color: Color| Span::styled(k, Style::default().fg(color).add_modifier(Modifier::BOLD));
+        let desc = |d: &'static str| Span::styled(d, Style::default().fg(Theme::FG_DARK));
+        let instructions = vec![
+            Line::from(vec![
+                key(" c ", Theme::CYAN),
+                desc("checkpoint & stop   "),
+                key(" l ", Theme::CYAN),
+                desc("checkpoint, leave running"),
+            ]),
+            Line::from(vec![
+                key(" Enter ", Theme::GREEN),
+                desc("restore   "),
+                key(" d ", Theme::RED),
+                desc("delete   "),
+                key(" Esc ", Theme::RED),
+                desc("close"),
+            ]),
+        ];
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[2]);
+    }
+}
@@ -0,0 +1,356 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph},
+};
+
+use crate::models::{CleanupItem, CleanupKind};
+use crate::ui::{centered_modal, fit_width, format_bytes, Theme};
+
+/// Where the wizard is: picking items, confirming them, removing them, or
+/// showing the report
+#[derive(Debug, Clone, PartialEq)]
+pub enum CleanupPhase {
+    Select,
+    /// Counts and sizes of the selection, before anything is removed
+    Confirm,
+    /// Removing the queued items one per tick
+    Running,
+    Done,
+}
+
+/// Cleanup wizard: pick unused resources, prune them with a progress report
+#[derive(Debug, Clone)]
+pub struct CleanupModal {
+    pub items: Vec<CleanupItem>,
+    pub checked: Vec<bool>,
+    pub state: ListState,
+    pub phase: CleanupPhase,
+    /// Indices still to remove while running
+    queue: Vec<usize>,
+    total: usize,
+    pub removed: usize,
+    pub reclaimed: u64,
+    pub errors: Vec<String>,
+    /// Stopped before the queue ran out
+    pub stopped: bool,
+}
+
+impl CleanupModal {
+    pub fn new(items: Vec<CleanupItem>) -> Self {
+        let mut state = ListState::default();
+        state.select((!items.is_empty()).then_some(0));
+        Self {
+            // Everything but volumes is selected by default: those may hold
+            // data, so they're only removed when ticked one by one (or with a)
+            checked: items.iter().map(|item| item.kind != CleanupKind::Volume).collect(),
+            items,
+            state,
+            phase: CleanupPhase::Select,
+            queue: Vec::new(),
+            total: 0,
+            removed: 0,
+            reclaimed: 0,
+            errors: Vec::new(),
+            stopped: false,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.items.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + 1) % self.items.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.items.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + self.items.len() - 1) % self.items.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        if let Some(checked) = self.state.selected().and_then(|i| self.checked.get_mut(i)) {
+            *checked = !*checked;
+        }
+    }
+
+    /// Select everything, or nothing if everything is already selected
+    pub fn toggle_all(&mut self) {
+        let all = self.checked.iter().all(|&c| c);
+        self.checked.iter_mut().for_each(|c| *c = !all);
+    }
+
+    /// Bytes freed by removing the selected items (as far as Docker reports sizes)
+    pub fn reclaimable(&self) -> u64 {
+        self.items
+            .iter()
+            .zip(&self.checked)
+            .filter(|(_, &checked)| checked)
+            .filter_map(|(item, _)| item.size)
+            .sum()
+    }
+
+    /// Selected items of a kind and their total size
+    fn selected_of(&self, kind: CleanupKind) -> (usize, u64) {
+        self.items
+            .iter()
+            .zip(&self.checked)
+            .filter(|(item, &checked)| checked && item.kind == kind)
+            .fold((0, 0), |(count, size), (item, _)| (count + 1, size + item.size.unwrap_or(0)))
+    }
+
+    /// Ask for confirmation of the selection (nothing happens without one)
+    pub fn review(&mut self) {
+        if self.checked.contains(&true) {
+            self.phase = CleanupPhase::Confirm;
+        }
+    }
+
+    /// Back from the confirmation to the selection
+    pub fn back(&mut self) {
+        self.phase = CleanupPhase::Select;
+    }
+
+    /// Stop removing: what's left of the queue stays
+    pub fn stop(&mut self) {
+        self.stopped = !self.queue.is_empty();
+        self.queue.clear();
+        self.phase = CleanupPhase::Done;
+    }
+
+    /// Queue the selected items for removal
+    pub fn start(&mut self) {
+        // Containers first so the images and volumes they hold are freed
+        let order = [CleanupKind::Container, CleanupKind::Image, CleanupKind::Volume, CleanupKind::Network];
+        let mut queue = Vec::new();
+        for kind in order {
+            queue.extend((0..self.items.len()).filter(|&i| self.checked[i] && self.items[i].kind == kind));
+        }
+        queue.reverse(); // popped from the back
+        self.queue = queue;
+        self.total = self.queue.len();
+        self.phase = if self.total == 0 { CleanupPhase::Done } else { CleanupPhase::Running };
+    }
+
+    /// Next item to remove while running
+    pub fn next_item(&mut self) -> Option<CleanupItem> {
+        let item = self.queue.pop().map(|i| self.items[i].clone());
+        if item.is_none() {
+            self.phase = CleanupPhase::Done;
+        }
+        item
+    }
+
+    /// Record the outcome of removing an item
+    pub fn record(&mut self, item: &CleanupItem, result: anyhow::Result<()>) {
+        match result {
+            Ok(()) => {
+                self.removed += 1;
+                self.reclaimed += item.size.unwrap_or(0);
+            }
+            Err(e) => self.errors.push(format!("{:#}", e)),
+        }
+        if self.queue.is_empty() {
+            self.phase = CleanupPhase::Done;
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 80, 24);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(" Cleanup ")
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        match self.phase {
+            CleanupPhase::Select => self.render_select(frame, inner),
+            CleanupPhase::Confirm => self.render_confirm(frame, inner),
+            CleanupPhase::Running => self.render_progress(frame, inner),
+            CleanupPhase::Done => self.render_report(frame, inner),
+        }
+    }
+
+    fn render_select(&mut self, frame: &mut Frame, area: Rect) {
+        if self.items.is_empty() {
+            let msg = Paragraph::new("Nothing to clean up")
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center);
+            frame.render_widget(msg, area);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(2)])
+            .split(area);
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .zip(&self.checked)
+            .map(|(item, &checked)| {
+                let check = if checked { "[x]" } else { "[ ]" };
+                let size = item.size.map(format_bytes).unwrap_or_else(|| "-".to_string());
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!(" {} ", check), Style::default().fg(if checked { Theme::GREEN } else { Theme::FG_DARK })),
+                    Span::styled(format!("{:<10}", item.kind.as_str()), Style::default().fg(Theme::MAUVE)),
+                    Span::styled(format!("{} ", fit_width(&item.name, 32)), Style::default().fg(Theme::FG)),
+                    Span::styled(format!("{:>9}  ", size), Style::default().fg(Theme::YELLOW)),
+                    Span::styled(item.detail.clone(), Style::default().fg(Theme::FG_DARK)),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(Style::default().bg(Theme::BG_HIGHLIGHT));
+        frame.render_stateful_widget(list, chunks[0], &mut self.state);
+
+        let selected = self.checked.iter().filter(|&&c| c).count();
+        let footer = Line::from(vec![
+            Span::styled(format!(" {} selected, {} reclaimable   ", selected, format_bytes(self.reclaimable())), Style::default().fg(Theme::FG)),
+            Span::styled("Space ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("toggle  ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled("a ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("all  ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled("Enter ", Style::default().fg(Theme::GREEN).add_modifier(Modifier::BOLD)),
+            Span::styled("review  ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled("Esc ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
+            Span::styled("cancel", Style::default().fg(Theme::FG_DARK)),
+        ]);
+        frame.render_widget(Paragraph::new(footer).alignment(Alignment::Center), chunks[1]);
+    }
+
+    fn render_confirm(&self, frame: &mut Frame, area: Rect) {
+        let mut lines = vec![Line::raw(""), Line::styled(" Remove:", Style::default().fg(Theme::FG).add_modifier(Modifier::BOLD))];
+        for kind in [CleanupKind::Container, CleanupKind::Image, CleanupKind::Volume, CleanupKind::Network] {
+            let (count, size) = self.selected_of(kind);
+            if count == 0 {
+                continue;
+            }
+            // Volume data can't be recreated from the recycle bin
+            let (color, note) = match kind {
+                CleanupKind::Volume => (Theme::RED, "  their data is deleted for good"),
+                CleanupKind::Container => (Theme::FG, "  recreatable from the recycle bin"),
+                _ => (Theme::FG, ""),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("   {:>4} {:<11}", count, format!("{}s", kind.as_str())), Style::default().fg(color)),
+                Span::styled(format!("{:>9}", format_bytes(size)), Style::default().fg(Theme::YELLOW)),
+                Span::styled(note, Style::default().fg(color)),
+            ]));
+        }
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(format!(" {} reclaimable", format_bytes(self.reclaimable())), Style::default().fg(Theme::GREEN)));
+        lines.push(Line::raw(""));
+        lines.push(Line::from(vec![
+            Span::styled(" y ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
+            Span::styled("remove them  ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled("n/Esc ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("back to the list", Style::default().fg(Theme::FG_DARK)),
+        ]));
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
+    fn render_progress(&self, frame: &mut Frame, area: Rect) {
+        let done = self.total - self.queue.len();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Length(1), Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+
+        frame.render_widget(
+            Paragraph::new(format!("Removing {}/{}...  Esc stops", done, self.total)).style(Style::default().fg(Theme::FG)),
+            chunks[1],
+        );
+        let ratio = if self.total == 0 { 1.0 } else { done as f64 / self.total as f64 };
+        frame.render_widget(
+            Gauge::default()
+                .gauge_style(Style::default().fg(Theme::GREEN).bg(Theme::BG))
+                .ratio(ratio)
+                .label(format!("{} reclaimed", format_bytes(self.reclaimed))),
+            chunks[2],
+        );
+    }
+
+    fn render_report(&self, frame: &mut Frame, area: Rect) {
+        let mut lines = vec![
+            Line::raw(""),
+            Line::styled(
+                format!(" Removed {} items, reclaimed {}", self.removed, format_bytes(self.reclaimed)),
+                Style::default().fg(Theme::GREEN).add_modifier(Modifier::BOLD),
+            ),
+        ];
+        if self.stopped {
+            lines.push(Line::styled(format!(" Stopped; {} left as they were", self.total - self.removed - self.errors.len()), Style::default().fg(Theme::YELLOW)));
+        }
+        if !self.errors.is_empty() {
+            lines.push(Line::raw(""));
+            lines.push(Line::styled(format!(" {} failed:", self.errors.len()), Style::default().fg(Theme::RED)));
+            lines.extend(self.errors.iter().map(|e| Line::styled(format!("  {}", e), Style::default().fg(Theme::FG_DARK))));
+        }
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(" Press Esc or Enter to close", Style::default().fg(Theme::FG_DARK)));
+        frame.render_widget(Paragraph::new(lines).wrap(ratatui::widgets::Wrap { trim: false }), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(kind: CleanupKind, name: &str, size: Option<u64>) -> CleanupItem {
+        CleanupItem { kind, id: name.to_string(), name: name.to_string(), detail: String::new(), size }
+    }
+
+    #[test]
+    fn test_removes_containers_before_images() {
+        let mut modal = CleanupModal::new(vec![
+            item(CleanupKind::Image, "img", Some(300)),
+            item(CleanupKind::Network, "net", None),
+            item(CleanupKind::Container, "old", Some(20)),
+        ]);
+        modal.checked[1] = false;
+        assert_eq!(modal.reclaimable(), 320);
+
+        modal.review();
+        assert_eq!(modal.phase, CleanupPhase::Confirm);
+        modal.start();
+        let order: Vec<String> = std::iter::from_fn(|| modal.next_item()).map(|i| i.name).collect();
+        assert_eq!(order, vec!["old", "img"]);
+        assert_eq!(modal.phase, CleanupPhase::Done);
+    }
+
+    #[test]
+    fn test_volumes_need_ticking_and_stop_keeps_the_rest() {
+        let mut modal = CleanupModal::new(vec![
+            item(CleanupKind::Volume, "pgdata", Some(4096)),
+            item(CleanupKind::Container, "a", None),
+            item(CleanupKind::Container, "b", None),
+        ]);
+        assert_eq!(modal.checked, vec![false, true, true]);
+        assert_eq!(modal.selected_of(CleanupKind::Volume), (0, 0));
+
+        modal.start();
+        let first = modal.next_item().unwrap();
+        modal.record(&first, Ok(()));
+        modal.stop();
+        assert_eq!(modal.phase, CleanupPhase::Done);
+        assert!(modal.stopped);
+        assert!(modal.next_item().is_none());
+        assert_eq!(modal.total - modal.removed, 1);
+
+        // Nothing selected: no confirmation to give
+        let mut modal = CleanupModal::new(vec![item(CleanupKind::Volume, "pgdata", None)]);
+        modal.review();
+        assert_eq!(modal.phase, CleanupPhase::Select);
+    }
+}
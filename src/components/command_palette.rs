@@ -0,0 +1,188 @@
+use crossterm::event::KeyCode;
+use fuzzy_matcher::FuzzyMatcher;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::components::filter_bar::matcher;
+use crate::keymap::{self, Command, KeyContext};
+use crate::ui::{centered_modal, Theme};
+
+/// Visible entries
+const PAGE: u16 = 14;
+
+/// A command as the palette lists it
+#[derive(Debug)]
+struct Entry {
+    command: Command,
+    desc: &'static str,
+    /// Key that runs it in the current view, if any
+    key: Option<KeyCode>,
+    label: String,
+}
+
+/// Fuzzy-searchable list of every command, the current view's first (Ctrl+P)
+#[derive(Debug)]
+pub struct CommandPalette {
+    pub query: String,
+    context: KeyContext,
+    entries: Vec<Entry>,
+    /// Indices of matching entries, best first, with the matched description chars
+    matches: Vec<(usize, Vec<usize>)>,
+    state: ListState,
+}
+
+impl CommandPalette {
+    pub fn new(context: KeyContext) -> Self {
+        let mut palette = Self {
+            query: String::new(),
+            context,
+            entries: entries(context),
+            matches: Vec::new(),
+            state: ListState::default(),
+        };
+        palette.update_matches();
+        palette
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        self.query.push(c);
+        self.update_matches();
+    }
+
+    pub fn handle_backspace(&mut self) {
+        self.query.pop();
+        self.update_matches();
+    }
+
+    pub fn next(&mut self) {
+        if !self.matches.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + 1) % self.matches.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.matches.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + self.matches.len() - 1) % self.matches.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    /// The highlighted command, with the key that runs it in this view if it has one
+    pub fn selected(&self) -> Option<(Command, Option<KeyCode>)> {
+        let (i, _) = self.state.selected().and_then(|i| self.matches.get(i))?;
+        let entry = &self.entries[*i];
+        Some((entry.command, entry.key))
+    }
+
+    /// Rank the entries against the query (stable for ties)
+    fn update_matches(&mut self) {
+        let mut scored: Vec<(i64, usize, Vec<usize>)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                if self.query.is_empty() {
+                    return Some((0, i, Vec::new()));
+                }
+                matcher().fuzzy_indices(entry.desc, &self.query).map(|(score, indices)| (score, i, indices))
+            })
+            .collect();
+        scored.sort_by_key(|(score, ..)| std::cmp::Reverse(*score));
+        self.matches = scored.into_iter().map(|(_, i, indices)| (i, indices)).collect();
+        self.state.select((!self.matches.is_empty()).then_some(0));
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 60, PAGE + 4);
+
+        // Clear the background
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(format!(" Commands: {} ", self.context.title()))
+            .title_bottom(Line::styled(" ↑↓ select  Enter run  Esc close ", Style::default().fg(Theme::FG_DARK)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MODAL_BORDER))
+            .style(Style::default().bg(Theme::MODAL_BG));
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(0)])
+            .split(inner);
+
+        let input = Line::from(vec![
+            Span::styled(" > ", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", self.query), Style::default().fg(Theme::FG)),
+        ]);
+        frame.render_widget(Paragraph::new(input), chunks[0]);
+
+        let width = chunks[1].width as usize;
+        let items: Vec<ListItem> = self
+            .matches
+            .iter()
+            .map(|(entry, indices)| {
+                let entry = &self.entries[*entry];
+                let mut spans = vec![Span::raw(" ")];
+                for (i, c) in entry.desc.chars().enumerate() {
+                    let style = if indices.contains(&i) {
+                        Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Theme::FG)
+                    };
+                    spans.push(Span::styled(c.to_string(), style));
+                }
+                // Key label right-aligned
+                let used = entry.desc.chars().count() + 1;
+                let pad = width.saturating_sub(used + entry.label.chars().count() + 1);
+                spans.push(Span::raw(" ".repeat(pad)));
+                spans.push(Span::styled(entry.label.clone(), Style::default().fg(Theme::CYAN)));
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items).highlight_style(Style::default().bg(Theme::BG_HIGHLIGHT));
+        frame.render_stateful_widget(list, chunks[1], &mut self.state);
+    }
+}
+
+/// Every command once: the view's bindings, then the global ones, then the
+/// rest under the description and view of their first binding
+fn entries(context: KeyContext) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = keymap::bindings_for(context)
+        .chain(keymap::bindings_for(KeyContext::Global))
+        .map(|b| Entry { command: b.command, desc: b.desc, key: Some(b.keys[0]), label: b.label() })
+        .collect();
+    for command in Command::ALL {
+        if entries.iter().any(|e| e.command == command) {
+            continue;
+        }
+        let home = keymap::BINDINGS.iter().find(|b| b.command == command);
+        entries.push(Entry {
+            command,
+            desc: keymap::describe(command),
+            key: None,
+            label: home.map(|b| format!("{} {}", b.context.title(), b.label())).unwrap_or_default(),
+        });
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lists_every_command_once_view_first() {
+        let palette = CommandPalette::new(KeyContext::Logs);
+        assert_eq!(palette.entries.len(), Command::ALL.len());
+        assert_eq!(palette.selected(), Some((Command::Down, Some(KeyCode::Char('j')))));
+
+        let pull = palette.entries.iter().find(|e| e.command == Command::Pull).unwrap();
+        assert_eq!(pull.key, None);
+        assert_eq!(pull.label, "Container List o");
+    }
+}
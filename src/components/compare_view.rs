@@ -0,0 +1,136 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph, Sparkline},
+};
+
+use crate::components::history_chart::{format_rate, range_tab};
+use crate::history::{Sample, TimeRange};
+use crate::ui::{border_style, Theme};
+
+/// Width of the value label after each sparkline
+const VALUE_WIDTH: u16 = 10;
+
+/// Side-by-side CPU/memory/network sparklines for marked containers, each metric on a shared scale
+pub struct CompareView;
+
+impl CompareView {
+    pub fn render(frame: &mut Frame, area: Rect, series: &[(String, Vec<Sample>)], range: TimeRange) {
+        let title = Line::from(vec![
+            Span::styled(format!(" Compare ({}) ", series.len()), Style::default().fg(Theme::LAVENDER).add_modifier(Modifier::BOLD)),
+            Span::styled("│ ", Style::default().fg(Theme::BORDER)),
+            range_tab(TimeRange::FiveMinutes, range),
+            range_tab(TimeRange::OneHour, range),
+            range_tab(TimeRange::SixHours, range),
+            range_tab(TimeRange::Day, range),
+        ]);
+
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(border_style(true));
+
+        if series.len() < 2 {
+            let text = Paragraph::new("Mark two or more containers with Space to compare them")
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(text, area);
+            return;
+        }
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        // Shared scales so rows are directly comparable
+        let max_of = |value: fn(&Sample) -> f64| {
+            series
+                .iter()
+                .flat_map(|(_, samples)| samples.iter().map(value))
+                .fold(0.0_f64, f64::max)
+        };
+        let cpu_max = max_of(|s| s.cpu).max(1.0);
+        let mem_max = max_of(|s| s.mem).max(1.0);
+        let net_max = max_of(|s| s.rx + s.tx).max(1.0);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(inner);
+        let row_height = (rows[1].height / series.len() as u16).clamp(1, 4);
+        let row_areas = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(series.iter().map(|_| Constraint::Length(row_height)))
+            .split(rows[1]);
+
+        let columns = |area: Rect| {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Length(20),
+                    Constraint::Ratio(1, 3),
+                    Constraint::Ratio(1, 3),
+                    Constraint::Ratio(1, 3),
+                ])
+                .spacing(1)
+                .split(area)
+        };
+
+        // Column headers with the shared maximum
+        let header = columns(rows[0]);
+        let header_style = Style::default().fg(Theme::FG_DARK).add_modifier(Modifier::BOLD);
+        frame.render_widget(Paragraph::new(Span::styled("Container", header_style)), header[0]);
+        frame.render_widget(Paragraph::new(Span::styled(format!("CPU (max {:.1}%)", cpu_max), header_style)), header[1]);
+        frame.render_widget(Paragraph::new(Span::styled(format!("MEM (max {:.1}%)", mem_max), header_style)), header[2]);
+        frame.render_widget(Paragraph::new(Span::styled(format!("NET rx+tx (max {})", format_rate(net_max)), header_style)), header[3]);
+
+        for ((name, samples), row) in series.iter().zip(row_areas.iter()) {
+            let cells = columns(*row);
+            frame.render_widget(
+                Paragraph::new(Span::styled(name.clone(), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))),
+                cells[0],
+            );
+
+            let last = samples.last();
+            render_cell(frame, cells[1], samples, |s| s.cpu, cpu_max, Theme::CYAN,
+                last.map(|s| format!("{:.1}%", s.cpu)));
+            render_cell(frame, cells[2], samples, |s| s.mem, mem_max, Theme::MAGENTA,
+                last.map(|s| format!("{:.1}%", s.mem)));
+            render_cell(frame, cells[3], samples, |s| s.rx + s.tx, net_max, Theme::GREEN,
+                last.map(|s| format_rate(s.rx + s.tx)));
+        }
+    }
+}
+
+/// One sparkline cell scaled to `max`, with the latest value on the right
+fn render_cell(
+    frame: &mut Frame,
+    area: Rect,
+    samples: &[Sample],
+    value: fn(&Sample) -> f64,
+    max: f64,
+    color: Color,
+    latest: Option<String>,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(VALUE_WIDTH)])
+        .split(area);
+
+    // Sparkline takes integers: scale to 1/100ths of the shared max
+    let width = chunks[0].width as usize;
+    let data: Vec<u64> = samples
+        .iter()
+        .skip(samples.len().saturating_sub(width))
+        .map(|s| (value(s) / max * 100.0).round() as u64)
+        .collect();
+    frame.render_widget(
+        Sparkline::default().data(&data).max(100).style(Style::default().fg(color)),
+        chunks[0],
+    );
+
+    let label = latest.unwrap_or_else(|| "-".to_string());
+    frame.render_widget(
+        Paragraph::new(Span::styled(format!("{:>w$}", label, w = VALUE_WIDTH as usize), Style::default().fg(Theme::FG))),
+        chunks[1],
+    );
+}
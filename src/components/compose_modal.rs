@@ -0,0 +1,248 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::ui::{centered_modal, Theme};
+
+/// One compose file of the project: its path and contents, or why it
+/// couldn't be read
+#[derive(Debug, Clone)]
+pub struct ComposeFile {
+    pub path: String,
+    pub content: Result<String, String>,
+}
+
+/// Read-only view of a container's compose files, opened at its service
+#[derive(Debug, Clone)]
+pub struct ComposeModal {
+    pub project: String,
+    /// Service of the container it was opened for
+    pub service: Option<String>,
+    pub files: Vec<ComposeFile>,
+    /// File shown (Tab cycles)
+    pub file: usize,
+    pub scroll: usize,
+}
+
+impl ComposeModal {
+    /// Opens on the first file that defines the service, at its definition
+    pub fn new(project: String, service: Option<String>, files: Vec<ComposeFile>) -> Self {
+        let mut modal = Self { project, service, files, file: 0, scroll: 0 };
+        if let Some(file) = (0..modal.files.len()).find(|&i| modal.service_line(i).is_some()) {
+            modal.file = file;
+            modal.jump_to_service();
+        }
+        modal
+    }
+
+    fn service_line(&self, file: usize) -> Option<usize> {
+        let service = self.service.as_deref()?;
+        let content = self.files.get(file)?.content.as_ref().ok()?;
+        service_line(content, service)
+    }
+
+    /// Scroll to the container's service in the file shown
+    pub fn jump_to_service(&mut self) {
+        if let Some(line) = self.service_line(self.file) {
+            self.scroll = line;
+        }
+    }
+
+    pub fn next_file(&mut self) {
+        if !self.files.is_empty() {
+            self.file = (self.file + 1) % self.files.len();
+            self.scroll = 0;
+        }
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// Clamped to the content when rendered
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll += lines;
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = usize::MAX;
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 90, area.height.saturating_sub(4));
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let Some(file) = self.files.get(self.file) else {
+            return;
+        };
+        let position = match self.files.len() {
+            1 => String::new(),
+            count => format!(" ({}/{})", self.file + 1, count),
+        };
+        let block = Block::default()
+            .title(format!(" Compose: {} - {}{} ", self.project, file.path, position))
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(inner);
+
+        let content = match file.content {
+            Ok(ref content) => content,
+            Err(ref e) => {
+                let msg = Paragraph::new(format!("Can't read {}: {}", file.path, e))
+                    .style(Style::default().fg(Theme::RED))
+                    .alignment(Alignment::Center);
+                frame.render_widget(msg, chunks[0]);
+                return;
+            }
+        };
+
+        let total = content.lines().count();
+        let height = chunks[0].height as usize;
+        self.scroll = self.scroll.min(total.saturating_sub(height));
+        let service_line = self.service_line(self.file);
+        let lines: Vec<Line> = content
+            .lines()
+            .enumerate()
+            .skip(self.scroll)
+            .take(height)
+            .map(|(i, line)| {
+                let number_style = match service_line == Some(i) {
+                    true => Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD),
+                    false => Style::default().fg(Theme::FG_DARK),
+                };
+                let mut spans = vec![Span::styled(format!("{:>4} ", i + 1), number_style)];
+                spans.extend(highlight_yaml(line));
+                Line::from(spans)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+        let mut instructions = vec![
+            Span::styled(" ↑↓ ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("scroll   ", Style::default().fg(Theme::FG_DARK)),
+        ];
+        if let Some(ref service) = self.service {
+            instructions.push(Span::styled(" s ", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)));
+            instructions.push(Span::styled(format!("go to {}   ", service), Style::default().fg(Theme::FG_DARK)));
+        }
+        if self.files.len() > 1 {
+            instructions.push(Span::styled(" Tab ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)));
+            instructions.push(Span::styled("next file   ", Style::default().fg(Theme::FG_DARK)));
+        }
+        instructions.push(Span::styled(" Esc ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)));
+        instructions.push(Span::styled("close   ", Style::default().fg(Theme::FG_DARK)));
+        instructions.push(Span::styled(
+            format!(" [{}/{}] ", (self.scroll + 1).min(total), total),
+            Style::default().fg(Theme::FG_DARK),
+        ));
+        frame.render_widget(Paragraph::new(Line::from(instructions)).alignment(Alignment::Center), chunks[1]);
+    }
+}
+
+/// Line of a service's definition under the top-level `services:` key
+fn service_line(content: &str, service: &str) -> Option<usize> {
+    let mut in_services = false;
+    let mut service_indent = None;
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let indent = line.len() - trimmed.len();
+        if indent == 0 {
+            in_services = trimmed.trim_end() == "services:";
+            continue;
+        }
+        // Services are the first indentation level below `services:`
+        if in_services && indent == *service_indent.get_or_insert(indent) {
+            let key = trimmed.split(':').next().unwrap_or_default().trim_matches(['"', '\'']);
+            if key == service {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Colors for one line of YAML: keys, list dashes, scalars and comments
+fn highlight_yaml(line: &str) -> Vec<Span<'static>> {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    let mut spans = vec![Span::raw(indent.to_string())];
+    if trimmed.starts_with('#') {
+        spans.push(Span::styled(trimmed.to_string(), Style::default().fg(Theme::FG_DARK)));
+        return spans;
+    }
+
+    let mut rest = trimmed;
+    while let Some(item) = rest.strip_prefix("- ") {
+        spans.push(Span::styled("- ", Style::default().fg(Theme::ORANGE)));
+        rest = item;
+    }
+
+    // Comments after a value (a # inside quotes is rare enough in compose files)
+    let (rest, comment) = match rest.find(" #") {
+        Some(at) => (&rest[..at], Some(&rest[at..])),
+        None => (rest, None),
+    };
+
+    let key_end = rest.find(": ").or_else(|| rest.strip_suffix(':').map(str::len));
+    let value = match key_end {
+        Some(end) if !rest.starts_with(['"', '\'']) || rest[..end].ends_with(['"', '\'']) => {
+            let key_style = match indent.is_empty() {
+                true => Style::default().fg(Theme::LAVENDER).add_modifier(Modifier::BOLD),
+                false => Style::default().fg(Theme::CYAN),
+            };
+            spans.push(Span::styled(rest[..end].to_string(), key_style));
+            spans.push(Span::styled(":", Style::default().fg(Theme::FG_DARK)));
+            &rest[end + 1..]
+        }
+        _ => rest,
+    };
+    if !value.is_empty() {
+        spans.push(Span::styled(value.to_string(), Style::default().fg(scalar_color(value.trim()))));
+    }
+    if let Some(comment) = comment {
+        spans.push(Span::styled(comment.to_string(), Style::default().fg(Theme::FG_DARK)));
+    }
+    spans
+}
+
+/// Strings green, numbers and constants orange, anchors and the rest plain
+fn scalar_color(value: &str) -> Color {
+    if value.starts_with(['"', '\'']) {
+        Theme::GREEN
+    } else if value.parse::<f64>().is_ok() || matches!(value, "true" | "false" | "null" | "~") {
+        Theme::ORANGE
+    } else if value.starts_with(['&', '*', '|', '>']) {
+        Theme::MAGENTA
+    } else {
+        Theme::FG
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_line() {
+        let compose = "name: app\nservices:\n  # the site\n  web:\n    image: nginx\n    environment:\n      db: x\n  \"db\":\n    image: postgres\nvolumes:\n  cache:\n";
+        assert_eq!(service_line(compose, "web"), Some(3));
+        assert_eq!(service_line(compose, "db"), Some(7));
+        assert_eq!(service_line(compose, "cache"), None);
+        assert_eq!(service_line(compose, "image"), None);
+    }
+}
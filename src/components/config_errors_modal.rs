@@ -0,0 +1,78 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::config::ConfigIssue;
+use crate::ui::{centered_modal, Theme};
+
+/// Problems found in config.toml and the project manifests, shown at
+/// startup (and when an edited config doesn't load) instead of being
+/// printed behind the alternate screen
+#[derive(Debug, Clone)]
+pub struct ConfigErrorsModal {
+    pub issues: Vec<ConfigIssue>,
+    pub scroll: usize,
+}
+
+impl ConfigErrorsModal {
+    pub fn new(issues: Vec<ConfigIssue>) -> Self {
+        Self { issues, scroll: 0 }
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// Clamped to the content when rendered
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll += lines;
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 80, 20);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(format!(" Config problems ({}) ", self.issues.len()))
+            .title_style(Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::RED))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(2)])
+            .split(inner);
+
+        let mut lines = Vec::new();
+        for issue in &self.issues {
+            lines.push(Line::styled(format!(" {}", issue.position()), Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)));
+            lines.push(Line::styled(format!("   {}", issue.message), Style::default().fg(Theme::FG)));
+            lines.push(Line::raw(""));
+        }
+
+        self.scroll = self.scroll.min(lines.len().saturating_sub(chunks[0].height as usize));
+        let text = Paragraph::new(lines).wrap(Wrap { trim: false }).scroll((self.scroll as u16, 0));
+        frame.render_widget(text, chunks[0]);
+
+        let instructions = vec![
+            Line::styled(
+                "Running with the defaults for a config that didn't load; saving a fix reloads it",
+                Style::default().fg(Theme::FG_DARK),
+            ),
+            Line::from(vec![
+                Span::styled(" ↑↓ ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled("scroll   ", Style::default().fg(Theme::FG_DARK)),
+                Span::styled(" Esc ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
+                Span::styled("close", Style::default().fg(Theme::FG_DARK)),
+            ]),
+        ];
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[1]);
+    }
+}
@@ -1,49 +1,205 @@
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 
+use crate::config::ConfirmConfig;
+use crate::models::RemovalPreview;
 use crate::ui::{centered_modal, Theme};
 
 /// Confirm action modal component
 pub struct ConfirmModal;
 
-#[derive(Debug, Clone)]
+/// Operation applied to several containers at once
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BatchOp {
+    Start,
+    Stop,
+}
+
+impl BatchOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Stop => "stop",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Start => "Start",
+            Self::Stop => "Stop",
+        }
+    }
+
+    pub fn past_tense(&self) -> &'static str {
+        match self {
+            Self::Start => "Started",
+            Self::Stop => "Stopped",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum ConfirmAction {
     Delete(String),
     Stop(String),
+    /// Stop without a grace period (SIGKILL right away)
+    StopNow(String),
+    Restart(String),
+    /// Restart without a grace period
+    RestartNow(String),
+    /// Stop and remove every container and network of a compose project
+    ComposeDown(String),
+    /// Remove all stopped containers
+    PruneContainers,
+    /// Apply an operation to several containers
+    Batch(BatchOp, Vec<String>),
+    /// Pull the newest image and recreate the container with it
+    UpdateImage(String),
+    /// Rolling restart of a Swarm service
+    RestartService(String),
 }
 
-impl ConfirmModal {
-    pub fn render(frame: &mut Frame, area: Rect, action: &ConfirmAction) {
-        let modal_area = centered_modal(area, 50, 8);
+impl ConfirmAction {
+    /// Whether the config asks for a prompt before running this action
+    pub fn needs_confirmation(&self, config: &ConfirmConfig) -> bool {
+        match self {
+            Self::Delete(_) => config.delete,
+            Self::Stop(_) | Self::StopNow(_) => config.stop,
+            Self::Restart(_) | Self::RestartNow(_) => config.restart,
+            Self::ComposeDown(_) => config.compose_down,
+            Self::PruneContainers => config.prune,
+            Self::Batch(..) => config.batch,
+            Self::RestartService(_) => config.restart,
+            Self::UpdateImage(_) => config.restart,
+        }
+    }
 
-        // Clear the background
-        frame.render_widget(Clear, modal_area);
+    /// Secondary choice offered next to confirm: (key, label, action)
+    pub fn alternative(&self) -> Option<(char, &'static str, ConfirmAction)> {
+        match self {
+            Self::Delete(name) => Some(('s', "Stop & keep", Self::Stop(name.clone()))),
+            Self::Stop(name) => Some(('k', "Stop now", Self::StopNow(name.clone()))),
+            Self::Restart(name) => Some(('k', "Restart now", Self::RestartNow(name.clone()))),
+            _ => None,
+        }
+    }
 
-        let (title, message) = match action {
-            ConfirmAction::Delete(name) => (
-                " Confirm Delete ",
-                format!("Are you sure you want to delete '{}'?\n\nThis action cannot be undone.", name),
+    fn title_and_message(&self) -> (String, String) {
+        match self {
+            Self::Delete(name) => (
+                " Confirm Delete ".to_string(),
+                format!("Are you sure you want to delete '{}'?\n\nIts config is kept so it can be recreated (U).", name),
             ),
-            ConfirmAction::Stop(name) => (
-                " Confirm Stop ",
+            Self::Stop(name) => (
+                " Confirm Stop ".to_string(),
                 format!("Are you sure you want to stop '{}'?", name),
             ),
-        };
-
-        let text = vec![
-            Line::raw(""),
-            Line::styled(&message, Style::default().fg(Color::White)),
-            Line::raw(""),
-            Line::raw(""),
-            Line::from(vec![
-                Span::styled("  y/Enter ", Style::default().fg(Color::Green)),
-                Span::raw("Confirm    "),
-                Span::styled("n/Esc ", Style::default().fg(Color::Red)),
-                Span::raw("Cancel"),
-            ]),
+            Self::StopNow(name) => (
+                " Confirm Stop Now ".to_string(),
+                format!("Kill '{}' without waiting for it to shut down?", name),
+            ),
+            Self::Restart(name) => (
+                " Confirm Restart ".to_string(),
+                format!("Are you sure you want to restart '{}'?", name),
+            ),
+            Self::RestartNow(name) => (
+                " Confirm Restart Now ".to_string(),
+                format!("Kill and restart '{}' without waiting for it to shut down?", name),
+            ),
+            Self::ComposeDown(project) => (
+                " Confirm Compose Down ".to_string(),
+                format!(
+                    "Stop and remove all containers and networks of project '{}'?\n\nThis action cannot be undone.",
+                    project
+                ),
+            ),
+            Self::PruneContainers => (
+                " Confirm Prune ".to_string(),
+                "Remove all stopped containers?\n\nThis action cannot be undone.".to_string(),
+            ),
+            Self::UpdateImage(name) => (
+                " Confirm Update ".to_string(),
+                format!("Pull the latest image for '{}' and recreate it?\n\nIts config is kept; its writable layer is not.", name),
+            ),
+            Self::RestartService(name) => (
+                " Confirm Service Restart ".to_string(),
+                format!("Redeploy every task of service '{}'?\n\nTasks are replaced per its update config.", name),
+            ),
+            Self::Batch(op, names) => {
+                let list = short_list(names.iter().map(String::as_str), 3);
+                (
+                    format!(" Confirm {} {} ", op.label(), names.len()),
+                    format!("{} {} containers?\n\n{}", op.label(), names.len(), list),
+                )
+            }
+        }
+    }
+}
+
+/// "a, b, c, +2 more"
+fn short_list<'a>(names: impl ExactSizeIterator<Item = &'a str>, max: usize) -> String {
+    let total = names.len();
+    let mut list = names.take(max).collect::<Vec<_>>().join(", ");
+    if total > max {
+        list.push_str(&format!(", +{} more", total - max));
+    }
+    list
+}
+
+/// Lines listing what a compose down or prune removes, and what it leaves
+fn preview_lines(preview: &RemovalPreview) -> Vec<Line<'static>> {
+    let mb = preview.reclaimed() as f64 / (1024.0 * 1024.0);
+    let mut lines = vec![Line::styled(
+        format!("Removes {} containers (~{:.1} MB of writable layers):", preview.containers.len(), mb),
+        Style::default().fg(Theme::RED),
+    )];
+    let names = preview.containers.iter().map(|(name, _)| name.as_str());
+    lines.push(Line::styled(short_list(names, 8), Style::default().fg(Theme::FG)));
+    if !preview.networks.is_empty() {
+        lines.push(Line::styled(format!("and {} networks:", preview.networks.len()), Style::default().fg(Theme::RED)));
+        lines.push(Line::styled(short_list(preview.networks.iter().map(String::as_str), 6), Style::default().fg(Theme::FG)));
+    }
+    if !preview.volumes.is_empty() {
+        let short: Vec<&str> = preview.volumes.iter().map(|v| &v[..12]).collect();
+        lines.push(Line::styled(
+            format!("Keeps {} anonymous volumes (remove them with W):", preview.volumes.len()),
+            Style::default().fg(Theme::YELLOW),
+        ));
+        lines.push(Line::styled(short_list(short.into_iter(), 4), Style::default().fg(Theme::FG_DARK)));
+    }
+    lines
+}
+
+impl ConfirmModal {
+    pub fn render(frame: &mut Frame, area: Rect, action: &ConfirmAction, preview: Option<&RemovalPreview>) {
+        let (width, height) = if preview.is_some() { (70, 18) } else { (56, 10) };
+        let modal_area = centered_modal(area, width, height);
+
+        // Clear the background
+        frame.render_widget(Clear, modal_area);
+
+        let (title, message) = action.title_and_message();
+
+        let mut text = vec![Line::raw("")];
+        text.extend(message.lines().map(|l| Line::styled(l.to_string(), Style::default().fg(Color::White))));
+        text.push(Line::raw(""));
+        if let Some(preview) = preview {
+            text.extend(preview_lines(preview));
+            text.push(Line::raw(""));
+        }
+        let mut keys = vec![
+            Span::styled("  y/Enter ", Style::default().fg(Color::Green)),
+            Span::raw("Confirm    "),
         ];
+        if let Some((key, label, _)) = action.alternative() {
+            keys.push(Span::styled(format!("{} ", key), Style::default().fg(Color::Yellow)));
+            keys.push(Span::raw(format!("{}    ", label)));
+        }
+        keys.push(Span::styled("n/Esc ", Style::default().fg(Color::Red)));
+        keys.push(Span::raw("Cancel"));
+        text.push(Line::from(keys));
 
         let block = Block::default()
             .title(title)
@@ -53,7 +209,8 @@ impl ConfirmModal {
 
         let paragraph = Paragraph::new(text)
             .block(block)
-            .alignment(Alignment::Center);
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
 
         frame.render_widget(paragraph, modal_area);
     }
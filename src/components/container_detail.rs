@@ -3,11 +3,12 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
+use crate::docker::logs::line_timestamp;
 use crate::models::ContainerInfo;
-use crate::ui::{border_style, status_color, Theme};
+use crate::ui::{border_style, status_color, truncate_width, Theme};
 use crate::components::sparkline::StatsHistory;
 
-/// Container detail component (top of right pane)
+/// Container detail component (the split view's right pane)
 pub struct ContainerDetail;
 
 impl ContainerDetail {
@@ -57,7 +58,7 @@ impl ContainerDetail {
                     ]),
                     Line::from(vec![
                         Span::styled("Image:  ", Style::default().fg(Theme::FG_DARK)),
-                        Span::styled(truncate(&c.image, 40), Style::default().fg(Theme::FG)),
+                        Span::styled(truncate_width(&c.image, 40), Style::default().fg(Theme::FG)),
                     ]),
                     Line::from(vec![
                         Span::styled("Status: ", Style::default().fg(Theme::FG_DARK)),
@@ -69,7 +70,7 @@ impl ContainerDetail {
                     ]),
                     Line::from(vec![
                         Span::styled("Ports:  ", Style::default().fg(Theme::FG_DARK)),
-                        Span::styled(truncate(&ports_str, 40), Style::default().fg(Theme::YELLOW)),
+                        Span::styled(truncate_width(&ports_str, 40), Style::default().fg(Theme::YELLOW)),
                     ]),
                 ];
 
@@ -90,6 +91,33 @@ impl ContainerDetail {
         }
     }
 
+    /// Newest log lines that fit, with the time of day instead of the full timestamp
+    pub fn render_log_tail(frame: &mut Frame, area: Rect, lines: Option<&[String]>) {
+        let block = Block::default()
+            .title(" Logs ")
+            .title_style(Style::default().fg(Theme::FG_DARK))
+            .borders(Borders::ALL)
+            .border_style(border_style(false));
+        let Some(lines) = lines else {
+            let text = Paragraph::new("Loading logs...").style(Style::default().fg(Theme::FG_DARK)).block(block);
+            frame.render_widget(text, area);
+            return;
+        };
+
+        let height = block.inner(area).height as usize;
+        let tail: Vec<Line> = lines[lines.len().saturating_sub(height)..]
+            .iter()
+            .map(|line| match (line_timestamp(line), line.split_once(' ')) {
+                (Some(at), Some((_, message))) => Line::from(vec![
+                    Span::styled(at.format("%H:%M:%S ").to_string(), Style::default().fg(Theme::FG_DARK)),
+                    Span::raw(message),
+                ]),
+                _ => Line::raw(line.as_str()),
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(tail).block(block), area);
+    }
+
     fn render_stats(frame: &mut Frame, area: Rect, container: &ContainerInfo, history: &StatsHistory) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -97,8 +125,8 @@ impl ContainerDetail {
             .split(area);
 
         // Get sparkline data
-        let cpu_history = history.get_cpu(&container.name);
-        let mem_history = history.get_mem(&container.name);
+        let cpu_history = history.get_cpu(&container.id);
+        let mem_history = history.get_mem(&container.id);
 
         let sparkline_width = 20;
         let cpu_spark = StatsHistory::to_sparkline(&cpu_history, sparkline_width);
@@ -133,14 +161,6 @@ impl ContainerDetail {
     }
 }
 
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}…", &s[..max_len - 1])
-    }
-}
-
 fn percent_color(percent: f32) -> Color {
     if percent > 80.0 {
         Theme::RED
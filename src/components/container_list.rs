@@ -1,28 +1,119 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, Cell, HighlightSpacing, Paragraph, Row, Table, TableState},
 };
 
 use crate::app::{ListViewMode, StatusFilter};
-use crate::models::ContainerInfo;
-use crate::ui::{border_style, selected_style, status_color, status_icon, Theme, title_style};
+use crate::columns::{Column, ColumnSpec, ColumnsConfig};
+use crate::components::filter_bar::{match_container, match_positions, MatchField};
+use crate::components::vuln_modal::severity_color;
+use crate::models::{ContainerInfo, ContainerStats, ContainerStatus, ContainerTotals, ProjectSort, Transition};
+use crate::trivy::{Severity, SeverityCounts};
+use crate::ui::{border_style, fit_width, format_bytes, selected_style, status_color, status_icon, truncate_width, Theme, title_style};
+
+/// Containers per status across the whole list, shown on the filter tabs
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StatusCounts {
+    pub running: usize,
+    pub paused: usize,
+    /// Everything else: exited, created, dead, restarting...
+    pub stopped: usize,
+}
+
+impl StatusCounts {
+    pub fn of(containers: &[ContainerInfo]) -> Self {
+        let mut counts = Self::default();
+        for c in containers {
+            match c.status {
+                ContainerStatus::Running => counts.running += 1,
+                ContainerStatus::Paused => counts.paused += 1,
+                _ => counts.stopped += 1,
+            }
+        }
+        counts
+    }
+
+    pub fn total(&self) -> usize {
+        self.running + self.paused + self.stopped
+    }
+}
 
 /// Container list component (full-width with inline stats)
 pub struct ContainerList {
-    pub state: ListState,
+    pub state: TableState,
     pub focused: bool,
     /// When in Groups mode, maps visual index to container index (None = header row)
     item_to_container: Vec<Option<usize>>,
+    /// (ID, name) of the container on each row (None = header row), so the
+    /// selection can follow its container when the rows change
+    row_keys: Vec<Option<(String, String)>>,
+    /// Containers with an active alert (rows highlighted)
+    pub alerting: HashSet<String>,
+    /// Active name filter, used to highlight matched characters
+    pub match_query: String,
+    /// Keep-alive watched containers and their restart counts
+    pub keep_alive: HashMap<String, u32>,
+    /// Containers marked for the compare view
+    pub marked: HashSet<String>,
+    /// Containers whose image has a newer version in its registry
+    pub updates: HashSet<String>,
+    /// Containers watched for their exit (notify when they stop)
+    pub waiting: HashSet<String>,
+    /// Active user-defined group filter, shown in the title
+    pub group: Option<String>,
+    /// Containers pinned to the top of the list
+    pub pinned: HashSet<String>,
+    /// Containers restarting over and over (warning after the name)
+    pub crash_looping: HashSet<String>,
+    /// Rows that fit at the last render, for half-page and H/M/L moves
+    pub page_height: usize,
+    /// Per-status totals for the filter tabs
+    pub counts: StatusCounts,
+    /// Image sizes by image ID (Details mode; missing while being looked up)
+    pub image_sizes: BTreeMap<String, u64>,
+    /// Order of projects in Groups mode, highlighted in their headers
+    pub project_sort: ProjectSort,
+    /// Start/stop/... in flight by container name, shown until they finish
+    pub transitions: HashMap<String, Transition>,
+    /// Current spinner frame for rows in transition
+    pub spinner: &'static str,
+    /// trivy counts by image ID (Details mode badge)
+    pub vulns: HashMap<String, SeverityCounts>,
+    /// Image IDs being scanned by trivy
+    pub scanning: HashSet<String>,
+    /// Columns of each view mode, from `[columns]`
+    pub columns: ColumnsConfig,
 }
 
 impl ContainerList {
     pub fn new() -> Self {
-        let mut state = ListState::default();
+        let mut state = TableState::default();
         state.select(Some(0));
         Self {
             state,
             focused: true,
             item_to_container: Vec::new(),
+            row_keys: Vec::new(),
+            alerting: HashSet::new(),
+            match_query: String::new(),
+            keep_alive: HashMap::new(),
+            marked: HashSet::new(),
+            updates: HashSet::new(),
+            waiting: HashSet::new(),
+            group: None,
+            pinned: HashSet::new(),
+            crash_looping: HashSet::new(),
+            page_height: 0,
+            counts: StatusCounts::default(),
+            image_sizes: BTreeMap::new(),
+            project_sort: ProjectSort::default(),
+            transitions: HashMap::new(),
+            spinner: "",
+            vulns: HashMap::new(),
+            scanning: HashSet::new(),
+            columns: ColumnsConfig::default(),
         }
     }
 
@@ -82,6 +173,39 @@ impl ContainerList {
         self.state.select(Some(i));
     }
 
+    /// Move selection by `delta` rows, stopping at the ends (count prefixes, half pages)
+    pub fn move_by(&mut self, delta: isize, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let current = self.state.selected().unwrap_or(0);
+        self.select_row(current.saturating_add_signed(delta), delta >= 0, len);
+    }
+
+    /// Select the first, middle or last row on screen; `at` is 0.0 to 1.0
+    pub fn select_on_screen(&mut self, at: f32, len: usize) {
+        let offset = self.state.offset();
+        if offset >= len {
+            return;
+        }
+        let visible = self.page_height.clamp(1, len - offset);
+        let row = offset + ((visible - 1) as f32 * at).round() as usize;
+        // Look for a container row inwards from the screen edge
+        self.select_row(row, at < 1.0, len);
+    }
+
+    /// Select a row, stepping past group headers in the direction of travel
+    fn select_row(&mut self, row: usize, forward: bool, len: usize) {
+        let row = row.min(len - 1);
+        let is_container = |i: &usize| self.item_to_container.is_empty() || self.item_to_container.get(*i).copied().flatten().is_some();
+        let found = if forward {
+            (row..len).chain((0..row).rev()).find(is_container)
+        } else {
+            (0..=row).rev().chain(row + 1..len).find(is_container)
+        };
+        self.state.select(Some(found.unwrap_or(row)));
+    }
+
     /// Go to top (skips header if present)
     pub fn top(&mut self) {
         let mut i = 0;
@@ -129,27 +253,45 @@ impl ContainerList {
     }
 
     /// Render the container list (full-width with inline stats)
-    pub fn render(&mut self, frame: &mut Frame, area: Rect, containers: &[ContainerInfo], view_mode: ListViewMode, status_filter: StatusFilter, total_count: usize) {
-        // Build items - either flat or grouped
-        let (items, item_count) = if status_filter == StatusFilter::Groups {
-            self.build_grouped_items(containers, view_mode)
-        } else {
-            self.item_to_container.clear(); // Clear mapping for non-groups mode
-            let items: Vec<ListItem> = containers
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, containers: &[&ContainerInfo], view_mode: ListViewMode, status_filter: StatusFilter, total_count: usize) {
+        let grouped = status_filter == StatusFilter::Groups;
+        // Grouped rows have the project in their header instead of a column
+        let columns: Vec<ColumnSpec> = self
+            .columns
+            .for_mode(view_mode)
+            .iter()
+            .copied()
+            .filter(|spec| !(grouped && spec.column == Column::Project))
+            .collect();
+
+        // Build rows - either flat or grouped. Group headers span the whole
+        // width, so they're drawn over empty rows once the table is down
+        let mut group_headers = Vec::new();
+        let rows: Vec<Row> = if grouped {
+            self.item_to_container = group_rows(containers.iter().map(|c| c.compose_project.as_deref()));
+            self.item_to_container
                 .iter()
-                .map(|c| {
-                    let icon = status_icon(&c.status);
-                    let line = match view_mode {
-                        ListViewMode::Stats => self.render_stats_line(c, icon, false),
-                        ListViewMode::Network => self.render_network_line(c, icon),
-                        ListViewMode::Details => self.render_details_line(c, icon),
-                    };
-                    ListItem::new(line)
+                .enumerate()
+                .map(|(row, entry)| match entry {
+                    Some(idx) => self.container_row(containers[*idx], &columns, true),
+                    None => {
+                        // Header rows are always followed by the first container of their project
+                        let project = self.item_to_container
+                            .get(row + 1)
+                            .copied()
+                            .flatten()
+                            .and_then(|idx| containers[idx].compose_project.as_deref());
+                        let members: Vec<&ContainerInfo> = containers.iter().copied().filter(|c| c.compose_project.as_deref() == project).collect();
+                        group_headers.push((row, self.render_group_header(project, &members)));
+                        Row::default().style(Style::default().bg(Theme::BG_DARK))
+                    }
                 })
-                .collect();
-            let len = items.len();
-            (items, len)
+                .collect()
+        } else {
+            self.item_to_container.clear(); // Clear mapping for non-groups mode
+            containers.iter().map(|c| self.container_row(c, &columns, false)).collect()
         };
+        let item_count = rows.len();
 
         // Build tab indicator
         let tabs = self.build_tabs(view_mode);
@@ -158,89 +300,260 @@ impl ContainerList {
         let filter_spans = self.build_filter_indicator(status_filter);
 
         // Show filtered count vs total if filtering is active
-        let count_str = if status_filter == StatusFilter::All || status_filter == StatusFilter::Groups {
+        let unfiltered = status_filter == StatusFilter::All || status_filter == StatusFilter::Groups;
+        let count_str = if unfiltered && self.group.is_none() {
             format!(" Containers ({}) ", containers.len())
         } else {
             format!(" Containers ({}/{}) ", containers.len(), total_count)
         };
 
-        let title = Line::from(vec![
+        let mut title = vec![
             Span::styled(count_str, title_style(self.focused)),
             Span::styled("│ ", Style::default().fg(Theme::BORDER)),
             tabs.0, tabs.1, tabs.2,
             Span::styled(" │ ", Style::default().fg(Theme::BORDER)),
-            filter_spans.0, filter_spans.1, filter_spans.2, filter_spans.3,
-        ]);
+        ];
+        title.extend(filter_spans);
+        if status_filter == StatusFilter::Groups && self.project_sort != ProjectSort::Name {
+            title.push(Span::styled(format!("by {} ", self.project_sort.as_str()), Style::default().fg(Theme::CYAN)));
+        }
+        if let Some(ref group) = self.group {
+            title.push(Span::styled(" │ ", Style::default().fg(Theme::BORDER)));
+            title.push(Span::styled(format!("★ {} ", group), Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)));
+        }
+        let title = Line::from(title);
+
+        // Status icon (indented under the group headers), then the columns
+        let icon_width = if grouped { 4 } else { 2 };
+        let widths: Vec<Constraint> = std::iter::once(Constraint::Length(icon_width))
+            .chain(columns.iter().map(|spec| Constraint::Length(spec.width())))
+            .collect();
+
+        // Column labels, with an arrow on the one the rows are ordered by
+        let sorted = self.sorted_column(grouped, containers, &columns);
+        let header = Row::new(std::iter::once(Cell::default()).chain(columns.iter().map(|spec| {
+            let (label, style) = match sorted {
+                Some((column, arrow)) if column == spec.column => {
+                    (format!("{} {}", spec.column.label(), arrow), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+                }
+                _ => (spec.column.label().to_string(), Style::default().fg(Theme::FG_DARK).add_modifier(Modifier::BOLD)),
+            };
+            let line = Line::styled(label, style);
+            Cell::from(if spec.column.right_aligned() { line.right_aligned() } else { line })
+        })));
 
-        let list = List::new(items)
+        let table = Table::new(rows, widths)
+            .header(header)
             .block(
                 Block::default()
                     .title(title)
                     .borders(Borders::ALL)
                     .border_style(border_style(self.focused)),
             )
-            .highlight_style(selected_style())
-            .highlight_symbol("▶");
-
-        frame.render_stateful_widget(list, area, &mut self.state);
+            .row_highlight_style(selected_style())
+            .highlight_symbol("▶")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        frame.render_stateful_widget(table, area, &mut self.state);
+        // Borders and the header row
+        self.page_height = area.height.saturating_sub(3) as usize;
+
+        // Group headers over their rows (below the header row), after the selection column
+        let inner = area.inner(Margin::new(1, 1));
+        let offset = self.state.offset();
+        for (row, header) in group_headers {
+            if row < offset || row - offset >= self.page_height {
+                continue;
+            }
+            let y = inner.y + 1 + (row - offset) as u16;
+            frame.render_widget(Paragraph::new(header), Rect::new(inner.x + 1, y, inner.width.saturating_sub(1), 1));
+        }
 
         // Ensure selection is valid for grouped mode
-        if status_filter == StatusFilter::Groups && !self.item_to_container.is_empty() {
-            if let Some(sel) = self.state.selected() {
-                // If current selection is a header, move to next container
-                if self.item_to_container.get(sel).copied().flatten().is_none() {
-                    self.next(item_count);
-                }
-            }
+        if grouped && !self.item_to_container.is_empty() {
+            self.clamp_selection(item_count);
+        }
+    }
+
+    /// Rebuild the row mapping for the given (filtered) containers.
+    /// Keeps navigation in sync with the rows that will be rendered.
+    pub fn set_rows(&mut self, containers: &[&ContainerInfo], grouped: bool) {
+        let key = |c: &ContainerInfo| Some((c.id.clone(), c.name.clone()));
+        if grouped {
+            self.item_to_container = group_rows(containers.iter().map(|c| c.compose_project.as_deref()));
+            self.row_keys = self.item_to_container.iter().map(|entry| entry.and_then(|i| key(containers[i]))).collect();
+        } else {
+            self.item_to_container.clear();
+            self.row_keys = containers.iter().map(|c| key(c)).collect();
         }
     }
 
-    /// Build grouped items with project headers
-    fn build_grouped_items(&mut self, containers: &[ContainerInfo], view_mode: ListViewMode) -> (Vec<ListItem<'static>>, usize) {
-        let mut items: Vec<ListItem> = Vec::new();
-        self.item_to_container.clear();
-        let mut current_project: Option<&str> = Some("__initial__"); // Sentinel to force first header
+    /// (ID, name) of the selected container as of the last `set_rows`
+    pub fn selected_key(&self) -> Option<(String, String)> {
+        self.state.selected().and_then(|i| self.row_keys.get(i).cloned().flatten())
+    }
 
-        for (idx, c) in containers.iter().enumerate() {
-            let container_project = c.compose_project.as_deref();
+    /// Select the row of a container after the rows changed: by ID, else by
+    /// name (recreated containers get a new ID). Only when it's gone does
+    /// the selection stay at its position, clamped to `len` rows
+    pub fn reselect(&mut self, key: Option<(String, String)>, len: usize) {
+        let row = key.and_then(|(id, name)| {
+            let by_id = self.row_keys.iter().position(|k| k.as_ref().is_some_and(|k| !id.is_empty() && k.0 == id));
+            by_id.or_else(|| self.row_keys.iter().position(|k| k.as_ref().is_some_and(|k| k.1 == name)))
+        });
+        match row {
+            Some(row) => self.state.select(Some(row)),
+            None if self.state.selected().is_none() => self.state.select(Some(0)),
+            None => {}
+        }
+        self.clamp_selection(len);
+    }
 
-            // Check if we're entering a new project group
-            if container_project != current_project {
-                current_project = container_project;
-                // Add project header
-                let header = self.render_group_header(container_project);
-                items.push(header);
-                self.item_to_container.push(None); // Header row
-            }
+    /// Clamp the selection to `len` rows and move it off a header row
+    pub fn clamp_selection(&mut self, len: usize) {
+        if len == 0 {
+            self.state.select(None);
+            return;
+        }
+        let sel = self.state.selected().unwrap_or(0).min(len - 1);
+        self.state.select(Some(sel));
+        if self.item_to_container.get(sel).is_some_and(|e| e.is_none()) {
+            self.next(len);
+        }
+    }
 
-            let icon = status_icon(&c.status);
-            let line = match view_mode {
-                ListViewMode::Stats => self.render_stats_line(c, icon, true),
-                ListViewMode::Network => self.render_network_line(c, icon),
-                ListViewMode::Details => self.render_details_line(c, icon),
-            };
-            items.push(ListItem::new(line));
-            self.item_to_container.push(Some(idx));
+    /// Name with its pin, mark, keep-alive, update, crash-loop, wait and transition tags
+    fn name_spans(&self, c: &ContainerInfo, width: usize) -> Vec<Span<'static>> {
+        let mut spans = Vec::new();
+        let mut width = width;
+
+        // Pin and compare marks before the name
+        if self.pinned.contains(&c.name) {
+            spans.push(Span::styled("⚑", Style::default().fg(Theme::YELLOW)));
+            width = width.saturating_sub(1).max(1);
+        }
+        if self.marked.contains(&c.name) {
+            spans.push(Span::styled("◆", Style::default().fg(Theme::MAUVE)));
+            width = width.saturating_sub(1).max(1);
         }
 
-        let len = items.len();
-        (items, len)
+        // Keep-alive marker with restart count and image update marker after the name
+        let tag = self.keep_alive.get(&c.name).map(|restarts| format!(" ↻{}", restarts));
+        if let Some(ref tag) = tag {
+            width = width.saturating_sub(tag.chars().count()).max(1);
+        }
+        let update = self.updates.contains(&c.name);
+        if update {
+            width = width.saturating_sub(2).max(1);
+        }
+        let looping = self.crash_looping.contains(&c.name);
+        if looping {
+            width = width.saturating_sub(2).max(1);
+        }
+        let waiting = self.waiting.contains(&c.name);
+        if waiting {
+            width = width.saturating_sub(2).max(1);
+        }
+        let transition = self.transitions.get(&c.name).map(|t| format!(" {}", t.label()));
+        if let Some(ref transition) = transition {
+            width = width.saturating_sub(transition.chars().count()).max(1);
+        }
+
+        spans.extend(self.matched_name_spans(c, width));
+        if let Some(tag) = tag {
+            spans.push(Span::styled(tag, Style::default().fg(Theme::GREEN)));
+        }
+        if update {
+            spans.push(Span::styled(" ⬆", Style::default().fg(Theme::PEACH).add_modifier(Modifier::BOLD)));
+        }
+        if looping {
+            spans.push(Span::styled(" ⚠", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)));
+        }
+        if waiting {
+            spans.push(Span::styled(" ⌛", Style::default().fg(Theme::SKY)));
+        }
+        if let Some(transition) = transition {
+            spans.push(Span::styled(transition, Style::default().fg(Theme::YELLOW).add_modifier(Modifier::ITALIC)));
+        }
+        spans
+    }
+
+    /// Status icon, or the spinner while a start/stop/... is in flight
+    fn row_icon(&self, c: &ContainerInfo) -> &'static str {
+        if self.transitions.contains_key(&c.name) {
+            self.spinner
+        } else {
+            status_icon(&c.status)
+        }
+    }
+
+    /// Name padded to `width`, with filter matches highlighted. When the
+    /// filter matched another field, a tag naming that field is shown instead
+    fn matched_name_spans(&self, c: &ContainerInfo, width: usize) -> Vec<Span<'static>> {
+        let style = Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD);
+        let highlight = style.fg(Theme::YELLOW).add_modifier(Modifier::UNDERLINED);
+
+        match match_container(&self.match_query, c) {
+            Some((_, field)) if !self.match_query.is_empty() && field != MatchField::Name => {
+                let tag = format!("[{}]", field.prefix());
+                let name_width = width.saturating_sub(tag.len() + 1).max(1);
+                vec![
+                    Span::styled(format!("{} ", fit_width(&c.name, name_width)), style),
+                    Span::styled(tag, Style::default().fg(Theme::YELLOW)),
+                ]
+            }
+            _ => {
+                let shown = truncate_width(&c.name, width);
+                // Only highlight characters that survived truncation (the last one may be "…")
+                let visible = shown.chars().count() - usize::from(shown != c.name);
+                let display = fit_width(&c.name, width);
+                let positions = match_positions(&self.match_query, &c.name);
+                if positions.is_empty() {
+                    return vec![Span::styled(display, style)];
+                }
+
+                display
+                    .chars()
+                    .enumerate()
+                    .map(|(i, ch)| {
+                        let matched = i < visible && positions.contains(&i);
+                        Span::styled(ch.to_string(), if matched { highlight } else { style })
+                    })
+                    .collect()
+            }
+        }
     }
 
-    /// Render a group header row
-    fn render_group_header(&self, project: Option<&str>) -> ListItem<'static> {
+    /// Render a group header row: the project with the summed usage of its containers
+    fn render_group_header(&self, project: Option<&str>, members: &[&ContainerInfo]) -> Line<'static> {
         let project_name = project.unwrap_or("Ungrouped");
         let header_style = Style::default()
             .fg(Theme::MAUVE)
             .add_modifier(Modifier::BOLD);
+        let totals = ContainerTotals::sum(members.iter().filter_map(|c| c.stats.as_ref()));
+        let running = members.iter().filter(|c| c.status.is_running()).count();
+        // The column projects are sorted by stands out
+        let usage_style = |sort: ProjectSort| {
+            if self.project_sort == sort {
+                Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Theme::FG_DARK)
+            }
+        };
 
-        let line = Line::from(vec![
+        Line::from(vec![
             Span::styled("   ", Style::default()), // Indent to align with container names
             Span::styled(format!("┌─ {} ", project_name), header_style),
+            Span::styled(format!("{}/{} up ", running, members.len()), Style::default().fg(Theme::FG_DARK)),
+            Span::styled("─ ", Style::default().fg(Theme::BORDER)),
+            Span::styled(format!("CPU {:.1}% ", totals.cpu_percent), usage_style(ProjectSort::Cpu)),
+            Span::styled(format!("MEM {} ", format_bytes((totals.memory_usage_mb * 1024.0 * 1024.0) as u64)), usage_style(ProjectSort::Memory)),
+            Span::styled(
+                format!("NET ↓{} ↑{} ", format_bytes_rate(totals.net_rx_rate), format_bytes_rate(totals.net_tx_rate)),
+                usage_style(ProjectSort::Network),
+            ),
             Span::styled("─".repeat(60), Style::default().fg(Theme::BORDER)),
-        ]);
-
-        ListItem::new(line).style(Style::default().bg(Theme::BG_DARK))
+        ])
     }
 
     /// Build styled tab spans for the view mode indicator
@@ -259,13 +572,15 @@ impl ContainerList {
 
         (
             Span::styled(" Stats ", stats_style),
-            Span::styled(" Network ", network_style),
+            Span::styled(" Network/IO ", network_style),
             Span::styled(" Details ", details_style),
         )
     }
 
     /// Build styled spans for status filter indicator
-    fn build_filter_indicator(&self, status_filter: StatusFilter) -> (Span<'static>, Span<'static>, Span<'static>, Span<'static>) {
+    /// Filter tabs with the number of containers each one covers; paused ones
+    /// (counted as stopped) get their own marker
+    fn build_filter_indicator(&self, status_filter: StatusFilter) -> Vec<Span<'static>> {
         let active_style = Style::default()
             .fg(Theme::BG_DARK)
             .bg(Theme::TEAL)
@@ -279,141 +594,208 @@ impl ContainerList {
             StatusFilter::Stopped => (inactive_style, inactive_style, inactive_style, active_style),
         };
 
-        (
-            Span::styled(" All ", all_style),
+        let counts = self.counts;
+        // Draw the eye to stopped containers unless that tab is already active
+        let stopped_style = if status_filter != StatusFilter::Stopped && counts.stopped > 0 {
+            stopped_style.fg(Theme::RED)
+        } else {
+            stopped_style
+        };
+
+        let mut spans = vec![
+            Span::styled(format!(" All {} ", counts.total()), all_style),
             Span::styled(" Groups ", groups_style),
-            Span::styled(" Running ", running_style),
-            Span::styled(" Stopped ", stopped_style),
-        )
+            Span::styled(format!(" Running {} ", counts.running), running_style),
+            Span::styled(format!(" Stopped {} ", counts.stopped + counts.paused), stopped_style),
+        ];
+        if counts.paused > 0 {
+            spans.push(Span::styled(format!(" ⏸ {} ", counts.paused), Style::default().fg(Theme::YELLOW)));
+        }
+        spans
     }
 
-    /// Render Stats view line: Name, Project, Port, CPU bar, MEM bar, GPU
-    /// When grouped=true, project column is hidden (shown in header instead)
-    fn render_stats_line(&self, c: &ContainerInfo, icon: &str, grouped: bool) -> Line<'static> {
-        // Format ports (show first port or "-")
-        let port_str = if c.ports.is_empty() {
-            "-".to_string()
-        } else if c.ports.len() == 1 {
-            c.ports[0].display()
+    /// A container's row: its status icon, then the view mode's columns.
+    /// Highlighted if an alert is firing
+    fn container_row(&self, c: &ContainerInfo, columns: &[ColumnSpec], grouped: bool) -> Row<'static> {
+        let indent = if grouped { "  " } else { "" };
+        let mut cells = vec![Cell::from(Span::styled(
+            format!("{} {}", indent, self.row_icon(c)),
+            Style::default().fg(status_color(&c.status)),
+        ))];
+        cells.extend(columns.iter().map(|spec| self.cell(c, spec)));
+
+        let row = Row::new(cells);
+        if self.alerting.contains(&c.name) {
+            row.style(Style::default().bg(Theme::ALERT_BG))
         } else {
-            format!("{}+{}", c.ports[0].display(), c.ports.len() - 1)
-        };
+            row
+        }
+    }
 
-        // CPU/MEM bars and values (only if running with stats)
-        let (cpu_bar, cpu_val, mem_bar, mem_val, gpu_val) = if let Some(stats) = &c.stats {
-            let cpu_bar = make_bar(stats.cpu_percent, 8);
-            let cpu_val = format!("{:>5.1}%", stats.cpu_percent);
-            let mem_bar = make_bar(stats.memory_percent, 8);
-            let mem_val = format!("{:>5.1}%", stats.memory_percent);
-            // GPU VRAM usage
-            let gpu_val = match stats.vram_usage_mb {
-                Some(vram) if vram >= 1024.0 => format!("{:.1}G", vram / 1024.0),
-                Some(vram) => format!("{:.0}M", vram),
-                None => "─".to_string(),
-            };
-            (cpu_bar, cpu_val, mem_bar, mem_val, gpu_val)
-        } else if c.status.is_running() {
-            ("        ".to_string(), "  ... ".to_string(),
-             "        ".to_string(), "  ... ".to_string(), "─".to_string())
-        } else {
-            ("────────".to_string(), "   -  ".to_string(),
-             "────────".to_string(), "   -  ".to_string(), "─".to_string())
+    /// Column the rows are ordered by, with the direction: the flat list is
+    /// by project (then name) unless the name filter ranks the rows or
+    /// pinned containers come first. Groups mode orders whole projects, as
+    /// its title and headers show, so no column gets an arrow
+    fn sorted_column(&self, grouped: bool, containers: &[&ContainerInfo], columns: &[ColumnSpec]) -> Option<(Column, &'static str)> {
+        if grouped || !self.match_query.is_empty() || containers.iter().any(|c| self.pinned.contains(&c.name)) {
+            return None;
+        }
+        columns.iter().any(|spec| spec.column == Column::Project).then_some((Column::Project, "▲"))
+    }
+
+    /// One column of a container's row
+    fn cell(&self, c: &ContainerInfo, spec: &ColumnSpec) -> Cell<'static> {
+        let width = spec.value_width() as usize;
+        let styled = |text: String, color: Color| vec![Span::styled(text, Style::default().fg(color))];
+        // Figures that need stats: "..." while they're on their way, "-" for stopped containers
+        let stat = |value: fn(&ContainerStats) -> String| {
+            let blank = if c.status.is_running() { "..." } else { "-" };
+            let value = c.stats.as_ref().map_or_else(|| blank.to_string(), value);
+            format!("{:>width$}", value)
         };
 
-        let cpu_color = percent_color(c.stats.as_ref().map(|s| s.cpu_percent).unwrap_or(0.0));
-        let mem_color = percent_color(c.stats.as_ref().map(|s| s.memory_percent).unwrap_or(0.0));
-        let gpu_color = if c.stats.as_ref().and_then(|s| s.vram_usage_mb).is_some() {
-            Theme::GREEN
-        } else {
-            Theme::FG_DARK
+        let spans = match spec.column {
+            Column::Name => self.name_spans(c, width),
+            Column::Project => styled(truncate_width(c.compose_project.as_deref().unwrap_or("─"), width), Theme::TEAL),
+            Column::Port => {
+                // First port, with a count of the others
+                let port_str = match c.ports.len() {
+                    0 => "-".to_string(),
+                    1 => c.ports[0].display(),
+                    n => format!("{}+{}", c.ports[0].display(), n - 1),
+                };
+                styled(truncate_width(&port_str, width), Theme::YELLOW)
+            }
+            Column::Cpu => usage_spans(c.stats.as_ref().map(|s| s.cpu_percent), c.status.is_running(), width, Theme::CYAN),
+            Column::Mem => usage_spans(c.stats.as_ref().map(|s| s.memory_percent), c.status.is_running(), width, Theme::MAGENTA),
+            Column::Gpu => {
+                // GPU VRAM usage
+                let vram = c.stats.as_ref().and_then(|s| s.vram_usage_mb);
+                let gpu_val = match vram {
+                    Some(vram) if vram >= 1024.0 => format!("{:.1}G", vram / 1024.0),
+                    Some(vram) => format!("{:.0}M", vram),
+                    None => "─".to_string(),
+                };
+                styled(format!("{:>width$}", gpu_val), if vram.is_some() { Theme::GREEN } else { Theme::FG_DARK })
+            }
+            Column::Ip => {
+                // First address, with a count of the other networks
+                let ip_str = match (c.ip(), c.networks.len()) {
+                    (Some(ip), n) if n > 1 => format!("{} +{}", ip, n - 1),
+                    (Some(ip), _) => ip.to_string(),
+                    (None, _) => "-".to_string(),
+                };
+                styled(ip_str, Theme::LAVENDER)
+            }
+            Column::NetRx => styled(stat(|s| format_bytes_rate(s.net_rx_rate)), Theme::GREEN),
+            Column::NetTx => styled(stat(|s| format_bytes_rate(s.net_tx_rate)), Theme::PEACH),
+            Column::RxTotal => styled(stat(|s| format_bytes(s.net_rx_bytes)), Theme::TEAL),
+            Column::TxTotal => styled(stat(|s| format_bytes(s.net_tx_bytes)), Theme::FLAMINGO),
+            Column::DiskRead => styled(stat(|s| format_bytes_rate(s.blk_read_rate)), Theme::SKY),
+            Column::DiskWrite => styled(stat(|s| format_bytes_rate(s.blk_write_rate)), Theme::YELLOW),
+            Column::Image => styled(truncate_width(&c.image, width), Theme::LAVENDER),
+            Column::Size => {
+                let size_str = self.image_sizes.get(&c.image_id).map_or_else(|| "…".to_string(), |&size| format_bytes(size));
+                styled(format!("{:>width$}", size_str), Theme::OVERLAY)
+            }
+            Column::Vulns => vec![self.vuln_badge(&c.image_id)],
+            Column::Uptime => {
+                // Uptime of the current run; created date shown separately
+                let uptime = if c.status.is_active() { format_uptime(c.started_at) } else { "-".to_string() };
+                styled(format!("{:>width$}", uptime), Theme::SKY)
+            }
+            Column::Created => {
+                let created_str = c.created
+                    .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+                    .map(|dt| dt.with_timezone(&chrono::Local).format("%Y-%m-%d").to_string())
+                    .unwrap_or_else(|| "─".to_string());
+                styled(created_str, Theme::OVERLAY)
+            }
+            Column::Exit => {
+                let exit_str = match c.exit_code {
+                    Some(code) if c.oom_killed => format!("{} OOM", code),
+                    Some(code) => code.to_string(),
+                    None => "─".to_string(),
+                };
+                styled(exit_str, exit_color(c))
+            }
+            Column::Restarts => styled(c.restart_count.to_string(), restarts_color(c.restart_count, self.crash_looping.contains(&c.name))),
         };
 
-        if grouped {
-            // In grouped mode: show indent, no project column (project shown in header)
-            Line::from(vec![
-                Span::styled("  ", Style::default()), // Indent for group hierarchy
-                Span::styled(format!(" {} ", icon), Style::default().fg(status_color(&c.status))),
-                Span::styled(format!("{:<20}", truncate_name(&c.name, 20)), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("{:<12}", truncate_name(&port_str, 12)), Style::default().fg(Theme::YELLOW)),
-                Span::styled(" CPU ", Style::default().fg(Theme::FG_DARK)),
-                Span::styled(cpu_bar, Style::default().fg(Theme::CYAN)),
-                Span::styled(cpu_val, Style::default().fg(cpu_color)),
-                Span::styled(" MEM ", Style::default().fg(Theme::FG_DARK)),
-                Span::styled(mem_bar, Style::default().fg(Theme::MAGENTA)),
-                Span::styled(mem_val, Style::default().fg(mem_color)),
-                Span::styled(" GPU ", Style::default().fg(Theme::FG_DARK)),
-                Span::styled(format!("{:>5}", gpu_val), Style::default().fg(gpu_color)),
-            ])
-        } else {
-            // Normal mode: show project column
-            let project_str = c.compose_project.as_ref()
-                .map(|p| truncate_name(p, 8))
-                .unwrap_or_else(|| "─".to_string());
-
-            Line::from(vec![
-                Span::styled(format!(" {} ", icon), Style::default().fg(status_color(&c.status))),
-                Span::styled(format!("{:<18}", truncate_name(&c.name, 18)), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(format!(" {:<8} ", project_str), Style::default().fg(Theme::LAVENDER)),
-                Span::styled(format!("{:<10}", truncate_name(&port_str, 10)), Style::default().fg(Theme::YELLOW)),
-                Span::styled(" CPU ", Style::default().fg(Theme::FG_DARK)),
-                Span::styled(cpu_bar, Style::default().fg(Theme::CYAN)),
-                Span::styled(cpu_val, Style::default().fg(cpu_color)),
-                Span::styled(" MEM ", Style::default().fg(Theme::FG_DARK)),
-                Span::styled(mem_bar, Style::default().fg(Theme::MAGENTA)),
-                Span::styled(mem_val, Style::default().fg(mem_color)),
-                Span::styled(" GPU ", Style::default().fg(Theme::FG_DARK)),
-                Span::styled(format!("{:>5}", gpu_val), Style::default().fg(gpu_color)),
-            ])
-        }
-    }
-
-    /// Render Network view line: Name, ↓RX rate, ↑TX rate, Total RX, Total TX
-    fn render_network_line(&self, c: &ContainerInfo, icon: &str) -> Line<'static> {
-        let (rx_rate, tx_rate, rx_total, tx_total) = if let Some(stats) = &c.stats {
-            (
-                format_bytes_rate(stats.net_rx_rate),
-                format_bytes_rate(stats.net_tx_rate),
-                format_bytes(stats.net_rx_bytes),
-                format_bytes(stats.net_tx_bytes),
-            )
-        } else if c.status.is_running() {
-            ("...".to_string(), "...".to_string(), "...".to_string(), "...".to_string())
+        let line = Line::from(spans);
+        Cell::from(if spec.column.right_aligned() { line.right_aligned() } else { line })
+    }
+
+    /// "C2 H5 M10" from the image's trivy scan, colored by its worst finding
+    fn vuln_badge(&self, image_id: &str) -> Span<'static> {
+        if self.scanning.contains(image_id) {
+            return Span::styled("scanning…", Style::default().fg(Theme::FG_DARK));
+        }
+        let Some(counts) = self.vulns.get(image_id) else {
+            return Span::raw("");
+        };
+        let color = if counts.critical > 0 {
+            severity_color(Severity::Critical)
+        } else if counts.high > 0 {
+            severity_color(Severity::High)
+        } else if counts.medium > 0 {
+            severity_color(Severity::Medium)
         } else {
-            ("-".to_string(), "-".to_string(), "-".to_string(), "-".to_string())
+            Theme::GREEN
         };
+        let badge = format!("C{} H{} M{}", counts.critical, counts.high, counts.medium);
+        Span::styled(badge, Style::default().fg(color))
+    }
+}
 
-        Line::from(vec![
-            Span::styled(format!(" {} ", icon), Style::default().fg(status_color(&c.status))),
-            Span::styled(format!("{:<20}", truncate_name(&c.name, 20)), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
-            Span::styled(" ↓ ", Style::default().fg(Theme::GREEN)),
-            Span::styled(format!("{:>10}", rx_rate), Style::default().fg(Theme::GREEN)),
-            Span::styled(" ↑ ", Style::default().fg(Theme::PEACH)),
-            Span::styled(format!("{:>10}", tx_rate), Style::default().fg(Theme::PEACH)),
-            Span::styled("  Total↓ ", Style::default().fg(Theme::FG_DARK)),
-            Span::styled(format!("{:>8}", rx_total), Style::default().fg(Theme::TEAL)),
-            Span::styled("  Total↑ ", Style::default().fg(Theme::FG_DARK)),
-            Span::styled(format!("{:>8}", tx_total), Style::default().fg(Theme::FLAMINGO)),
-        ])
+/// A usage bar filling what the percentage leaves of `width`, then the
+/// percentage. Blank while stats are on their way, dashed when stopped
+fn usage_spans(percent: Option<f64>, running: bool, width: usize, bar_color: Color) -> Vec<Span<'static>> {
+    let bar_width = width.saturating_sub(6);
+    let (bar, value) = match percent {
+        Some(percent) => (make_bar(percent, bar_width), format!("{:>5.1}%", percent)),
+        None if running => (" ".repeat(bar_width), "  ... ".to_string()),
+        None => ("─".repeat(bar_width), "   -  ".to_string()),
+    };
+    vec![
+        Span::styled(bar, Style::default().fg(bar_color)),
+        Span::styled(value, Style::default().fg(percent_color(percent.unwrap_or(0.0)))),
+    ]
+}
+
+/// Color for an exit code: red for failures and OOM kills
+fn exit_color(c: &ContainerInfo) -> Color {
+    match c.exit_code {
+        Some(code) if code != 0 || c.oom_killed => Theme::RED,
+        Some(_) => Theme::GREEN,
+        None => Theme::FG_DARK,
+    }
+}
+
+/// Color for a restart count: red while crash looping, yellow once it has restarted
+fn restarts_color(count: i64, looping: bool) -> Color {
+    match count {
+        _ if looping => Theme::RED,
+        0 => Theme::FG_DARK,
+        _ => Theme::YELLOW,
     }
+}
 
-    /// Render Details view line: Name, Image, Project, Uptime
-    fn render_details_line(&self, c: &ContainerInfo, icon: &str) -> Line<'static> {
-        let project_str = c.compose_project.as_ref()
-            .map(|p| truncate_name(p, 12))
-            .unwrap_or_else(|| "─".to_string());
-        let uptime = format_uptime(c.created);
+/// Compute visual rows for grouped mode: a header (`None`) before each new
+/// project run, followed by the container indices belonging to it
+fn group_rows<'a>(projects: impl Iterator<Item = Option<&'a str>>) -> Vec<Option<usize>> {
+    let mut rows = Vec::new();
+    let mut current_project: Option<Option<&str>> = None;
 
-        Line::from(vec![
-            Span::styled(format!(" {} ", icon), Style::default().fg(status_color(&c.status))),
-            Span::styled(format!("{:<20}", truncate_name(&c.name, 20)), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
-            Span::styled(" Image: ", Style::default().fg(Theme::FG_DARK)),
-            Span::styled(format!("{:<20}", truncate_name(&c.image, 20)), Style::default().fg(Theme::LAVENDER)),
-            Span::styled(" Project: ", Style::default().fg(Theme::FG_DARK)),
-            Span::styled(format!("{:<12}", project_str), Style::default().fg(Theme::TEAL)),
-            Span::styled(" Up: ", Style::default().fg(Theme::FG_DARK)),
-            Span::styled(format!("{:>12}", uptime), Style::default().fg(Theme::SKY)),
-        ])
+    for (idx, project) in projects.enumerate() {
+        if current_project != Some(project) {
+            current_project = Some(project);
+            rows.push(None);
+        }
+        rows.push(Some(idx));
     }
+
+    rows
 }
 
 /// Create a progress bar string
@@ -456,15 +838,6 @@ fn percent_color(percent: f64) -> Color {
     }
 }
 
-/// Truncate a name to fit in the given width
-fn truncate_name(name: &str, max_len: usize) -> String {
-    if name.len() <= max_len {
-        name.to_string()
-    } else {
-        format!("{}…", &name[..max_len - 1])
-    }
-}
-
 impl Default for ContainerList {
     fn default() -> Self {
         Self::new()
@@ -482,26 +855,6 @@ impl ContainerList {
     }
 }
 
-/// Format bytes as human readable (KB, MB, GB)
-fn format_bytes(bytes: u64) -> String {
-    if bytes == 0 {
-        return "0 B".to_string();
-    }
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-
-    if bytes >= GB {
-        format!("{:.1}GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.1}MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.1}KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{}B", bytes)
-    }
-}
-
 /// Format bytes per second as human readable rate
 fn format_bytes_rate(bytes_per_sec: f64) -> String {
     if bytes_per_sec < 1.0 {
@@ -522,9 +875,9 @@ fn format_bytes_rate(bytes_per_sec: f64) -> String {
     }
 }
 
-/// Format uptime from created timestamp
-fn format_uptime(created: Option<i64>) -> String {
-    match created {
+/// Format elapsed time since a unix timestamp (e.g. start time)
+fn format_uptime(since: Option<i64>) -> String {
+    match since {
         Some(ts) => {
             let now = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
@@ -552,3 +905,94 @@ fn format_uptime(created: Option<i64>) -> String {
         None => "-".to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container(name: &str, id: &str, project: Option<&str>) -> ContainerInfo {
+        let mut c = ContainerInfo::new(name.to_string());
+        c.id = id.to_string();
+        c.compose_project = project.map(str::to_string);
+        c
+    }
+
+    #[test]
+    fn test_selection_follows_container() {
+        let (web, db, cache) = (container("web", "a1", Some("app")), container("db", "b2", Some("app")), container("cache", "c3", None));
+        let mut list = ContainerList::new();
+        list.set_rows(&[&web, &db, &cache], false);
+        list.state.select(Some(1));
+
+        // Grouped: a header row comes first, so db moves down a row
+        let selected = list.selected_key();
+        list.set_rows(&[&web, &db, &cache], true);
+        list.reselect(selected, list.item_count());
+        assert_eq!(list.selected_key().map(|k| k.1).as_deref(), Some("db"));
+
+        // Recreated under a new ID, then removed
+        let db = container("db", "d4", Some("app"));
+        let selected = list.selected_key();
+        list.set_rows(&[&db, &cache], false);
+        list.reselect(selected, 2);
+        assert_eq!(list.selected(), Some(0));
+        let selected = list.selected_key();
+        list.set_rows(&[&cache], false);
+        list.reselect(selected, 1);
+        assert_eq!(list.selected_key().map(|k| k.1).as_deref(), Some("cache"));
+    }
+
+    /// Two projects of two containers: rows are [app, a1, a2, db, b1, b2]
+    fn grouped_list() -> ContainerList {
+        let containers = [
+            container("a1", "1", Some("app")),
+            container("a2", "2", Some("app")),
+            container("b1", "3", Some("db")),
+            container("b2", "4", Some("db")),
+        ];
+        let mut list = ContainerList::new();
+        list.set_rows(&containers.iter().collect::<Vec<_>>(), true);
+        list
+    }
+
+    #[test]
+    fn test_move_by_skips_headers_and_stops_at_the_ends() {
+        let mut list = grouped_list();
+        list.state.select(Some(2));
+        list.move_by(1, 6);
+        assert_eq!(list.selected(), Some(4));
+        list.move_by(-1, 6);
+        assert_eq!(list.selected(), Some(2));
+        list.move_by(100, 6);
+        assert_eq!(list.selected(), Some(5));
+        // Row 0 is a header: the first container below it instead
+        list.move_by(-100, 6);
+        assert_eq!(list.selected(), Some(1));
+    }
+
+    #[test]
+    fn test_select_on_screen() {
+        let mut list = grouped_list();
+        list.page_height = 4;
+        list.select_on_screen(0.0, 6);
+        assert_eq!(list.selected(), Some(1));
+        list.select_on_screen(0.5, 6);
+        assert_eq!(list.selected(), Some(2));
+        // The last row on screen is the db header: the container above it
+        list.select_on_screen(1.0, 6);
+        assert_eq!(list.selected(), Some(2));
+    }
+
+    #[test]
+    fn test_select_row_steps_past_headers() {
+        let mut list = grouped_list();
+        list.select_row(3, true, 6);
+        assert_eq!(list.selected(), Some(4));
+        list.select_row(3, false, 6);
+        assert_eq!(list.selected(), Some(2));
+        // Flat lists have no headers to skip
+        let mut flat = ContainerList::new();
+        flat.select_row(9, true, 3);
+        assert_eq!(flat.selected(), Some(2));
+    }
+}
@@ -3,7 +3,33 @@ use ratatui::{
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 
-use crate::ui::{centered_modal, Theme};
+use crate::ui::{centered_modal, display_width, Theme};
+
+/// One environment variable in the env editor
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnvRow {
+    pub key: String,
+    pub value: String,
+}
+
+impl EnvRow {
+    fn is_blank(&self) -> bool {
+        self.key.is_empty() && self.value.is_empty()
+    }
+
+    /// What's wrong with the row, if anything (blank rows are ignored)
+    pub fn error(&self) -> Option<&'static str> {
+        if self.is_blank() {
+            None
+        } else if self.key.is_empty() {
+            Some("missing name")
+        } else if self.key.contains(|c: char| c == '=' || c.is_whitespace()) {
+            Some("name can't contain '=' or spaces")
+        } else {
+            None
+        }
+    }
+}
 
 /// Form field for container creation
 #[derive(Debug, Clone, Default)]
@@ -12,12 +38,35 @@ pub struct CreateContainerForm {
     pub image: String,
     pub port_host: String,
     pub port_container: String,
-    pub env_vars: String,
+    /// Environment variables, edited as rows (field 4)
+    pub env_rows: Vec<EnvRow>,
+    /// Selected env row and column (0 = name, 1 = value)
+    pub env_row: usize,
+    pub env_col: usize,
+    /// Path being typed for a .env import (Ctrl+O in the env editor)
+    pub env_file: Option<String>,
+    /// Path being typed for a template import (Ctrl+O in the form)
+    pub template_file: Option<String>,
     pub volumes: String,
     pub command: String,
+    /// Device mounts (/dev/x[:/dev/y[:rwm]],...)
+    pub devices: String,
+    /// Pass all GPUs through (`--gpus all`)
+    pub gpu: bool,
+    /// Memory limit with a unit suffix (512m, 2g)
+    pub memory_limit: String,
+    /// CPU limit in cores (0.5, 2)
+    pub cpu_limit: String,
+    /// Network to attach to (empty = default bridge)
+    pub network: String,
+    pub hostname: String,
+    /// DNS servers (comma-separated)
+    pub dns: String,
     pub selected_field: usize,
     pub selected_image_idx: usize,
     pub available_images: Vec<String>,
+    pub selected_network_idx: usize,
+    pub available_networks: Vec<String>,
     pub mode: CreateMode,
 }
 
@@ -26,6 +75,9 @@ pub enum CreateMode {
     #[default]
     Form,
     ImageSelect,
+    NetworkSelect,
+    /// Editing the env rows
+    EnvEdit,
 }
 
 impl CreateContainerForm {
@@ -35,18 +87,46 @@ impl CreateContainerForm {
             image: String::new(),
             port_host: String::new(),
             port_container: String::new(),
-            env_vars: String::new(),
+            env_rows: vec![EnvRow::default()],
+            env_row: 0,
+            env_col: 0,
+            env_file: None,
+            template_file: None,
             volumes: String::new(),
             command: String::new(),
+            devices: String::new(),
+            gpu: false,
+            memory_limit: String::new(),
+            cpu_limit: String::new(),
+            network: String::new(),
+            hostname: String::new(),
+            dns: String::new(),
             selected_field: 0,
             selected_image_idx: 0,
             available_images: Vec::new(),
+            selected_network_idx: 0,
+            available_networks: Vec::new(),
             mode: CreateMode::Form,
         }
     }
 
     pub fn field_count() -> usize {
-        7 // name, image, port_host, port_container, env_vars, volumes, command
+        14 // name, image, port_host, port_container, env_vars, volumes, command, devices, gpu, memory, cpus, network, hostname, dns
+    }
+
+    /// Field that opens a picker on Tab instead of moving on
+    pub fn picker_for_field(&self) -> Option<CreateMode> {
+        match self.selected_field {
+            1 => Some(CreateMode::ImageSelect),
+            4 => Some(CreateMode::EnvEdit),
+            11 => Some(CreateMode::NetworkSelect),
+            _ => None,
+        }
+    }
+
+    /// The GPU checkbox has focus (Space toggles it)
+    pub fn on_gpu_toggle(&self) -> bool {
+        self.selected_field == 8
     }
 
     pub fn next_field(&mut self) {
@@ -67,19 +147,41 @@ impl CreateContainerForm {
             1 => &mut self.image,
             2 => &mut self.port_host,
             3 => &mut self.port_container,
-            4 => &mut self.env_vars,
+            4 => self.env_cell_mut(),
             5 => &mut self.volumes,
             6 => &mut self.command,
+            7 => &mut self.devices,
+            9 => &mut self.memory_limit,
+            10 => &mut self.cpu_limit,
+            11 => &mut self.network,
+            12 => &mut self.hostname,
+            13 => &mut self.dns,
             _ => &mut self.name,
         }
     }
 
     pub fn type_char(&mut self, c: char) {
+        if self.on_gpu_toggle() {
+            if c == ' ' {
+                self.gpu = !self.gpu;
+            }
+            return;
+        }
+        if self.selected_field == 4 {
+            // Typing on the env field starts editing; '=' moves on to the value
+            self.mode = CreateMode::EnvEdit;
+            if c == '=' && self.env_col == 0 {
+                self.env_col = 1;
+                return;
+            }
+        }
         self.current_field_mut().push(c);
     }
 
     pub fn backspace(&mut self) {
-        self.current_field_mut().pop();
+        if !self.on_gpu_toggle() {
+            self.current_field_mut().pop();
+        }
     }
 
     pub fn select_image(&mut self) {
@@ -105,31 +207,234 @@ impl CreateContainerForm {
         }
     }
 
+    /// Memory limit in bytes; Err if the field can't be parsed
+    pub fn memory_bytes(&self) -> Result<Option<i64>, String> {
+        optional(&self.memory_limit, parse_memory).ok_or_else(|| format!("Invalid memory limit: {}", self.memory_limit))
+    }
+
+    /// CPU limit in billionths of a core (`HostConfig.nano_cpus`)
+    pub fn nano_cpus(&self) -> Result<Option<i64>, String> {
+        optional(&self.cpu_limit, parse_cpus).ok_or_else(|| format!("Invalid CPU limit: {}", self.cpu_limit))
+    }
+
+    pub fn select_network(&mut self) {
+        if let Some(network) = self.available_networks.get(self.selected_network_idx) {
+            self.network = network.clone();
+            self.mode = CreateMode::Form;
+        }
+    }
+
+    pub fn next_network(&mut self) {
+        if !self.available_networks.is_empty() {
+            self.selected_network_idx = (self.selected_network_idx + 1) % self.available_networks.len();
+        }
+    }
+
+    pub fn prev_network(&mut self) {
+        if !self.available_networks.is_empty() {
+            let len = self.available_networks.len();
+            self.selected_network_idx = (self.selected_network_idx + len - 1) % len;
+        }
+    }
+
+    fn env_cell_mut(&mut self) -> &mut String {
+        if self.env_rows.is_empty() {
+            self.env_rows.push(EnvRow::default());
+        }
+        self.env_row = self.env_row.min(self.env_rows.len() - 1);
+        let row = &mut self.env_rows[self.env_row];
+        if self.env_col == 0 {
+            &mut row.key
+        } else {
+            &mut row.value
+        }
+    }
+
+    /// Insert an empty row below the selected one and start on its name
+    pub fn env_add_row(&mut self) {
+        let at = (self.env_row + 1).min(self.env_rows.len());
+        self.env_rows.insert(at, EnvRow::default());
+        self.env_row = at;
+        self.env_col = 0;
+    }
+
+    pub fn env_remove_row(&mut self) {
+        if self.env_row < self.env_rows.len() {
+            self.env_rows.remove(self.env_row);
+        }
+        if self.env_rows.is_empty() {
+            self.env_rows.push(EnvRow::default());
+        }
+        self.env_row = self.env_row.min(self.env_rows.len() - 1);
+    }
+
+    pub fn env_toggle_column(&mut self) {
+        self.env_col = 1 - self.env_col.min(1);
+    }
+
+    /// Merge variables into the rows: existing names get the new value,
+    /// new ones are appended. Returns how many were merged.
+    pub fn merge_env(&mut self, vars: Vec<(String, String)>) -> usize {
+        let count = vars.len();
+        self.env_rows.retain(|row| !row.is_blank());
+        for (key, value) in vars {
+            match self.env_rows.iter_mut().find(|row| row.key == key) {
+                Some(row) => row.value = value,
+                None => self.env_rows.push(EnvRow { key, value }),
+            }
+        }
+        if self.env_rows.is_empty() {
+            self.env_rows.push(EnvRow::default());
+        }
+        self.env_row = self.env_row.min(self.env_rows.len() - 1);
+        count
+    }
+
+    /// `KEY=value` pairs from the non-blank rows, or the first invalid row
+    pub fn env_vars(&self) -> Result<Vec<String>, String> {
+        self.env_rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| !row.is_blank())
+            .map(|(i, row)| match row.error() {
+                Some(error) => Err(format!("Env row {}: {}", i + 1, error)),
+                None => Ok(format!("{}={}", row.key, row.value)),
+            })
+            .collect()
+    }
+
+    /// Move down in the open picker, or to the next field
+    pub fn down(&mut self) {
+        match self.mode {
+            CreateMode::Form => self.next_field(),
+            CreateMode::ImageSelect => self.next_image(),
+            CreateMode::NetworkSelect => self.next_network(),
+            CreateMode::EnvEdit => self.env_row = (self.env_row + 1).min(self.env_rows.len().saturating_sub(1)),
+        }
+    }
+
+    /// Move up in the open picker, or to the previous field
+    pub fn up(&mut self) {
+        match self.mode {
+            CreateMode::Form => self.prev_field(),
+            CreateMode::ImageSelect => self.prev_image(),
+            CreateMode::NetworkSelect => self.prev_network(),
+            CreateMode::EnvEdit => self.env_row = self.env_row.saturating_sub(1),
+        }
+    }
+
+    /// DNS servers from the comma-separated field
+    pub fn dns_servers(&self) -> Vec<String> {
+        self.dns.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect()
+    }
+
     pub fn is_valid(&self) -> bool {
         !self.name.is_empty() && !self.image.is_empty()
     }
 }
 
+/// Parse a .env file: `KEY=value` lines with optional `export ` and quotes;
+/// blank lines and `#` comments are skipped
+pub fn parse_env_file(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim();
+            let value = match value.chars().next() {
+                Some(quote @ ('"' | '\'')) => value[1..].rsplit_once(quote).map_or(&value[1..], |(inner, _)| inner),
+                // Unquoted values end at an inline comment
+                _ => value.split_once(" #").map_or(value, |(v, _)| v).trim_end(),
+            };
+            Some((key.trim().to_string(), value.to_string()))
+        })
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
+}
+
+/// Empty fields are unset; otherwise the parser has to succeed
+fn optional(value: &str, parse: fn(&str) -> Option<i64>) -> Option<Option<i64>> {
+    let value = value.trim();
+    if value.is_empty() {
+        Some(None)
+    } else {
+        parse(value).map(Some)
+    }
+}
+
+/// Parse a size like docker's `--memory`: plain bytes or a b/k/m/g suffix
+fn parse_memory(value: &str) -> Option<i64> {
+    let value = value.to_lowercase();
+    let value = value.strip_suffix('b').filter(|v| v.ends_with(['k', 'm', 'g'])).unwrap_or(&value);
+    let (number, multiplier) = match value.char_indices().last()? {
+        (i, 'k') => (&value[..i], 1i64 << 10),
+        (i, 'm') => (&value[..i], 1 << 20),
+        (i, 'g') => (&value[..i], 1 << 30),
+        (i, 'b') => (&value[..i], 1),
+        _ => (value, 1),
+    };
+    let bytes = number.trim().parse::<f64>().ok()? * multiplier as f64;
+    (bytes >= 1.0).then_some(bytes as i64)
+}
+
+/// Parse a core count like docker's `--cpus`
+fn parse_cpus(value: &str) -> Option<i64> {
+    let cores = value.parse::<f64>().ok()?;
+    (cores > 0.0 && cores.is_finite()).then_some((cores * 1e9) as i64)
+}
+
 /// Create container modal component
 pub struct CreateModal;
 
 impl CreateModal {
     pub fn render(frame: &mut Frame, area: Rect, form: &mut CreateContainerForm) {
-        let modal_area = centered_modal(area, 70, 22);
+        let modal_area = centered_modal(area, 70, 31);
 
         // Clear background
         frame.render_widget(Clear, modal_area);
 
         match form.mode {
             CreateMode::Form => Self::render_form(frame, modal_area, form),
-            CreateMode::ImageSelect => Self::render_image_select(frame, modal_area, form),
+            CreateMode::ImageSelect => Self::render_picker(
+                frame,
+                modal_area,
+                " Select Image ",
+                &form.available_images,
+                form.selected_image_idx,
+                "No images found. Pull an image first.",
+            ),
+            CreateMode::NetworkSelect => Self::render_picker(
+                frame,
+                modal_area,
+                " Select Network ",
+                &form.available_networks,
+                form.selected_network_idx,
+                "No networks found.",
+            ),
+            CreateMode::EnvEdit => Self::render_env_editor(frame, modal_area, form),
         }
     }
 
     fn render_form(frame: &mut Frame, area: Rect, form: &CreateContainerForm) {
+        let footer = match form.template_file {
+            Some(ref path) => Line::from(vec![
+                Span::styled(" Import template: ", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}│ ", path), Style::default().fg(Theme::FG)),
+            ]),
+            None => Line::from(vec![
+                Span::styled(" Ctrl+S", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)),
+                Span::styled(" export template  ", Style::default().fg(Theme::FG_DARK)),
+                Span::styled("Ctrl+O", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)),
+                Span::styled(" import ", Style::default().fg(Theme::FG_DARK)),
+            ]),
+        };
         let block = Block::default()
             .title(" Create Container ")
             .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .title_bottom(footer)
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Theme::MAGENTA))
             .style(Style::default().bg(Theme::BG_DARK));
@@ -148,6 +453,9 @@ impl CreateModal {
                 Constraint::Length(3), // Env
                 Constraint::Length(3), // Volumes
                 Constraint::Length(3), // Command
+                Constraint::Length(3), // Devices + GPU
+                Constraint::Length(3), // Limits
+                Constraint::Length(3), // Network, hostname, DNS
                 Constraint::Min(0),    // Instructions
             ])
             .split(inner);
@@ -182,7 +490,15 @@ impl CreateModal {
         Self::render_field(frame, port_chunks[1], "Container Port", &form.port_container, form.selected_field == 3);
 
         // Env vars
-        Self::render_field(frame, chunks[3], "Env (KEY=val,KEY2=val2)", &form.env_vars, form.selected_field == 4);
+        let env_summary = form
+            .env_rows
+            .iter()
+            .filter(|row| !row.is_blank())
+            .map(|row| format!("{}={}", row.key, row.value))
+            .collect::<Vec<_>>()
+            .join("  ");
+        let env_label = if form.selected_field == 4 { "Env (Tab to edit)" } else { "Env" };
+        Self::render_field(frame, chunks[3], env_label, &env_summary, form.selected_field == 4);
 
         // Volumes
         Self::render_field(frame, chunks[4], "Volumes (/host:/container)", &form.volumes, form.selected_field == 5);
@@ -190,6 +506,43 @@ impl CreateModal {
         // Command
         Self::render_field(frame, chunks[5], "Command (optional)", &form.command, form.selected_field == 6);
 
+        // Devices and the GPU toggle
+        let device_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(16)])
+            .split(chunks[6]);
+        Self::render_field(frame, device_chunks[0], "Devices (/dev/x,/dev/y:/dev/z)", &form.devices, form.selected_field == 7);
+        let gpu_focused = form.on_gpu_toggle();
+        let gpu = Paragraph::new(Line::from(vec![
+            Span::styled(if form.gpu { " [x] " } else { " [ ] " }, Style::default().fg(if form.gpu { Theme::GREEN } else { Theme::FG_DARK })),
+            Span::styled("all GPUs", Style::default().fg(if gpu_focused { Theme::FG } else { Theme::FG_DARK })),
+        ]))
+        .block(
+            Block::default()
+                .title(if gpu_focused { " GPU (Space) " } else { " GPU " })
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(if gpu_focused { Theme::CYAN } else { Theme::BORDER })),
+        );
+        frame.render_widget(gpu, device_chunks[1]);
+
+        // Resource limits (split into two)
+        let limit_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[7]);
+        Self::render_field(frame, limit_chunks[0], "Memory Limit (512m, 2g)", &form.memory_limit, form.selected_field == 9);
+        Self::render_field(frame, limit_chunks[1], "CPU Limit (cores)", &form.cpu_limit, form.selected_field == 10);
+
+        // Network (with browse hint), hostname and DNS
+        let network_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(34), Constraint::Percentage(33), Constraint::Percentage(33)])
+            .split(chunks[8]);
+        let network_label = if form.selected_field == 11 { "Network (Tab to browse)" } else { "Network" };
+        Self::render_field(frame, network_chunks[0], network_label, &form.network, form.selected_field == 11);
+        Self::render_field(frame, network_chunks[1], "Hostname", &form.hostname, form.selected_field == 12);
+        Self::render_field(frame, network_chunks[2], "DNS (1.1.1.1,...)", &form.dns, form.selected_field == 13);
+
         // Instructions
         let instructions = Paragraph::new(Line::from(vec![
             Span::styled("Tab", Style::default().fg(Theme::BLUE).add_modifier(Modifier::BOLD)),
@@ -202,7 +555,7 @@ impl CreateModal {
             Span::styled(" cancel", Style::default().fg(Theme::FG_DARK)),
         ]))
         .alignment(Alignment::Center);
-        frame.render_widget(instructions, chunks[6]);
+        frame.render_widget(instructions, chunks[9]);
     }
 
     fn render_field(frame: &mut Frame, area: Rect, label: &str, value: &str, focused: bool) {
@@ -230,9 +583,86 @@ impl CreateModal {
         frame.render_widget(text, area);
     }
 
-    fn render_image_select(frame: &mut Frame, area: Rect, form: &mut CreateContainerForm) {
+    fn render_env_editor(frame: &mut Frame, area: Rect, form: &CreateContainerForm) {
+        let block = Block::default()
+            .title(" Environment ")
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Min(0), Constraint::Length(1), Constraint::Length(1)])
+            .split(inner);
+
+        if let Some(ref path) = form.env_file {
+            let prompt = Line::from(vec![
+                Span::styled(" Load .env: ", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}│", path), Style::default().fg(Theme::FG)),
+            ]);
+            frame.render_widget(Paragraph::new(prompt), chunks[1]);
+        }
+
+        let key_width = form.env_rows.iter().map(|row| display_width(&row.key)).max().unwrap_or(0).clamp(8, 24) + 1;
+        let visible = chunks[0].height as usize;
+        let skip = (form.env_row + 1).saturating_sub(visible);
+        let lines: Vec<Line> = form
+            .env_rows
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .take(visible)
+            .map(|(i, row)| {
+                let selected = i == form.env_row;
+                let cell = |text: &str, col: usize, color: Color| {
+                    let cursor = if selected && form.env_col == col { "│" } else { "" };
+                    let style = Style::default().fg(color);
+                    Span::styled(format!("{}{}", text, cursor), if selected && form.env_col == col { style.bg(Theme::SELECTION_BG) } else { style })
+                };
+                let key_color = if row.error().is_some() { Theme::RED } else { Theme::CYAN };
+                let padding = " ".repeat(key_width.saturating_sub(display_width(&row.key) + usize::from(selected && form.env_col == 0)));
+                let mut spans = vec![
+                    Span::styled(if selected { "▶ " } else { "  " }, Style::default().fg(Theme::CYAN)),
+                    cell(&row.key, 0, key_color),
+                    Span::raw(padding),
+                    Span::styled("= ", Style::default().fg(Theme::FG_DARK)),
+                    cell(&row.value, 1, Theme::FG),
+                ];
+                if let Some(error) = row.error() {
+                    spans.push(Span::styled(format!("  {}", error), Style::default().fg(Theme::RED)));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+        let key = |k: &'static str, color: Color| Span::styled(k, Style::default().fg(color).add_modifier(Modifier::BOLD));
+        let desc = |d: &'static str| Span::styled(d, Style::default().fg(Theme::FG_DARK));
+        let instructions = Line::from(vec![
+            key("↑↓", Theme::BLUE),
+            desc(" row  "),
+            key("Tab", Theme::BLUE),
+            desc(" name/value  "),
+            key("Enter", Theme::GREEN),
+            desc(" add  "),
+            key("Ctrl+D", Theme::RED),
+            desc(" delete  "),
+            key("Ctrl+O", Theme::YELLOW),
+            desc(" load .env  "),
+            key("Esc", Theme::BLUE),
+            desc(" done"),
+        ]);
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[2]);
+    }
+
+    fn render_picker(frame: &mut Frame, area: Rect, title: &str, items: &[String], selected: usize, empty: &str) {
         let block = Block::default()
-            .title(" Select Image ")
+            .title(title)
             .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Theme::MAGENTA))
@@ -241,16 +671,15 @@ impl CreateModal {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        if form.available_images.is_empty() {
-            let text = Paragraph::new("No images found. Pull an image first.")
+        if items.is_empty() {
+            let text = Paragraph::new(empty)
                 .style(Style::default().fg(Theme::FG_DARK))
                 .alignment(Alignment::Center);
             frame.render_widget(text, inner);
             return;
         }
 
-        let items: Vec<ListItem> = form
-            .available_images
+        let items: Vec<ListItem> = items
             .iter()
             .map(|img| {
                 ListItem::new(Line::from(vec![
@@ -261,7 +690,7 @@ impl CreateModal {
             .collect();
 
         let mut state = ListState::default();
-        state.select(Some(form.selected_image_idx));
+        state.select(Some(selected));
 
         let list = List::new(items)
             .highlight_style(
@@ -275,3 +704,53 @@ impl CreateModal {
         frame.render_stateful_widget(list, inner, &mut state);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_memory() {
+        assert_eq!(parse_memory("512m"), Some(512 << 20));
+        assert_eq!(parse_memory("2G"), Some(2 << 30));
+        assert_eq!(parse_memory("1.5gb"), Some(3 << 29));
+        assert_eq!(parse_memory("4096"), Some(4096));
+        assert_eq!(parse_memory("lots"), None);
+    }
+
+    #[test]
+    fn test_env_vars() {
+        let mut form = CreateContainerForm::new();
+        form.selected_field = 4;
+        for c in "URLS=a,b".chars() {
+            form.type_char(c);
+        }
+        form.env_add_row();
+        form.env_add_row();
+        form.type_char('X');
+        assert_eq!(form.env_vars(), Ok(vec!["URLS=a,b".to_string(), "X=".to_string()]));
+
+        form.env_rows.push(EnvRow { key: String::new(), value: "orphan".to_string() });
+        assert_eq!(form.env_vars(), Err("Env row 4: missing name".to_string()));
+    }
+
+    #[test]
+    fn test_parse_env_file() {
+        let content = "# db\nexport DB_URL=\"postgres://a b\" # main\nDEBUG=1 # on\n\nNAME='x=y'\nbroken line\n";
+        assert_eq!(
+            parse_env_file(content),
+            vec![
+                ("DB_URL".to_string(), "postgres://a b".to_string()),
+                ("DEBUG".to_string(), "1".to_string()),
+                ("NAME".to_string(), "x=y".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_cpus() {
+        assert_eq!(parse_cpus("0.5"), Some(500_000_000));
+        assert_eq!(parse_cpus("2"), Some(2_000_000_000));
+        assert_eq!(parse_cpus("0"), None);
+    }
+}
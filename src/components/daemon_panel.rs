@@ -0,0 +1,65 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use crate::models::DaemonInfo;
+use crate::ui::Theme;
+
+/// Panel with the Docker engine's version and setup and its recent journal errors
+pub struct DaemonPanel;
+
+impl DaemonPanel {
+    pub fn render(frame: &mut Frame, area: Rect, info: Option<&DaemonInfo>) {
+        let block = Block::default()
+            .title(Span::styled(" Docker Daemon ", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::BORDER));
+
+        let Some(info) = info else {
+            let items = vec![ListItem::new(Line::styled(" Daemon info unavailable", Style::default().fg(Theme::FG_DARK)))];
+            frame.render_widget(List::new(items).block(block), area);
+            return;
+        };
+
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(Theme::FG_DARK));
+        let value = |text: &str| Span::styled(text.to_string(), Style::default().fg(Theme::FG));
+        let mut items = vec![
+            ListItem::new(Line::from(vec![
+                label(" Engine "),
+                value(&info.version),
+                label("  API "),
+                value(&info.api_version),
+                label("  Storage "),
+                value(&info.storage_driver),
+                label("  Cgroup "),
+                value(&info.cgroup),
+            ])),
+            ListItem::new(Line::from(vec![label(" OS "), value(&info.os), label("  Kernel "), value(&info.kernel)])),
+        ];
+        items.extend(
+            info.warnings
+                .iter()
+                .map(|w| ListItem::new(Line::styled(format!(" ⚠ {}", w), Style::default().fg(Theme::YELLOW)))),
+        );
+
+        // Newest journal errors first
+        if let Some(ref note) = info.journal_note {
+            items.push(ListItem::new(Line::styled(format!(" {}", note), Style::default().fg(Theme::FG_DARK))));
+        } else if info.journal.is_empty() {
+            items.push(ListItem::new(Line::styled(
+                " No recent dockerd errors in the journal (or no access to it)",
+                Style::default().fg(Theme::GREEN),
+            )));
+        } else {
+            items.extend(
+                info.journal
+                    .iter()
+                    .rev()
+                    .map(|line| ListItem::new(Line::styled(format!(" {}", line), Style::default().fg(Theme::RED)))),
+            );
+        }
+
+        frame.render_widget(List::new(items).block(block), area);
+    }
+}
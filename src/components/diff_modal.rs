@@ -0,0 +1,173 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::models::diff::group_by_dir;
+use crate::models::{ChangeKind, FileChange};
+use crate::ui::{centered_modal, Theme};
+
+/// Visible lines
+const PAGE: usize = 18;
+
+/// Filesystem changes of a container (docker diff), grouped by directory
+#[derive(Debug, Clone)]
+pub struct DiffModal {
+    pub container_name: String,
+    pub changes: Vec<FileChange>,
+    /// Path search (case-insensitive substring)
+    pub query: String,
+    /// Typing goes to the search query
+    pub searching: bool,
+    pub scroll: usize,
+}
+
+impl DiffModal {
+    pub fn new(container_name: String, changes: Vec<FileChange>) -> Self {
+        Self {
+            container_name,
+            changes,
+            query: String::new(),
+            searching: false,
+            scroll: 0,
+        }
+    }
+
+    pub fn start_search(&mut self) {
+        self.searching = true;
+    }
+
+    /// Leave search mode; `clear` also drops the query
+    pub fn stop_search(&mut self, clear: bool) {
+        self.searching = false;
+        if clear {
+            self.query.clear();
+            self.scroll = 0;
+        }
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        self.query.push(c);
+        self.scroll = 0;
+    }
+
+    pub fn handle_backspace(&mut self) {
+        self.query.pop();
+        self.scroll = 0;
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        let max_scroll = self.lines().len().saturating_sub(PAGE);
+        self.scroll = (self.scroll + lines).min(max_scroll);
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = self.lines().len().saturating_sub(PAGE);
+    }
+
+    /// Changes whose path matches the search query
+    fn matching(&self) -> Vec<&FileChange> {
+        let query = self.query.to_lowercase();
+        self.changes
+            .iter()
+            .filter(|c| query.is_empty() || c.path.to_lowercase().contains(&query))
+            .collect()
+    }
+
+    /// Directory headers followed by their entries
+    fn lines(&self) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        for (dir, entries) in group_by_dir(&self.matching()) {
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {}/", dir.trim_end_matches('/')), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(format!(" ({})", entries.len()), Style::default().fg(Theme::FG_DARK)),
+            ]));
+            for change in entries {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("   {} ", change.kind.symbol()), Style::default().fg(kind_color(change.kind)).add_modifier(Modifier::BOLD)),
+                    Span::styled(change.file_name().to_string(), Style::default().fg(Theme::FG)),
+                ]));
+            }
+        }
+        lines
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 80, 24);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let count = |kind: ChangeKind| self.changes.iter().filter(|c| c.kind == kind).count();
+        let block = Block::default()
+            .title(format!(
+                " Diff: {} (A {} / C {} / D {}) ",
+                self.container_name,
+                count(ChangeKind::Added),
+                count(ChangeKind::Changed),
+                count(ChangeKind::Deleted)
+            ))
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        if self.changes.is_empty() {
+            let msg = Paragraph::new("No changes since the container was created")
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center);
+            frame.render_widget(msg, inner);
+            return;
+        }
+
+        // Split for search, changes and instructions
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0), Constraint::Length(2)])
+            .split(inner);
+
+        let search = if self.searching || !self.query.is_empty() {
+            let cursor = if self.searching { "█" } else { "" };
+            Line::from(vec![
+                Span::styled(" / ", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}{}", self.query, cursor), Style::default().fg(Theme::FG)),
+            ])
+        } else {
+            Line::styled(" Press / to search paths", Style::default().fg(Theme::FG_DARK))
+        };
+        frame.render_widget(Paragraph::new(search), chunks[0]);
+
+        let lines = self.lines();
+        let total = lines.len();
+        let visible: Vec<Line> = lines.into_iter().skip(self.scroll).take(PAGE).collect();
+        frame.render_widget(Paragraph::new(visible), chunks[1]);
+
+        // Instructions
+        let instructions = Line::from(vec![
+            Span::styled(" ↑↓ ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("scroll   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" / ", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)),
+            Span::styled("search   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" Esc/F ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
+            Span::styled("close   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(format!(" [{}/{}] ", (self.scroll + 1).min(total), total), Style::default().fg(Theme::FG_DARK)),
+        ]);
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[2]);
+    }
+}
+
+/// Added green, changed yellow, deleted red
+fn kind_color(kind: ChangeKind) -> Color {
+    match kind {
+        ChangeKind::Added => Theme::GREEN,
+        ChangeKind::Changed => Theme::YELLOW,
+        ChangeKind::Deleted => Theme::RED,
+    }
+}
@@ -0,0 +1,129 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::docker::events::{DockerEvent, EventLog};
+use crate::ui::{border_style, selected_style, Theme};
+
+/// Docker events received from the daemon, newest first, optionally only
+/// those of one container
+#[derive(Debug, Default)]
+pub struct EventsView {
+    pub state: TableState,
+    /// Name filter applied to the events' objects
+    pub filter: String,
+    /// Filter being typed while the input is open
+    pub input: Option<String>,
+}
+
+impl EventsView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Events shown with the current filter, newest first
+    pub fn visible<'a>(&self, log: &'a EventLog) -> Vec<&'a DockerEvent> {
+        log.events.iter().rev().filter(|e| e.matches(&self.filter)).collect()
+    }
+
+    pub fn next(&mut self, count: usize) {
+        if count > 0 {
+            let i = self.state.selected().map_or(0, |i| (i + 1).min(count - 1));
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn previous(&mut self, count: usize) {
+        if count > 0 {
+            let i = self.state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn top(&mut self) {
+        self.state.select(Some(0));
+    }
+
+    pub fn bottom(&mut self, count: usize) {
+        self.state.select(count.checked_sub(1));
+    }
+
+    /// Show only events of objects whose name contains `filter` (all when empty)
+    pub fn set_filter(&mut self, filter: &str) {
+        self.filter = filter.trim().to_string();
+        self.state.select(Some(0));
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, log: &EventLog) {
+        let events = self.visible(log);
+        let title = match self.filter.as_str() {
+            "" => format!(" Events ({}) ", events.len()),
+            filter => format!(" Events matching '{}' ({} of {}) ", filter, events.len(), log.events.len()),
+        };
+        let mut block = Block::default()
+            .title(Span::styled(title, Style::default().fg(Theme::LAVENDER).add_modifier(Modifier::BOLD)))
+            .borders(Borders::ALL)
+            .border_style(border_style(true));
+        block = match (&self.input, &log.error) {
+            (Some(input), _) => block.title_bottom(Line::from(vec![
+                Span::styled(" Container: ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}█ ", input), Style::default().fg(Theme::FG)),
+            ])),
+            (None, Some(error)) => block.title_bottom(Line::styled(format!(" {} (reconnecting) ", error), Style::default().fg(Theme::RED))),
+            (None, None) => block.title_bottom(Line::styled(" / filter by container ", Style::default().fg(Theme::FG_DARK))),
+        };
+
+        if events.is_empty() {
+            let text = Paragraph::new(if log.events.is_empty() { "No events yet" } else { "No events match the filter" })
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(text, area);
+            return;
+        }
+
+        let header_row = Row::new(vec!["TIME", "TYPE", "NAME", "ACTION", "ATTRIBUTES"])
+            .style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = events
+            .iter()
+            .map(|e| {
+                Row::new(vec![
+                    Cell::from(e.at.format("%m-%d %H:%M:%S").to_string()).style(Style::default().fg(Theme::FG_DARK)),
+                    Cell::from(e.kind.clone()).style(Style::default().fg(Theme::FG_DARK)),
+                    Cell::from(e.actor.clone()).style(Style::default().fg(Theme::CYAN)),
+                    Cell::from(e.action.clone()).style(Style::default().fg(action_color(&e.action))),
+                    Cell::from(e.describe_attributes()).style(Style::default().fg(Theme::FG_DARK)),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(14),     // TIME
+            Constraint::Length(9),      // TYPE
+            Constraint::Percentage(20), // NAME
+            Constraint::Length(24),     // ACTION
+            Constraint::Min(20),        // ATTRIBUTES
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header_row)
+            .block(block)
+            .column_spacing(1)
+            .row_highlight_style(selected_style())
+            .highlight_symbol("▶");
+
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+}
+
+/// Failures red, starts green, stops yellow
+fn action_color(action: &str) -> Color {
+    match action {
+        "die" | "oom" | "kill" | "health_status: unhealthy" => Theme::RED,
+        "start" | "unpause" | "health_status: healthy" => Theme::GREEN,
+        "stop" | "pause" | "destroy" => Theme::YELLOW,
+        _ => Theme::FG,
+    }
+}
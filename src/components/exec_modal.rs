@@ -1,6 +1,6 @@
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 
 use crate::ui::{centered_modal, Theme};
@@ -8,12 +8,30 @@ use crate::ui::{centered_modal, Theme};
 /// Available shells for exec
 pub const SHELLS: &[&str] = &["/bin/bash", "/bin/sh", "/bin/zsh", "/bin/ash"];
 
+/// Preferred default when several shells exist (indices into SHELLS)
+const PREFERENCE: [usize; 4] = [0, 2, 3, 1];
+
+/// Part of the exec modal that has focus
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExecFocus {
+    Shell,
+    User,
+    Workdir,
+}
+
 /// Exec shell modal
 #[derive(Debug, Clone)]
 pub struct ExecModal {
     pub container_name: String,
     pub selected: usize,
     pub state: ListState,
+    /// Which SHELLS exist in the container (all assumed present if the probe failed)
+    pub available: [bool; 4],
+    /// `-u` (e.g. "root" or "1000:1000"); empty = the image's user
+    pub user: String,
+    /// `-w`; empty = the image's working directory
+    pub workdir: String,
+    pub focus: ExecFocus,
 }
 
 impl ExecModal {
@@ -24,19 +42,87 @@ impl ExecModal {
             container_name,
             selected: 0,
             state,
+            available: [true; 4],
+            user: String::new(),
+            workdir: String::new(),
+            focus: ExecFocus::Shell,
+        }
+    }
+
+    pub fn next_focus(&mut self) {
+        self.focus = match self.focus {
+            ExecFocus::Shell => ExecFocus::User,
+            ExecFocus::User => ExecFocus::Workdir,
+            ExecFocus::Workdir => ExecFocus::Shell,
+        };
+    }
+
+    pub fn prev_focus(&mut self) {
+        self.focus = match self.focus {
+            ExecFocus::Shell => ExecFocus::Workdir,
+            ExecFocus::User => ExecFocus::Shell,
+            ExecFocus::Workdir => ExecFocus::User,
+        };
+    }
+
+    /// Type into the focused text field (the shell list takes no text)
+    pub fn handle_char(&mut self, c: char) {
+        match self.focus {
+            ExecFocus::User if !c.is_whitespace() => self.user.push(c),
+            ExecFocus::Workdir => self.workdir.push(c),
+            _ => {}
+        }
+    }
+
+    pub fn handle_backspace(&mut self) {
+        match self.focus {
+            ExecFocus::User => self.user.pop(),
+            ExecFocus::Workdir => self.workdir.pop(),
+            ExecFocus::Shell => None,
+        };
+    }
+
+    /// Arguments for `docker`, e.g. `exec -it -u root -w /app web /bin/sh`
+    pub fn exec_args(&self) -> Vec<String> {
+        let mut args = vec!["exec".to_string(), "-it".to_string()];
+        if !self.user.trim().is_empty() {
+            args.extend(["-u".to_string(), self.user.trim().to_string()]);
+        }
+        if !self.workdir.trim().is_empty() {
+            args.extend(["-w".to_string(), self.workdir.trim().to_string()]);
+        }
+        args.extend([self.container_name.clone(), self.selected_shell().to_string()]);
+        args
+    }
+
+    /// Mark the shells found in the container and select the best of them
+    pub fn set_available(&mut self, found: &[String]) {
+        self.available = std::array::from_fn(|i| found.iter().any(|s| s == SHELLS[i]));
+        if let Some(&best) = PREFERENCE.iter().find(|&&i| self.available[i]) {
+            self.selected = best;
+            self.state.select(Some(best));
+        } else {
+            // Nothing matched; let the user try anyway
+            self.available = [true; 4];
         }
     }
 
     pub fn next(&mut self) {
-        self.selected = (self.selected + 1) % SHELLS.len();
+        for _ in 0..SHELLS.len() {
+            self.selected = (self.selected + 1) % SHELLS.len();
+            if self.available[self.selected] {
+                break;
+            }
+        }
         self.state.select(Some(self.selected));
     }
 
     pub fn previous(&mut self) {
-        if self.selected == 0 {
-            self.selected = SHELLS.len() - 1;
-        } else {
-            self.selected -= 1;
+        for _ in 0..SHELLS.len() {
+            self.selected = (self.selected + SHELLS.len() - 1) % SHELLS.len();
+            if self.available[self.selected] {
+                break;
+            }
         }
         self.state.select(Some(self.selected));
     }
@@ -46,7 +132,7 @@ impl ExecModal {
     }
 
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let modal_area = centered_modal(area, 50, 12);
+        let modal_area = centered_modal(area, 50, 18);
 
         // Clear background
         frame.render_widget(Clear, modal_area);
@@ -64,40 +150,89 @@ impl ExecModal {
         // Shell selection list
         let items: Vec<ListItem> = SHELLS
             .iter()
-            .map(|shell| {
-                ListItem::new(Line::from(vec![
-                    Span::styled("  ", Style::default()),
-                    Span::styled(*shell, Style::default().fg(Theme::FG)),
-                ]))
+            .zip(self.available)
+            .map(|(shell, available)| {
+                let line = if available {
+                    Line::from(vec![
+                        Span::styled("  ", Style::default()),
+                        Span::styled(*shell, Style::default().fg(Theme::FG)),
+                    ])
+                } else {
+                    Line::from(vec![
+                        Span::styled("  ", Style::default()),
+                        Span::styled(*shell, Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(" (not found)", Style::default().fg(Theme::FG_DARK)),
+                    ])
+                };
+                ListItem::new(line)
             })
             .collect();
 
+        let highlight = if self.focus == ExecFocus::Shell {
+            Style::default().bg(Theme::SELECTION_BG).fg(Theme::CYAN).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Theme::CYAN)
+        };
         let list = List::new(items)
-            .highlight_style(
-                Style::default()
-                    .bg(Theme::SELECTION_BG)
-                    .fg(Theme::CYAN)
-                    .add_modifier(Modifier::BOLD),
-            )
+            .highlight_style(highlight)
             .highlight_symbol("▶ ");
 
-        // Split for list and instructions
+        // Split for list, user and workdir fields, and instructions
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(4), Constraint::Length(2)])
+            .constraints([Constraint::Min(4), Constraint::Length(3), Constraint::Length(3), Constraint::Length(2)])
             .split(inner);
 
         frame.render_stateful_widget(list, chunks[0], &mut self.state);
 
+        let fields = [
+            (ExecFocus::User, "User (-u, e.g. root)", &self.user),
+            (ExecFocus::Workdir, "Working directory (-w)", &self.workdir),
+        ];
+        for (i, (focus, label, value)) in fields.into_iter().enumerate() {
+            let selected = self.focus == focus;
+            let cursor = if selected { "█" } else { "" };
+            let border = if selected { Theme::CYAN } else { Theme::BORDER };
+            let input = Paragraph::new(format!(" {}{}", value, cursor))
+                .style(Style::default().fg(Theme::FG))
+                .block(
+                    Block::default()
+                        .title(format!(" {} ", label))
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(border)),
+                );
+            frame.render_widget(input, chunks[1 + i]);
+        }
+
         // Instructions
         let instructions = Line::from(vec![
             Span::styled(" Enter ", Style::default().fg(Theme::GREEN).add_modifier(Modifier::BOLD)),
             Span::styled("exec  ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" F2 ", Style::default().fg(Theme::GREEN).add_modifier(Modifier::BOLD)),
+            Span::styled("in pane  ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" Tab ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("user/dir  ", Style::default().fg(Theme::FG_DARK)),
             Span::styled(" Esc ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
             Span::styled("cancel", Style::default().fg(Theme::FG_DARK)),
         ]);
         let instructions_widget = ratatui::widgets::Paragraph::new(instructions)
             .alignment(Alignment::Center);
-        frame.render_widget(instructions_widget, chunks[1]);
+        frame.render_widget(instructions_widget, chunks[3]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exec_args() {
+        let mut modal = ExecModal::new("web".to_string());
+        modal.set_available(&["/bin/sh".to_string(), "/bin/ash".to_string()]);
+        assert_eq!(modal.exec_args(), ["exec", "-it", "web", "/bin/ash"]);
+
+        modal.user = "root".to_string();
+        modal.workdir = "/var/log ".to_string();
+        assert_eq!(modal.exec_args(), ["exec", "-it", "-u", "root", "-w", "/var/log", "web", "/bin/ash"]);
     }
 }
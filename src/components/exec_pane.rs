@@ -0,0 +1,82 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders},
+};
+
+use crate::docker::exec_session::ExecSession;
+use crate::ui::{border_style, Theme};
+
+/// Embedded shell pane beside the container list
+pub struct ExecPane;
+
+impl ExecPane {
+    /// Draw the session's screen; returns the inner size (rows, cols) the
+    /// exec TTY should have
+    pub fn render(frame: &mut Frame, area: Rect, session: &ExecSession, focused: bool) -> (u16, u16) {
+        let hint = if focused { " F2 list " } else { " F2 shell " };
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" {} in {} ", session.shell, session.container),
+                Style::default().fg(Theme::LAVENDER).add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::styled(hint, Style::default().fg(Theme::FG_DARK)))
+            .borders(Borders::ALL)
+            .border_style(border_style(focused));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let Ok(parser) = session.parser().lock() else {
+            return (inner.height, inner.width);
+        };
+        let screen = parser.screen();
+        let buf = frame.buffer_mut();
+        for row in 0..inner.height {
+            for col in 0..inner.width {
+                let (Some(cell), Some(target)) =
+                    (screen.cell(row, col), buf.cell_mut((inner.x + col, inner.y + row)))
+                else {
+                    continue;
+                };
+                if cell.is_wide_continuation() {
+                    continue;
+                }
+                let contents = cell.contents();
+                let symbol: &str = contents.as_ref();
+                target.set_symbol(if symbol.is_empty() { " " } else { symbol });
+
+                let mut style = Style::default().fg(color(cell.fgcolor(), Theme::FG)).bg(color(cell.bgcolor(), Theme::BG));
+                if cell.bold() {
+                    style = style.add_modifier(Modifier::BOLD);
+                }
+                if cell.italic() {
+                    style = style.add_modifier(Modifier::ITALIC);
+                }
+                if cell.underline() {
+                    style = style.add_modifier(Modifier::UNDERLINED);
+                }
+                if cell.inverse() {
+                    style = style.add_modifier(Modifier::REVERSED);
+                }
+                target.set_style(style);
+            }
+        }
+
+        if focused && !screen.hide_cursor() {
+            let (row, col) = screen.cursor_position();
+            if row < inner.height && col < inner.width {
+                frame.set_cursor_position((inner.x + col, inner.y + row));
+            }
+        }
+
+        (inner.height, inner.width)
+    }
+}
+
+/// vt100 color to a ratatui color (`default` for the terminal default)
+fn color(color: vt100::Color, default: Color) -> Color {
+    match color {
+        vt100::Color::Default => default,
+        vt100::Color::Idx(i) => Color::Indexed(i),
+        vt100::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    }
+}
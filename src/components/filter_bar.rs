@@ -1,10 +1,105 @@
+use std::sync::OnceLock;
+
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Paragraph},
 };
 
+use crate::models::ContainerInfo;
 use crate::ui::Theme;
 
+/// Shared skim-style matcher (smart case)
+pub fn matcher() -> &'static SkimMatcherV2 {
+    static MATCHER: OnceLock<SkimMatcherV2> = OnceLock::new();
+    MATCHER.get_or_init(|| SkimMatcherV2::default().smart_case())
+}
+
+/// Container field a filter query matched against
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchField {
+    Name,
+    Image,
+    Id,
+    Project,
+}
+
+impl MatchField {
+    /// Query prefix that restricts matching to this field
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Image => "image",
+            Self::Id => "id",
+            Self::Project => "project",
+        }
+    }
+}
+
+/// Split a field prefix off a query ("image:nginx" -> (Image, "nginx"))
+fn parse_query(query: &str) -> (Option<MatchField>, &str) {
+    if let Some((prefix, rest)) = query.split_once(':') {
+        let field = match prefix {
+            "name" | "n" => Some(MatchField::Name),
+            "image" | "img" | "i" => Some(MatchField::Image),
+            "id" => Some(MatchField::Id),
+            "project" | "proj" | "p" => Some(MatchField::Project),
+            _ => None,
+        };
+        if field.is_some() {
+            return (field, rest);
+        }
+    }
+    (None, query)
+}
+
+/// Score `pattern` against a single container field
+fn match_field(field: MatchField, pattern: &str, c: &ContainerInfo) -> Option<i64> {
+    match field {
+        MatchField::Name => matcher().fuzzy_match(&c.name, pattern),
+        MatchField::Image => matcher().fuzzy_match(&c.image, pattern),
+        MatchField::Project => matcher().fuzzy_match(c.compose_project.as_deref()?, pattern),
+        // IDs only make sense as a prefix
+        MatchField::Id => {
+            let pattern = pattern.to_lowercase();
+            c.id.starts_with(&pattern).then_some(pattern.len() as i64 * 16)
+        }
+    }
+}
+
+/// Best match of `query` against a container's fields, None if nothing matches.
+/// Without a prefix, the name wins ties so highlights stay on the name.
+pub fn match_container(query: &str, c: &ContainerInfo) -> Option<(i64, MatchField)> {
+    let (field, pattern) = parse_query(query);
+    if pattern.is_empty() {
+        return Some((0, field.unwrap_or(MatchField::Name)));
+    }
+
+    match field {
+        Some(field) => match_field(field, pattern, c).map(|score| (score, field)),
+        None => [MatchField::Name, MatchField::Image, MatchField::Project, MatchField::Id]
+            .into_iter()
+            .filter_map(|f| match_field(f, pattern, c).map(|score| (score, f)))
+            .fold(None, |best: Option<(i64, MatchField)>, m| match best {
+                Some(b) if b.0 >= m.0 => Some(b),
+                _ => Some(m),
+            }),
+    }
+}
+
+/// Char indices of `name` matched by a fuzzy `query` (empty unless the name matched)
+pub fn match_positions(query: &str, name: &str) -> Vec<usize> {
+    let (field, pattern) = parse_query(query);
+    if pattern.is_empty() || field.is_some_and(|f| f != MatchField::Name) {
+        return vec![];
+    }
+    matcher()
+        .fuzzy_indices(name, pattern)
+        .map(|(_, indices)| indices)
+        .unwrap_or_default()
+}
+
 /// Filter bar component for fuzzy searching containers
 #[derive(Debug, Clone, Default)]
 pub struct FilterBar {
@@ -45,35 +140,22 @@ impl FilterBar {
         self.query.is_empty()
     }
 
-    /// Check if a container name matches the filter (fuzzy)
-    pub fn matches(&self, name: &str) -> bool {
-        if self.query.is_empty() {
-            return true;
-        }
-
-        let query_lower = self.query.to_lowercase();
-        let name_lower = name.to_lowercase();
-
-        // Simple substring match (can be enhanced to true fuzzy)
-        name_lower.contains(&query_lower)
+    /// Check if a container matches the filter (fuzzy, across fields)
+    pub fn matches(&self, container: &ContainerInfo) -> bool {
+        self.score(container).is_some()
     }
 
-    /// Get match positions for highlighting
-    pub fn match_positions(&self, name: &str) -> Vec<usize> {
+    /// Fuzzy match score (higher is better), None if the container doesn't match
+    pub fn score(&self, container: &ContainerInfo) -> Option<i64> {
         if self.query.is_empty() {
-            return vec![];
+            return Some(0);
         }
+        match_container(&self.query, container).map(|(score, _)| score)
+    }
 
-        let query_lower = self.query.to_lowercase();
-        let name_lower = name.to_lowercase();
-
-        let mut positions = Vec::new();
-        if let Some(start) = name_lower.find(&query_lower) {
-            for i in start..(start + query_lower.len()) {
-                positions.push(i);
-            }
-        }
-        positions
+    /// Get match positions (char indices) for highlighting
+    pub fn match_positions(&self, name: &str) -> Vec<usize> {
+        match_positions(&self.query, name)
     }
 
     pub fn render(&self, frame: &mut Frame, area: Rect, match_count: usize, total_count: usize) {
@@ -105,3 +187,34 @@ impl FilterBar {
         frame.render_widget(paragraph, inner);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container(name: &str, image: &str, id: &str, project: Option<&str>) -> ContainerInfo {
+        let mut c = ContainerInfo::new(name.to_string());
+        c.image = image.to_string();
+        c.id = id.to_string();
+        c.compose_project = project.map(str::to_string);
+        c
+    }
+
+    #[test]
+    fn test_prefix_restricts_field() {
+        let c = container("api", "nginx:latest", "abc123def456", Some("web"));
+        assert_eq!(match_container("image:nginx", &c).map(|m| m.1), Some(MatchField::Image));
+        assert_eq!(match_container("project:web", &c).map(|m| m.1), Some(MatchField::Project));
+        assert_eq!(match_container("id:abc1", &c).map(|m| m.1), Some(MatchField::Id));
+        assert!(match_container("name:nginx", &c).is_none());
+        assert!(match_container("id:123", &c).is_none());
+    }
+
+    #[test]
+    fn test_unprefixed_matches_any_field() {
+        let c = container("api", "postgres:16", "abc123def456", None);
+        assert_eq!(match_container("postgres", &c).map(|m| m.1), Some(MatchField::Image));
+        assert_eq!(match_container("api", &c).map(|m| m.1), Some(MatchField::Name));
+        assert!(match_positions("image:api", "api").is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::forwards::{default_local_port, PortForward};
+use crate::models::ContainerInfo;
+use crate::ui::{centered_modal, Theme};
+
+/// Which port field is being typed into
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ForwardField {
+    ContainerPort,
+    LocalPort,
+}
+
+/// Dialog to forward a local port to a container port, listing the
+/// forwards already running so they can be stopped
+#[derive(Debug, Clone)]
+pub struct ForwardModal {
+    pub container: String,
+    pub ip: String,
+    pub container_port: String,
+    pub local_port: String,
+    pub field: ForwardField,
+    pub error: Option<String>,
+    /// Selected running forward
+    pub selected: usize,
+}
+
+impl ForwardModal {
+    /// Preset with the container's first unpublished TCP port
+    pub fn new(container: &ContainerInfo, ip: &str) -> Self {
+        let port = container
+            .ports
+            .iter()
+            .filter(|p| p.protocol == "tcp")
+            .min_by_key(|p| p.host_port.is_some())
+            .map(|p| p.container_port);
+        Self {
+            container: container.name.clone(),
+            ip: ip.to_string(),
+            container_port: port.map(|p| p.to_string()).unwrap_or_default(),
+            local_port: port.map(|p| default_local_port(p).to_string()).unwrap_or_default(),
+            field: ForwardField::ContainerPort,
+            error: None,
+            selected: 0,
+        }
+    }
+
+    fn field_mut(&mut self) -> &mut String {
+        match self.field {
+            ForwardField::ContainerPort => &mut self.container_port,
+            ForwardField::LocalPort => &mut self.local_port,
+        }
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        if c.is_ascii_digit() && self.field_mut().len() < 5 {
+            self.field_mut().push(c);
+            self.error = None;
+        }
+    }
+
+    pub fn handle_backspace(&mut self) {
+        self.field_mut().pop();
+        self.error = None;
+    }
+
+    pub fn next_field(&mut self) {
+        self.field = match self.field {
+            ForwardField::ContainerPort => ForwardField::LocalPort,
+            ForwardField::LocalPort => ForwardField::ContainerPort,
+        };
+    }
+
+    /// Container and local port, if both are valid
+    pub fn ports(&self) -> Result<(u16, u16), String> {
+        let parse = |value: &str, what: &str| match value.parse::<u16>() {
+            Ok(port) if port > 0 => Ok(port),
+            _ => Err(format!("Enter a {} port (1-65535)", what)),
+        };
+        Ok((parse(&self.container_port, "container")?, parse(&self.local_port, "local")?))
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, forwards: &[PortForward]) {
+        let modal_area = centered_modal(area, 64, 18);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(format!(" Forward port: {} ({}) ", self.container, self.ip))
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let fields = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[0]);
+        for (area, field, title, value) in [
+            (fields[0], ForwardField::ContainerPort, " Container port ", &self.container_port),
+            (fields[1], ForwardField::LocalPort, " Local port ", &self.local_port),
+        ] {
+            let focused = self.field == field;
+            let border = if focused { Theme::CYAN } else { Theme::BORDER };
+            let cursor = if focused { "█" } else { "" };
+            let input = Paragraph::new(format!(" {}{}", value, cursor))
+                .style(Style::default().fg(Theme::FG))
+                .block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(border)));
+            frame.render_widget(input, area);
+        }
+
+        let note = match self.error {
+            Some(ref error) => Line::styled(format!(" {}", error), Style::default().fg(Theme::RED)),
+            None => Line::styled(" Listens on 127.0.0.1 until stopped or the TUI exits", Style::default().fg(Theme::FG_DARK)),
+        };
+        frame.render_widget(Paragraph::new(note), chunks[1]);
+
+        let items: Vec<ListItem> = forwards
+            .iter()
+            .map(|f| {
+                let (state, color) = match f.exited {
+                    Some(ref exited) => (exited.as_str(), Theme::RED),
+                    None => ("running", Theme::GREEN),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!(" 127.0.0.1:{:<6}", f.local_port), Style::default().fg(Theme::FG)),
+                    Span::styled(format!("→ {} ({}) ", f.container, f.target), Style::default().fg(Theme::FG_DARK)),
+                    Span::styled(state.to_string(), Style::default().fg(color)),
+                ]))
+            })
+            .collect();
+        self.selected = self.selected.min(items.len().saturating_sub(1));
+        let list = List::new(items)
+            .block(Block::default().title(" Forwards ").borders(Borders::TOP).border_style(Style::default().fg(Theme::BORDER)))
+            .highlight_style(Style::default().bg(Theme::BG_HIGHLIGHT));
+        let mut state = ListState::default().with_selected((!forwards.is_empty()).then_some(self.selected));
+        frame.render_stateful_widget(list, chunks[2], &mut state);
+
+        let key = |k: &'static str, color: Color| Span::styled(k, Style::default().fg(color).add_modifier(Modifier::BOLD));
+        let desc = |d: &'static str| Span::styled(d, Style::default().fg(Theme::FG_DARK));
+        let instructions = Line::from(vec![
+            key(" Enter ", Theme::GREEN),
+            desc("forward   "),
+            key(" Tab ", Theme::CYAN),
+            desc("field   "),
+            key(" ↑↓ ", Theme::CYAN),
+            desc("select   "),
+            key(" d ", Theme::YELLOW),
+            desc("stop   "),
+            key(" Esc ", Theme::RED),
+            desc("close"),
+        ]);
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[3]);
+    }
+}
@@ -0,0 +1,37 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use crate::forwards::PortForward;
+use crate::ui::{pad_width, Theme};
+
+/// Panel listing the port forwards started from the TUI
+pub struct ForwardsPanel;
+
+impl ForwardsPanel {
+    pub fn render(frame: &mut Frame, area: Rect, forwards: &[PortForward]) {
+        let block = Block::default()
+            .title(Span::styled(" Port Forwards ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::BORDER));
+
+        let items: Vec<ListItem> = forwards
+            .iter()
+            .map(|f| {
+                let (state, color) = match f.exited {
+                    Some(ref exited) => (exited.as_str(), Theme::RED),
+                    None => ("running", Theme::GREEN),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!(" 127.0.0.1:{:<6}", f.local_port), Style::default().fg(Theme::FG)),
+                    Span::styled(format!("→ {} {}", pad_width(&f.container, 20), pad_width(&f.target, 22)), Style::default().fg(Theme::FG_DARK)),
+                    Span::styled(format!("{:<6} ", f.via), Style::default().fg(Theme::FG_DARK)),
+                    Span::styled(state.to_string(), Style::default().fg(color)),
+                ]))
+            })
+            .collect();
+
+        frame.render_widget(List::new(items).block(block), area);
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::{BTreeMap, HashMap};
+
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::docker::gpu::GpuDevice;
+use crate::models::{ContainerInfo, GpuAccess};
+use crate::ui::{centered_modal, pad_width, status_color, status_icon, Theme};
+
+/// GPUs with the containers allowed to use each one (from their config)
+/// next to the memory they actually hold on it
+#[derive(Debug, Clone, Default)]
+pub struct GpuModal {
+    pub scroll: usize,
+}
+
+impl GpuModal {
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// Clamped to the content when rendered
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll += lines;
+    }
+
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        devices: &[GpuDevice],
+        containers: &[ContainerInfo],
+        usage: &HashMap<String, BTreeMap<u32, f64>>,
+    ) {
+        let modal_area = centered_modal(area, 80, 24);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(format!(" GPUs ({}) ", devices.len()))
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(inner);
+
+        let mut lines = Vec::new();
+        for device in devices {
+            let util = device.util_percent.map_or_else(|| "-".to_string(), |u| format!("{:.0}%", u));
+            lines.push(Line::from(vec![
+                Span::styled(format!(" GPU {} ", device.index), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(pad_width(&device.name, 32), Style::default().fg(Theme::LAVENDER)),
+                Span::styled(" util ", Style::default().fg(Theme::FG_DARK)),
+                Span::styled(format!("{:<5}", util), Style::default().fg(Theme::FG)),
+                Span::styled(" VRAM ", Style::default().fg(Theme::FG_DARK)),
+                Span::styled(
+                    format!("{}/{}", format_mb(device.vram_used_mb), format_mb(device.vram_total_mb)),
+                    Style::default().fg(Theme::FG),
+                ),
+            ]));
+
+            let members: Vec<&ContainerInfo> = containers
+                .iter()
+                .filter(|c| c.gpus.allows(device.index, &device.uuid) || using(usage, c, device.index).is_some())
+                .collect();
+            if members.is_empty() {
+                lines.push(Line::styled("     no containers", Style::default().fg(Theme::FG_DARK)));
+            }
+            for c in members {
+                lines.push(container_line(c, using(usage, c, device.index)));
+            }
+            lines.push(Line::raw(""));
+        }
+
+        // Without per-card data (Intel, or no GPU tool) list the access alone
+        if devices.is_empty() {
+            lines.push(Line::styled(
+                " Per-GPU details need nvidia-smi or an AMD card",
+                Style::default().fg(Theme::FG_DARK),
+            ));
+            lines.push(Line::raw(""));
+            for c in containers.iter().filter(|c| c.gpus != GpuAccess::None) {
+                lines.push(container_line(c, c.stats.as_ref().and_then(|s| s.vram_usage_mb)));
+            }
+        }
+
+        self.scroll = self.scroll.min(lines.len().saturating_sub(chunks[0].height as usize));
+        frame.render_widget(Paragraph::new(lines).scroll((self.scroll as u16, 0)), chunks[0]);
+
+        let instructions = Line::from(vec![
+            Span::styled(" ↑↓ ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("scroll   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" Esc ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
+            Span::styled("close", Style::default().fg(Theme::FG_DARK)),
+        ]);
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[1]);
+    }
+}
+
+/// Memory a container holds on one card
+fn using(usage: &HashMap<String, BTreeMap<u32, f64>>, container: &ContainerInfo, index: u32) -> Option<f64> {
+    usage.get(&container.id).and_then(|gpus| gpus.get(&index)).copied()
+}
+
+fn container_line(c: &ContainerInfo, using: Option<f64>) -> Line<'static> {
+    let access = match c.gpus {
+        GpuAccess::None => Span::styled(format!("{:<16}", "not in config"), Style::default().fg(Theme::ORANGE)),
+        ref gpus => Span::styled(format!("{:<16}", format!("allowed: {}", gpus.describe())), Style::default().fg(Theme::FG_DARK)),
+    };
+    let using = match using {
+        Some(mb) => Span::styled(format!("using {}", format_mb(mb)), Style::default().fg(Theme::GREEN)),
+        None => Span::styled("idle", Style::default().fg(Theme::FG_DARK)),
+    };
+    Line::from(vec![
+        Span::styled(format!("   {} ", status_icon(&c.status)), Style::default().fg(status_color(&c.status))),
+        Span::styled(format!("{} ", pad_width(&c.name, 24)), Style::default().fg(Theme::FG)),
+        access,
+        using,
+    ])
+}
+
+fn format_mb(mb: f64) -> String {
+    if mb >= 1024.0 {
+        format!("{:.1}G", mb / 1024.0)
+    } else {
+        format!("{:.0}M", mb)
+    }
+}
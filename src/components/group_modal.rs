@@ -0,0 +1,142 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::ui::{centered_modal, Theme};
+
+/// Assigns a container to user-defined groups
+#[derive(Debug, Clone)]
+pub struct GroupModal {
+    pub container_name: String,
+    /// Every group and whether the container is a member
+    pub groups: Vec<(String, bool)>,
+    pub state: ListState,
+    /// Name being typed for a new group
+    pub new_group: Option<String>,
+}
+
+impl GroupModal {
+    pub fn new(container_name: String, groups: Vec<(String, bool)>) -> Self {
+        let mut modal = Self {
+            container_name,
+            groups: Vec::new(),
+            state: ListState::default(),
+            new_group: None,
+        };
+        modal.set_groups(groups);
+        modal
+    }
+
+    /// Replace the list, keeping the selection in range
+    pub fn set_groups(&mut self, groups: Vec<(String, bool)>) {
+        self.groups = groups;
+        let selected = self.state.selected().unwrap_or(0).min(self.groups.len().saturating_sub(1));
+        self.state.select((!self.groups.is_empty()).then_some(selected));
+    }
+
+    pub fn next(&mut self) {
+        if !self.groups.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + 1) % self.groups.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.groups.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + self.groups.len() - 1) % self.groups.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn selected(&self) -> Option<&String> {
+        self.state.selected().and_then(|i| self.groups.get(i)).map(|(name, _)| name)
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        // Group names end up as TOML keys, keep them simple
+        if let Some(ref mut name) = self.new_group {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                name.push(c);
+            }
+        }
+    }
+
+    pub fn handle_backspace(&mut self) {
+        if let Some(ref mut name) = self.new_group {
+            name.pop();
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 50, 16);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(format!(" Groups: {} ", self.container_name))
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3), Constraint::Length(1)])
+            .split(inner);
+
+        if self.groups.is_empty() {
+            let msg = Paragraph::new("No groups yet, press n to create one")
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center);
+            frame.render_widget(msg, chunks[0]);
+        } else {
+            let items: Vec<ListItem> = self
+                .groups
+                .iter()
+                .map(|(name, member)| {
+                    let (check, color) = if *member { ("[x]", Theme::GREEN) } else { ("[ ]", Theme::FG_DARK) };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!(" {} ", check), Style::default().fg(color)),
+                        Span::styled(name.clone(), Style::default().fg(Theme::FG)),
+                    ]))
+                })
+                .collect();
+            let list = List::new(items).highlight_style(Style::default().bg(Theme::BG_HIGHLIGHT));
+            frame.render_stateful_widget(list, chunks[0], &mut self.state);
+        }
+
+        if let Some(ref name) = self.new_group {
+            let input = Paragraph::new(format!(" {}█", name))
+                .style(Style::default().fg(Theme::FG))
+                .block(
+                    Block::default()
+                        .title(" New group ")
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Theme::CYAN)),
+                );
+            frame.render_widget(input, chunks[1]);
+        }
+
+        // Instructions
+        let key = |k: &'static str, color: Color| Span::styled(k, Style::default().fg(color).add_modifier(Modifier::BOLD));
+        let desc = |d: &'static str| Span::styled(d, Style::default().fg(Theme::FG_DARK));
+        let instructions = if self.new_group.is_some() {
+            Line::from(vec![key(" Enter ", Theme::GREEN), desc("create & add   "), key(" Esc ", Theme::RED), desc("cancel")])
+        } else {
+            Line::from(vec![
+                key(" Space ", Theme::GREEN),
+                desc("add/remove   "),
+                key(" n ", Theme::CYAN),
+                desc("new group   "),
+                key(" Esc ", Theme::RED),
+                desc("close"),
+            ])
+        };
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[2]);
+    }
+}
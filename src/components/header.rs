@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use ratatui::{
     prelude::*,
     widgets::Paragraph,
@@ -10,23 +12,36 @@ use crate::ui::Theme;
 pub struct Header;
 
 impl Header {
-    pub fn render(frame: &mut Frame, area: Rect, stats: &SystemStats, vram: Option<f32>, loading: bool) {
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        stats: &SystemStats,
+        vram: Option<f32>,
+        loading: bool,
+        spinner: &str,
+        last_refresh: Duration,
+    ) {
         use crate::ui::layout::header_layout;
 
         let (title_area, stats_area) = header_layout(area);
 
-        // Title with optional loading indicator
-        let title_text = if loading {
-            " Backplane TUI  ⟳"
-        } else {
-            " Backplane TUI "
-        };
-        let title_style = if loading {
-            Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)
+        // Title with loading spinner or time since last refresh
+        let title_line = if loading {
+            Line::from(vec![
+                Span::styled(" Backplane TUI ", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)),
+                Span::styled(format!(" {} ", spinner), Style::default().fg(Theme::YELLOW)),
+                Span::styled("refreshing", Style::default().fg(Theme::FG_DARK)),
+            ])
         } else {
-            Style::default().fg(Theme::BLUE).add_modifier(Modifier::BOLD)
+            Line::from(vec![
+                Span::styled(" Backplane TUI ", Style::default().fg(Theme::BLUE).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    format!(" refreshed {} ago", format_elapsed(last_refresh)),
+                    Style::default().fg(Theme::FG_DARK),
+                ),
+            ])
         };
-        let title = Paragraph::new(title_text).style(title_style);
+        let title = Paragraph::new(title_line);
         frame.render_widget(title, title_area);
 
         // System stats with colors
@@ -55,11 +70,99 @@ impl Header {
             spans.push(Span::styled("VRAM ", Style::default().fg(Theme::FG_DARK)));
             spans.push(Span::styled(format!("{:>4.0}%", vram_percent), Style::default().fg(vram_color)));
         }
+        if let Some(util) = stats.gpu_util_percent {
+            spans.push(Span::styled(" │ ", Style::default().fg(Theme::BORDER)));
+            spans.push(Span::styled("GPU ", Style::default().fg(Theme::FG_DARK)));
+            spans.push(Span::styled(format!("{:>4.0}%", util), Style::default().fg(stat_color(util))));
+        }
 
         let stats_line = Line::from(spans);
         let stats_widget = Paragraph::new(stats_line).alignment(Alignment::Right);
         frame.render_widget(stats_widget, stats_area);
     }
+
+    /// Expanded header line: a heat strip with one bar per core, load
+    /// averages, CPU temperature and GPU temperature/power/clock
+    pub fn render_cores(frame: &mut Frame, area: Rect, stats: &SystemStats) {
+        let mut spans = vec![Span::styled(" CORES ", Style::default().fg(Theme::FG_DARK))];
+        spans.extend(
+            stats
+                .cpu_cores
+                .iter()
+                .map(|&usage| Span::styled(core_bar(usage).to_string(), Style::default().fg(stat_color(usage)))),
+        );
+
+        let [one, five, fifteen] = stats.load_average;
+        let load_color = if stats.cpu_count > 0 && one > stats.cpu_count as f64 { Theme::RED } else { Theme::FG };
+        spans.push(Span::styled(" │ ", Style::default().fg(Theme::BORDER)));
+        spans.push(Span::styled("LOAD ", Style::default().fg(Theme::FG_DARK)));
+        spans.push(Span::styled(format!("{:.2} {:.2} {:.2}", one, five, fifteen), Style::default().fg(load_color)));
+
+        if let Some(temp) = stats.temperature {
+            // The 40/60/80 usage thresholds read just as well in °C
+            let temp_color = stat_color(temp);
+            spans.push(Span::styled(" │ ", Style::default().fg(Theme::BORDER)));
+            spans.push(Span::styled("TEMP ", Style::default().fg(Theme::FG_DARK)));
+            spans.push(Span::styled(format!("{:.0}°C", temp), Style::default().fg(temp_color)));
+        }
+
+        let gpu = &stats.gpu_sensors;
+        if gpu.temperature.is_some() || gpu.power_watts.is_some() || gpu.clock_mhz.is_some() {
+            spans.push(Span::styled(" │ ", Style::default().fg(Theme::BORDER)));
+            spans.push(Span::styled("GPU", Style::default().fg(Theme::FG_DARK)));
+            if let Some(temp) = gpu.temperature {
+                spans.push(Span::styled(format!(" {:.0}°C", temp), Style::default().fg(gpu_temp_color(temp))));
+            }
+            if let Some(watts) = gpu.power_watts {
+                // Against the power cap when the driver reports one
+                let color = match gpu.power_limit_watts {
+                    Some(limit) if limit > 0.0 => stat_color(watts / limit * 100.0),
+                    _ => Theme::FG,
+                };
+                spans.push(Span::styled(format!(" {:.0}W", watts), Style::default().fg(color)));
+                if let Some(limit) = gpu.power_limit_watts {
+                    spans.push(Span::styled(format!("/{:.0}W", limit), Style::default().fg(Theme::FG_DARK)));
+                }
+            }
+            if let Some(mhz) = gpu.clock_mhz {
+                spans.push(Span::styled(format!(" {:.0}MHz", mhz), Style::default().fg(Theme::FG)));
+            }
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
+
+/// GPUs run hotter than CPUs before it matters; most throttle in the 80s
+fn gpu_temp_color(celsius: f32) -> Color {
+    if celsius >= 85.0 {
+        Theme::RED
+    } else if celsius >= 75.0 {
+        Theme::ORANGE
+    } else if celsius >= 65.0 {
+        Theme::YELLOW
+    } else {
+        Theme::GREEN
+    }
+}
+
+/// Eighth-block bar for a usage percentage
+fn core_bar(percent: f32) -> char {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let index = (percent.clamp(0.0, 100.0) / 100.0 * 7.0).round() as usize;
+    BARS[index]
+}
+
+/// Format a short elapsed time ("3s", "2m")
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h", secs / 3600)
+    }
 }
 
 /// Get color based on usage percentage
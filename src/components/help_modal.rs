@@ -3,100 +3,117 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
+use std::time::Duration;
+
+use crate::keymap::{self, KeyContext};
 use crate::ui::{centered_modal, Theme};
 
-/// Help modal component
-pub struct HelpModal;
+/// Help modal listing key bindings from the keymap
+#[derive(Debug, Clone, Default)]
+pub struct HelpModal {
+    pub scroll: u16,
+    /// Show every context instead of just the current view's bindings
+    pub show_all: bool,
+}
 
 impl HelpModal {
-    pub fn render(frame: &mut Frame, area: Rect) {
-        let modal_area = centered_modal(area, 60, 24);
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn scroll_down(&mut self, lines: u16) {
+        self.scroll = self.scroll.saturating_add(lines);
+    }
+
+    pub fn scroll_up(&mut self, lines: u16) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    pub fn toggle_scope(&mut self) {
+        self.show_all = !self.show_all;
+        self.scroll = 0;
+    }
+
+    /// Build help lines: the current view's bindings plus global ones, or every
+    /// context, followed by the effective refresh intervals. In read-only mode
+    /// mutating bindings are grayed out
+    fn lines(&self, context: KeyContext, intervals: &[(&'static str, Duration)], read_only: bool) -> Vec<Line<'static>> {
+        let contexts: Vec<KeyContext> = if self.show_all {
+            KeyContext::ALL.to_vec()
+        } else {
+            vec![context, KeyContext::Global]
+        };
+
+        let mut lines = Vec::new();
+        for ctx in contexts {
+            if !lines.is_empty() {
+                lines.push(Line::raw(""));
+            }
+            lines.push(Line::styled(ctx.title(), Style::default().bold().fg(Color::Cyan)));
+            for binding in keymap::bindings_for(ctx) {
+                if read_only && binding.command.is_mutating() {
+                    lines.push(Line::styled(
+                        format!("  {:<9}{} (read-only)", binding.label(), binding.desc),
+                        Style::default().fg(Theme::FG_DARK),
+                    ));
+                    continue;
+                }
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {:<9}", binding.label()), Style::default().fg(Color::Yellow)),
+                    Span::raw(binding.desc),
+                ]));
+            }
+        }
+
+        lines.push(Line::raw(""));
+        lines.push(Line::styled("Refresh Intervals", Style::default().bold().fg(Color::Cyan)));
+        for (source, interval) in intervals {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<11}", source), Style::default().fg(Color::Yellow)),
+                Span::raw(format!("{:.2}s", interval.as_secs_f64())),
+            ]));
+        }
+        lines
+    }
+
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        context: KeyContext,
+        intervals: &[(&'static str, Duration)],
+        read_only: bool,
+    ) {
+        let lines = self.lines(context, intervals, read_only);
+
+        // Fit the frame; the content scrolls when it doesn't
+        let height = (lines.len() as u16 + 2).min(area.height.saturating_sub(2)).max(5);
+        let modal_area = centered_modal(area, 60, height);
+        let visible = modal_area.height.saturating_sub(2);
+        let max_scroll = (lines.len() as u16).saturating_sub(visible);
+        self.scroll = self.scroll.min(max_scroll);
 
         // Clear the background
         frame.render_widget(Clear, modal_area);
 
-        let help_text = vec![
-            Line::styled("Keyboard Shortcuts", Style::default().bold().fg(Color::Cyan)),
-            Line::raw(""),
-            Line::from(vec![
-                Span::styled("  j/↓    ", Style::default().fg(Color::Yellow)),
-                Span::raw("Move down"),
-            ]),
-            Line::from(vec![
-                Span::styled("  k/↑    ", Style::default().fg(Color::Yellow)),
-                Span::raw("Move up"),
-            ]),
-            Line::from(vec![
-                Span::styled("  g      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Go to top"),
-            ]),
-            Line::from(vec![
-                Span::styled("  G      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Go to bottom"),
-            ]),
-            Line::from(vec![
-                Span::styled("  ←/→    ", Style::default().fg(Color::Yellow)),
-                Span::raw("Switch view (Stats/Network/Details)"),
-            ]),
-            Line::from(vec![
-                Span::styled("  f      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Filter (All/Groups/Running/Stopped)"),
-            ]),
-            Line::from(vec![
-                Span::styled("  /      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Filter by name"),
-            ]),
-            Line::from(vec![
-                Span::styled("  Enter/l", Style::default().fg(Color::Yellow)),
-                Span::raw("View logs"),
-            ]),
-            Line::from(vec![
-                Span::styled("  e      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Exec shell into container"),
-            ]),
-            Line::from(vec![
-                Span::styled("  n      ", Style::default().fg(Color::Yellow)),
-                Span::raw("New container"),
-            ]),
-            Line::from(vec![
-                Span::styled("  s      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Start container"),
-            ]),
-            Line::from(vec![
-                Span::styled("  x      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Stop container"),
-            ]),
-            Line::from(vec![
-                Span::styled("  R      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Restart container"),
-            ]),
-            Line::from(vec![
-                Span::styled("  d      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Delete container"),
-            ]),
-            Line::from(vec![
-                Span::styled("  r      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Refresh list"),
-            ]),
-            Line::from(vec![
-                Span::styled("  Esc    ", Style::default().fg(Color::Yellow)),
-                Span::raw("Back / Close modal"),
-            ]),
-            Line::from(vec![
-                Span::styled("  q      ", Style::default().fg(Color::Yellow)),
-                Span::raw("Quit"),
-            ]),
-            Line::raw(""),
-            Line::styled("Press Esc to close", Style::default().fg(Color::DarkGray)),
-        ];
-
+        let scope = if self.show_all { "all views" } else { "this view" };
+        let position = if max_scroll > 0 {
+            format!(" {}/{} ", self.scroll + 1, max_scroll + 1)
+        } else {
+            String::new()
+        };
         let block = Block::default()
-            .title(" Help ")
+            .title(format!(" Help ({}) ", scope))
+            .title_bottom(Line::styled(
+                " ↑↓ scroll  Tab all/this view  Esc close ",
+                Style::default().fg(Color::DarkGray),
+            ))
+            .title_bottom(Line::styled(position, Style::default().fg(Theme::FG_DARK)).right_aligned())
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Theme::MODAL_BORDER))
             .style(Style::default().bg(Theme::MODAL_BG));
 
-        let paragraph = Paragraph::new(help_text).block(block);
+        let paragraph = Paragraph::new(lines).block(block).scroll((self.scroll, 0));
 
         frame.render_widget(paragraph, modal_area);
     }
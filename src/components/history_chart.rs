@@ -0,0 +1,143 @@
+use ratatui::{
+    prelude::*,
+    symbols::Marker,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, LegendPosition, Paragraph},
+};
+
+use crate::history::{Sample, TimeRange};
+use crate::ui::{border_style, Theme};
+
+/// Full-screen metrics charts for one container: CPU/memory and network rates
+pub struct HistoryChart;
+
+impl HistoryChart {
+    pub fn render(frame: &mut Frame, area: Rect, container_name: &str, samples: &[Sample], range: TimeRange) {
+        let title = Line::from(vec![
+            Span::styled(format!(" History: {} ", container_name), Style::default().fg(Theme::LAVENDER).add_modifier(Modifier::BOLD)),
+            Span::styled("│ ", Style::default().fg(Theme::BORDER)),
+            range_tab(TimeRange::FiveMinutes, range),
+            range_tab(TimeRange::OneHour, range),
+            range_tab(TimeRange::SixHours, range),
+            range_tab(TimeRange::Day, range),
+        ]);
+
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(border_style(true));
+
+        if samples.len() < 2 {
+            let text = Paragraph::new("Not enough samples yet for this range")
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(text, area);
+            return;
+        }
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        // Usage chart on top, network chart below
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(inner);
+
+        let now = chrono::Utc::now().timestamp();
+        let x_axis = || {
+            Axis::default()
+                .style(Style::default().fg(Theme::FG_DARK))
+                .bounds([-(range.secs() as f64), 0.0])
+                .labels([format_offset(range.secs()), format_offset(range.secs() / 2), "now".to_string()])
+        };
+        let points = |value: fn(&Sample) -> f64| -> Vec<(f64, f64)> {
+            samples.iter().map(|s| ((s.t - now) as f64, value(s))).collect()
+        };
+
+        // CPU / memory overlay (CPU can exceed 100% on multi-core hosts)
+        let cpu = points(|s| s.cpu);
+        let mem = points(|s| s.mem);
+        let usage_max = samples.iter().map(|s| s.cpu.max(s.mem)).fold(100.0_f64, f64::max).ceil();
+
+        let usage_chart = Chart::new(vec![
+            line_dataset("CPU %", &cpu, Theme::CYAN),
+            line_dataset("MEM %", &mem, Theme::MAGENTA),
+        ])
+        .block(Block::default().title(Span::styled(" Usage ", Style::default().fg(Theme::FG_DARK))))
+        .x_axis(x_axis())
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Theme::FG_DARK))
+                .bounds([0.0, usage_max])
+                .labels(["0%".to_string(), format!("{:.0}%", usage_max / 2.0), format!("{:.0}%", usage_max)]),
+        )
+        .legend_position(Some(LegendPosition::TopLeft));
+        frame.render_widget(usage_chart, chunks[0]);
+
+        // Network RX / TX rate overlay
+        let rx = points(|s| s.rx);
+        let tx = points(|s| s.tx);
+        let net_max = samples.iter().map(|s| s.rx.max(s.tx)).fold(1024.0_f64, f64::max);
+
+        let net_chart = Chart::new(vec![
+            line_dataset("↓ RX", &rx, Theme::GREEN),
+            line_dataset("↑ TX", &tx, Theme::PEACH),
+        ])
+        .block(Block::default().title(Span::styled(" Network ", Style::default().fg(Theme::FG_DARK))))
+        .x_axis(x_axis())
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Theme::FG_DARK))
+                .bounds([0.0, net_max])
+                .labels(["0".to_string(), format_rate(net_max / 2.0), format_rate(net_max)]),
+        )
+        .legend_position(Some(LegendPosition::TopLeft));
+        frame.render_widget(net_chart, chunks[1]);
+    }
+}
+
+/// Braille line dataset with a legend name
+fn line_dataset<'a>(name: &'static str, data: &'a [(f64, f64)], color: Color) -> Dataset<'a> {
+    Dataset::default()
+        .name(name)
+        .marker(Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(color))
+        .data(data)
+}
+
+/// Format a rate (bytes per second) for axis labels
+pub fn format_rate(rate: f64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+
+    if rate >= MB {
+        format!("{:.1}MB/s", rate / MB)
+    } else if rate >= KB {
+        format!("{:.1}KB/s", rate / KB)
+    } else {
+        format!("{:.0}B/s", rate)
+    }
+}
+
+/// Styled tab for a time range (active one highlighted)
+pub fn range_tab(tab: TimeRange, active: TimeRange) -> Span<'static> {
+    let style = if tab == active {
+        Style::default().fg(Theme::BG_DARK).bg(Theme::MAUVE).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Theme::FG_DARK)
+    };
+    Span::styled(format!(" {} ", tab.as_str()), style)
+}
+
+/// Format a past offset as "-5m", "-2h"
+fn format_offset(secs: i64) -> String {
+    if secs >= 3600 {
+        format!("-{}h", secs / 3600)
+    } else if secs >= 60 {
+        format!("-{}m", secs / 60)
+    } else {
+        format!("-{}s", secs)
+    }
+}
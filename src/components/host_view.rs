@@ -0,0 +1,165 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::models::{group_by_container, HostProcess, ProcessSort};
+use crate::ui::{border_style, selected_style, Theme};
+
+/// Host processes (top by CPU or memory) with their owning container, or
+/// grouped by container with per-container totals
+#[derive(Debug, Default)]
+pub struct HostView {
+    pub state: TableState,
+    pub sort: ProcessSort,
+    pub grouped: bool,
+    /// Only a baseline sample so far; CPU% needs a second one
+    pub cpu_pending: bool,
+}
+
+impl HostView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.state.selected()
+    }
+
+    pub fn next(&mut self, count: usize) {
+        if count > 0 {
+            let i = self.state.selected().map_or(0, |i| (i + 1).min(count - 1));
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn previous(&mut self, count: usize) {
+        if count > 0 {
+            let i = self.state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.state.select(Some(i));
+        }
+    }
+
+    /// Keep the selection valid after the list changes
+    pub fn clamp_selection(&mut self, count: usize) {
+        let selected = if count == 0 {
+            None
+        } else {
+            Some(self.state.selected().unwrap_or(0).min(count - 1))
+        };
+        self.state.select(selected);
+    }
+
+    /// Rows shown for these processes: one per process, plus a header per
+    /// container when grouped
+    pub fn row_count(&self, processes: &[HostProcess]) -> usize {
+        if self.grouped {
+            let groups = group_by_container(processes, self.sort);
+            groups.len() + groups.iter().map(|g| g.processes.len()).sum::<usize>()
+        } else {
+            processes.len()
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, processes: &[HostProcess]) {
+        let groups = if self.grouped { group_by_container(processes, self.sort) } else { Vec::new() };
+        let title = if self.grouped {
+            let count = groups.iter().map(|g| g.processes.len()).sum::<usize>();
+            format!(" Container Processes ({} containers, {} processes) ", groups.len(), count)
+        } else {
+            let in_containers = processes.iter().filter(|p| p.container.is_some()).count();
+            format!(" Host Processes ({}, {} in containers) ", processes.len(), in_containers)
+        };
+        let block = Block::default()
+            .title(Span::styled(title, Style::default().fg(Theme::LAVENDER).add_modifier(Modifier::BOLD)))
+            .title_bottom(Line::styled(
+                format!(" sorted by {} (s to switch, g to group by container) ", self.sort.as_str()),
+                Style::default().fg(Theme::FG_DARK),
+            ))
+            .borders(Borders::ALL)
+            .border_style(border_style(true));
+
+        if processes.is_empty() || (self.grouped && groups.is_empty()) {
+            let text = Paragraph::new(if self.grouped { "No processes in containers" } else { "No processes" })
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(text, area);
+            return;
+        }
+
+        let sorted_header = |label: &'static str, sort: ProcessSort| {
+            let style = if self.sort == sort {
+                Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            Cell::from(label).style(style)
+        };
+        let header_row = Row::new(vec![
+            Cell::from("PID"),
+            sorted_header("CPU%", ProcessSort::Cpu),
+            sorted_header("MEM", ProcessSort::Memory),
+            Cell::from("CONTAINER"),
+            Cell::from("COMMAND"),
+        ])
+        .style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD));
+
+        let cpu = |percent: f32| if self.cpu_pending { "...".to_string() } else { format!("{:.1}", percent) };
+        let process_row = |process: &HostProcess| {
+            let command = if process.command.is_empty() { &process.name } else { &process.command };
+            Row::new(vec![
+                Cell::from(process.pid.to_string()).style(Style::default().fg(Theme::FG_DARK)),
+                Cell::from(cpu(process.cpu_percent)).style(Style::default().fg(Theme::FG)),
+                Cell::from(format_memory(process.memory_mb)).style(Style::default().fg(Theme::FG)),
+                Cell::from(process.container.clone().unwrap_or_else(|| "-".to_string()))
+                    .style(Style::default().fg(if process.container.is_some() { Theme::CYAN } else { Theme::FG_DARK })),
+                Cell::from(command.clone()).style(Style::default().fg(Theme::FG)),
+            ])
+        };
+        let rows: Vec<Row> = if self.grouped {
+            let total = Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD);
+            groups
+                .iter()
+                .flat_map(|group| {
+                    let header = Row::new(vec![
+                        Cell::from(""),
+                        Cell::from(cpu(group.cpu_percent)).style(total),
+                        Cell::from(format_memory(group.memory_mb)).style(total),
+                        Cell::from(group.container).style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+                        Cell::from(format!("{} processes", group.processes.len())).style(Style::default().fg(Theme::FG_DARK)),
+                    ]);
+                    std::iter::once(header).chain(group.processes.iter().map(|p| process_row(p)))
+                })
+                .collect()
+        } else {
+            processes.iter().map(process_row).collect()
+        };
+
+        let widths = [
+            Constraint::Length(8),      // PID
+            Constraint::Length(6),      // CPU%
+            Constraint::Length(8),      // MEM
+            Constraint::Percentage(20), // CONTAINER
+            Constraint::Min(20),        // COMMAND
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header_row)
+            .block(block)
+            .column_spacing(1)
+            .row_highlight_style(selected_style())
+            .highlight_symbol("▶");
+
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+}
+
+/// "512M", "1.5G"
+fn format_memory(mb: f64) -> String {
+    if mb >= 1024.0 {
+        format!("{:.1}G", mb / 1024.0)
+    } else {
+        format!("{:.0}M", mb)
+    }
+}
@@ -0,0 +1,148 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+};
+
+use crate::models::image::{clean_created_by, cumulative_sizes};
+use crate::models::ImageLayer;
+use crate::ui::{centered_modal, format_bytes, Theme};
+
+/// Visible table rows
+const PAGE: usize = 17;
+
+/// Layer inspector for an image (docker history)
+#[derive(Debug, Clone)]
+pub struct ImageHistoryModal {
+    pub image: String,
+    pub layers: Vec<ImageLayer>,
+    /// Size of each layer plus all layers below it
+    cumulative: Vec<u64>,
+    pub scroll: usize,
+}
+
+impl ImageHistoryModal {
+    pub fn new(image: String, layers: Vec<ImageLayer>) -> Self {
+        let cumulative = cumulative_sizes(&layers);
+        Self {
+            image,
+            layers,
+            cumulative,
+            scroll: 0,
+        }
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        let max_scroll = self.layers.len().saturating_sub(PAGE);
+        self.scroll = (self.scroll + lines).min(max_scroll);
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = self.layers.len().saturating_sub(PAGE);
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 100, 22);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let total = self.cumulative.first().copied().unwrap_or(0);
+        let block = Block::default()
+            .title(format!(" Image history: {} ({}) ", self.image, format_bytes(total)))
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        if self.layers.is_empty() {
+            let msg = Paragraph::new("No history available for this image")
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center);
+            frame.render_widget(msg, inner);
+            return;
+        }
+
+        // Split for table and instructions
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(2)])
+            .split(inner);
+
+        let header_row = Row::new(vec!["CREATED", "SIZE", "CUMULATIVE", "CREATED BY"])
+            .style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = self.layers
+            .iter()
+            .zip(&self.cumulative)
+            .skip(self.scroll)
+            .take(PAGE)
+            .map(|(layer, cumulative)| {
+                Row::new(vec![
+                    Cell::from(format_age(layer.created)).style(Style::default().fg(Theme::FG_DARK)),
+                    Cell::from(format_bytes(layer.size)).style(Style::default().fg(size_color(layer.size))),
+                    Cell::from(format_bytes(*cumulative)).style(Style::default().fg(Theme::FG_DARK)),
+                    Cell::from(clean_created_by(&layer.created_by).to_string()).style(Style::default().fg(Theme::FG)),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(10), // CREATED
+            Constraint::Length(9),  // SIZE
+            Constraint::Length(11), // CUMULATIVE
+            Constraint::Min(20),    // CREATED BY (flexible)
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header_row)
+            .column_spacing(1);
+
+        frame.render_widget(table, chunks[0]);
+
+        // Instructions
+        let instructions = Line::from(vec![
+            Span::styled(" ↑↓ ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("scroll   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" Esc/I ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
+            Span::styled("close   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(format!(" [{}/{} layers] ", self.scroll + 1, self.layers.len()), Style::default().fg(Theme::FG_DARK)),
+        ]);
+        let instructions_widget = Paragraph::new(instructions).alignment(Alignment::Center);
+        frame.render_widget(instructions_widget, chunks[1]);
+    }
+}
+
+/// Highlight bloated layers
+fn size_color(size: u64) -> Color {
+    const MB: u64 = 1024 * 1024;
+    if size >= 100 * MB {
+        Theme::RED
+    } else if size >= 10 * MB {
+        Theme::YELLOW
+    } else {
+        Theme::FG
+    }
+}
+
+/// Rough age of a unix timestamp ("3 days", "5 months")
+fn format_age(ts: i64) -> String {
+    let secs = chrono::Utc::now().timestamp() - ts;
+    if ts <= 0 || secs < 0 {
+        return "-".to_string();
+    }
+    let (value, unit) = match secs {
+        s if s < 3600 => (s / 60, "min"),
+        s if s < 86400 => (s / 3600, "hour"),
+        s if s < 30 * 86400 => (s / 86400, "day"),
+        s if s < 365 * 86400 => (s / (30 * 86400), "month"),
+        s => (s / (365 * 86400), "year"),
+    };
+    format!("{} {}{}", value, unit, if value == 1 { "" } else { "s" })
+}
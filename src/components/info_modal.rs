@@ -1,10 +1,12 @@
+use std::collections::BTreeMap;
+
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
-use crate::models::ContainerInfo;
-use crate::ui::{centered_modal, status_color, status_icon, Theme};
+use crate::models::{ContainerInfo, GpuAccess, RuntimeInfo};
+use crate::ui::{centered_modal, fit_width, status_color, status_icon, truncate_width, Theme};
 use crate::components::sparkline::StatsHistory;
 
 /// Network/Info modal component
@@ -15,13 +17,19 @@ impl InfoModal {
         frame: &mut Frame,
         area: Rect,
         container: Option<&ContainerInfo>,
+        runtime: Option<&RuntimeInfo>,
+        gpu_usage: Option<&BTreeMap<u32, f64>>,
         stats_history: &StatsHistory,
     ) {
+        let gpu_usage = gpu_usage.filter(|usage| !usage.is_empty());
         // Dynamic height based on content
         let modal_height = match container {
             Some(c) => {
                 let port_lines = if c.ports.is_empty() { 1 } else { c.ports.len().min(4) };
-                22 + port_lines as u16
+                let exit_lines = if c.exit_code.is_some() { 1 } else { 0 };
+                let gpu_lines = if c.gpus != GpuAccess::None || gpu_usage.is_some() { 1 } else { 0 };
+                let network_lines = c.networks.len().clamp(1, 4) + 2;
+                31 + port_lines as u16 + exit_lines + gpu_lines + network_lines as u16
             }
             None => 8,
         };
@@ -45,8 +53,8 @@ impl InfoModal {
                 let sparkline_width = 28;
 
                 // Get sparkline data
-                let cpu_history = stats_history.get_cpu(&c.name);
-                let mem_history = stats_history.get_mem(&c.name);
+                let cpu_history = stats_history.get_cpu(&c.id);
+                let mem_history = stats_history.get_mem(&c.id);
                 let cpu_spark = StatsHistory::to_sparkline(&cpu_history, sparkline_width);
                 let mem_spark = StatsHistory::to_sparkline(&mem_history, sparkline_width);
 
@@ -62,6 +70,52 @@ impl InfoModal {
                     ("-".to_string(), "-".to_string(), "-".to_string(), "-".to_string())
                 };
 
+                let (read_str, write_str, read_rate, write_rate) = match c.stats {
+                    Some(ref stats) => (
+                        format_bytes(stats.blk_read_bytes),
+                        format_bytes(stats.blk_write_bytes),
+                        format_rate(stats.blk_read_rate),
+                        format_rate(stats.blk_write_rate),
+                    ),
+                    None => ("-".to_string(), "-".to_string(), "-".to_string(), "-".to_string()),
+                };
+
+                // Working set above; what it's made of, plus reclaimable cache
+                let memory_line = match c.stats.as_ref().map(|s| (s.memory_rss_mb, s.memory_cache_mb)) {
+                    Some((Some(rss), Some(cache))) => Line::from(vec![
+                        Span::styled("          RSS ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(format!("{:.0}MB", rss), Style::default().fg(Theme::MAGENTA)),
+                        Span::styled("  │  Cache ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(format!("{:.0}MB", cache), Style::default().fg(Theme::OVERLAY)),
+                        Span::styled("  (inactive cache not counted)", Style::default().fg(Theme::FG_DARK)),
+                    ]),
+                    _ => Line::styled("          RSS/cache breakdown unavailable", Style::default().fg(Theme::FG_DARK)),
+                };
+
+                // PIDs against the pids limit; near the limit, forks start failing
+                let pids_line = match c.stats.as_ref().and_then(|s| s.pids.map(|pids| (pids, s.pids_limit, s.pids_percent()))) {
+                    Some((pids, Some(limit), Some(pct))) => {
+                        let mut spans = vec![
+                            Span::styled("  PIDs:   ", Style::default().fg(Theme::FG_DARK)),
+                            Span::styled(format!("{} / {}", pids, limit), Style::default().fg(percent_color(pct))),
+                            Span::styled(format!(" ({:.0}%)", pct), Style::default().fg(percent_color(pct))),
+                        ];
+                        if pct >= 80.0 {
+                            spans.push(Span::styled("  near the pids limit", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)));
+                        }
+                        Line::from(spans)
+                    }
+                    Some((pids, _, _)) => Line::from(vec![
+                        Span::styled("  PIDs:   ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(pids.to_string(), Style::default().fg(Theme::FG)),
+                        Span::styled(" (no limit)", Style::default().fg(Theme::FG_DARK)),
+                    ]),
+                    None => Line::from(vec![
+                        Span::styled("  PIDs:   ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled("-", Style::default().fg(Theme::FG_DARK)),
+                    ]),
+                };
+
                 let cpu_pct = c.stats.as_ref().map(|s| s.cpu_percent).unwrap_or(0.0);
                 let mem_pct = c.stats.as_ref().map(|s| s.memory_percent).unwrap_or(0.0);
                 let mem_mb = c.stats.as_ref().map(|s| s.memory_usage_mb).unwrap_or(0.0);
@@ -69,8 +123,9 @@ impl InfoModal {
                 // Container ID (short)
                 let short_id = if c.id.len() >= 12 { &c.id[..12] } else { &c.id };
 
-                // Uptime
-                let uptime = format_uptime(c.created);
+                // Uptime of the current run (StartedAt, not Created)
+                let uptime = if c.status.is_active() { format_uptime(c.started_at) } else { "-".to_string() };
+                let created = c.created.and_then(format_timestamp).unwrap_or_else(|| "-".to_string());
 
                 // Type
                 let type_str = if c.is_cli { "CLI" } else { "Web" };
@@ -92,6 +147,8 @@ impl InfoModal {
                     Line::from(vec![
                         Span::styled("  ID:      ", Style::default().fg(Theme::FG_DARK)),
                         Span::styled(short_id, Style::default().fg(Theme::OVERLAY)),
+                        Span::styled("  │  Created: ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(created, Style::default().fg(Theme::OVERLAY)),
                     ]),
                     Line::from(vec![
                         Span::styled("  Status:  ", Style::default().fg(Theme::FG_DARK)),
@@ -99,10 +156,69 @@ impl InfoModal {
                         Span::styled("  │  Uptime: ", Style::default().fg(Theme::FG_DARK)),
                         Span::styled(&uptime, Style::default().fg(Theme::SKY)),
                     ]),
+                ];
+
+                // Clock, locale, OS and main process (OS and zone need it running)
+                let unknown = || if c.status.is_running() { "-".to_string() } else { "- (not running)".to_string() };
+                let runtime = runtime.cloned().unwrap_or_default();
+                let width = inner.width.saturating_sub(11) as usize;
+                let command = truncate_width(&runtime.command, width);
+                lines.extend([
+                    Line::from(vec![
+                        Span::styled("  OS:      ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(runtime.distro.unwrap_or_else(unknown), Style::default().fg(Theme::FG)),
+                    ]),
+                    Line::from(vec![
+                        Span::styled("  TZ:      ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(runtime.timezone.unwrap_or_else(unknown), Style::default().fg(Theme::SKY)),
+                        Span::styled("  │  Locale: ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(runtime.locale.unwrap_or_else(|| "-".to_string()), Style::default().fg(Theme::OVERLAY)),
+                    ]),
+                    Line::from(vec![
+                        Span::styled("  Command: ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(command, Style::default().fg(Theme::PEACH)),
+                    ]),
+                ]);
+
+                // GPUs it may use (config) and holds memory on (nvidia-smi)
+                if c.gpus != GpuAccess::None || gpu_usage.is_some() {
+                    let (allowed, allowed_color) = match c.gpus {
+                        GpuAccess::None => ("not in config".to_string(), Theme::ORANGE),
+                        ref gpus => (gpus.describe(), Theme::FG),
+                    };
+                    let using = gpu_usage
+                        .map(|usage| usage.iter().map(|(index, mb)| format!("{} ({:.0}MB)", index, mb)).collect::<Vec<_>>().join(", "))
+                        .unwrap_or_else(|| "-".to_string());
+                    lines.push(Line::from(vec![
+                        Span::styled("  GPUs:    ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(allowed, Style::default().fg(allowed_color)),
+                        Span::styled("  │  Using: ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(using, Style::default().fg(Theme::GREEN)),
+                    ]));
+                }
+
+                // Exit details for stopped containers
+                if let Some(code) = c.exit_code {
+                    let exit_color = if code != 0 || c.oom_killed { Theme::RED } else { Theme::GREEN };
+                    let mut exit_line = vec![
+                        Span::styled("  Exit:    ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(code.to_string(), Style::default().fg(exit_color).add_modifier(Modifier::BOLD)),
+                    ];
+                    if c.oom_killed {
+                        exit_line.push(Span::styled(" (OOM killed)", Style::default().fg(Theme::RED)));
+                    }
+                    if let Some(finished) = c.finished_at.and_then(format_timestamp) {
+                        exit_line.push(Span::styled("  │  Finished: ", Style::default().fg(Theme::FG_DARK)));
+                        exit_line.push(Span::styled(finished, Style::default().fg(Theme::SKY)));
+                    }
+                    lines.push(Line::from(exit_line));
+                }
+
+                lines.extend(vec![
                     Line::raw(""),
                     // Ports section
                     Line::styled("── Ports ──", Style::default().fg(Theme::OVERLAY)),
-                ];
+                ]);
 
                 // Add port lines
                 if c.ports.is_empty() {
@@ -138,6 +254,26 @@ impl InfoModal {
                     }
                 }
 
+                lines.extend(vec![
+                    Line::raw(""),
+                    Line::styled("── Networks ──", Style::default().fg(Theme::OVERLAY)),
+                ]);
+                if c.networks.is_empty() {
+                    lines.push(Line::styled("  No networks", Style::default().fg(Theme::FG_DARK)));
+                }
+                for network in c.networks.iter().take(4) {
+                    let ip = if network.ip.is_empty() { "-" } else { &network.ip };
+                    let mut spans = vec![
+                        Span::styled(format!("  {}", fit_width(&network.network, 16)), Style::default().fg(Theme::TEAL)),
+                        Span::styled(format!("{:<16}", ip), Style::default().fg(Theme::LAVENDER)),
+                    ];
+                    if !network.aliases.is_empty() {
+                        spans.push(Span::styled(" aliases: ", Style::default().fg(Theme::FG_DARK)));
+                        spans.push(Span::styled(network.aliases.join(", "), Style::default().fg(Theme::FG)));
+                    }
+                    lines.push(Line::from(spans));
+                }
+
                 lines.extend(vec![
                     Line::raw(""),
                     // Resource usage section
@@ -152,6 +288,8 @@ impl InfoModal {
                         Span::styled(&mem_spark, Style::default().fg(Theme::MAGENTA)),
                         Span::styled(format!(" {:>5.1}% ({:.0}MB)", mem_pct, mem_mb), Style::default().fg(percent_color(mem_pct))),
                     ]),
+                    memory_line,
+                    pids_line,
                     Line::raw(""),
                     // Network I/O section
                     Line::styled("── Network I/O ──", Style::default().fg(Theme::OVERLAY)),
@@ -168,6 +306,18 @@ impl InfoModal {
                         Span::styled(format!("({}/s)", tx_rate), Style::default().fg(Theme::PEACH)),
                     ]),
                     Line::raw(""),
+                    Line::styled("── Disk I/O ──", Style::default().fg(Theme::OVERLAY)),
+                    Line::from(vec![
+                        Span::styled("  Read:  ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(format!("{:<10}", read_str), Style::default().fg(Theme::FG)),
+                        Span::styled(format!("({}/s)", read_rate), Style::default().fg(Theme::SKY)),
+                    ]),
+                    Line::from(vec![
+                        Span::styled("  Write: ", Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(format!("{:<10}", write_str), Style::default().fg(Theme::FG)),
+                        Span::styled(format!("({}/s)", write_rate), Style::default().fg(Theme::YELLOW)),
+                    ]),
+                    Line::raw(""),
                     Line::styled("                    Press Esc or i to close", Style::default().fg(Theme::FG_DARK)),
                 ]);
 
@@ -227,9 +377,9 @@ fn percent_color(percent: f64) -> Color {
     }
 }
 
-/// Format uptime from created timestamp
-fn format_uptime(created: Option<i64>) -> String {
-    match created {
+/// Format elapsed time since a unix timestamp (e.g. start time)
+fn format_uptime(since: Option<i64>) -> String {
+    match since {
         Some(ts) => {
             let now = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
@@ -257,3 +407,9 @@ fn format_uptime(created: Option<i64>) -> String {
         None => "-".to_string(),
     }
 }
+
+/// Format a unix timestamp in local time
+fn format_timestamp(ts: i64) -> Option<String> {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|dt| dt.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+}
@@ -0,0 +1,148 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::models::{LabelChange, LabelEdit};
+use crate::ui::{centered_modal, Theme};
+
+/// Edits labels of one or more containers: type the edits, review the dry
+/// run, then recreate the containers that change
+#[derive(Debug, Clone)]
+pub struct LabelModal {
+    pub targets: Vec<String>,
+    pub input: String,
+    pub error: Option<String>,
+    /// Parsed edits, once checked
+    pub edits: Vec<LabelEdit>,
+    /// Dry run per container, shown for review before applying
+    pub review: Option<Vec<(String, Vec<LabelChange>)>>,
+    pub scroll: usize,
+}
+
+impl LabelModal {
+    pub fn new(targets: Vec<String>) -> Self {
+        Self {
+            targets,
+            input: String::new(),
+            error: None,
+            edits: Vec::new(),
+            review: None,
+            scroll: 0,
+        }
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        self.input.push(c);
+        self.error = None;
+    }
+
+    pub fn handle_backspace(&mut self) {
+        self.input.pop();
+        self.error = None;
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll += 1;
+    }
+
+    /// Containers the reviewed edits would change
+    pub fn changed(&self) -> Vec<String> {
+        self.review
+            .iter()
+            .flatten()
+            .filter(|(_, changes)| !changes.is_empty())
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    fn title(&self) -> String {
+        match self.targets.as_slice() {
+            [name] => format!(" Labels: {} ", name),
+            targets => format!(" Labels: {} containers ", targets.len()),
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 70, 20);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(self.title())
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(inner);
+
+        let key = |k: &'static str, color: Color| Span::styled(k, Style::default().fg(color).add_modifier(Modifier::BOLD));
+        let desc = |d: &'static str| Span::styled(d, Style::default().fg(Theme::FG_DARK));
+
+        let instructions = match self.review {
+            Some(ref review) => {
+                let mut lines = Vec::new();
+                for (name, changes) in review {
+                    lines.push(Line::styled(format!(" {}", name), Style::default().fg(Theme::FG).add_modifier(Modifier::BOLD)));
+                    if changes.is_empty() {
+                        lines.push(Line::styled("   no change, left as is", Style::default().fg(Theme::FG_DARK)));
+                    }
+                    for change in changes {
+                        lines.push(match change {
+                            LabelChange::Added(k, v) => Line::styled(format!("   + {}={}", k, v), Style::default().fg(Theme::GREEN)),
+                            LabelChange::Changed(k, old, new) => {
+                                Line::styled(format!("   ~ {}: {} → {}", k, old, new), Style::default().fg(Theme::YELLOW))
+                            }
+                            LabelChange::Removed(k, v) => Line::styled(format!("   - {}={}", k, v), Style::default().fg(Theme::RED)),
+                        });
+                    }
+                }
+                lines.push(Line::raw(""));
+                lines.push(Line::styled(
+                    " Changed containers are recreated: config is kept, the writable layer is not.",
+                    Style::default().fg(Theme::PEACH),
+                ));
+                self.scroll = self.scroll.min(lines.len().saturating_sub(1));
+                frame.render_widget(Paragraph::new(lines).scroll((self.scroll as u16, 0)), chunks[0]);
+
+                Line::from(vec![key(" Enter ", Theme::GREEN), desc("apply   "), key(" ↑↓ ", Theme::CYAN), desc("scroll   "), key(" Esc ", Theme::RED), desc("edit")])
+            }
+            None => {
+                let body = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(2), Constraint::Length(3), Constraint::Min(0)])
+                    .split(chunks[0]);
+                let hint = Paragraph::new(" key=value sets a label, -key removes one; separate edits with spaces")
+                    .style(Style::default().fg(Theme::FG_DARK));
+                frame.render_widget(hint, body[0]);
+
+                let input = Paragraph::new(format!(" {}█", self.input))
+                    .style(Style::default().fg(Theme::FG))
+                    .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Theme::BORDER)));
+                frame.render_widget(input, body[1]);
+
+                let mut lines: Vec<Line> = Vec::new();
+                if let Some(ref error) = self.error {
+                    lines.push(Line::styled(format!(" {}", error), Style::default().fg(Theme::RED)));
+                }
+                lines.push(Line::styled(format!(" Applies to: {}", self.targets.join(", ")), Style::default().fg(Theme::FG_DARK)));
+                frame.render_widget(Paragraph::new(lines).wrap(ratatui::widgets::Wrap { trim: true }), body[2]);
+
+                Line::from(vec![key(" Enter ", Theme::GREEN), desc("dry run   "), key(" Esc ", Theme::RED), desc("cancel")])
+            }
+        };
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[1]);
+    }
+}
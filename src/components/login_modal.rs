@@ -0,0 +1,136 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::config::RegistryAuth;
+use crate::ui::{centered_modal, Theme};
+
+/// Registry login modal: host, username and a masked password
+#[derive(Debug, Clone)]
+pub struct LoginModal {
+    pub registry: String,
+    pub username: String,
+    pub password: String,
+    /// 0 = registry, 1 = username, 2 = password
+    pub selected_field: usize,
+    pub error: Option<String>,
+}
+
+impl LoginModal {
+    pub fn new(registry: String) -> Self {
+        Self {
+            // Start on the username when the registry is already known
+            selected_field: if registry.is_empty() { 0 } else { 1 },
+            registry,
+            username: String::new(),
+            password: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn next_field(&mut self) {
+        self.selected_field = (self.selected_field + 1) % 3;
+    }
+
+    pub fn prev_field(&mut self) {
+        self.selected_field = (self.selected_field + 2) % 3;
+    }
+
+    fn current_field_mut(&mut self) -> &mut String {
+        match self.selected_field {
+            0 => &mut self.registry,
+            1 => &mut self.username,
+            _ => &mut self.password,
+        }
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        // Registry hosts and usernames never contain spaces
+        if self.selected_field == 2 || !c.is_whitespace() {
+            self.current_field_mut().push(c);
+        }
+    }
+
+    pub fn handle_backspace(&mut self) {
+        self.current_field_mut().pop();
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.registry.is_empty() && !self.username.is_empty() && !self.password.is_empty()
+    }
+
+    pub fn auth(&self) -> RegistryAuth {
+        RegistryAuth {
+            username: self.username.clone(),
+            password: self.password.clone(),
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 55, 14);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(" Registry Login ")
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let masked = "•".repeat(self.password.chars().count());
+        let fields = [
+            ("Registry (e.g. docker.io, ghcr.io)", self.registry.as_str()),
+            ("Username", self.username.as_str()),
+            ("Password / token", masked.as_str()),
+        ];
+        for (i, (label, value)) in fields.iter().enumerate() {
+            let selected = i == self.selected_field;
+            let cursor = if selected { "█" } else { "" };
+            let border = if selected { Theme::CYAN } else { Theme::BORDER };
+            let input = Paragraph::new(format!(" {}{}", value, cursor))
+                .style(Style::default().fg(Theme::FG))
+                .block(
+                    Block::default()
+                        .title(format!(" {} ", label))
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(border)),
+                );
+            frame.render_widget(input, chunks[i]);
+        }
+
+        if let Some(ref error) = self.error {
+            let msg = Paragraph::new(format!(" {}", error))
+                .style(Style::default().fg(Theme::RED))
+                .wrap(ratatui::widgets::Wrap { trim: false });
+            frame.render_widget(msg, chunks[3]);
+        }
+
+        // Instructions
+        let instructions = Line::from(vec![
+            Span::styled(" Enter ", Style::default().fg(Theme::GREEN).add_modifier(Modifier::BOLD)),
+            Span::styled("log in   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" Tab ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("next   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" Esc ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
+            Span::styled("cancel", Style::default().fg(Theme::FG_DARK)),
+        ]);
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[4]);
+    }
+}
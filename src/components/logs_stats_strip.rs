@@ -0,0 +1,65 @@
+use ratatui::{prelude::*, widgets::Paragraph};
+
+use crate::components::history_chart::format_rate;
+use crate::components::StatsHistory;
+use crate::models::ContainerInfo;
+use crate::ui::Theme;
+
+/// Samples shown per sparkline at most (the history keeps 30)
+const MAX_SPARK_WIDTH: usize = 30;
+
+/// One line above the logs with the container's recent CPU and memory and
+/// its network rates, to line up log bursts with load
+pub struct LogsStatsStrip;
+
+impl LogsStatsStrip {
+    pub fn render(frame: &mut Frame, area: Rect, container: &ContainerInfo, history: &StatsHistory) {
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(Theme::FG_DARK));
+        let sep = || Span::styled(" │ ", Style::default().fg(Theme::BORDER));
+
+        let Some(ref stats) = container.stats else {
+            let line = Line::styled(
+                format!(" {} is {}: no stats", container.name, container.status.as_str()),
+                Style::default().fg(Theme::FG_DARK),
+            );
+            frame.render_widget(Paragraph::new(line), area);
+            return;
+        };
+
+        // Two sparklines share what the figures leave of the line
+        let spark_width = (area.width as usize).saturating_sub(60).div_ceil(2).clamp(5, MAX_SPARK_WIDTH);
+        let cpu_spark = StatsHistory::to_sparkline(&history.get_cpu(&container.id), spark_width);
+        let mem_spark = StatsHistory::to_sparkline(&history.get_mem(&container.id), spark_width);
+
+        let line = Line::from(vec![
+            label(" CPU "),
+            Span::styled(cpu_spark, Style::default().fg(Theme::CYAN)),
+            Span::styled(format!(" {:>5.1}%", stats.cpu_percent), Style::default().fg(percent_color(stats.cpu_percent))),
+            sep(),
+            label("MEM "),
+            Span::styled(mem_spark, Style::default().fg(Theme::MAGENTA)),
+            Span::styled(format!(" {:>5.1}%", stats.memory_percent), Style::default().fg(percent_color(stats.memory_percent))),
+            Span::styled(format!(" ({:.0}M)", stats.memory_usage_mb), Style::default().fg(Theme::FG_DARK)),
+            sep(),
+            label("NET "),
+            Span::styled(format!("↓{}", format_rate(stats.net_rx_rate)), Style::default().fg(Theme::GREEN)),
+            Span::raw(" "),
+            Span::styled(format!("↑{}", format_rate(stats.net_tx_rate)), Style::default().fg(Theme::BLUE)),
+        ]);
+
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
+
+/// Get color based on percentage
+fn percent_color(percent: f64) -> Color {
+    if percent > 80.0 {
+        Theme::RED
+    } else if percent > 60.0 {
+        Theme::ORANGE
+    } else if percent > 40.0 {
+        Theme::YELLOW
+    } else {
+        Theme::GREEN
+    }
+}
@@ -3,13 +3,40 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
 };
 
-use crate::ui::border_style;
+use chrono::{DateTime, Utc};
+
+use crate::docker::logs::{line_timestamp, LogBuffer};
+use crate::ui::{border_style, Theme};
+
+/// Gaps between consecutive lines longer than this get a time marker
+const MARKER_GAP_SECS: i64 = 60;
+
+/// A display row: a time marker or a log line (by index)
+enum LogRow {
+    Marker { at: DateTime<Utc>, gap_secs: i64 },
+    Line(usize),
+}
 
 /// Logs view component
 pub struct LogsView {
+    /// First visible row (log lines plus time markers)
     pub scroll: usize,
     pub follow: bool,
     pub focused: bool,
+    /// Go-to-time input while it is open
+    pub goto: Option<String>,
+    /// Line being typed for the container's stdin while the input is open
+    pub stdin: Option<String>,
+    /// Filter being typed while the filter input is open
+    pub filter_input: Option<String>,
+    /// Filter picked with ↑↓ in the filter input, to toggle or remove
+    pub filter_selected: usize,
+    /// Log line to bring into view on the next render
+    pub jump_to: Option<usize>,
+    /// Rows that fit at the last render, for half-page jumps
+    pub page_height: usize,
+    /// Lines carry a "container |" prefix (project logs), colored per container
+    pub prefixed: bool,
 }
 
 impl LogsView {
@@ -18,9 +45,22 @@ impl LogsView {
             scroll: 0,
             follow: true,
             focused: false,
+            goto: None,
+            stdin: None,
+            filter_input: None,
+            filter_selected: 0,
+            jump_to: None,
+            page_height: 0,
+            prefixed: false,
         }
     }
 
+    /// Scroll so that a log line is at the top (below its time marker)
+    pub fn jump_to_line(&mut self, line: usize) {
+        self.jump_to = Some(line);
+        self.follow = false;
+    }
+
     /// Scroll up
     pub fn scroll_up(&mut self, amount: usize) {
         self.scroll = self.scroll.saturating_sub(amount);
@@ -38,6 +78,12 @@ impl LogsView {
         self.follow = false;
     }
 
+    /// Go to the middle of the buffer
+    pub fn middle(&mut self, max: usize) {
+        self.scroll = max.saturating_sub(self.page_height) / 2;
+        self.follow = false;
+    }
+
     /// Go to bottom and enable follow mode
     pub fn bottom(&mut self, max: usize) {
         self.scroll = max;
@@ -56,38 +102,121 @@ impl LogsView {
         }
     }
 
+    /// Log lines that pass the filters, with a marker wherever the time
+    /// jumps by more than the gap
+    fn rows(logs: &LogBuffer) -> Vec<LogRow> {
+        let mut rows = Vec::with_capacity(logs.len());
+        let mut previous: Option<DateTime<Utc>> = None;
+        for (i, line) in logs.iter().enumerate().filter(|(i, _)| logs.is_shown(*i)) {
+            if let Some(at) = line_timestamp(line) {
+                if let Some(prev) = previous {
+                    let gap_secs = (at - prev).num_seconds();
+                    if gap_secs > MARKER_GAP_SECS {
+                        rows.push(LogRow::Marker { at, gap_secs });
+                    }
+                }
+                previous = Some(at);
+            }
+            rows.push(LogRow::Line(i));
+        }
+        rows
+    }
+
     /// Render the logs view
-    pub fn render(&mut self, frame: &mut Frame, area: Rect, logs: &[String], container_name: &str) {
-        let block = Block::default()
-            .title(format!(
-                " Logs: {} {} ",
-                container_name,
-                if self.follow { "[following]" } else { "" }
-            ))
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, logs: &LogBuffer, container_name: &str) {
+        let mut title = vec![Span::raw(format!(
+            " Logs: {} {} ",
+            container_name,
+            if self.follow { "[following]" } else { "" }
+        ))];
+        if logs.dropped > 0 {
+            title.push(Span::styled(
+                format!("{} older lines dropped (m: load more) ", logs.dropped),
+                Style::default().fg(Theme::YELLOW),
+            ));
+        }
+        for (i, filter) in logs.filters().iter().enumerate() {
+            let color = if filter.exclude { Theme::RED } else { Theme::GREEN };
+            let mut style = match filter.enabled {
+                true => Style::default().fg(color),
+                false => Style::default().fg(Theme::FG_DARK).add_modifier(Modifier::CROSSED_OUT),
+            };
+            if self.filter_input.is_some() && i == self.filter_selected {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            title.push(Span::styled(format!("[{}]", filter.label()), style));
+            title.push(Span::raw(" "));
+        }
+        let hidden = logs.hidden();
+        if hidden > 0 {
+            title.push(Span::styled(format!("{} hidden ", hidden), Style::default().fg(Theme::FG_DARK)));
+        }
+        let mut block = Block::default()
+            .title(Line::from(title))
             .borders(Borders::ALL)
             .border_style(border_style(self.focused));
+        if let Some(ref input) = self.goto {
+            block = block.title_bottom(Line::from(vec![
+                Span::styled(" Go to (UTC, HH:MM[:SS]): ", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}█ ", input), Style::default().fg(Theme::FG)),
+            ]));
+        } else if let Some(ref input) = self.filter_input {
+            block = block.title_bottom(Line::from(vec![
+                Span::styled(" Filter (regex, !regex hides): ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}█ ", input), Style::default().fg(Theme::FG)),
+                Span::styled("(Enter add, ↑↓ pick, Tab toggle, Del remove, Esc close) ", Style::default().fg(Theme::FG_DARK)),
+            ]));
+        } else if let Some(ref input) = self.stdin {
+            block = block.title_bottom(Line::from(vec![
+                Span::styled(" stdin> ", Style::default().fg(Theme::GREEN).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}█ ", input), Style::default().fg(Theme::FG)),
+                Span::styled("(Enter send, Esc close) ", Style::default().fg(Theme::FG_DARK)),
+            ]));
+        }
 
         let inner = block.inner(area);
         let visible_height = inner.height as usize;
+        self.page_height = visible_height;
+        let rows = Self::rows(logs);
 
-        // Update scroll position if following
-        self.update_logs(logs.len(), visible_height);
+        // Update scroll position if following or jumping, and never past the end
+        self.update_logs(rows.len(), visible_height);
+        if let Some(line) = self.jump_to.take() {
+            if let Some(pos) = rows.iter().position(|row| matches!(row, LogRow::Line(i) if *i == line)) {
+                let marker_above = pos > 0 && matches!(rows[pos - 1], LogRow::Marker { .. });
+                self.scroll = if marker_above { pos - 1 } else { pos };
+            }
+        }
+        self.scroll = self.scroll.min(rows.len().saturating_sub(visible_height));
 
-        // Get visible logs
-        let visible_logs: Vec<Line> = logs
+        // Get visible rows
+        let visible_logs: Vec<Line> = rows
             .iter()
             .skip(self.scroll)
             .take(visible_height)
-            .map(|line| {
-                // Parse timestamp if present and style it
-                if line.len() > 30 && line.chars().nth(4) == Some('-') {
-                    let (timestamp, rest) = line.split_at(30.min(line.len()));
-                    Line::from(vec![
-                        Span::styled(timestamp, Style::default().fg(Color::DarkGray)),
-                        Span::raw(rest),
-                    ])
-                } else {
-                    Line::raw(line)
+            .map(|row| match *row {
+                LogRow::Marker { at, gap_secs } => Line::styled(
+                    format!("──── {} (+{}) ────", at.format("%Y-%m-%d %H:%M:%S"), format_gap(gap_secs)),
+                    Style::default().fg(Theme::MAUVE),
+                ),
+                LogRow::Line(i) => {
+                    let line = &logs[i];
+                    // Parse timestamp if present and style it
+                    let (timestamp, rest) = if line.len() > 30 && line.chars().nth(4) == Some('-') {
+                        line.split_at(30.min(line.len()))
+                    } else {
+                        ("", line.as_str())
+                    };
+                    let mut spans = vec![Span::styled(timestamp, Style::default().fg(Color::DarkGray))];
+                    match rest.split_once(" | ").filter(|_| self.prefixed) {
+                        Some((container, message)) => {
+                            spans.push(Span::styled(container, Style::default().fg(prefix_color(container.trim()))));
+                            spans.push(Span::styled(" | ", Style::default().fg(Theme::FG_DARK)));
+                            spans.push(Span::raw(message));
+                        }
+                        None => spans.push(Span::raw(rest)),
+                    }
+                    Line::from(spans)
                 }
             })
             .collect();
@@ -97,13 +226,13 @@ impl LogsView {
         frame.render_widget(paragraph, area);
 
         // Render scrollbar
-        if logs.len() > visible_height {
+        if rows.len() > visible_height {
             let scrollbar = Scrollbar::default()
                 .orientation(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some("▲"))
                 .end_symbol(Some("▼"));
 
-            let mut scrollbar_state = ScrollbarState::new(logs.len().saturating_sub(visible_height))
+            let mut scrollbar_state = ScrollbarState::new(rows.len().saturating_sub(visible_height))
                 .position(self.scroll);
 
             frame.render_stateful_widget(
@@ -118,6 +247,25 @@ impl LogsView {
     }
 }
 
+/// Same color for a container's prefix on every line and refresh
+fn prefix_color(container: &str) -> Color {
+    const COLORS: [Color; 8] =
+        [Theme::CYAN, Theme::YELLOW, Theme::GREEN, Theme::MAUVE, Theme::BLUE, Theme::PEACH, Theme::PINK, Theme::SKY];
+    let hash = container.bytes().fold(0usize, |hash, b| hash.wrapping_mul(31).wrapping_add(b as usize));
+    COLORS[hash % COLORS.len()]
+}
+
+/// Gap length for a marker ("90s", "12m", "3h")
+fn format_gap(secs: i64) -> String {
+    if secs < 120 {
+        format!("{}s", secs)
+    } else if secs < 7200 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h", secs / 3600)
+    }
+}
+
 impl Default for LogsView {
     fn default() -> Self {
         Self::new()
@@ -1,6 +1,9 @@
+pub mod action_menu;
+pub mod command_palette;
 pub mod container_list;
 pub mod container_detail;
 pub mod logs_view;
+pub mod logs_stats_strip;
 pub mod status_bar;
 pub mod help_modal;
 pub mod confirm_modal;
@@ -8,14 +11,49 @@ pub mod create_modal;
 pub mod header;
 pub mod filter_bar;
 pub mod exec_modal;
+pub mod exec_pane;
 pub mod sparkline;
 pub mod info_modal;
 pub mod rename_modal;
 pub mod processes_modal;
+pub mod image_history_modal;
+pub mod vuln_modal;
+pub mod gpu_modal;
+pub mod diff_modal;
+pub mod compose_modal;
+pub mod config_errors_modal;
+pub mod checkpoint_modal;
+pub mod group_modal;
+pub mod label_modal;
+pub mod forward_modal;
+pub mod cleanup_modal;
+pub mod pull_modal;
+pub mod login_modal;
 pub mod copy_files_modal;
+pub mod toast;
+pub mod history_chart;
+pub mod compare_view;
+pub mod services_view;
+pub mod pods_view;
+pub mod host_view;
+pub mod events_view;
+pub mod alerts_panel;
+pub mod audit_panel;
+pub mod daemon_panel;
+pub mod forwards_panel;
+pub mod recycle_panel;
+pub mod schedule_panel;
+pub mod startup_panel;
+pub mod stats_footer;
+pub mod tab_bar;
+pub mod yank_menu;
 
+pub use action_menu::ActionMenu;
+pub use command_palette::CommandPalette;
 pub use container_list::ContainerList;
+pub use container_detail::ContainerDetail;
 pub use logs_view::LogsView;
+pub use logs_stats_strip::LogsStatsStrip;
 pub use status_bar::StatusBar;
 pub use help_modal::HelpModal;
 pub use confirm_modal::ConfirmModal;
@@ -23,8 +61,39 @@ pub use create_modal::{CreateModal, CreateContainerForm, CreateMode};
 pub use header::Header;
 pub use filter_bar::FilterBar;
 pub use exec_modal::ExecModal;
+pub use exec_pane::ExecPane;
 pub use sparkline::StatsHistory;
 pub use info_modal::InfoModal;
 pub use rename_modal::RenameModal;
 pub use processes_modal::ProcessesModal;
+pub use image_history_modal::ImageHistoryModal;
+pub use vuln_modal::VulnModal;
+pub use gpu_modal::GpuModal;
+pub use diff_modal::DiffModal;
+pub use compose_modal::ComposeModal;
+pub use config_errors_modal::ConfigErrorsModal;
+pub use checkpoint_modal::CheckpointModal;
+pub use group_modal::GroupModal;
+pub use label_modal::LabelModal;
+pub use forward_modal::ForwardModal;
+pub use cleanup_modal::CleanupModal;
+pub use pull_modal::PullModal;
+pub use login_modal::LoginModal;
 pub use copy_files_modal::CopyFilesModal;
+pub use toast::Toast;
+pub use history_chart::HistoryChart;
+pub use compare_view::CompareView;
+pub use services_view::ServicesView;
+pub use pods_view::PodsView;
+pub use host_view::HostView;
+pub use events_view::EventsView;
+pub use alerts_panel::AlertsPanel;
+pub use audit_panel::AuditPanel;
+pub use daemon_panel::DaemonPanel;
+pub use forwards_panel::ForwardsPanel;
+pub use recycle_panel::RecyclePanel;
+pub use schedule_panel::SchedulePanel;
+pub use startup_panel::StartupPanel;
+pub use stats_footer::StatsFooter;
+pub use tab_bar::TabBar;
+pub use yank_menu::YankMenu;
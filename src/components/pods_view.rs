@@ -0,0 +1,125 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::models::PodInfo;
+use crate::ui::{border_style, selected_style, Theme};
+
+/// Kubernetes pods table for one namespace (read-only)
+#[derive(Debug, Default)]
+pub struct PodsView {
+    pub state: TableState,
+}
+
+impl PodsView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.state.selected()
+    }
+
+    pub fn next(&mut self, count: usize) {
+        if count > 0 {
+            let i = self.state.selected().map_or(0, |i| (i + 1).min(count - 1));
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn previous(&mut self, count: usize) {
+        if count > 0 {
+            let i = self.state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.state.select(Some(i));
+        }
+    }
+
+    /// Keep the selection valid after the list changes
+    pub fn clamp_selection(&mut self, count: usize) {
+        let selected = if count == 0 {
+            None
+        } else {
+            Some(self.state.selected().unwrap_or(0).min(count - 1))
+        };
+        self.state.select(selected);
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, pods: &[PodInfo], namespace: &str) {
+        let title = Line::from(vec![
+            Span::styled(" Containers ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled("│", Style::default().fg(Theme::BORDER)),
+            Span::styled(
+                format!(" Pods: {} ({}) ", namespace, pods.len()),
+                Style::default().fg(Theme::LAVENDER).add_modifier(Modifier::BOLD),
+            ),
+        ]);
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(border_style(true));
+
+        if pods.is_empty() {
+            let text = Paragraph::new(format!("No pods in namespace {}", namespace))
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(text, area);
+            return;
+        }
+
+        let header_row = Row::new(vec!["NAME", "READY", "STATUS", "RESTARTS", "AGE", "NODE"])
+            .style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = pods
+            .iter()
+            .map(|pod| {
+                let status_color = if pod.is_healthy() {
+                    Theme::GREEN
+                } else if pod.status == "Pending" || pod.status == "ContainerCreating" {
+                    Theme::YELLOW
+                } else {
+                    Theme::RED
+                };
+                let restarts_color = if pod.restarts > 0 { Theme::YELLOW } else { Theme::FG_DARK };
+                Row::new(vec![
+                    Cell::from(pod.name.clone()).style(Style::default().fg(Theme::FG)),
+                    Cell::from(format!("{}/{}", pod.ready, pod.containers)).style(Style::default().fg(Theme::FG)),
+                    Cell::from(pod.status.clone()).style(Style::default().fg(status_color)),
+                    Cell::from(pod.restarts.to_string()).style(Style::default().fg(restarts_color)),
+                    Cell::from(pod.created.map(format_age).unwrap_or_default()).style(Style::default().fg(Theme::FG_DARK)),
+                    Cell::from(pod.node.clone().unwrap_or_default()).style(Style::default().fg(Theme::FG_DARK)),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Percentage(40), // NAME
+            Constraint::Length(6),      // READY
+            Constraint::Length(18),     // STATUS
+            Constraint::Length(9),      // RESTARTS
+            Constraint::Length(6),      // AGE
+            Constraint::Min(8),         // NODE
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header_row)
+            .block(block)
+            .column_spacing(1)
+            .row_highlight_style(selected_style())
+            .highlight_symbol("▶");
+
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+}
+
+/// Compact age like kubectl's ("45s", "3h", "12d")
+fn format_age(created: chrono::DateTime<chrono::Utc>) -> String {
+    let secs = (chrono::Utc::now() - created).num_seconds().max(0);
+    match secs {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h", s / 3600),
+        s => format!("{}d", s / 86400),
+    }
+}
@@ -3,16 +3,7 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph, Row, Table},
 };
 
-use crate::ui::{centered_modal, Theme};
-
-/// Truncate string to max length
-fn truncate(s: &str, max: usize) -> String {
-    if s.len() <= max {
-        s.to_string()
-    } else {
-        format!("{}…", &s[..max.saturating_sub(1)])
-    }
-}
+use crate::ui::{centered_modal, truncate_width, Theme};
 
 /// Container processes modal (docker top)
 #[derive(Debug, Clone)]
@@ -99,17 +90,13 @@ impl ProcessesModal {
                 } else {
                     proc.last().cloned().unwrap_or_default()
                 };
-                let cmd_display = if command.len() > 55 {
-                    format!("{}...", &command[..52])
-                } else {
-                    command
-                };
+                let cmd_display = truncate_width(&command, 55);
 
                 Row::new(vec![
                     Text::from(proc.get(1).cloned().unwrap_or_default()), // PID
                     Text::from(proc.get(2).cloned().unwrap_or_default()), // %CPU
                     Text::from(proc.get(3).cloned().unwrap_or_default()), // %MEM
-                    Text::from(truncate(proc.get(0).map(|s| s.as_str()).unwrap_or(""), 10)), // USER
+                    Text::from(truncate_width(proc.first().map(|s| s.as_str()).unwrap_or(""), 10)), // USER
                     Text::from(cmd_display), // COMMAND
                 ])
                 .style(Style::default().fg(Theme::FG))
@@ -0,0 +1,225 @@
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::docker::registry::{RegistryTag, RepoRef};
+use crate::ui::{centered_modal, fit_width, format_bytes, Theme};
+
+/// Which input of the pull dialog has focus
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PullFocus {
+    Repository,
+    Tags,
+}
+
+/// Pull an image: type a repository, browse its registry tags, pick one
+#[derive(Debug, Clone)]
+pub struct PullModal {
+    pub repository: String,
+    /// Repository the current tags belong to
+    pub fetched: Option<RepoRef>,
+    pub tags: Vec<RegistryTag>,
+    /// Tag search query
+    pub query: String,
+    /// Indices into `tags` matching the query, best first
+    matches: Vec<usize>,
+    pub state: ListState,
+    pub focus: PullFocus,
+    pub error: Option<String>,
+}
+
+impl PullModal {
+    pub fn new(repository: String) -> Self {
+        Self {
+            repository,
+            fetched: None,
+            tags: Vec::new(),
+            query: String::new(),
+            matches: Vec::new(),
+            state: ListState::default(),
+            focus: PullFocus::Repository,
+            error: None,
+        }
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        match self.focus {
+            PullFocus::Repository => {
+                if !c.is_whitespace() {
+                    self.repository.push(c);
+                }
+            }
+            PullFocus::Tags => {
+                self.query.push(c);
+                self.update_matches();
+            }
+        }
+    }
+
+    pub fn handle_backspace(&mut self) {
+        match self.focus {
+            PullFocus::Repository => {
+                self.repository.pop();
+            }
+            PullFocus::Tags => {
+                self.query.pop();
+                self.update_matches();
+            }
+        }
+    }
+
+    /// Switch between the repository input and the tag list
+    pub fn toggle_focus(&mut self) {
+        self.focus = match self.focus {
+            PullFocus::Repository if !self.tags.is_empty() => PullFocus::Tags,
+            _ => PullFocus::Repository,
+        };
+    }
+
+    /// Show the result of a tag lookup
+    pub fn set_tags(&mut self, repo: RepoRef, result: anyhow::Result<Vec<RegistryTag>>) {
+        self.query.clear();
+        match result {
+            Ok(tags) => {
+                self.error = tags.is_empty().then(|| "No tags found".to_string());
+                self.focus = if tags.is_empty() { PullFocus::Repository } else { PullFocus::Tags };
+                self.tags = tags;
+                self.fetched = Some(repo);
+            }
+            Err(e) => {
+                self.error = Some(e.to_string());
+                self.tags.clear();
+                self.fetched = None;
+                self.focus = PullFocus::Repository;
+            }
+        }
+        self.update_matches();
+    }
+
+    fn update_matches(&mut self) {
+        if self.query.is_empty() {
+            self.matches = (0..self.tags.len()).collect();
+        } else {
+            let matcher = SkimMatcherV2::default().smart_case();
+            let mut scored: Vec<(i64, usize)> = self
+                .tags
+                .iter()
+                .enumerate()
+                .filter_map(|(i, tag)| matcher.fuzzy_match(&tag.name, &self.query).map(|score| (score, i)))
+                .collect();
+            // Stable, so equal scores keep the registry's order
+            scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+            self.matches = scored.into_iter().map(|(_, i)| i).collect();
+        }
+        self.state.select((!self.matches.is_empty()).then_some(0));
+    }
+
+    pub fn next(&mut self) {
+        if !self.matches.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + 1) % self.matches.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.matches.is_empty() {
+            let i = self.state.selected().map_or(0, |i| (i + self.matches.len() - 1) % self.matches.len());
+            self.state.select(Some(i));
+        }
+    }
+
+    /// Image and tag to pull for the highlighted tag
+    pub fn selected_pull(&self) -> Option<(String, String)> {
+        let repo = self.fetched.as_ref()?;
+        let tag = self.state.selected().and_then(|i| self.matches.get(i)).map(|&i| &self.tags[i])?;
+        Some((repo.image(), tag.name.clone()))
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 70, 24);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(" Pull Image ")
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let focused = |focus: PullFocus| {
+            Style::default().fg(if self.focus == focus { Theme::CYAN } else { Theme::BORDER })
+        };
+        let cursor = |focus: PullFocus| if self.focus == focus { "█" } else { "" };
+
+        let repository = Paragraph::new(format!(" {}{}", self.repository, cursor(PullFocus::Repository)))
+            .style(Style::default().fg(Theme::FG))
+            .block(Block::default().title(" Repository ").borders(Borders::ALL).border_style(focused(PullFocus::Repository)));
+        frame.render_widget(repository, chunks[0]);
+
+        let search_title = match self.fetched {
+            Some(ref repo) => format!(" Tags: {} ({}/{}) ", repo.image(), self.matches.len(), self.tags.len()),
+            None => " Tags ".to_string(),
+        };
+        let search = Paragraph::new(format!(" {}{}", self.query, cursor(PullFocus::Tags)))
+            .style(Style::default().fg(Theme::FG))
+            .block(Block::default().title(search_title).borders(Borders::ALL).border_style(focused(PullFocus::Tags)));
+        frame.render_widget(search, chunks[1]);
+
+        if let Some(ref error) = self.error {
+            let msg = Paragraph::new(format!(" {}", error))
+                .style(Style::default().fg(Theme::RED))
+                .wrap(ratatui::widgets::Wrap { trim: false });
+            frame.render_widget(msg, chunks[2]);
+        } else if self.fetched.is_none() {
+            let msg = Paragraph::new(" Press Enter to look up tags (e.g. nginx, grafana/grafana, ghcr.io/org/app)")
+                .style(Style::default().fg(Theme::FG_DARK))
+                .wrap(ratatui::widgets::Wrap { trim: false });
+            frame.render_widget(msg, chunks[2]);
+        } else {
+            let items: Vec<ListItem> = self
+                .matches
+                .iter()
+                .map(|&i| {
+                    let tag = &self.tags[i];
+                    let updated = tag.updated.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default();
+                    let size = tag.size.map(format_bytes).unwrap_or_default();
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!(" {} ", fit_width(&tag.name, 40)), Style::default().fg(Theme::FG)),
+                        Span::styled(format!("{:>10}  ", size), Style::default().fg(Theme::YELLOW)),
+                        Span::styled(updated, Style::default().fg(Theme::FG_DARK)),
+                    ]))
+                })
+                .collect();
+            let list = List::new(items).highlight_style(Style::default().bg(Theme::BG_HIGHLIGHT));
+            frame.render_stateful_widget(list, chunks[2], &mut self.state);
+        }
+
+        let instructions = Line::from(vec![
+            Span::styled(" Enter ", Style::default().fg(Theme::GREEN).add_modifier(Modifier::BOLD)),
+            Span::styled(if self.focus == PullFocus::Tags { "pull   " } else { "find tags   " }, Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" Tab ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("switch   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" Esc ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
+            Span::styled("cancel", Style::default().fg(Theme::FG_DARK)),
+        ]);
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[3]);
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::VecDeque;
+
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use crate::recycle::DeletedContainer;
+use crate::ui::Theme;
+
+/// Panel listing recently deleted containers (newest first, recreated with U)
+pub struct RecyclePanel;
+
+impl RecyclePanel {
+    pub fn render(frame: &mut Frame, area: Rect, deleted: &VecDeque<DeletedContainer>) {
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Recently Deleted ({}) ", deleted.len()),
+                Style::default().fg(Theme::PEACH).add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Line::styled(" U recreate newest ", Style::default().fg(Theme::FG_DARK)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::BORDER));
+
+        let items: Vec<ListItem> = if deleted.is_empty() {
+            vec![ListItem::new(Line::styled(" Nothing deleted yet", Style::default().fg(Theme::FG_DARK)))]
+        } else {
+            deleted
+                .iter()
+                .map(|d| {
+                    let state = if d.was_running { "running" } else { "stopped" };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!(" {} ", d.deleted_at.format("%H:%M:%S")), Style::default().fg(Theme::FG_DARK)),
+                        Span::styled(format!("{} ", d.name), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+                        Span::styled(format!("{} ", d.image), Style::default().fg(Theme::LAVENDER)),
+                        Span::styled(format!("({})", state), Style::default().fg(Theme::FG_DARK)),
+                    ]))
+                })
+                .collect()
+        };
+
+        frame.render_widget(List::new(items).block(block), area);
+    }
+}
@@ -0,0 +1,54 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use crate::schedule::Scheduler;
+use crate::ui::Theme;
+
+/// Panel with the scheduled rules by next run, then their recent runs
+pub struct SchedulePanel;
+
+impl SchedulePanel {
+    pub fn render(frame: &mut Frame, area: Rect, scheduler: &Scheduler) {
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Schedule ({} rules) ", scheduler.rules.len()),
+                Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::BORDER));
+
+        if scheduler.rules.is_empty() {
+            let items = vec![ListItem::new(Line::styled(
+                " No [[schedule]] rules in config.toml",
+                Style::default().fg(Theme::FG_DARK),
+            ))];
+            frame.render_widget(List::new(items).block(block), area);
+            return;
+        }
+
+        let mut rules: Vec<_> = scheduler.rules.iter().collect();
+        rules.sort_by_key(|r| r.next);
+        let mut items: Vec<ListItem> = rules
+            .iter()
+            .map(|r| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!(" {} ", r.next.format("%a %m-%d %H:%M")), Style::default().fg(Theme::SKY)),
+                    Span::styled(r.describe(), Style::default().fg(Theme::FG)),
+                ]))
+            })
+            .collect();
+        items.extend(scheduler.history.iter().map(|run| {
+            let (icon, color) = if run.ok { ("✓", Theme::GREEN) } else { ("✗", Theme::RED) };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!(" {} ", run.at.format("%m-%d %H:%M")), Style::default().fg(Theme::FG_DARK)),
+                Span::styled(format!("{} ", icon), Style::default().fg(color)),
+                Span::styled(run.description.clone(), Style::default().fg(Theme::FG_DARK)),
+                Span::styled(format!("  {}", run.result), Style::default().fg(color)),
+            ]))
+        }));
+
+        frame.render_widget(List::new(items).block(block), area);
+    }
+}
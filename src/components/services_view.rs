@@ -0,0 +1,110 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::models::ServiceInfo;
+use crate::ui::{border_style, selected_style, Theme};
+
+/// Swarm services table (name, mode, replicas, image, update status)
+#[derive(Debug, Default)]
+pub struct ServicesView {
+    pub state: TableState,
+}
+
+impl ServicesView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.state.selected()
+    }
+
+    pub fn next(&mut self, count: usize) {
+        if count > 0 {
+            let i = self.state.selected().map_or(0, |i| (i + 1).min(count - 1));
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn previous(&mut self, count: usize) {
+        if count > 0 {
+            let i = self.state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.state.select(Some(i));
+        }
+    }
+
+    /// Keep the selection valid after the list changes
+    pub fn clamp_selection(&mut self, count: usize) {
+        let selected = if count == 0 {
+            None
+        } else {
+            Some(self.state.selected().unwrap_or(0).min(count - 1))
+        };
+        self.state.select(selected);
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, services: &[ServiceInfo]) {
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Services ({}) ", services.len()),
+                Style::default().fg(Theme::LAVENDER).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(border_style(true));
+
+        if services.is_empty() {
+            let text = Paragraph::new("No services")
+                .style(Style::default().fg(Theme::FG_DARK))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(text, area);
+            return;
+        }
+
+        let header_row = Row::new(vec!["NAME", "MODE", "REPLICAS", "IMAGE", "UPDATE"])
+            .style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = services
+            .iter()
+            .map(|service| {
+                let replicas_color = if service.is_degraded() { Theme::YELLOW } else { Theme::GREEN };
+                let update = match (&service.update_state, &service.update_message) {
+                    (Some(state), Some(message)) => format!("{}: {}", state, message),
+                    (Some(state), None) => state.clone(),
+                    _ => String::new(),
+                };
+                let update_color = match service.update_state.as_deref() {
+                    Some("updating") | Some("rollback_started") => Theme::YELLOW,
+                    Some("paused") | Some("rollback_paused") => Theme::RED,
+                    _ => Theme::FG_DARK,
+                };
+                Row::new(vec![
+                    Cell::from(service.name.clone()).style(Style::default().fg(Theme::FG)),
+                    Cell::from(service.mode.clone()).style(Style::default().fg(Theme::MAUVE)),
+                    Cell::from(format!("{}/{}", service.running, service.desired)).style(Style::default().fg(replicas_color)),
+                    Cell::from(service.image.clone()).style(Style::default().fg(Theme::FG_DARK)),
+                    Cell::from(update).style(Style::default().fg(update_color)),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Percentage(25), // NAME
+            Constraint::Length(14),     // MODE
+            Constraint::Length(9),      // REPLICAS
+            Constraint::Percentage(35), // IMAGE
+            Constraint::Min(10),        // UPDATE
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header_row)
+            .block(block)
+            .column_spacing(1)
+            .row_highlight_style(selected_style())
+            .highlight_symbol("▶");
+
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+}
@@ -1,11 +1,12 @@
 use std::collections::{HashMap, VecDeque};
 
-/// Rolling history for sparkline display
+/// Rolling history for sparkline display, keyed by container ID so a
+/// rename keeps it
 #[derive(Debug, Clone, Default)]
 pub struct StatsHistory {
-    /// CPU history per container (container_name -> values)
+    /// CPU history per container (container_id -> values)
     cpu: HashMap<String, VecDeque<f64>>,
-    /// Memory history per container (container_name -> values)
+    /// Memory history per container (container_id -> values)
     mem: HashMap<String, VecDeque<f64>>,
     /// Maximum samples to keep
     max_samples: usize,
@@ -48,10 +49,10 @@ impl StatsHistory {
         self.mem.get(container).map(|v| v.iter().copied().collect()).unwrap_or_default()
     }
 
-    /// Remove history for a container (when it's removed)
-    pub fn remove(&mut self, container: &str) {
-        self.cpu.remove(container);
-        self.mem.remove(container);
+    /// Drop the history of containers that no longer exist
+    pub fn retain(&mut self, exists: impl Fn(&str) -> bool) {
+        self.cpu.retain(|id, _| exists(id));
+        self.mem.retain(|id, _| exists(id));
     }
 
     /// Convert values to sparkline string
@@ -0,0 +1,49 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use crate::docker::startup::{StepState, Startup};
+use crate::ui::Theme;
+
+/// Progress of an ordered start, one line per container
+pub struct StartupPanel;
+
+impl StartupPanel {
+    pub fn render(frame: &mut Frame, area: Rect, startup: &Startup) {
+        let done = startup.steps.iter().filter(|s| s.state == StepState::Done).count();
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" {} ({}/{}) ", startup.title, done, startup.steps.len()),
+                Style::default().fg(Theme::GREEN).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::BORDER));
+
+        let items: Vec<ListItem> = startup
+            .steps
+            .iter()
+            .map(|step| {
+                let (symbol, label, color) = match &step.state {
+                    StepState::Pending => ("·", "waiting".to_string(), Theme::FG_DARK),
+                    StepState::Starting => ("▶", "starting".to_string(), Theme::CYAN),
+                    StepState::WaitingHealthy => ("◌", "waiting for healthy".to_string(), Theme::YELLOW),
+                    StepState::Done => ("✓", "up".to_string(), Theme::GREEN),
+                    StepState::Failed(e) => ("✗", e.clone(), Theme::RED),
+                    StepState::Skipped => ("-", "skipped (dependency failed)".to_string(), Theme::FG_DARK),
+                };
+                let mut spans = vec![
+                    Span::styled(format!(" {} ", symbol), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                    Span::styled(format!("{} ", step.name), Style::default().fg(Theme::FG)),
+                    Span::styled(label, Style::default().fg(color)),
+                ];
+                if !step.depends_on.is_empty() {
+                    spans.push(Span::styled(format!("  after {}", step.depends_on.join(", ")), Style::default().fg(Theme::FG_DARK)));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        frame.render_widget(List::new(items).block(block), area);
+    }
+}
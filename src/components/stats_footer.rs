@@ -0,0 +1,54 @@
+use ratatui::{prelude::*, widgets::Paragraph};
+
+use crate::components::history_chart::format_rate;
+use crate::models::{ContainerTotals, SystemStats};
+use crate::ui::Theme;
+
+/// One-line totals under the container list: container CPU, memory and
+/// network next to the host figures
+pub struct StatsFooter;
+
+impl StatsFooter {
+    pub fn render(frame: &mut Frame, area: Rect, totals: &ContainerTotals, system: &SystemStats) {
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(Theme::FG_DARK));
+        let sep = || Span::styled(" │ ", Style::default().fg(Theme::BORDER));
+        let host = |share: f64, host_percent: f32| {
+            Span::styled(
+                format!(" ({:.0}% of host, host {:.0}%)", share, host_percent),
+                Style::default().fg(Theme::FG_DARK),
+            )
+        };
+
+        let host_memory_mb = system.memory_total_gb as f64 * 1024.0;
+        let memory_share = if host_memory_mb > 0.0 {
+            totals.memory_usage_mb / host_memory_mb * 100.0
+        } else {
+            0.0
+        };
+
+        let line = Line::from(vec![
+            Span::styled(
+                format!(" Σ {} active", totals.containers),
+                Style::default().fg(Theme::LAVENDER).add_modifier(Modifier::BOLD),
+            ),
+            sep(),
+            label("CPU "),
+            Span::styled(format!("{:.0}%", totals.cpu_percent), Style::default().fg(Theme::CYAN)),
+            host(totals.host_cpu_share(system.cpu_count), system.cpu_percent),
+            sep(),
+            label("MEM "),
+            Span::styled(
+                format!("{:.1}/{:.0}G", totals.memory_usage_mb / 1024.0, system.memory_total_gb),
+                Style::default().fg(Theme::CYAN),
+            ),
+            host(memory_share, system.memory_percent),
+            sep(),
+            label("NET "),
+            Span::styled(format!("↓{}", format_rate(totals.net_rx_rate)), Style::default().fg(Theme::GREEN)),
+            Span::raw(" "),
+            Span::styled(format!("↑{}", format_rate(totals.net_tx_rate)), Style::default().fg(Theme::BLUE)),
+        ]);
+
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
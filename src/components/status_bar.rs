@@ -3,6 +3,9 @@ use ratatui::{
     widgets::Paragraph,
 };
 
+use crossterm::event::KeyCode;
+
+use crate::keymap::{self, KeyContext};
 use crate::ui::{key_span, key_desc_span, Theme};
 
 /// Keybinding definition
@@ -37,11 +40,61 @@ impl StatusBar {
         vec![
             KeyBinding { key: "↑↓", desc: "scroll" },
             KeyBinding { key: "g/G", desc: "top/end" },
+            KeyBinding { key: "^d/^u", desc: "half page" },
+            KeyBinding { key: "t", desc: "go to time" },
+            KeyBinding { key: "Esc", desc: "back" },
+            KeyBinding { key: "q", desc: "quit" },
+        ]
+    }
+
+    /// Get keybindings for history charts view
+    pub fn charts_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "←→", desc: "range" },
             KeyBinding { key: "Esc", desc: "back" },
             KeyBinding { key: "q", desc: "quit" },
         ]
     }
 
+    /// Get keybindings for Swarm services view
+    pub fn services_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "nav" },
+            KeyBinding { key: "+/-", desc: "scale" },
+            KeyBinding { key: "R", desc: "rolling restart" },
+            KeyBinding { key: "Esc", desc: "back" },
+        ]
+    }
+
+    /// Get keybindings for Kubernetes pods view
+    pub fn pods_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "nav" },
+            KeyBinding { key: "l", desc: "logs" },
+            KeyBinding { key: "n", desc: "namespace" },
+            KeyBinding { key: "Tab", desc: "containers" },
+        ]
+    }
+
+    /// Get keybindings for host processes view
+    pub fn host_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "nav" },
+            KeyBinding { key: "s", desc: "sort" },
+            KeyBinding { key: "g", desc: "group" },
+            KeyBinding { key: "Esc", desc: "back" },
+        ]
+    }
+
+    /// Get keybindings for the events view
+    pub fn events_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "nav" },
+            KeyBinding { key: "/", desc: "container" },
+            KeyBinding { key: "Esc", desc: "back" },
+        ]
+    }
+
     /// Get keybindings for create view
     pub fn create_keybindings() -> Vec<KeyBinding> {
         vec![
@@ -64,6 +117,7 @@ impl StatusBar {
     pub fn exec_keybindings() -> Vec<KeyBinding> {
         vec![
             KeyBinding { key: "↑↓", desc: "select" },
+            KeyBinding { key: "Tab", desc: "user/dir" },
             KeyBinding { key: "Enter", desc: "exec" },
             KeyBinding { key: "Esc", desc: "cancel" },
         ]
@@ -94,6 +148,126 @@ impl StatusBar {
         ]
     }
 
+    /// Get keybindings for image history view
+    pub fn image_history_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "scroll" },
+            KeyBinding { key: "g/G", desc: "top/base" },
+            KeyBinding { key: "Esc", desc: "close" },
+        ]
+    }
+
+    /// Get keybindings for the vulnerability report
+    pub fn vulns_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "scroll" },
+            KeyBinding { key: "r", desc: "rescan" },
+            KeyBinding { key: "Esc", desc: "close" },
+        ]
+    }
+
+    /// Get keybindings for the GPU view
+    pub fn gpus_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "scroll" },
+            KeyBinding { key: "Esc", desc: "close" },
+        ]
+    }
+
+    /// Get keybindings for diff view
+    pub fn diff_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "scroll" },
+            KeyBinding { key: "/", desc: "search" },
+            KeyBinding { key: "Esc", desc: "close" },
+        ]
+    }
+
+    /// Get keybindings for the config problems view
+    pub fn config_errors_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "scroll" },
+            KeyBinding { key: "Esc", desc: "close" },
+        ]
+    }
+
+    /// Get keybindings for the compose file viewer
+    pub fn compose_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "scroll" },
+            KeyBinding { key: "s", desc: "service" },
+            KeyBinding { key: "Tab", desc: "next file" },
+            KeyBinding { key: "Esc", desc: "close" },
+        ]
+    }
+
+    /// Get keybindings for checkpoint manager
+    pub fn checkpoint_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "c/l", desc: "checkpoint" },
+            KeyBinding { key: "Enter", desc: "restore" },
+            KeyBinding { key: "d", desc: "delete" },
+            KeyBinding { key: "Esc", desc: "close" },
+        ]
+    }
+
+    /// Get keybindings for group assignment
+    pub fn groups_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "↑↓", desc: "select" },
+            KeyBinding { key: "Space", desc: "add/remove" },
+            KeyBinding { key: "n", desc: "new group" },
+            KeyBinding { key: "Esc", desc: "close" },
+        ]
+    }
+
+    /// Get keybindings for the label editor
+    pub fn labels_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "Enter", desc: "dry run/apply" },
+            KeyBinding { key: "Esc", desc: "back" },
+        ]
+    }
+
+    /// Get keybindings for the port forward dialog
+    pub fn forward_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "Enter", desc: "forward" },
+            KeyBinding { key: "Tab", desc: "field" },
+            KeyBinding { key: "d", desc: "stop" },
+            KeyBinding { key: "Esc", desc: "close" },
+        ]
+    }
+
+    /// Get keybindings for cleanup wizard
+    pub fn cleanup_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "Space", desc: "toggle" },
+            KeyBinding { key: "a", desc: "all" },
+            KeyBinding { key: "Enter", desc: "remove" },
+            KeyBinding { key: "Esc", desc: "cancel" },
+        ]
+    }
+
+    /// Get keybindings for pull dialog
+    pub fn pull_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "Enter", desc: "tags/pull" },
+            KeyBinding { key: "Tab", desc: "repo/tags" },
+            KeyBinding { key: "↑↓", desc: "select" },
+            KeyBinding { key: "Esc", desc: "cancel" },
+        ]
+    }
+
+    /// Get keybindings for registry login
+    pub fn login_keybindings() -> Vec<KeyBinding> {
+        vec![
+            KeyBinding { key: "Tab", desc: "next field" },
+            KeyBinding { key: "Enter", desc: "log in" },
+            KeyBinding { key: "Esc", desc: "cancel" },
+        ]
+    }
+
     /// Get keybindings for copy view
     pub fn copy_keybindings() -> Vec<KeyBinding> {
         vec![
@@ -104,10 +278,35 @@ impl StatusBar {
         ]
     }
 
-    pub fn render(frame: &mut Frame, area: Rect, view: &str) {
+    /// Whether a status bar key runs a mutating command in the view's context
+    fn is_mutating(view: &str, key: &str) -> bool {
+        let context = match view {
+            "logs" => KeyContext::Logs,
+            "services" => KeyContext::Services,
+            "pods" => KeyContext::Pods,
+            "host" => KeyContext::Host,
+            "events" => KeyContext::Events,
+            "list" => KeyContext::List,
+            _ => return false,
+        };
+        // Labels like "p/P" or "+/-": the first key decides
+        let first = key.split('/').next().unwrap_or_default();
+        let mut chars = first.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => keymap::lookup(context, KeyCode::Char(c)).is_some_and(|cmd| cmd.is_mutating()),
+            _ => false,
+        }
+    }
+
+    pub fn render(frame: &mut Frame, area: Rect, view: &str, read_only: bool) {
         // Keybindings based on view
         let keybindings = match view {
             "logs" => Self::logs_keybindings(),
+            "charts" | "compare" => Self::charts_keybindings(),
+            "services" => Self::services_keybindings(),
+            "pods" => Self::pods_keybindings(),
+            "host" => Self::host_keybindings(),
+            "events" => Self::events_keybindings(),
             "create" => Self::create_keybindings(),
             "filter" => Self::filter_keybindings(),
             "exec" => Self::exec_keybindings(),
@@ -115,11 +314,37 @@ impl StatusBar {
             "rename" => Self::rename_keybindings(),
             "processes" => Self::processes_keybindings(),
             "copy" => Self::copy_keybindings(),
+            "image_history" => Self::image_history_keybindings(),
+            "vulns" => Self::vulns_keybindings(),
+            "gpus" => Self::gpus_keybindings(),
+            "cleanup" => Self::cleanup_keybindings(),
+            "diff" => Self::diff_keybindings(),
+            "compose" => Self::compose_keybindings(),
+            "config_errors" => Self::config_errors_keybindings(),
+            "checkpoints" => Self::checkpoint_keybindings(),
+            "groups" => Self::groups_keybindings(),
+            "labels" => Self::labels_keybindings(),
+            "forward" => Self::forward_keybindings(),
+            "pull" => Self::pull_keybindings(),
+            "login" => Self::login_keybindings(),
             _ => Self::list_keybindings(),
         };
 
         let mut spans: Vec<Span> = Vec::new();
+        if read_only {
+            spans.push(Span::styled(
+                " READ-ONLY ",
+                Style::default().bg(Theme::RED).fg(Theme::BG_DARK).add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::raw(" "));
+        }
         for kb in keybindings {
+            if read_only && Self::is_mutating(view, kb.key) {
+                let dimmed = Style::default().fg(Theme::FG_DARK).add_modifier(Modifier::CROSSED_OUT);
+                spans.push(Span::styled(format!(" {} ", kb.key), dimmed));
+                spans.push(Span::styled(format!(" {}   ", kb.desc), dimmed));
+                continue;
+            }
             spans.push(key_span(kb.key));
             spans.push(key_desc_span(kb.desc));
         }
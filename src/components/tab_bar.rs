@@ -0,0 +1,22 @@
+use ratatui::{prelude::*, widgets::Paragraph};
+
+use crate::ui::Theme;
+
+/// One-line bar of the open tabs, shown above the view once there are two
+pub struct TabBar;
+
+impl TabBar {
+    pub fn render(frame: &mut Frame, area: Rect, labels: &[String], active: usize) {
+        let mut spans = Vec::new();
+        for (i, label) in labels.iter().enumerate() {
+            let style = match i == active {
+                true => Style::default().fg(Theme::BG).bg(Theme::MAUVE).add_modifier(Modifier::BOLD),
+                false => Style::default().fg(Theme::FG_DARK),
+            };
+            spans.push(Span::styled(format!(" {} {} ", i + 1, label), style));
+            spans.push(Span::raw(" "));
+        }
+        spans.push(Span::styled("Alt+1-9 switch  Alt+T new  Alt+W close", Style::default().fg(Theme::OVERLAY)));
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
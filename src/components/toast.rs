@@ -0,0 +1,76 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::ui::{display_width, Theme};
+
+/// How long a toast stays visible
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Toast severity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToastLevel {
+    Info,
+    Error,
+}
+
+/// Short-lived notification shown in the bottom-right corner
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub level: ToastLevel,
+    created: Instant,
+}
+
+impl Toast {
+    pub fn info(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            level: ToastLevel::Info,
+            created: Instant::now(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            level: ToastLevel::Error,
+            created: Instant::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed() >= TOAST_DURATION
+    }
+
+    /// Render above the footer, right-aligned
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let color = match self.level {
+            ToastLevel::Info => Theme::GREEN,
+            ToastLevel::Error => Theme::RED,
+        };
+
+        let width = (display_width(&self.message) as u16 + 4).min(area.width);
+        let height = 3.min(area.height);
+        let toast_area = Rect {
+            x: area.right().saturating_sub(width),
+            y: area.bottom().saturating_sub(height + 1),
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, toast_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let text = Paragraph::new(Line::styled(format!(" {}", self.message), Style::default().fg(Theme::FG)))
+            .block(block);
+        frame.render_widget(text, toast_area);
+    }
+}
@@ -0,0 +1,130 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+};
+
+use crate::trivy::{ScanReport, Severity};
+use crate::ui::{centered_modal, Theme};
+
+/// Visible table rows
+const PAGE: usize = 17;
+
+/// Vulnerability report of an image, from a trivy scan
+#[derive(Debug, Clone)]
+pub struct VulnModal {
+    pub report: ScanReport,
+    pub scroll: usize,
+}
+
+impl VulnModal {
+    pub fn new(report: ScanReport) -> Self {
+        Self { report, scroll: 0 }
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        let max_scroll = self.report.vulnerabilities.len().saturating_sub(PAGE);
+        self.scroll = (self.scroll + lines).min(max_scroll);
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = self.report.vulnerabilities.len().saturating_sub(PAGE);
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let modal_area = centered_modal(area, 110, 22);
+
+        // Clear background
+        frame.render_widget(Clear, modal_area);
+
+        let counts = self.report.counts();
+        let block = Block::default()
+            .title(format!(
+                " Vulnerabilities: {} ({} critical, {} high, {} medium) ",
+                self.report.image, counts.critical, counts.high, counts.medium
+            ))
+            .title_style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MAGENTA))
+            .style(Style::default().bg(Theme::BG_DARK));
+
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        if self.report.vulnerabilities.is_empty() {
+            let msg = Paragraph::new("No known vulnerabilities in this image")
+                .style(Style::default().fg(Theme::GREEN))
+                .alignment(Alignment::Center);
+            frame.render_widget(msg, inner);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(2)])
+            .split(inner);
+
+        let header_row = Row::new(vec!["SEVERITY", "ID", "PACKAGE", "INSTALLED", "FIXED IN", "TITLE"])
+            .style(Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = self.report.vulnerabilities
+            .iter()
+            .skip(self.scroll)
+            .take(PAGE)
+            .map(|v| {
+                Row::new(vec![
+                    Cell::from(v.severity.as_str()).style(Style::default().fg(severity_color(v.severity))),
+                    Cell::from(v.id.clone()).style(Style::default().fg(Theme::FG)),
+                    Cell::from(v.package.clone()).style(Style::default().fg(Theme::LAVENDER)),
+                    Cell::from(v.installed.clone()).style(Style::default().fg(Theme::FG_DARK)),
+                    match v.fixed {
+                        Some(ref fixed) => Cell::from(fixed.clone()).style(Style::default().fg(Theme::GREEN)),
+                        None => Cell::from("-").style(Style::default().fg(Theme::FG_DARK)),
+                    },
+                    Cell::from(v.title.clone()).style(Style::default().fg(Theme::FG_DARK)),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(8),  // SEVERITY
+            Constraint::Length(19), // ID
+            Constraint::Length(18), // PACKAGE
+            Constraint::Length(14), // INSTALLED
+            Constraint::Length(14), // FIXED IN
+            Constraint::Min(20),    // TITLE (flexible)
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header_row)
+            .column_spacing(1);
+
+        frame.render_widget(table, chunks[0]);
+
+        let instructions = Line::from(vec![
+            Span::styled(" ↑↓ ", Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
+            Span::styled("scroll   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" r ", Style::default().fg(Theme::YELLOW).add_modifier(Modifier::BOLD)),
+            Span::styled("rescan   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(" Esc ", Style::default().fg(Theme::RED).add_modifier(Modifier::BOLD)),
+            Span::styled("close   ", Style::default().fg(Theme::FG_DARK)),
+            Span::styled(
+                format!(" [{}/{}] ", self.scroll + 1, self.report.vulnerabilities.len()),
+                Style::default().fg(Theme::FG_DARK),
+            ),
+        ]);
+        frame.render_widget(Paragraph::new(instructions).alignment(Alignment::Center), chunks[1]);
+    }
+}
+
+pub fn severity_color(severity: Severity) -> Color {
+    match severity {
+        Severity::Critical => Theme::RED,
+        Severity::High => Theme::ORANGE,
+        Severity::Medium => Theme::YELLOW,
+        Severity::Low | Severity::Unknown => Theme::FG_DARK,
+    }
+}
@@ -0,0 +1,96 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::models::ContainerInfo;
+use crate::ui::{centered_modal, Theme};
+
+/// Container value that can be copied to the clipboard
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum YankTarget {
+    Id,
+    Name,
+    Image,
+    Port,
+    Ip,
+    ExecCommand,
+}
+
+impl YankTarget {
+    pub const ALL: [YankTarget; 6] = [Self::Id, Self::Name, Self::Image, Self::Port, Self::Ip, Self::ExecCommand];
+
+    /// Key that picks this target in the menu
+    pub fn key(&self) -> char {
+        match self {
+            Self::Id => 'i',
+            Self::Name => 'n',
+            Self::Image => 'm',
+            Self::Port => 'p',
+            Self::Ip => 'a',
+            Self::ExecCommand => 'e',
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Id => "ID",
+            Self::Name => "Name",
+            Self::Image => "Image",
+            Self::Port => "First port",
+            Self::Ip => "IP address",
+            Self::ExecCommand => "Exec command",
+        }
+    }
+
+    pub fn from_key(c: char) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.key() == c)
+    }
+
+    /// The value to copy, None if the container doesn't have one
+    pub fn value(&self, c: &ContainerInfo) -> Option<String> {
+        match self {
+            Self::Id => (!c.id.is_empty()).then(|| c.id.clone()),
+            Self::Name => Some(c.name.clone()),
+            Self::Image => (!c.image.is_empty()).then(|| c.image.clone()),
+            Self::Port => c.ports.first().map(|p| match p.host_port {
+                Some(host_port) => format!("localhost:{}", host_port),
+                None => format!("{}/{}", p.container_port, p.protocol),
+            }),
+            Self::Ip => c.ip().map(str::to_string),
+            Self::ExecCommand => Some(format!("docker exec -it {} sh", c.name)),
+        }
+    }
+}
+
+/// Menu for copying a container value (opened with y)
+pub struct YankMenu;
+
+impl YankMenu {
+    pub fn render(frame: &mut Frame, area: Rect, container: Option<&ContainerInfo>) {
+        let modal_area = centered_modal(area, 50, YankTarget::ALL.len() as u16 + 4);
+
+        // Clear the background
+        frame.render_widget(Clear, modal_area);
+
+        let mut lines = vec![Line::raw("")];
+        for target in YankTarget::ALL {
+            let value = container.and_then(|c| target.value(c));
+            let value_style = if value.is_some() { Theme::FG } else { Theme::FG_DARK };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}  ", target.key()), Style::default().fg(Theme::YELLOW)),
+                Span::styled(format!("{:<14}", target.label()), Style::default().fg(Theme::FG_DARK)),
+                Span::styled(value.unwrap_or_else(|| "-".to_string()), Style::default().fg(value_style)),
+            ]));
+        }
+
+        let block = Block::default()
+            .title(" Copy to clipboard ")
+            .title_bottom(Line::styled(" Esc cancel ", Style::default().fg(Theme::FG_DARK)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::MODAL_BORDER))
+            .style(Style::default().bg(Theme::MODAL_BG));
+
+        frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+    }
+}
@@ -1,8 +1,18 @@
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::SystemTime;
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::alerts::AlertConfig;
+use crate::columns::ColumnsConfig;
+use crate::schedule::{ScheduleRule, Scheduler};
+use crate::ui::ThemeName;
 
 /// Build configuration for Dockerfile-based projects
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -65,6 +75,25 @@ impl ProjectManifest {
         Ok(manifest)
     }
 
+    /// Parse and check a manifest, reporting where the first problem is
+    pub fn check(path: &Path, content: &str) -> std::result::Result<Self, ConfigIssue> {
+        let manifest: ProjectManifest = serde_yaml::from_str(content).map_err(|e| {
+            let location = e.location();
+            let message = e.to_string();
+            ConfigIssue {
+                file: path.to_path_buf(),
+                line: location.as_ref().map(|l| l.line()),
+                column: location.as_ref().map(|l| l.column()),
+                // The position is reported separately
+                message: message.split(" at line ").next().unwrap_or(&message).to_string(),
+            }
+        })?;
+        if manifest.image.is_none() && manifest.build.is_none() {
+            return Err(ConfigIssue::new(path, "Set either image or build"));
+        }
+        Ok(manifest)
+    }
+
     /// Check if this is a CLI container (no port, typically "sleep infinity")
     pub fn is_cli(&self) -> bool {
         self.port.is_none()
@@ -76,9 +105,11 @@ impl ProjectManifest {
     }
 }
 
-/// Scan for all project manifests in the base directory
-pub fn scan_projects<P: AsRef<Path>>(base_dir: P) -> Result<Vec<ProjectManifest>> {
+/// Scan for all project manifests in the base directory; the ones that
+/// don't load are returned as issues
+pub fn scan_projects<P: AsRef<Path>>(base_dir: P) -> Result<(Vec<ProjectManifest>, Vec<ConfigIssue>)> {
     let mut manifests = Vec::new();
+    let mut issues = Vec::new();
 
     let entries = fs::read_dir(base_dir.as_ref())
         .context(format!("Failed to read directory: {:?}", base_dir.as_ref()))?;
@@ -90,12 +121,12 @@ pub fn scan_projects<P: AsRef<Path>>(base_dir: P) -> Result<Vec<ProjectManifest>
         if path.is_dir() {
             let manifest_path = path.join("project.yaml");
             if manifest_path.exists() {
-                match ProjectManifest::load(&manifest_path) {
+                let checked = fs::read_to_string(&manifest_path)
+                    .map_err(|e| ConfigIssue::new(&manifest_path, &format!("Failed to read: {}", e)))
+                    .and_then(|content| ProjectManifest::check(&manifest_path, &content));
+                match checked {
                     Ok(manifest) => manifests.push(manifest),
-                    Err(e) => {
-                        // Log but don't fail on individual manifest errors
-                        eprintln!("Warning: Failed to load {:?}: {}", manifest_path, e);
-                    }
+                    Err(issue) => issues.push(issue),
                 }
             }
         }
@@ -103,6 +134,457 @@ pub fn scan_projects<P: AsRef<Path>>(base_dir: P) -> Result<Vec<ProjectManifest>
 
     // Sort by project name
     manifests.sort_by(|a, b| a.project.cmp(&b.project));
+    issues.sort_by(|a, b| a.file.cmp(&b.file));
+
+    Ok((manifests, issues))
+}
+
+/// A problem in a config file or manifest, with its position when known
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigIssue {
+    pub file: PathBuf,
+    /// 1-based
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    pub fn new(file: &Path, message: &str) -> Self {
+        Self { file: file.to_path_buf(), line: None, column: None, message: message.to_string() }
+    }
+
+    /// "path:line:column", as far as it is known
+    pub fn position(&self) -> String {
+        let mut position = self.file.display().to_string();
+        for n in [self.line, self.column].into_iter().flatten() {
+            position.push_str(&format!(":{}", n));
+        }
+        position
+    }
+}
+
+impl fmt::Display for ConfigIssue {
+    /// "path:line:column: message", like compiler errors
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.position(), self.message)
+    }
+}
+
+/// 1-based line and column of a byte offset
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+/// Directory for persistent app data ($XDG_DATA_HOME/backplane-tui or ~/.local/share/backplane-tui)
+pub fn data_dir() -> Option<PathBuf> {
+    let base = match std::env::var_os("XDG_DATA_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".local").join("share"),
+    };
+    Some(base.join("backplane-tui"))
+}
+
+/// Directory for user configuration ($XDG_CONFIG_HOME/backplane-tui or ~/.config/backplane-tui)
+pub fn config_dir() -> Option<PathBuf> {
+    let base = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(base.join("backplane-tui"))
+}
+
+/// Grace periods before Docker kills a container being stopped or restarted
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StopConfig {
+    /// Seconds between SIGTERM and SIGKILL on stop
+    pub timeout_secs: i64,
+    /// Seconds between SIGTERM and SIGKILL on restart
+    pub restart_timeout_secs: i64,
+    /// Per-container overrides of both, by name
+    pub containers: HashMap<String, i64>,
+}
+
+impl Default for StopConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: 10,
+            restart_timeout_secs: 10,
+            containers: HashMap::new(),
+        }
+    }
+}
+
+impl StopConfig {
+    /// Grace period for stopping (or restarting) a container
+    pub fn timeout(&self, name: &str, restart: bool) -> i64 {
+        match self.containers.get(name) {
+            Some(&secs) => secs,
+            None if restart => self.restart_timeout_secs,
+            None => self.timeout_secs,
+        }
+    }
+}
+
+/// Per-action confirmation toggles; set one to false to skip its prompt
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ConfirmConfig {
+    pub delete: bool,
+    pub stop: bool,
+    pub restart: bool,
+    pub compose_down: bool,
+    pub prune: bool,
+    pub batch: bool,
+}
+
+impl Default for ConfirmConfig {
+    fn default() -> Self {
+        Self {
+            delete: true,
+            stop: true,
+            restart: true,
+            compose_down: true,
+            prune: true,
+            batch: true,
+        }
+    }
+}
+
+/// Cleanup wizard settings
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CleanupConfig {
+    /// Only offer exited containers that stopped at least this many days ago
+    pub container_age_days: u64,
+}
+
+impl Default for CleanupConfig {
+    fn default() -> Self {
+        Self { container_age_days: 7 }
+    }
+}
+
+/// Polling intervals per data source, in milliseconds
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RefreshConfig {
+    /// Container list (overridden by --refresh-ms)
+    pub containers_ms: u64,
+    /// Per-container CPU/memory/network stats
+    pub stats_ms: u64,
+    /// nvidia-smi queries for VRAM and per-container GPU usage
+    pub vram_ms: u64,
+    /// Log tailing in the logs view
+    pub logs_ms: u64,
+    /// Minutes without a key press before polling slows down (0 = never)
+    pub idle_minutes: u64,
+    /// Interval multiplier while idle
+    pub idle_slowdown: f64,
+    /// Hours between registry checks for newer images (0 = only on demand)
+    pub image_updates_hours: u64,
+}
+
+impl Default for RefreshConfig {
+    fn default() -> Self {
+        Self {
+            containers_ms: 3000,
+            stats_ms: 2000,
+            vram_ms: 5000,
+            logs_ms: 2000,
+            idle_minutes: 5,
+            idle_slowdown: 4.0,
+            image_updates_hours: 0,
+        }
+    }
+}
+
+/// How much log history the logs view fetches and keeps
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LogsConfig {
+    /// Lines fetched when the view opens (`m` fetches more)
+    pub tail: usize,
+    /// Lines kept while following; older ones are dropped
+    pub max_lines: usize,
+    /// Memory kept for lines, in MB
+    pub max_mb: usize,
+}
 
-    Ok(manifests)
+impl Default for LogsConfig {
+    fn default() -> Self {
+        Self { tail: 500, max_lines: 10_000, max_mb: 8 }
+    }
+}
+
+impl LogsConfig {
+    pub fn max_bytes(&self) -> usize {
+        self.max_mb * 1024 * 1024
+    }
+}
+
+/// Exec session recording (asciinema v2 cast files)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RecordingConfig {
+    pub enabled: bool,
+    /// Where casts are written (default: recordings/ in the data directory)
+    pub dir: Option<PathBuf>,
+}
+
+impl RecordingConfig {
+    /// Directory for new recordings, if recording is on
+    pub fn target_dir(&self) -> Option<PathBuf> {
+        if !self.enabled {
+            return None;
+        }
+        self.dir.clone().or_else(|| data_dir().map(|dir| dir.join("recordings")))
+    }
+}
+
+/// Credentials for a registry host, from `[registries."host"]` or the system keyring
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RegistryAuth {
+    pub username: String,
+    pub password: String,
+}
+
+/// User settings from config.toml in the config directory
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    pub confirm: ConfirmConfig,
+    pub stop: StopConfig,
+    pub alerts: AlertConfig,
+    pub cleanup: CleanupConfig,
+    pub refresh: RefreshConfig,
+    pub logs: LogsConfig,
+    pub recording: RecordingConfig,
+    /// Container list columns per view mode
+    pub columns: ColumnsConfig,
+    /// Color theme; --theme wins over it
+    pub theme: Option<ThemeName>,
+    /// Disable every action that changes containers, images or the daemon
+    pub read_only: bool,
+    /// ASCII glyphs, a high-contrast palette and no animations
+    pub accessible: bool,
+    /// No fades, flashes or pulses, a still spinner and a slower redraw loop
+    pub reduced_motion: bool,
+    /// Registry credentials keyed by host ("docker.io", "ghcr.io", ...)
+    pub registries: HashMap<String, RegistryAuth>,
+    /// Actions run at set times while the TUI is open, `[[schedule]]` entries
+    pub schedule: Vec<ScheduleRule>,
+    /// Directory whose subdirectories hold project.yaml manifests
+    pub projects_dir: Option<PathBuf>,
+}
+
+impl AppConfig {
+    /// Parse and check config.toml, reporting where the first problem is.
+    /// Schedules are checked too, so a bad one doesn't surface later.
+    pub fn check(path: &Path, content: &str) -> std::result::Result<Self, ConfigIssue> {
+        let config: Self = toml::from_str(content).map_err(|e| {
+            let (line, column) = match e.span() {
+                Some(span) => line_column(content, span.start),
+                None => (0, 0),
+            };
+            ConfigIssue {
+                file: path.to_path_buf(),
+                line: (line > 0).then_some(line),
+                column: (column > 0).then_some(column),
+                message: e.message().to_string(),
+            }
+        })?;
+        if let Err(e) = Scheduler::new(&config.schedule, chrono::Local::now()) {
+            // "Invalid schedule entry N: ...": point at the Nth [[schedule]]
+            let entry = e.to_string().rsplit(' ').next().and_then(|n| n.parse::<usize>().ok()).unwrap_or(1);
+            let line = content.lines().enumerate().filter(|(_, l)| l.trim() == "[[schedule]]").nth(entry - 1);
+            return Err(ConfigIssue {
+                file: path.to_path_buf(),
+                line: line.map(|(i, _)| i + 1),
+                column: None,
+                message: format!("{:#}", e),
+            });
+        }
+        Ok(config)
+    }
+
+    /// Like `check` on a file; a missing file is the defaults
+    pub fn check_file(path: &Path) -> std::result::Result<Self, ConfigIssue> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path).map_err(|e| ConfigIssue::new(path, &format!("Failed to read: {}", e)))?;
+        Self::check(path, &content)
+    }
+
+    /// The config to start with, and what's wrong with the config file and
+    /// the project manifests. A config that fails the check is replaced by
+    /// the defaults.
+    pub fn load_checked() -> (Self, Vec<ConfigIssue>) {
+        let mut issues = Vec::new();
+        let config = match config_path() {
+            Some(path) => Self::check_file(&path).unwrap_or_else(|issue| {
+                issues.push(issue);
+                Self::default()
+            }),
+            None => Self::default(),
+        };
+        if let Some(ref dir) = config.projects_dir {
+            match scan_projects(dir) {
+                Ok((_, manifest_issues)) => issues.extend(manifest_issues),
+                Err(e) => issues.push(ConfigIssue::new(dir, &format!("{:#}", e))),
+            }
+        }
+        (config, issues)
+    }
+}
+
+/// config.toml in the default config directory
+pub fn config_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("config.toml"))
+}
+
+/// Notices edits to the config file through `notify`, watching its
+/// directory so editors that replace the file are seen too
+#[derive(Debug)]
+pub struct ConfigWatch {
+    pub path: PathBuf,
+    /// The watcher (kept alive) and its events. None when the directory
+    /// can't be watched (it doesn't exist yet): the modification time is
+    /// compared instead
+    events: Option<(RecommendedWatcher, Receiver<()>)>,
+    modified: Option<SystemTime>,
+}
+
+impl ConfigWatch {
+    pub fn new(path: PathBuf) -> Self {
+        let modified = modified(&path);
+        let events = watch(&path).ok();
+        Self { path, events, modified }
+    }
+
+    /// Whether the file was written, created or removed since the last call
+    pub fn changed(&mut self) -> bool {
+        if let Some((_, ref events)) = self.events {
+            return events.try_iter().count() > 0;
+        }
+        let modified = modified(&self.path);
+        let changed = modified != self.modified;
+        self.modified = modified;
+        changed
+    }
+}
+
+/// Watch the file's directory, passing on events for the file itself.
+/// Reads are left out, or reloading would trigger the next reload
+fn watch(path: &Path) -> notify::Result<(RecommendedWatcher, Receiver<()>)> {
+    let (tx, rx) = mpsc::channel();
+    let name = path.file_name().map(|name| name.to_os_string());
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        if !matches!(event.kind, EventKind::Access(_)) && event.paths.iter().any(|p| p.file_name() == name.as_deref()) {
+            let _ = tx.send(());
+        }
+    })?;
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    Ok((watcher, rx))
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_config_keeps_defaults() {
+        let config: AppConfig = toml::from_str("[confirm]\nrestart = false\n\n[alerts]\ncpu_percent = 75.0\n").unwrap();
+        assert!(!config.confirm.restart);
+        assert!(config.confirm.delete);
+        assert_eq!(config.alerts.cpu_percent, 75.0);
+        assert_eq!(config.alerts.cpu_duration_secs, 30);
+        assert_eq!(config.theme, None);
+
+        let config: AppConfig = toml::from_str("theme = \"Latte\"\n").unwrap();
+        assert_eq!(config.theme, Some(ThemeName::Latte));
+        assert!(toml::from_str::<AppConfig>("theme = \"neon\"\n").is_err());
+    }
+
+    #[test]
+    fn test_check_reports_positions() {
+        let path = Path::new("config.toml");
+        let issue = AppConfig::check(path, "read_only = false\n\n[refresh]\nstats_ms = \"fast\"\n").unwrap_err();
+        assert_eq!((issue.line, issue.column), (Some(4), Some(12)));
+        assert!(issue.to_string().starts_with("config.toml:4:12: "));
+
+        let schedule = "[[schedule]]\naction = \"stop\"\ncontainer = \"web\"\nat = \"22:00\"\n\n[[schedule]]\naction = \"start\"\ncontainer = \"web\"\nat = \"7am\"\n";
+        let issue = AppConfig::check(path, schedule).unwrap_err();
+        assert_eq!(issue.line, Some(6));
+        assert!(issue.message.contains("HH:MM"));
+
+        let manifest = Path::new("project.yaml");
+        let issue = ProjectManifest::check(manifest, "project: web\nport: http\nimage: nginx\n").unwrap_err();
+        assert_eq!(issue.line, Some(2));
+        assert!(!issue.message.contains("at line"));
+        assert!(ProjectManifest::check(manifest, "project: web\n").is_err());
+        assert!(ProjectManifest::check(manifest, "project: web\nimage: nginx\n").is_ok());
+    }
+
+    #[test]
+    fn test_config_watch() {
+        // Events arrive from the watcher's thread
+        fn changed_soon(watch: &mut ConfigWatch) -> bool {
+            (0..50).any(|_| {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                watch.changed()
+            })
+        }
+
+        let path = std::env::temp_dir().join(format!("backplane-config-watch-{}.toml", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut watch = ConfigWatch::new(path.clone());
+        assert!(watch.events.is_some());
+        assert!(!watch.changed());
+
+        fs::write(&path, "read_only = true\n").unwrap();
+        assert!(changed_soon(&mut watch));
+        // The rest of the write's events, then reads and other files are ignored
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        watch.changed();
+        fs::read_to_string(&path).unwrap();
+        fs::write(path.with_extension("other"), "").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        assert!(!watch.changed());
+
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(path.with_extension("other")).unwrap();
+        assert!(changed_soon(&mut watch));
+
+        // Without a watchable directory the modification time is compared
+        let mut watch = ConfigWatch::new(std::env::temp_dir().join("backplane-no-such-dir").join("config.toml"));
+        assert!(watch.events.is_none());
+        assert!(!watch.changed());
+    }
+
+    #[test]
+    fn test_stop_timeouts() {
+        let config: AppConfig = toml::from_str("[stop]
+timeout_secs = 20
+
+[stop.containers]
+postgres = 60
+").unwrap();
+        assert_eq!(config.stop.timeout("web", false), 20);
+        assert_eq!(config.stop.timeout("web", true), 10);
+        assert_eq!(config.stop.timeout("postgres", true), 60);
+    }
 }
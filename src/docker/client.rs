@@ -1,19 +1,64 @@
 use anyhow::{Context, Result};
 use bollard::container::{
-    Config, CreateContainerOptions, ListContainersOptions, RemoveContainerOptions,
-    RenameContainerOptions, RestartContainerOptions, StartContainerOptions, StopContainerOptions,
-    TopOptions,
+    AttachContainerOptions, Config, CreateContainerOptions, InspectContainerOptions, ListContainersOptions, NetworkingConfig,
+    PruneContainersOptions, RemoveContainerOptions, RenameContainerOptions, RestartContainerOptions, StartContainerOptions,
+    StopContainerOptions, TopOptions, WaitContainerOptions,
 };
-use bollard::image::ListImagesOptions;
-use bollard::models::{HostConfig, PortBinding};
-use bollard::Docker;
+use bollard::exec::{CreateExecOptions, StartExecResults};
+use bollard::image::{CreateImageOptions, ListImagesOptions, RemoveImageOptions};
+use bollard::network::ListNetworksOptions;
+use bollard::service::{ListServicesOptions, UpdateServiceOptions};
+use bollard::volume::RemoveVolumeOptions;
+use bollard::models::{
+    ChangeType, ContainerInspectResponse, DeviceMapping, DeviceRequest, EndpointSettings, HostConfig, ImageConfig, MountPoint,
+    MountPointTypeEnum, PortBinding,
+};
+use bollard::auth::DockerCredentials;
+use bollard::{Docker, API_DEFAULT_VERSION};
+use futures_util::StreamExt;
 use std::collections::HashMap;
+use std::process::Command;
+
+use crate::models::{
+    ChangeKind, CleanupItem, CleanupKind, ContainerInfo, RemovalPreview, ContainerStatus, DaemonInfo, FileChange, GpuAccess, NetworkAddress, ImageLayer, LabelEdit, PortMapping,
+    RunState, RuntimeInfo, ServiceInfo,
+};
+use crate::config::RegistryAuth;
+use crate::models::cleanup::is_anonymous_volume;
+use crate::recycle::DeletedContainer;
+use crate::scaffold::ProjectSource;
 
-use crate::models::{ContainerInfo, ContainerStatus, PortMapping};
+/// Settings for a new container, as parsed from the create form
+#[derive(Debug, Clone, Default)]
+pub struct ContainerSpec {
+    pub name: String,
+    pub image: String,
+    pub port_host: Option<u16>,
+    pub port_container: Option<u16>,
+    pub env_vars: Vec<String>,
+    pub volumes: Vec<String>,
+    pub command: Option<String>,
+    /// Request all GPUs (`--gpus all`)
+    pub gpu: bool,
+    /// Device mounts, `host[:container[:permissions]]` as in project.yaml
+    pub devices: Vec<String>,
+    /// Memory limit in bytes
+    pub memory: Option<i64>,
+    /// CPU limit in billionths of a core
+    pub nano_cpus: Option<i64>,
+    /// Network to attach to instead of the default bridge
+    pub network: Option<String>,
+    pub hostname: Option<String>,
+    /// DNS servers
+    pub dns: Vec<String>,
+}
 
 /// Wrapper around the bollard Docker client
+#[derive(Clone)]
 pub struct DockerClient {
     client: Docker,
+    /// Daemon URL for docker CLI calls (None = the CLI's default)
+    host: Option<String>,
 }
 
 impl DockerClient {
@@ -21,7 +66,33 @@ impl DockerClient {
     pub fn connect() -> Result<Self> {
         let client = Docker::connect_with_socket_defaults()
             .context("Failed to connect to Docker daemon")?;
-        Ok(Self { client })
+        Ok(Self { client, host: None })
+    }
+
+    /// Connect to a specific Docker host, or the local socket when `None`.
+    /// Accepts unix://, tcp:// and http:// URLs, or a docker context name.
+    pub fn connect_with_host(host: Option<&str>) -> Result<Self> {
+        let host = match host {
+            Some(h) => h,
+            None => return Self::connect(),
+        };
+
+        let url = if host.contains("://") {
+            host.to_string()
+        } else {
+            resolve_context_host(host)?
+        };
+
+        let client = if url.starts_with("unix://") {
+            Docker::connect_with_unix(&url, 120, API_DEFAULT_VERSION)
+        } else if url.starts_with("tcp://") || url.starts_with("http://") {
+            Docker::connect_with_http(&url, 120, API_DEFAULT_VERSION)
+        } else {
+            anyhow::bail!("Unsupported Docker host: {}", url);
+        }
+        .context(format!("Failed to connect to Docker host: {}", url))?;
+
+        Ok(Self { client, host: Some(url) })
     }
 
     /// Get the underlying bollard client (for stats/logs streaming)
@@ -29,6 +100,226 @@ impl DockerClient {
         &self.client
     }
 
+    /// The Docker host URL, or `None` for the local socket
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    /// Run a docker CLI command against the connected daemon, returning stdout
+    async fn docker_cli(&self, args: &[&str]) -> Result<String> {
+        let mut command = tokio::process::Command::new("docker");
+        if let Some(ref host) = self.host {
+            command.args(["-H", host]);
+        }
+        let output = command
+            .args(args)
+            .output()
+            .await
+            .context("Failed to run docker CLI")?;
+
+        if !output.status.success() {
+            anyhow::bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Copy files between a container and the host (`docker cp`); container
+    /// paths are given as `name:path`
+    pub async fn copy_files(&self, from: &str, to: &str) -> Result<()> {
+        self.docker_cli(&["cp", from, to])
+            .await
+            .context(format!("Failed to copy {} to {}", from, to))?;
+        Ok(())
+    }
+
+    /// Engine version and setup, plus recent errors from the dockerd journal
+    /// when the daemon is local and the journal readable
+    pub async fn daemon_info(&self) -> Result<DaemonInfo> {
+        let version = self.client.version().await.context("Failed to get daemon version")?;
+        let info = self.client.info().await.context("Failed to get daemon info")?;
+
+        let cgroup = match (info.cgroup_version, info.cgroup_driver) {
+            (Some(version), Some(driver)) => format!("v{} ({})", version, driver),
+            (Some(version), None) => format!("v{}", version),
+            _ => "-".to_string(),
+        };
+        let (journal, journal_note) = if self.host.is_some() {
+            (Vec::new(), Some("Journal is only read for a local daemon".to_string()))
+        } else {
+            match journal_errors().await {
+                Ok(lines) => (lines, None),
+                Err(e) => (Vec::new(), Some(format!("{:#}", e))),
+            }
+        };
+        Ok(DaemonInfo {
+            version: version.version.unwrap_or_default(),
+            api_version: version.api_version.unwrap_or_default(),
+            storage_driver: info.driver.unwrap_or_default(),
+            cgroup,
+            os: info.operating_system.unwrap_or_default(),
+            kernel: info.kernel_version.unwrap_or_default(),
+            warnings: info.warnings.unwrap_or_default(),
+            journal,
+            journal_note,
+        })
+    }
+
+    /// Whether the daemon is a Swarm manager (services can only be listed there)
+    pub async fn is_swarm_manager(&self) -> Result<bool> {
+        let info = self.client.info().await.context("Failed to get daemon info")?;
+        Ok(info.swarm.and_then(|s| s.control_available).unwrap_or(false))
+    }
+
+    /// Swarm services with running/desired task counts
+    pub async fn list_services(&self) -> Result<Vec<ServiceInfo>> {
+        let options = ListServicesOptions::<String> {
+            status: true,
+            ..Default::default()
+        };
+        let services = self.client
+            .list_services(Some(options))
+            .await
+            .context("Failed to list services")?;
+
+        let mut result: Vec<ServiceInfo> = services
+            .into_iter()
+            .map(|service| {
+                let spec = service.spec.unwrap_or_default();
+                let mode = spec.mode.unwrap_or_default();
+                let mode_name = if mode.global.is_some() {
+                    "global"
+                } else if mode.replicated_job.is_some() {
+                    "replicated-job"
+                } else if mode.global_job.is_some() {
+                    "global-job"
+                } else {
+                    "replicated"
+                };
+                let status = service.service_status.unwrap_or_default();
+                let update = service.update_status;
+                ServiceInfo {
+                    id: service.id.unwrap_or_default(),
+                    name: spec.name.unwrap_or_default(),
+                    image: spec
+                        .task_template
+                        .and_then(|t| t.container_spec)
+                        .and_then(|c| c.image)
+                        // Drop the pinned digest Swarm appends
+                        .map(|image| image.split('@').next().unwrap_or_default().to_string())
+                        .unwrap_or_default(),
+                    mode: mode_name.to_string(),
+                    desired: status.desired_tasks.unwrap_or(0),
+                    running: status.running_tasks.unwrap_or(0),
+                    update_state: update
+                        .as_ref()
+                        .and_then(|u| u.state)
+                        .map(|s| s.to_string())
+                        .filter(|s| !s.is_empty()),
+                    update_message: update.and_then(|u| u.message),
+                }
+            })
+            .collect();
+
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(result)
+    }
+
+    /// Set the replica count of a replicated service
+    pub async fn scale_service(&self, id: &str, replicas: u64) -> Result<()> {
+        self.update_service_spec(id, |spec| {
+            let replicated = spec
+                .mode
+                .as_mut()
+                .and_then(|m| m.replicated.as_mut())
+                .context("Only replicated services can be scaled")?;
+            replicated.replicas = Some(replicas as i64);
+            Ok(())
+        })
+        .await
+    }
+
+    /// Redeploy every task of a service (rolling, per its update config)
+    pub async fn force_update_service(&self, id: &str) -> Result<()> {
+        self.update_service_spec(id, |spec| {
+            let template = spec.task_template.get_or_insert_with(Default::default);
+            template.force_update = Some(template.force_update.unwrap_or(0) + 1);
+            Ok(())
+        })
+        .await
+    }
+
+    /// Inspect a service, edit its spec and submit it at the inspected version
+    async fn update_service_spec(
+        &self,
+        id: &str,
+        edit: impl FnOnce(&mut bollard::models::ServiceSpec) -> Result<()>,
+    ) -> Result<()> {
+        let service = self.client
+            .inspect_service(id, None)
+            .await
+            .context(format!("Failed to inspect service: {}", id))?;
+        let version = service
+            .version
+            .and_then(|v| v.index)
+            .context("Service has no version")?;
+        let mut spec = service.spec.unwrap_or_default();
+        edit(&mut spec)?;
+
+        let options = UpdateServiceOptions {
+            version,
+            ..Default::default()
+        };
+        self.client
+            .update_service(id, spec, options, None)
+            .await
+            .context(format!("Failed to update service: {}", id))?;
+        Ok(())
+    }
+
+    /// Checkpoints of a container (needs an experimental daemon with CRIU)
+    pub async fn list_checkpoints(&self, name: &str) -> Result<Vec<String>> {
+        let output = self
+            .docker_cli(&["checkpoint", "ls", name])
+            .await
+            .context(format!("Failed to list checkpoints of {}", name))?;
+        // First line is the "CHECKPOINT NAME" header
+        Ok(output
+            .lines()
+            .skip(1)
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    /// Checkpoint a container; it is stopped unless `leave_running`
+    pub async fn create_checkpoint(&self, name: &str, checkpoint: &str, leave_running: bool) -> Result<()> {
+        let mut args = vec!["checkpoint", "create"];
+        if leave_running {
+            args.push("--leave-running");
+        }
+        args.extend([name, checkpoint]);
+        self.docker_cli(&args)
+            .await
+            .context(format!("Failed to checkpoint {}", name))?;
+        Ok(())
+    }
+
+    /// Start a stopped container from a checkpoint
+    pub async fn restore_checkpoint(&self, name: &str, checkpoint: &str) -> Result<()> {
+        self.docker_cli(&["start", "--checkpoint", checkpoint, name])
+            .await
+            .context(format!("Failed to restore {} from {}", name, checkpoint))?;
+        Ok(())
+    }
+
+    pub async fn remove_checkpoint(&self, name: &str, checkpoint: &str) -> Result<()> {
+        self.docker_cli(&["checkpoint", "rm", name, checkpoint])
+            .await
+            .context(format!("Failed to remove checkpoint {}", checkpoint))?;
+        Ok(())
+    }
+
     /// List all containers (running and stopped)
     pub async fn list_containers(&self) -> Result<Vec<ContainerInfo>> {
         let mut filters = HashMap::new();
@@ -89,16 +380,37 @@ impl DockerClient {
             let has_exposed_ports = ports.iter().any(|p| p.host_port.is_some());
             let is_cli = !has_exposed_ports;
 
-            // Extract compose project from labels
-            let compose_project = container
-                .labels
-                .as_ref()
-                .and_then(|labels| labels.get("com.docker.compose.project").cloned());
+            // Extract compose project, service and dependencies from labels
+            let label = |key: &str| container.labels.as_ref().and_then(|labels| labels.get(key).cloned());
+            let compose_project = label("com.docker.compose.project");
+            let compose_service = label("com.docker.compose.service");
+            let depends_on = label("com.docker.compose.depends_on").map(|l| parse_depends_on(&l)).unwrap_or_default();
+            let compose_files = label("com.docker.compose.project.config_files")
+                .map(|l| parse_compose_files(&l, label("com.docker.compose.project.working_dir").as_deref()))
+                .unwrap_or_default();
+
+            // Health and exit code are only reported in the human-readable status
+            let status_text = container.status.unwrap_or_default();
+
+            // IPs per network; aliases come from inspect
+            let mut networks: Vec<NetworkAddress> = container
+                .network_settings
+                .and_then(|s| s.networks)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(network, endpoint)| NetworkAddress {
+                    network,
+                    ip: endpoint.ip_address.unwrap_or_default(),
+                    aliases: Vec::new(),
+                })
+                .collect();
+            networks.sort_by(|a, b| a.network.cmp(&b.network));
 
             result.push(ContainerInfo {
                 id: container.id.unwrap_or_default(),
                 name,
                 image,
+                image_id: container.image_id.unwrap_or_default(),
                 status,
                 is_cli,
                 port: first_port,
@@ -106,6 +418,18 @@ impl DockerClient {
                 stats: None,
                 created: container.created,
                 compose_project,
+                compose_service,
+                depends_on,
+                compose_files,
+                health: parse_health(&status_text),
+                exit_code: parse_exit_code(&status_text),
+                oom_killed: false,
+                finished_at: None,
+                started_at: None,
+                max_uptime: parse_max_uptime(&status_text),
+                restart_count: 0,
+                networks,
+                gpus: GpuAccess::None,
             });
         }
 
@@ -122,6 +446,206 @@ impl DockerClient {
         Ok(result)
     }
 
+    /// Get start/finish times, exit code, OOM kill flag, restart count,
+    /// network aliases and GPU access via inspect
+    pub async fn inspect_state(&self, name: &str) -> Result<RunState> {
+        let info = self.client
+            .inspect_container(name, None::<InspectContainerOptions>)
+            .await
+            .context(format!("Failed to inspect container: {}", name))?;
+
+        let name = info.name.as_deref().unwrap_or(name).trim_start_matches('/');
+        let id = info.id.as_deref().unwrap_or_default();
+        let aliases = info
+            .network_settings
+            .and_then(|s| s.networks)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(network, endpoint)| {
+                let names = endpoint.aliases.into_iter().flatten().chain(endpoint.dns_names.into_iter().flatten());
+                (network, dns_aliases(names, name, id))
+            })
+            .collect();
+
+        let env = info.config.and_then(|c| c.env).unwrap_or_default();
+        let gpus = gpu_access(info.host_config.as_ref(), &env);
+
+        let state = info.state.unwrap_or_default();
+        Ok(RunState {
+            status: state.status
+                .map(|s| ContainerStatus::from_docker_state(s.as_ref()))
+                .unwrap_or_default(),
+            health: state.health.and_then(|h| h.status).map(|s| s.to_string()).filter(|s| !s.is_empty() && s != "none"),
+            started_at: state.started_at.as_deref().and_then(parse_docker_time),
+            exit_code: state.exit_code,
+            oom_killed: state.oom_killed.unwrap_or(false),
+            finished_at: state.finished_at.as_deref().and_then(parse_docker_time),
+            restart_count: info.restart_count.unwrap_or(0),
+            aliases,
+            gpus,
+        })
+    }
+
+    /// Capture everything needed to recreate a container (before deleting it)
+    pub async fn snapshot_container(&self, name: &str) -> Result<DeletedContainer> {
+        let info = self.client
+            .inspect_container(name, None::<InspectContainerOptions>)
+            .await
+            .context(format!("Failed to inspect container: {}", name))?;
+
+        Ok(snapshot_from(name, info))
+    }
+
+    /// Config to recreate a container with: only what it sets beyond its
+    /// image's defaults (so a newer image's defaults apply), with its
+    /// anonymous volumes bound by name so their data carries across
+    async fn replacement_snapshot(&self, name: &str) -> Result<DeletedContainer> {
+        let info = self.client
+            .inspect_container(name, None::<InspectContainerOptions>)
+            .await
+            .context(format!("Failed to inspect container: {}", name))?;
+        let image_id = info.image.clone();
+        let mounts = info.mounts.clone().unwrap_or_default();
+        let mut snapshot = snapshot_from(name, info);
+        let image = image_id.unwrap_or_else(|| snapshot.image.clone());
+        let image_config = self
+            .client
+            .inspect_image(&image)
+            .await
+            .context(format!("Failed to inspect image: {}", image))?
+            .config
+            .unwrap_or_default();
+
+        strip_image_defaults(&mut snapshot.config, &image_config);
+        let volumes = anonymous_volume_binds(&mounts);
+        if !volumes.is_empty() {
+            let host_config = snapshot.config.host_config.get_or_insert_with(Default::default);
+            host_config.binds.get_or_insert_with(Vec::new).extend(volumes);
+        }
+        Ok(snapshot)
+    }
+
+    /// Swap a container for one created from `replacement`: the original is
+    /// renamed aside and stopped, and only removed once the new one is up.
+    /// If that fails the original is put back.
+    async fn replace_container(&self, replacement: &DeletedContainer) -> Result<()> {
+        let name = replacement.name.as_str();
+        let backup = format!("{}.replaced", name);
+        self.rename_container(name, &backup).await?;
+        let _ = self.stop_container(&backup, REMOVE_STOP_TIMEOUT).await;
+
+        if let Err(e) = self.recreate_container(replacement).await {
+            // Created but failed to start: drop it so the name is free again
+            let _ = self.remove_container(name).await;
+            if let Err(restore) = self.rename_container(&backup, name).await {
+                return Err(e.context(format!("Original kept as {}: {:#}", backup, restore)));
+            }
+            if replacement.was_running {
+                let _ = self.start_container(name).await;
+            }
+            return Err(e);
+        }
+        self.remove_container(&backup).await
+    }
+
+    /// What a container runs beyond its image's defaults, for a project.yaml
+    /// skeleton: the env it adds, its command if overridden, published ports,
+    /// named mounts, GPUs and devices
+    pub async fn project_source(&self, name: &str) -> Result<ProjectSource> {
+        let info = self.client
+            .inspect_container(name, None::<InspectContainerOptions>)
+            .await
+            .context(format!("Failed to inspect container: {}", name))?;
+        let config = info.config.unwrap_or_default();
+        let image = config.image.clone().unwrap_or_default();
+        let image_config = self
+            .client
+            .inspect_image(info.image.as_deref().unwrap_or(&image))
+            .await
+            .context(format!("Failed to inspect image: {}", image))?
+            .config
+            .unwrap_or_default();
+
+        let image_env = image_config.env.unwrap_or_default();
+        let container_env = config.env.unwrap_or_default();
+        let gpu = gpu_access(info.host_config.as_ref(), &container_env) != GpuAccess::None;
+        let env = container_env.into_iter().filter(|var| !image_env.contains(var)).collect();
+        let command = config.cmd.filter(|cmd| !cmd.is_empty() && Some(cmd) != image_config.cmd.as_ref());
+
+        let host_config = info.host_config.unwrap_or_default();
+        let mut ports: Vec<u16> = host_config
+            .port_bindings
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, bindings)| bindings.as_ref().is_some_and(|b| !b.is_empty()))
+            .filter_map(|(port, _)| port.split('/').next()?.parse().ok())
+            .collect();
+        ports.sort_unstable();
+        ports.dedup();
+
+        let volumes = info
+            .mounts
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|mount| {
+                let source = match mount.typ? {
+                    MountPointTypeEnum::BIND => mount.source?,
+                    MountPointTypeEnum::VOLUME => mount.name.filter(|n| !is_anonymous_volume(n))?,
+                    _ => return None,
+                };
+                let read_only = if mount.rw == Some(false) { ":ro" } else { "" };
+                Some(format!("{}:{}{}", source, mount.destination?, read_only))
+            })
+            .collect();
+        let devices = host_config.devices.unwrap_or_default().iter().map(format_device).collect();
+
+        Ok(ProjectSource {
+            name: name.to_string(),
+            image,
+            env,
+            command,
+            ports,
+            volumes,
+            gpu,
+            devices,
+        })
+    }
+
+    /// Labels of a container
+    pub async fn container_labels(&self, name: &str) -> Result<HashMap<String, String>> {
+        let info = self.client
+            .inspect_container(name, None::<InspectContainerOptions>)
+            .await
+            .context(format!("Failed to inspect container: {}", name))?;
+        Ok(info.config.and_then(|c| c.labels).unwrap_or_default())
+    }
+
+    /// Recreate a container from its current config with edited labels
+    /// (labels can't be changed on an existing container)
+    pub async fn relabel_container(&self, name: &str, edits: &[LabelEdit]) -> Result<()> {
+        let mut snapshot = self.replacement_snapshot(name).await?;
+        let labels = snapshot.config.labels.get_or_insert_with(HashMap::new);
+        crate::models::label::apply_label_edits(labels, edits);
+        self.replace_container(&snapshot).await
+    }
+
+    /// Recreate a deleted container from its snapshot, starting it if it was running
+    pub async fn recreate_container(&self, deleted: &DeletedContainer) -> Result<()> {
+        let options = CreateContainerOptions {
+            name: deleted.name.as_str(),
+            platform: None,
+        };
+        self.client
+            .create_container(Some(options), deleted.config.clone())
+            .await
+            .context(format!("Failed to recreate container: {}", deleted.name))?;
+
+        if deleted.was_running {
+            self.start_container(&deleted.name).await?;
+        }
+        Ok(())
+    }
+
     /// Start a container
     pub async fn start_container(&self, name: &str) -> Result<()> {
         self.client
@@ -131,9 +655,9 @@ impl DockerClient {
         Ok(())
     }
 
-    /// Stop a container
-    pub async fn stop_container(&self, name: &str) -> Result<()> {
-        let options = StopContainerOptions { t: 10 };
+    /// Stop a container, killing it if it's still running after `timeout` seconds
+    pub async fn stop_container(&self, name: &str, timeout: i64) -> Result<()> {
+        let options = StopContainerOptions { t: timeout };
         self.client
             .stop_container(name, Some(options))
             .await
@@ -141,9 +665,9 @@ impl DockerClient {
         Ok(())
     }
 
-    /// Restart a container
-    pub async fn restart_container(&self, name: &str) -> Result<()> {
-        let options = RestartContainerOptions { t: 10 };
+    /// Restart a container, killing it if it hasn't stopped after `timeout` seconds
+    pub async fn restart_container(&self, name: &str, timeout: i64) -> Result<()> {
+        let options = RestartContainerOptions { t: timeout as isize };
         self.client
             .restart_container(name, Some(options))
             .await
@@ -151,10 +675,23 @@ impl DockerClient {
         Ok(())
     }
 
+    /// Wait until a container stops running and return its exit code
+    pub async fn wait_for_exit(&self, name: &str) -> Result<i64> {
+        let options = WaitContainerOptions { condition: "not-running" };
+        let mut stream = self.client.wait_container(name, Some(options));
+        match stream.next().await {
+            Some(Ok(response)) => Ok(response.status_code),
+            // bollard reports non-zero exits as errors
+            Some(Err(bollard::errors::Error::DockerContainerWaitError { code, .. })) => Ok(code),
+            Some(Err(e)) => Err(e).context(format!("Failed to wait for container: {}", name)),
+            None => anyhow::bail!("Wait for {} ended without an exit code", name),
+        }
+    }
+
     /// Remove a container
     pub async fn remove_container(&self, name: &str) -> Result<()> {
         // First stop if running
-        let _ = self.stop_container(name).await;
+        let _ = self.stop_container(name, REMOVE_STOP_TIMEOUT).await;
 
         let options = RemoveContainerOptions {
             force: true,
@@ -167,6 +704,114 @@ impl DockerClient {
         Ok(())
     }
 
+    /// Stop and remove all containers of a compose project, then its networks.
+    /// Returns the number of containers removed.
+    pub async fn compose_down(&self, project: &str) -> Result<usize> {
+        let label = format!("com.docker.compose.project={}", project);
+
+        let mut filters = HashMap::new();
+        filters.insert("label", vec![label.as_str()]);
+        let options = ListContainersOptions {
+            all: true,
+            filters,
+            ..Default::default()
+        };
+        let containers = self
+            .client
+            .list_containers(Some(options))
+            .await
+            .context(format!("Failed to list containers of project: {}", project))?;
+
+        for container in &containers {
+            if let Some(id) = &container.id {
+                self.remove_container(id).await?;
+            }
+        }
+
+        let mut filters = HashMap::new();
+        filters.insert("label", vec![label.as_str()]);
+        let networks = self
+            .client
+            .list_networks(Some(ListNetworksOptions { filters }))
+            .await
+            .context(format!("Failed to list networks of project: {}", project))?;
+        for network in networks {
+            if let Some(name) = network.name {
+                self.client
+                    .remove_network(&name)
+                    .await
+                    .context(format!("Failed to remove network: {}", name))?;
+            }
+        }
+
+        Ok(containers.len())
+    }
+
+    /// What `compose_down` (with a project) or `prune_containers` (without)
+    /// would remove, without removing anything
+    pub async fn removal_preview(&self, project: Option<&str>) -> Result<RemovalPreview> {
+        let label = project.map(|p| format!("com.docker.compose.project={}", p));
+        let mut filters = HashMap::new();
+        match label {
+            Some(ref label) => filters.insert("label", vec![label.as_str()]),
+            // What the prune API counts as stopped
+            None => filters.insert("status", vec!["created", "exited", "dead"]),
+        };
+        let options = ListContainersOptions {
+            all: true,
+            size: true,
+            filters,
+            ..Default::default()
+        };
+        let containers = self
+            .client
+            .list_containers(Some(options))
+            .await
+            .context("Failed to list containers to remove")?;
+
+        let mut preview = RemovalPreview::default();
+        for container in containers {
+            let name = container
+                .names
+                .as_ref()
+                .and_then(|n| n.first())
+                .map(|n| n.trim_start_matches('/').to_string())
+                .or_else(|| container.id.as_ref().map(|id| id.chars().take(12).collect()))
+                .unwrap_or_default();
+            preview.containers.push((name, container.size_rw.unwrap_or(0).max(0) as u64));
+            let volumes = container.mounts.unwrap_or_default().into_iter().filter_map(|m| m.name);
+            preview.volumes.extend(volumes.filter(|v| is_anonymous_volume(v)));
+        }
+        preview.containers.sort();
+        preview.volumes.sort();
+        preview.volumes.dedup();
+
+        if let Some(ref label) = label {
+            let mut filters = HashMap::new();
+            filters.insert("label", vec![label.as_str()]);
+            let networks = self
+                .client
+                .list_networks(Some(ListNetworksOptions { filters }))
+                .await
+                .context("Failed to list networks to remove")?;
+            preview.networks = networks.into_iter().filter_map(|n| n.name).collect();
+            preview.networks.sort();
+        }
+        Ok(preview)
+    }
+
+    /// Remove all stopped containers. Returns (containers removed, bytes reclaimed).
+    pub async fn prune_containers(&self) -> Result<(usize, u64)> {
+        let response = self
+            .client
+            .prune_containers(None::<PruneContainersOptions<String>>)
+            .await
+            .context("Failed to prune containers")?;
+        let removed = response.containers_deleted.map(|ids| ids.len()).unwrap_or(0);
+        let reclaimed = response.space_reclaimed.unwrap_or(0).max(0) as u64;
+        Ok((removed, reclaimed))
+    }
+
     /// Pause a container
     pub async fn pause_container(&self, name: &str) -> Result<()> {
         self.client
@@ -195,6 +840,112 @@ impl DockerClient {
         Ok(())
     }
 
+    /// Which of the given shells exist in the container, probed with a single
+    /// `sh -c 'test -x ...'` exec (fails if the image has no `sh` at all)
+    pub async fn available_shells(&self, name: &str, shells: &[&str]) -> Result<Vec<String>> {
+        let script = shells
+            .iter()
+            .map(|shell| format!("test -x {0} && echo {0};", shell))
+            .collect::<String>();
+        let found = self
+            .run_script(name, &script)
+            .await
+            .context(format!("Failed to probe shells in {}", name))?;
+        Ok(found.lines().map(|line| line.trim().to_string()).filter(|line| !line.is_empty()).collect())
+    }
+
+    /// Run a one-shot `sh -c` script in a running container and collect its stdout
+    async fn run_script(&self, name: &str, script: &str) -> Result<String> {
+        let exec = self.client
+            .create_exec(name, CreateExecOptions {
+                cmd: Some(vec!["sh", "-c", script]),
+                attach_stdout: Some(true),
+                ..Default::default()
+            })
+            .await
+            .context(format!("Failed to exec in {}", name))?;
+
+        let mut stdout = String::new();
+        if let StartExecResults::Attached { mut output, .. } = self.client.start_exec(&exec.id, None).await? {
+            while let Some(chunk) = output.next().await {
+                stdout.push_str(&chunk?.to_string());
+            }
+        }
+        Ok(stdout)
+    }
+
+    /// Timezone, locale, distro and main command line for the info view. The
+    /// distro and zone files are read with a one-shot exec when it's running
+    pub async fn runtime_info(&self, name: &str) -> Result<RuntimeInfo> {
+        let info = self.client
+            .inspect_container(name, None::<InspectContainerOptions>)
+            .await
+            .context(format!("Failed to inspect container: {}", name))?;
+
+        let env: HashMap<String, String> = info
+            .config
+            .and_then(|c| c.env)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|var| var.split_once('='))
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let command = std::iter::once(info.path.unwrap_or_default())
+            .chain(info.args.unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let running = info.state.and_then(|s| s.running).unwrap_or(false);
+        let probe = if running {
+            tokio::time::timeout(PROBE_TIMEOUT, self.run_script(name, RUNTIME_PROBE)).await.ok().and_then(Result::ok)
+        } else {
+            None
+        };
+        let probe = probe.as_deref().map(parse_runtime_probe).unwrap_or_default();
+
+        let zone = env.get("TZ").cloned().or(probe.zone);
+        let timezone = match (zone, probe.offset) {
+            (Some(zone), Some(offset)) => Some(format!("{} ({})", zone, offset)),
+            (zone, offset) => zone.or(offset),
+        };
+        Ok(RuntimeInfo {
+            timezone,
+            locale: env.get("LC_ALL").or_else(|| env.get("LANG")).cloned(),
+            distro: probe.distro,
+            command,
+        })
+    }
+
+    /// Whether the container was started with stdin open (`-i`)
+    pub async fn stdin_open(&self, name: &str) -> Result<bool> {
+        let info = self.client
+            .inspect_container(name, None::<InspectContainerOptions>)
+            .await
+            .context(format!("Failed to inspect container: {}", name))?;
+        Ok(info.config.and_then(|c| c.open_stdin).unwrap_or(false))
+    }
+
+    /// Write a line to the container's stdin through a short-lived attach
+    pub async fn send_stdin(&self, name: &str, line: &str) -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let options = AttachContainerOptions::<String> {
+            stdin: Some(true),
+            stream: Some(true),
+            ..Default::default()
+        };
+        let mut attached = self.client
+            .attach_container(name, Some(options))
+            .await
+            .context(format!("Failed to attach to container: {}", name))?;
+        attached.input
+            .write_all(format!("{}\n", line).as_bytes())
+            .await
+            .context(format!("Failed to write to stdin of {}", name))?;
+        attached.input.flush().await?;
+        Ok(())
+    }
+
     /// Get running processes in a container (docker top)
     pub async fn top_container(&self, name: &str) -> Result<Vec<Vec<String>>> {
         let result = self.client
@@ -219,6 +970,222 @@ impl DockerClient {
         Ok(processes)
     }
 
+    /// Find dangling images, containers exited more than `min_age_days` ago,
+    /// and networks/volumes no container uses
+    pub async fn cleanup_candidates(&self, min_age_days: u64) -> Result<Vec<CleanupItem>> {
+        let usage = self.client.df().await.context("Failed to get disk usage")?;
+        let mut items = Vec::new();
+
+        for image in usage.images.unwrap_or_default() {
+            let dangling = image.repo_tags.iter().all(|t| t == "<none>:<none>");
+            if dangling && image.containers <= 0 {
+                let short_id = image.id.trim_start_matches("sha256:").chars().take(12).collect::<String>();
+                items.push(CleanupItem {
+                    kind: CleanupKind::Image,
+                    id: image.id.clone(),
+                    name: format!("<none> {}", short_id),
+                    detail: "dangling".to_string(),
+                    size: Some(image.size.max(0) as u64),
+                });
+            }
+        }
+
+        let containers = usage.containers.unwrap_or_default();
+        let cutoff = chrono::Utc::now().timestamp() - (min_age_days * 86400) as i64;
+        for container in &containers {
+            let (Some(id), Some("exited")) = (&container.id, container.state.as_deref()) else {
+                continue;
+            };
+            let finished = self.inspect_state(id).await.ok().and_then(|s| s.finished_at);
+            let Some(finished) = finished.or(container.created).filter(|&t| t <= cutoff) else {
+                continue;
+            };
+            let name = container
+                .names
+                .as_ref()
+                .and_then(|n| n.first())
+                .map(|n| n.trim_start_matches('/').to_string())
+                .unwrap_or_else(|| id.chars().take(12).collect());
+            let days = (chrono::Utc::now().timestamp() - finished) / 86400;
+            items.push(CleanupItem {
+                kind: CleanupKind::Container,
+                id: id.clone(),
+                name,
+                detail: format!("exited {} days ago", days),
+                size: container.size_rw.map(|s| s.max(0) as u64),
+            });
+        }
+
+        for volume in usage.volumes.unwrap_or_default() {
+            if volume.usage_data.as_ref().is_some_and(|u| u.ref_count == 0) {
+                items.push(CleanupItem {
+                    kind: CleanupKind::Volume,
+                    id: volume.name.clone(),
+                    name: volume.name.chars().take(40).collect(),
+                    detail: "unused".to_string(),
+                    size: volume.usage_data.map(|u| u.size.max(0) as u64),
+                });
+            }
+        }
+
+        // Networks aren't in df; unused ones have no container attached
+        let used: std::collections::HashSet<String> = containers
+            .iter()
+            .filter_map(|c| c.network_settings.as_ref()?.networks.as_ref())
+            .flat_map(|n| n.keys().cloned())
+            .collect();
+        let networks = self
+            .client
+            .list_networks(None::<ListNetworksOptions<String>>)
+            .await
+            .context("Failed to list networks")?;
+        for network in networks {
+            let Some(name) = network.name else { continue };
+            let builtin = matches!(name.as_str(), "bridge" | "host" | "none")
+                || network.ingress.unwrap_or(false);
+            if !builtin && !used.contains(&name) {
+                items.push(CleanupItem {
+                    kind: CleanupKind::Network,
+                    id: network.id.unwrap_or_else(|| name.clone()),
+                    name,
+                    detail: network.driver.unwrap_or_default(),
+                    size: None,
+                });
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Remove one resource found by the cleanup scan
+    pub async fn remove_cleanup_item(&self, item: &CleanupItem) -> Result<()> {
+        let result = match item.kind {
+            CleanupKind::Image => self
+                .client
+                .remove_image(&item.id, Some(RemoveImageOptions::default()), None)
+                .await
+                .map(|_| ()),
+            CleanupKind::Container => self
+                .client
+                .remove_container(&item.id, None::<RemoveContainerOptions>)
+                .await,
+            CleanupKind::Network => self.client.remove_network(&item.id).await,
+            CleanupKind::Volume => self
+                .client
+                .remove_volume(&item.id, None::<RemoveVolumeOptions>)
+                .await,
+        };
+        result.context(format!("Failed to remove {}: {}", item.kind.as_str(), item.name))
+    }
+
+    /// Layers of an image, newest first (docker history)
+    pub async fn image_history(&self, image: &str) -> Result<Vec<ImageLayer>> {
+        let history = self.client
+            .image_history(image)
+            .await
+            .context(format!("Failed to get history of image: {}", image))?;
+
+        Ok(history
+            .into_iter()
+            .map(|item| ImageLayer {
+                id: item.id,
+                created: item.created,
+                created_by: item.created_by,
+                size: item.size.max(0) as u64,
+            })
+            .collect())
+    }
+
+    /// Filesystem changes in a container relative to its image (docker diff)
+    pub async fn container_changes(&self, name: &str) -> Result<Vec<FileChange>> {
+        let changes = self.client
+            .container_changes(name)
+            .await
+            .context(format!("Failed to diff container: {}", name))?;
+
+        Ok(changes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|change| FileChange {
+                path: change.path,
+                kind: match change.kind {
+                    ChangeType::_0 => ChangeKind::Changed,
+                    ChangeType::_1 => ChangeKind::Added,
+                    ChangeType::_2 => ChangeKind::Deleted,
+                },
+            })
+            .collect())
+    }
+
+    /// Pull an image, waiting for every layer to finish; `auth` is (registry, credentials)
+    pub async fn pull_image(&self, image: &str, tag: &str, auth: Option<(String, RegistryAuth)>) -> Result<()> {
+        let options = CreateImageOptions {
+            from_image: image,
+            tag,
+            ..Default::default()
+        };
+        let credentials = auth.map(|(registry, auth)| DockerCredentials {
+            username: Some(auth.username),
+            password: Some(auth.password),
+            serveraddress: Some(registry),
+            ..Default::default()
+        });
+        let mut stream = self.client.create_image(Some(options), None, credentials);
+        while let Some(info) = stream.next().await {
+            let info = info.context(format!("Failed to pull {}:{}", image, tag))?;
+            if let Some(error) = info.error {
+                anyhow::bail!("Failed to pull {}:{}: {}", image, tag, error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Registry digests of a local image ("repo@sha256:..."); empty for
+    /// images that were built locally rather than pulled
+    pub async fn image_repo_digests(&self, image: &str) -> Result<Vec<String>> {
+        let info = self
+            .client
+            .inspect_image(image)
+            .await
+            .context(format!("Failed to inspect image: {}", image))?;
+        Ok(info.repo_digests.unwrap_or_default())
+    }
+
+    /// Size of an image in bytes
+    pub async fn image_size(&self, id: &str) -> Result<u64> {
+        let info = self
+            .client
+            .inspect_image(id)
+            .await
+            .context(format!("Failed to inspect image: {}", id))?;
+        Ok(info.size.unwrap_or(0).max(0) as u64)
+    }
+
+    /// Pull the container's image again and swap the container for one
+    /// created from its current config, so it runs the newer image
+    pub async fn update_container(&self, name: &str, auth: Option<(String, RegistryAuth)>) -> Result<()> {
+        let snapshot = self.replacement_snapshot(name).await?;
+        let image = snapshot.image.as_str();
+        let Some(tag) = crate::docker::registry::image_tag(image) else {
+            anyhow::bail!("{} is pinned to a digest: {}", name, image);
+        };
+        let repository = image.strip_suffix(&format!(":{}", tag)).unwrap_or(image);
+        self.pull_image(repository, tag, auth).await?;
+        self.replace_container(&snapshot).await
+    }
+
+    /// Names of all networks, sorted
+    pub async fn list_networks(&self) -> Result<Vec<String>> {
+        let networks = self
+            .client
+            .list_networks(None::<ListNetworksOptions<String>>)
+            .await
+            .context("Failed to list networks")?;
+        let mut names: Vec<String> = networks.into_iter().filter_map(|n| n.name).collect();
+        names.sort();
+        Ok(names)
+    }
+
     /// List all available images
     pub async fn list_images(&self) -> Result<Vec<String>> {
         let options = ListImagesOptions::<String> {
@@ -247,21 +1214,14 @@ impl DockerClient {
     }
 
     /// Create and start a new container
-    pub async fn create_container(
-        &self,
-        name: &str,
-        image: &str,
-        port_host: Option<u16>,
-        port_container: Option<u16>,
-        env_vars: Vec<String>,
-        volumes: Vec<String>,
-        command: Option<String>,
-    ) -> Result<()> {
+    pub async fn create_container(&self, spec: ContainerSpec) -> Result<()> {
+        let name = spec.name.as_str();
+
         // Build port bindings
         let mut port_bindings: HashMap<String, Option<Vec<PortBinding>>> = HashMap::new();
         let exposed_ports: HashMap<String, HashMap<(), ()>>;
 
-        if let (Some(host_port), Some(container_port)) = (port_host, port_container) {
+        if let (Some(host_port), Some(container_port)) = (spec.port_host, spec.port_container) {
             let container_port_key = format!("{}/tcp", container_port);
             port_bindings.insert(
                 container_port_key.clone(),
@@ -280,7 +1240,17 @@ impl DockerClient {
         // Build host config
         let host_config = HostConfig {
             port_bindings: Some(port_bindings),
-            binds: if volumes.is_empty() { None } else { Some(volumes) },
+            binds: if spec.volumes.is_empty() { None } else { Some(spec.volumes) },
+            memory: spec.memory,
+            nano_cpus: spec.nano_cpus,
+            network_mode: spec.network,
+            dns: if spec.dns.is_empty() { None } else { Some(spec.dns) },
+            device_requests: spec.gpu.then(|| vec![all_gpus()]),
+            devices: if spec.devices.is_empty() {
+                None
+            } else {
+                Some(spec.devices.iter().map(|d| parse_device(d)).collect())
+            },
             restart_policy: Some(bollard::models::RestartPolicy {
                 name: Some(bollard::models::RestartPolicyNameEnum::UNLESS_STOPPED),
                 maximum_retry_count: None,
@@ -289,7 +1259,7 @@ impl DockerClient {
         };
 
         // Parse command if provided
-        let cmd = command.map(|c| {
+        let cmd = spec.command.map(|c| {
             c.split_whitespace()
                 .map(|s| s.to_string())
                 .collect::<Vec<String>>()
@@ -297,8 +1267,9 @@ impl DockerClient {
 
         // Build container config
         let config = Config {
-            image: Some(image.to_string()),
-            env: if env_vars.is_empty() { None } else { Some(env_vars) },
+            image: Some(spec.image.clone()),
+            hostname: spec.hostname,
+            env: if spec.env_vars.is_empty() { None } else { Some(spec.env_vars) },
             exposed_ports: Some(exposed_ports),
             host_config: Some(host_config),
             cmd,
@@ -327,3 +1298,449 @@ impl DockerClient {
         Ok(())
     }
 }
+
+/// Resolve a docker context name to its endpoint URL via the docker CLI
+fn resolve_context_host(context: &str) -> Result<String> {
+    let output = Command::new("docker")
+        .args(["context", "inspect", context, "--format", "{{.Endpoints.docker.Host}}"])
+        .output()
+        .context("Failed to run docker context inspect")?;
+
+    if !output.status.success() {
+        anyhow::bail!("Unknown docker context: {}", context);
+    }
+
+    let host = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if host.is_empty() {
+        anyhow::bail!("Docker context has no endpoint: {}", context);
+    }
+    Ok(host)
+}
+
+/// Service names from compose's depends_on label ("db:service_healthy:false,cache:service_started:false")
+fn parse_depends_on(label: &str) -> Vec<String> {
+    label
+        .split(',')
+        .filter_map(|dep| dep.split(':').next())
+        .map(str::trim)
+        .filter(|service| !service.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Paths from compose's config_files label ("compose.yml,compose.override.yml"),
+/// relative ones resolved against the project's working_dir label
+fn parse_compose_files(label: &str, working_dir: Option<&str>) -> Vec<String> {
+    label
+        .split(',')
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .map(|path| match working_dir {
+            Some(dir) if !path.starts_with('/') => format!("{}/{}", dir.trim_end_matches('/'), path),
+            _ => path.to_string(),
+        })
+        .collect()
+}
+
+/// Extract the health state from a status like "Up 5 minutes (unhealthy)"
+fn parse_health(status: &str) -> Option<String> {
+    let start = status.rfind('(')?;
+    let inner = status[start + 1..].trim_end_matches(')');
+    let health = inner.strip_prefix("health: ").unwrap_or(inner);
+    match health {
+        "healthy" | "unhealthy" | "starting" => Some(health.to_string()),
+        _ => None,
+    }
+}
+
+/// Extract the exit code from a status like "Exited (137) 2 hours ago"
+fn parse_exit_code(status: &str) -> Option<i64> {
+    let rest = status.strip_prefix("Exited (")?;
+    rest.split(')').next()?.parse().ok()
+}
+
+/// Upper bound in seconds on the uptime in a status like "Up 5 seconds" or
+/// "Up About an hour (healthy)"; None past a day, where Docker rounds too
+/// coarsely to tell a restart from the clock moving on
+fn parse_max_uptime(status: &str) -> Option<i64> {
+    let uptime = status.strip_prefix("Up ")?.split(" (").next()?;
+    let (count, unit) = match uptime {
+        "Less than a second" => return Some(1),
+        "About a minute" => return Some(2 * 60),
+        "About an hour" => return Some(2 * 60 * 60),
+        _ => uptime.split_once(' ')?,
+    };
+    let count: i64 = count.parse().ok()?;
+    let unit = match unit.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 60 * 60,
+        _ => return None,
+    };
+    Some((count + 1) * unit)
+}
+
+/// Aliases worth showing: without duplicates, the container's own name, or
+/// the short ID Docker registers on user networks
+fn dns_aliases(names: impl Iterator<Item = String>, name: &str, id: &str) -> Vec<String> {
+    let mut aliases: Vec<String> = names.filter(|alias| alias != name && !id.starts_with(alias.as_str())).collect();
+    aliases.sort();
+    aliases.dedup();
+    aliases
+}
+
+/// Grace period when stopping a container to remove or recreate it
+const REMOVE_STOP_TIMEOUT: i64 = 10;
+
+/// Error lines kept from the dockerd journal
+const JOURNAL_LINES: &str = "20";
+
+/// Recent error-level entries of the docker unit (`journalctl -u docker -p err`)
+async fn journal_errors() -> Result<Vec<String>> {
+    let command = tokio::process::Command::new("journalctl")
+        .args(["-u", "docker", "-p", "err", "-n", JOURNAL_LINES, "--no-pager", "-q", "-o", "short-iso"])
+        .output();
+    let output = tokio::time::timeout(PROBE_TIMEOUT, command)
+        .await
+        .context("journalctl timed out")?
+        .context("journalctl not available")?;
+    if !output.status.success() {
+        anyhow::bail!("journalctl: {}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// How long the info view waits for the runtime probe
+const PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// os-release, the configured zone, and the current zone abbreviation and offset
+const RUNTIME_PROBE: &str = "cat /etc/os-release 2>/dev/null; echo @@; \
+    cat /etc/timezone 2>/dev/null || readlink /etc/localtime 2>/dev/null; echo @@; date +%Z%z";
+
+/// What the runtime probe found
+#[derive(Debug, Default, PartialEq)]
+struct RuntimeProbe {
+    distro: Option<String>,
+    zone: Option<String>,
+    offset: Option<String>,
+}
+
+/// Parse the output of `RUNTIME_PROBE`
+fn parse_runtime_probe(output: &str) -> RuntimeProbe {
+    let mut sections = output.split("@@");
+    let os_release = sections.next().unwrap_or_default();
+    let non_empty = |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+    let distro = os_release
+        .lines()
+        .find_map(|line| line.strip_prefix("PRETTY_NAME="))
+        .and_then(|name| non_empty(name.trim_matches('"')));
+    // The localtime link points into the zoneinfo database
+    let zone = sections.next().and_then(non_empty).map(|zone| match zone.split_once("zoneinfo/") {
+        Some((_, name)) => name.to_string(),
+        None => zone,
+    });
+    let offset = sections.next().and_then(non_empty);
+    RuntimeProbe { distro, zone, offset }
+}
+
+/// Request every GPU through the NVIDIA runtime, like `--gpus all`
+fn all_gpus() -> DeviceRequest {
+    DeviceRequest {
+        driver: Some(String::new()),
+        count: Some(-1),
+        capabilities: Some(vec![vec!["gpu".to_string()]]),
+        ..Default::default()
+    }
+}
+
+/// GPUs from a GPU device request (`--gpus all`, `--gpus 2`,
+/// `--gpus device=0,2`), else NVIDIA_VISIBLE_DEVICES if the container runs
+/// under the nvidia runtime (images like nvidia/cuda set it regardless)
+fn gpu_access(host_config: Option<&HostConfig>, env: &[String]) -> GpuAccess {
+    let is_gpu = |r: &&DeviceRequest| {
+        r.driver.as_deref() == Some("nvidia")
+            || r.capabilities.iter().flatten().flatten().any(|c| c == "gpu")
+    };
+    let request = host_config.and_then(|h| h.device_requests.as_ref()).and_then(|r| r.iter().find(is_gpu));
+    if let Some(request) = request {
+        return match (request.count, request.device_ids.as_deref()) {
+            (_, Some(ids)) if !ids.is_empty() => GpuAccess::Devices(ids.to_vec()),
+            (Some(count), _) if count > 0 => GpuAccess::Devices((0..count).map(|i| i.to_string()).collect()),
+            (Some(0), _) => GpuAccess::None,
+            _ => GpuAccess::All,
+        };
+    }
+    if host_config.and_then(|h| h.runtime.as_deref()) != Some("nvidia") {
+        return GpuAccess::None;
+    }
+    match env.iter().find_map(|var| var.strip_prefix("NVIDIA_VISIBLE_DEVICES=")) {
+        Some("all") => GpuAccess::All,
+        None | Some("" | "none" | "void") => GpuAccess::None,
+        Some(ids) => GpuAccess::Devices(ids.split(',').map(|id| id.trim().to_string()).collect()),
+    }
+}
+
+/// Parse a `--device` spec: `host[:container[:permissions]]`
+fn parse_device(spec: &str) -> DeviceMapping {
+    let mut parts = spec.trim().splitn(3, ':');
+    let host = parts.next().unwrap_or_default().to_string();
+    let container = parts.next().filter(|p| !p.is_empty()).map_or_else(|| host.clone(), str::to_string);
+    let permissions = parts.next().filter(|p| !p.is_empty()).unwrap_or("rwm").to_string();
+    DeviceMapping {
+        path_on_host: Some(host),
+        path_in_container: Some(container),
+        cgroup_permissions: Some(permissions),
+    }
+}
+
+/// A device mapping as a `--device` spec, leaving out what `parse_device`
+/// fills in by default
+fn format_device(device: &DeviceMapping) -> String {
+    let host = device.path_on_host.clone().unwrap_or_default();
+    let container = device.path_in_container.as_deref().filter(|c| *c != host);
+    match (container, device.cgroup_permissions.as_deref().filter(|p| *p != "rwm")) {
+        (container, Some(permissions)) => format!("{}:{}:{}", host, container.unwrap_or(&host), permissions),
+        (Some(container), None) => format!("{}:{}", host, container),
+        (None, None) => host,
+    }
+}
+
+/// Parse an RFC 3339 timestamp from inspect; Docker uses year 1 for "never"
+fn parse_docker_time(value: &str) -> Option<i64> {
+    let ts = chrono::DateTime::parse_from_rfc3339(value).ok()?.timestamp();
+    (ts > 0).then_some(ts)
+}
+
+/// Recreate config of an inspected container
+fn snapshot_from(name: &str, info: ContainerInspectResponse) -> DeletedContainer {
+    let mut config: Config<String> = info.config.unwrap_or_default().into();
+    config.host_config = info.host_config;
+    // Reattach to the same networks with the same aliases; addresses are reassigned
+    config.networking_config = info
+        .network_settings
+        .and_then(|n| n.networks)
+        .map(|networks| NetworkingConfig {
+            endpoints_config: networks
+                .into_iter()
+                .map(|(network, endpoint)| {
+                    let settings = EndpointSettings { aliases: endpoint.aliases, ..Default::default() };
+                    (network, settings)
+                })
+                .collect(),
+        });
+
+    let was_running = info
+        .state
+        .and_then(|s| s.running)
+        .unwrap_or(false);
+
+    DeletedContainer {
+        name: name.to_string(),
+        image: config.image.clone().unwrap_or_default(),
+        config,
+        was_running,
+        deleted_at: chrono::Local::now(),
+    }
+}
+
+/// Drop the env, command, entrypoint, labels and volumes a container only
+/// has because its image set them
+fn strip_image_defaults(config: &mut Config<String>, image: &ImageConfig) {
+    if let (Some(env), Some(image_env)) = (config.env.as_mut(), image.env.as_ref()) {
+        env.retain(|var| !image_env.contains(var));
+    }
+    if config.cmd.is_some() && config.cmd == image.cmd {
+        config.cmd = None;
+    }
+    if config.entrypoint.is_some() && config.entrypoint == image.entrypoint {
+        config.entrypoint = None;
+    }
+    if let (Some(labels), Some(image_labels)) = (config.labels.as_mut(), image.labels.as_ref()) {
+        labels.retain(|key, value| image_labels.get(key) != Some(value));
+    }
+    if let (Some(volumes), Some(image_volumes)) = (config.volumes.as_mut(), image.volumes.as_ref()) {
+        volumes.retain(|path, _| !image_volumes.contains_key(path));
+    }
+}
+
+/// Anonymous volumes of a container as `name:destination` binds
+fn anonymous_volume_binds(mounts: &[MountPoint]) -> Vec<String> {
+    mounts
+        .iter()
+        .filter(|mount| mount.typ == Some(MountPointTypeEnum::VOLUME))
+        .filter_map(|mount| {
+            let name = mount.name.as_deref().filter(|n| is_anonymous_volume(n))?;
+            let read_only = if mount.rw == Some(false) { ":ro" } else { "" };
+            Some(format!("{}:{}{}", name, mount.destination.as_deref()?, read_only))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_health() {
+        assert_eq!(parse_health("Up 5 minutes (unhealthy)").as_deref(), Some("unhealthy"));
+        assert_eq!(parse_health("Up 2 seconds (health: starting)").as_deref(), Some("starting"));
+        assert_eq!(parse_health("Up 3 hours"), None);
+        assert_eq!(parse_health("Up 1 minute (Paused)"), None);
+    }
+
+    #[test]
+    fn test_parse_depends_on() {
+        assert_eq!(parse_depends_on("db:service_healthy:false,cache:service_started:true"), vec!["db", "cache"]);
+        assert!(parse_depends_on("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_compose_files() {
+        assert_eq!(
+            parse_compose_files("/srv/app/compose.yml,compose.override.yml", Some("/srv/app/")),
+            vec!["/srv/app/compose.yml", "/srv/app/compose.override.yml"]
+        );
+        assert_eq!(parse_compose_files("compose.yml", None), vec!["compose.yml"]);
+        assert!(parse_compose_files("", Some("/srv")).is_empty());
+    }
+
+    #[test]
+    fn test_parse_exit_code() {
+        assert_eq!(parse_exit_code("Exited (137) 2 hours ago"), Some(137));
+        assert_eq!(parse_exit_code("Exited (0) 5 seconds ago"), Some(0));
+        assert_eq!(parse_exit_code("Up 3 hours"), None);
+    }
+
+    #[test]
+    fn test_parse_max_uptime() {
+        assert_eq!(parse_max_uptime("Up 5 seconds"), Some(6));
+        assert_eq!(parse_max_uptime("Up Less than a second"), Some(1));
+        assert_eq!(parse_max_uptime("Up About a minute (healthy)"), Some(120));
+        assert_eq!(parse_max_uptime("Up 3 minutes (Paused)"), Some(240));
+        assert_eq!(parse_max_uptime("Up 1 hour"), Some(7200));
+        assert_eq!(parse_max_uptime("Up 2 days"), None);
+        assert_eq!(parse_max_uptime("Exited (0) 3 seconds ago"), None);
+        assert_eq!(parse_max_uptime("Restarting (1) 2 seconds ago"), None);
+    }
+
+    #[test]
+    fn test_parse_docker_time() {
+        assert_eq!(parse_docker_time("2024-01-02T03:04:05.123456789Z"), Some(1704164645));
+        assert_eq!(parse_docker_time("0001-01-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn test_parse_runtime_probe() {
+        let output = "NAME=\"Alpine Linux\"\nPRETTY_NAME=\"Alpine Linux v3.19\"\n@@\n/usr/share/zoneinfo/Europe/Paris\n@@\nCET+0100\n";
+        assert_eq!(
+            parse_runtime_probe(output),
+            RuntimeProbe {
+                distro: Some("Alpine Linux v3.19".to_string()),
+                zone: Some("Europe/Paris".to_string()),
+                offset: Some("CET+0100".to_string()),
+            }
+        );
+        // Distroless: nothing but the date
+        let probe = parse_runtime_probe("@@\n@@\nUTC+0000\n");
+        assert_eq!((probe.distro, probe.zone, probe.offset.as_deref()), (None, None, Some("UTC+0000")));
+    }
+
+    #[test]
+    fn test_dns_aliases() {
+        let names = ["web", "3f2a9c1d0b7e", "api", "web", "api"].into_iter().map(str::to_string);
+        assert_eq!(dns_aliases(names, "web", "3f2a9c1d0b7e5544"), ["api"]);
+    }
+
+    #[test]
+    fn test_gpu_access() {
+        let request = |count: i64, ids: &[&str]| HostConfig {
+            device_requests: Some(vec![DeviceRequest {
+                count: Some(count),
+                device_ids: (!ids.is_empty()).then(|| ids.iter().map(|s| s.to_string()).collect()),
+                ..all_gpus()
+            }]),
+            ..Default::default()
+        };
+        assert_eq!(gpu_access(Some(&request(-1, &[])), &[]), GpuAccess::All);
+        assert_eq!(gpu_access(Some(&request(2, &[])), &[]), GpuAccess::Devices(vec!["0".into(), "1".into()]));
+        assert_eq!(gpu_access(Some(&request(0, &["GPU-ab", "3"])), &[]), GpuAccess::Devices(vec!["GPU-ab".into(), "3".into()]));
+
+        let env = ["NVIDIA_VISIBLE_DEVICES=0,2".to_string()];
+        assert_eq!(gpu_access(None, &env), GpuAccess::None);
+        let nvidia = HostConfig { runtime: Some("nvidia".to_string()), ..Default::default() };
+        assert_eq!(gpu_access(Some(&nvidia), &env), GpuAccess::Devices(vec!["0".into(), "2".into()]));
+        assert!(gpu_access(Some(&nvidia), &env).allows(2, "GPU-x"));
+        assert!(!gpu_access(Some(&nvidia), &env).allows(1, "GPU-x"));
+    }
+
+    #[test]
+    fn test_parse_device() {
+        let device = parse_device("/dev/snd");
+        assert_eq!(device.path_in_container.as_deref(), Some("/dev/snd"));
+        assert_eq!(device.cgroup_permissions.as_deref(), Some("rwm"));
+        let device = parse_device("/dev/video0:/dev/cam:r");
+        assert_eq!(device.path_on_host.as_deref(), Some("/dev/video0"));
+        assert_eq!(device.path_in_container.as_deref(), Some("/dev/cam"));
+        assert_eq!(device.cgroup_permissions.as_deref(), Some("r"));
+        for spec in ["/dev/snd", "/dev/video0:/dev/cam", "/dev/video0:/dev/video0:r"] {
+            assert_eq!(format_device(&parse_device(spec)), spec);
+        }
+    }
+
+    #[test]
+    fn test_strip_image_defaults() {
+        let image = ImageConfig {
+            env: Some(vec!["PATH=/usr/bin".into(), "VERSION=1.0".into()]),
+            cmd: Some(vec!["serve".into()]),
+            entrypoint: Some(vec!["/init".into()]),
+            labels: Some(HashMap::from([("vendor".into(), "acme".into())])),
+            volumes: Some(HashMap::from([("/data".into(), HashMap::new())])),
+            ..Default::default()
+        };
+        let mut config = Config {
+            env: Some(vec!["PATH=/usr/bin".into(), "VERSION=1.0".into(), "TOKEN=x".into()]),
+            cmd: Some(vec!["serve".into(), "--debug".into()]),
+            entrypoint: Some(vec!["/init".into()]),
+            labels: Some(HashMap::from([("vendor".into(), "acme".into()), ("tier".into(), "web".into())])),
+            volumes: Some(HashMap::from([("/data".into(), HashMap::new()), ("/cache".into(), HashMap::new())])),
+            ..Default::default()
+        };
+        strip_image_defaults(&mut config, &image);
+        assert_eq!(config.env, Some(vec!["TOKEN=x".to_string()]));
+        assert_eq!(config.cmd, Some(vec!["serve".to_string(), "--debug".to_string()]));
+        assert_eq!(config.entrypoint, None);
+        assert_eq!(config.labels, Some(HashMap::from([("tier".to_string(), "web".to_string())])));
+        assert_eq!(config.volumes.unwrap().into_keys().collect::<Vec<_>>(), vec!["/cache".to_string()]);
+    }
+
+    #[test]
+    fn test_anonymous_volume_binds() {
+        let anonymous = "3f".repeat(32);
+        let mounts = vec![
+            MountPoint {
+                typ: Some(MountPointTypeEnum::VOLUME),
+                name: Some(anonymous.clone()),
+                destination: Some("/data".into()),
+                rw: Some(false),
+                ..Default::default()
+            },
+            MountPoint {
+                typ: Some(MountPointTypeEnum::VOLUME),
+                name: Some("media_config".into()),
+                destination: Some("/config".into()),
+                ..Default::default()
+            },
+            MountPoint {
+                typ: Some(MountPointTypeEnum::BIND),
+                source: Some("/srv".into()),
+                destination: Some("/srv".into()),
+                ..Default::default()
+            },
+        ];
+        assert_eq!(anonymous_volume_binds(&mounts), vec![format!("{}:/data:ro", anonymous)]);
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bollard::models::EventMessage;
+use bollard::system::EventsOptions;
+use chrono::{DateTime, Local, TimeZone};
+use futures_util::StreamExt;
+
+use crate::docker::client::DockerClient;
+
+/// Events kept for the events view
+const MAX_EVENTS: usize = 5000;
+/// History asked for when the stream starts (the daemon only keeps the
+/// most recent events, so this is a bound, not a promise)
+const BACKFILL_SECS: i64 = 24 * 3600;
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// One event from the daemon's event stream
+#[derive(Debug, Clone, PartialEq)]
+pub struct DockerEvent {
+    pub at: DateTime<Local>,
+    time_nano: i64,
+    /// Object type: container, image, network, volume, ...
+    pub kind: String,
+    /// die, start, health_status: healthy, ...
+    pub action: String,
+    /// Name of the object, or its short ID when it has none
+    pub actor: String,
+    /// The other actor attributes (image, exitCode, signal, labels, ...)
+    pub attributes: BTreeMap<String, String>,
+}
+
+impl DockerEvent {
+    fn from_message(message: EventMessage) -> Option<Self> {
+        let time_nano = message.time_nano.or(message.time.map(|secs| secs * 1_000_000_000))?;
+        let actor = message.actor.unwrap_or_default();
+        let mut attributes: BTreeMap<String, String> = actor.attributes.unwrap_or_default().into_iter().collect();
+        let id = actor.id.unwrap_or_default();
+        let name = attributes.remove("name").unwrap_or_else(|| id.chars().take(12).collect());
+        Some(Self {
+            at: Local.timestamp_nanos(time_nano),
+            time_nano,
+            kind: message.typ.map(|t| t.to_string()).unwrap_or_default(),
+            action: message.action.unwrap_or_default(),
+            actor: name,
+            attributes,
+        })
+    }
+
+    /// "exitCode=137 image=nginx"; labels (dotted keys) are left out
+    pub fn describe_attributes(&self) -> String {
+        self.attributes
+            .iter()
+            .filter(|(key, _)| !key.contains('.'))
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Whether the event is about an object whose name contains `filter`
+    pub fn matches(&self, filter: &str) -> bool {
+        filter.is_empty() || self.actor.to_lowercase().contains(&filter.to_lowercase())
+    }
+}
+
+/// Events received since the TUI started (plus what the daemon still had),
+/// shared with the task reading the stream
+#[derive(Debug, Default)]
+pub struct EventLog {
+    /// Oldest first
+    pub events: VecDeque<DockerEvent>,
+    /// Why the stream is down, while it reconnects
+    pub error: Option<String>,
+}
+
+impl EventLog {
+    fn push(&mut self, event: DockerEvent) {
+        // A reconnect asks again from the last second seen
+        if self.events.back().is_some_and(|last| event.time_nano <= last.time_nano) {
+            return;
+        }
+        self.events.push_back(event);
+        if self.events.len() > MAX_EVENTS {
+            self.events.pop_front();
+        }
+    }
+
+    fn set_error(shared: &Mutex<Self>, error: Option<String>) {
+        if let Ok(mut log) = shared.lock() {
+            log.error = error;
+        }
+    }
+
+    /// Follow the daemon's events on a background task, reconnecting when
+    /// the stream drops
+    pub fn spawn(docker: DockerClient) -> Arc<Mutex<Self>> {
+        let shared = Arc::new(Mutex::new(Self::default()));
+        let log = shared.clone();
+        tokio::spawn(async move {
+            let mut since = Local::now().timestamp() - BACKFILL_SECS;
+            loop {
+                let options = EventsOptions::<String> { since: Some(since.to_string()), ..Default::default() };
+                let mut stream = docker.inner().events(Some(options));
+                let mut error = "Event stream closed".to_string();
+                while let Some(message) = stream.next().await {
+                    match message {
+                        Ok(message) => {
+                            let Some(event) = DockerEvent::from_message(message) else { continue };
+                            since = event.at.timestamp();
+                            if let Ok(mut log) = log.lock() {
+                                log.error = None;
+                                log.push(event);
+                            }
+                        }
+                        Err(e) => {
+                            error = format!("Event stream failed: {}", e);
+                            break;
+                        }
+                    }
+                }
+                Self::set_error(&log, Some(error));
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        });
+        shared
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{EventActor, EventMessageTypeEnum};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_event_from_message() {
+        let attributes = HashMap::from([
+            ("name".to_string(), "web".to_string()),
+            ("exitCode".to_string(), "137".to_string()),
+            ("image".to_string(), "nginx".to_string()),
+            ("com.docker.compose.project".to_string(), "site".to_string()),
+        ]);
+        let message = EventMessage {
+            typ: Some(EventMessageTypeEnum::CONTAINER),
+            action: Some("die".to_string()),
+            actor: Some(EventActor { id: Some("0123456789abcdef".to_string()), attributes: Some(attributes) }),
+            time: Some(1_700_000_000),
+            ..Default::default()
+        };
+        let event = DockerEvent::from_message(message).unwrap();
+        assert_eq!((event.kind.as_str(), event.action.as_str(), event.actor.as_str()), ("container", "die", "web"));
+        assert_eq!(event.describe_attributes(), "exitCode=137 image=nginx");
+        assert!(event.matches("WE") && !event.matches("db"));
+
+        let unnamed = EventMessage { actor: Some(EventActor { id: Some("0123456789abcdef".to_string()), attributes: None }), time: Some(1), ..Default::default() };
+        assert_eq!(DockerEvent::from_message(unnamed).unwrap().actor, "0123456789ab");
+        assert!(DockerEvent::from_message(EventMessage::default()).is_none());
+    }
+}
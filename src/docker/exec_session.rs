@@ -0,0 +1,210 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use bollard::exec::{CreateExecOptions, ResizeExecOptions, StartExecResults};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use futures_util::StreamExt;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+
+use crate::docker::client::DockerClient;
+use crate::recording::CastRecorder;
+
+/// Lines kept above the visible screen
+const SCROLLBACK: usize = 1000;
+
+/// An interactive `docker exec` with a TTY, rendered through a vt100 parser
+/// instead of handing over the whole terminal
+pub struct ExecSession {
+    pub container: String,
+    pub shell: String,
+    exec_id: String,
+    docker: DockerClient,
+    parser: Arc<Mutex<vt100::Parser>>,
+    input: mpsc::UnboundedSender<Vec<u8>>,
+    exited: Arc<AtomicBool>,
+    /// Size the exec TTY was last resized to (rows, cols)
+    size: (u16, u16),
+    /// Cast file the session is being recorded to
+    pub recording: Option<PathBuf>,
+}
+
+impl ExecSession {
+    /// Start a shell in the container with a TTY of the given size,
+    /// optionally recording its output to a cast file in `record_dir`
+    pub async fn start(
+        docker: &DockerClient,
+        container: &str,
+        shell: &str,
+        user: Option<&str>,
+        workdir: Option<&str>,
+        size: (u16, u16),
+        record_dir: Option<&Path>,
+    ) -> Result<Self> {
+        let exec = docker
+            .inner()
+            .create_exec(container, CreateExecOptions {
+                cmd: Some(vec![shell]),
+                user,
+                working_dir: workdir,
+                attach_stdin: Some(true),
+                attach_stdout: Some(true),
+                attach_stderr: Some(true),
+                tty: Some(true),
+                ..Default::default()
+            })
+            .await
+            .context(format!("Failed to create exec in {}", container))?;
+
+        let StartExecResults::Attached { mut output, mut input } = docker
+            .inner()
+            .start_exec(&exec.id, None)
+            .await
+            .context(format!("Failed to start {} in {}", shell, container))?
+        else {
+            anyhow::bail!("Exec in {} started detached", container);
+        };
+
+        let parser = Arc::new(Mutex::new(vt100::Parser::new(size.0, size.1, SCROLLBACK)));
+        let exited = Arc::new(AtomicBool::new(false));
+        let mut recorder = match record_dir {
+            Some(dir) => Some(CastRecorder::create(dir, container, size)?),
+            None => None,
+        };
+        let recording = recorder.as_ref().map(|r| r.path.clone());
+
+        // Container output -> parser (and recording), until the shell exits
+        let (reader_parser, reader_exited) = (parser.clone(), exited.clone());
+        tokio::spawn(async move {
+            while let Some(Ok(chunk)) = output.next().await {
+                let bytes = chunk.into_bytes();
+                if let Some(ref mut rec) = recorder {
+                    // Stop recording on write errors rather than the shell
+                    if rec.output(&bytes).is_err() {
+                        recorder = None;
+                    }
+                }
+                if let Ok(mut parser) = reader_parser.lock() {
+                    parser.process(&bytes);
+                }
+            }
+            reader_exited.store(true, Ordering::Relaxed);
+        });
+
+        // Key bytes -> container stdin
+        let (tx, mut rx) = mpsc::unbounded_channel::<Vec<u8>>();
+        tokio::spawn(async move {
+            while let Some(bytes) = rx.recv().await {
+                if input.write_all(&bytes).await.is_err() || input.flush().await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut session = Self {
+            container: container.to_string(),
+            shell: shell.to_string(),
+            exec_id: exec.id,
+            docker: docker.clone(),
+            parser,
+            input: tx,
+            exited,
+            size: (0, 0),
+            recording,
+        };
+        session.resize(size).await;
+        Ok(session)
+    }
+
+    /// The shell has exited (or the connection dropped)
+    pub fn has_exited(&self) -> bool {
+        self.exited.load(Ordering::Relaxed)
+    }
+
+    /// Forward a key press to the shell
+    pub fn send_key(&self, key: KeyEvent) {
+        let bytes = key_bytes(key);
+        if !bytes.is_empty() {
+            let _ = self.input.send(bytes);
+        }
+    }
+
+    /// Shared parser, locked while rendering
+    pub fn parser(&self) -> &Mutex<vt100::Parser> {
+        &self.parser
+    }
+
+    /// Resize the parser and the exec TTY if the pane changed size
+    pub async fn resize(&mut self, size: (u16, u16)) {
+        if size == self.size || size.0 == 0 || size.1 == 0 {
+            return;
+        }
+        self.size = size;
+        if let Ok(mut parser) = self.parser.lock() {
+            parser.set_size(size.0, size.1);
+        }
+        let options = ResizeExecOptions { height: size.0, width: size.1 };
+        let _ = self.docker.inner().resize_exec(&self.exec_id, options).await;
+    }
+}
+
+/// Bytes a terminal sends for a key press
+pub fn key_bytes(key: KeyEvent) -> Vec<u8> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let alt = key.modifiers.contains(KeyModifiers::ALT);
+    let mut bytes = match key.code {
+        KeyCode::Char(c) if ctrl => match c.to_ascii_lowercase() {
+            c @ 'a'..='z' => vec![c as u8 - b'a' + 1],
+            '@' | ' ' => vec![0],
+            '[' => vec![0x1b],
+            '\\' => vec![0x1c],
+            ']' => vec![0x1d],
+            _ => Vec::new(),
+        },
+        KeyCode::Char(c) => c.to_string().into_bytes(),
+        KeyCode::Enter => b"\r".to_vec(),
+        KeyCode::Backspace => vec![0x7f],
+        KeyCode::Tab => b"\t".to_vec(),
+        KeyCode::BackTab => b"\x1b[Z".to_vec(),
+        KeyCode::Esc => vec![0x1b],
+        KeyCode::Up => b"\x1b[A".to_vec(),
+        KeyCode::Down => b"\x1b[B".to_vec(),
+        KeyCode::Right => b"\x1b[C".to_vec(),
+        KeyCode::Left => b"\x1b[D".to_vec(),
+        KeyCode::Home => b"\x1b[H".to_vec(),
+        KeyCode::End => b"\x1b[F".to_vec(),
+        KeyCode::PageUp => b"\x1b[5~".to_vec(),
+        KeyCode::PageDown => b"\x1b[6~".to_vec(),
+        KeyCode::Insert => b"\x1b[2~".to_vec(),
+        KeyCode::Delete => b"\x1b[3~".to_vec(),
+        KeyCode::F(n @ 1..=4) => vec![0x1b, b'O', b'P' + n - 1],
+        KeyCode::F(n @ 5..=12) => {
+            let code = [15, 17, 18, 19, 20, 21, 23, 24][(n - 5) as usize];
+            format!("\x1b[{}~", code).into_bytes()
+        }
+        _ => Vec::new(),
+    };
+    if alt && !bytes.is_empty() {
+        bytes.insert(0, 0x1b);
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_bytes() {
+        let key = |code, modifiers| key_bytes(KeyEvent::new(code, modifiers));
+        assert_eq!(key(KeyCode::Char('c'), KeyModifiers::CONTROL), [3]);
+        assert_eq!(key(KeyCode::Char('D'), KeyModifiers::CONTROL | KeyModifiers::SHIFT), [4]);
+        assert_eq!(key(KeyCode::Char('b'), KeyModifiers::ALT), b"\x1bb");
+        assert_eq!(key(KeyCode::Char('é'), KeyModifiers::NONE), "é".as_bytes());
+        assert_eq!(key(KeyCode::Up, KeyModifiers::NONE), b"\x1b[A");
+        assert_eq!(key(KeyCode::F(2), KeyModifiers::NONE), b"\x1bOQ");
+        assert_eq!(key(KeyCode::F(5), KeyModifiers::NONE), b"\x1b[15~");
+    }
+}
@@ -1,37 +1,420 @@
-use std::collections::HashMap;
-use std::process::Command;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
 
-/// GPU process info from nvidia-smi
-#[derive(Debug)]
+use super::pids::pid_to_container_id;
+use crate::models::GpuSensors;
+
+/// GPU process info from nvidia-smi, rocm-smi or intel_gpu_top
+#[derive(Debug, Clone, PartialEq)]
 struct GpuProcess {
     pid: u32,
     memory_mb: f64,
+    /// Card the memory is on (nvidia-smi only)
+    gpu_uuid: Option<String>,
+}
+
+/// Who made the host's GPU, which decides the tools used to read it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GpuVendor {
+    Nvidia,
+    Amd,
+    Intel,
+}
+
+/// PCI vendor IDs in /sys/class/drm/cardN/device/vendor
+const PCI_AMD: &str = "0x1002";
+const PCI_INTEL: &str = "0x8086";
+
+impl GpuVendor {
+    /// nvidia-smi if it works, else the first AMD or Intel card in sysfs
+    pub fn detect() -> Option<Self> {
+        let nvidia = Command::new("nvidia-smi").arg("-L").output().is_ok_and(|o| o.status.success());
+        if nvidia {
+            return Some(Self::Nvidia);
+        }
+        drm_cards().iter().find_map(|(_, vendor)| match vendor.as_str() {
+            PCI_AMD => Some(Self::Amd),
+            PCI_INTEL => Some(Self::Intel),
+            _ => None,
+        })
+    }
+}
+
+/// Host GPU usage in the same shape whichever vendor reported it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpuSample {
+    /// VRAM used across all cards (None without dedicated memory)
+    pub vram_percent: Option<f32>,
+    /// Busy percent of the busiest card or engine
+    pub util_percent: Option<f32>,
+    pub sensors: GpuSensors,
+    /// GPU memory per container ID, in MB
+    pub containers: HashMap<String, f64>,
+    /// Cards by index (NVIDIA and AMD)
+    pub devices: Vec<GpuDevice>,
+    /// GPU memory per container ID and card index, in MB
+    pub container_gpus: HashMap<String, BTreeMap<u32, f64>>,
+}
+
+/// One card, for the GPU view
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpuDevice {
+    pub index: u32,
+    /// "GPU-..." on NVIDIA, empty elsewhere
+    pub uuid: String,
+    pub name: String,
+    pub util_percent: Option<f32>,
+    pub vram_used_mb: f64,
+    pub vram_total_mb: f64,
+}
+
+/// Polls the host's GPU. Intel's counters come from an `intel_gpu_top`
+/// kept running in the background, since it only reports over an interval
+pub struct GpuMonitor {
+    vendor: Option<GpuVendor>,
+    intel: Option<IntelGpuTop>,
+}
+
+impl GpuMonitor {
+    pub fn new() -> Self {
+        let vendor = GpuVendor::detect();
+        let intel = (vendor == Some(GpuVendor::Intel)).then(IntelGpuTop::spawn).flatten();
+        Self { vendor, intel }
+    }
+
+    pub fn sample(&mut self) -> GpuSample {
+        let (mut sample, processes) = match self.vendor {
+            Some(GpuVendor::Nvidia) => (nvidia_usage().unwrap_or_default(), get_gpu_processes()),
+            Some(GpuVendor::Amd) => (amd_usage(), get_gpu_processes_rocm_smi()),
+            Some(GpuVendor::Intel) => {
+                let intel = self.intel.as_ref().and_then(IntelGpuTop::latest).unwrap_or_default();
+                // intel_gpu_top has no temperature; discrete cards have hwmon
+                let mut sensors = intel.sensors;
+                for (card, _) in drm_cards().into_iter().filter(|(_, vendor)| vendor == PCI_INTEL) {
+                    sensors.temperature = sensors.temperature.or(hwmon_sensors(&card.join("device")).temperature);
+                }
+                (GpuSample { util_percent: intel.util_percent, sensors, ..Default::default() }, Some(intel.processes))
+            }
+            None => (GpuSample::default(), None),
+        };
+        (sample.containers, sample.container_gpus) = by_container(processes.unwrap_or_default(), &sample.devices);
+        sample
+    }
 }
 
-/// Get per-container GPU memory usage by mapping nvidia-smi PIDs to containers
+/// Get per-container GPU memory usage by mapping GPU process PIDs to containers
 /// Returns a HashMap of container_id -> total VRAM usage in MB
 pub fn get_container_gpu_usage() -> HashMap<String, f64> {
+    GpuMonitor::new().sample().containers
+}
+
+/// Sum process memory per container, in total and per card
+fn by_container(
+    gpu_processes: Vec<GpuProcess>,
+    devices: &[GpuDevice],
+) -> (HashMap<String, f64>, HashMap<String, BTreeMap<u32, f64>>) {
     let mut container_vram: HashMap<String, f64> = HashMap::new();
+    let mut per_gpu: HashMap<String, BTreeMap<u32, f64>> = HashMap::new();
+
+    // Map each PID to its container
+    for proc in gpu_processes {
+        if let Some(container_id) = pid_to_container_id(proc.pid) {
+            *container_vram.entry(container_id.clone()).or_insert(0.0) += proc.memory_mb;
+            let device = proc.gpu_uuid.as_ref().and_then(|uuid| devices.iter().find(|d| d.uuid == *uuid));
+            if let Some(device) = device {
+                *per_gpu.entry(container_id).or_default().entry(device.index).or_insert(0.0) += proc.memory_mb;
+            }
+        }
+    }
+
+    (container_vram, per_gpu)
+}
+
+/// VRAM percent over all GPUs, the busiest GPU's utilization and sensors
+fn nvidia_usage() -> Option<GpuSample> {
+    let output = Command::new("nvidia-smi")
+        .args([
+            "--query-gpu=index,uuid,name,memory.used,memory.total,utilization.gpu,temperature.gpu,power.draw,power.limit,clocks.sm",
+            "--format=csv,noheader,nounits",
+        ])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+    parse_nvidia_usage(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// One "index, uuid, name, used, total, util, temp, power, limit, clock"
+/// line per GPU
+fn parse_nvidia_usage(stdout: &str) -> Option<GpuSample> {
+    let (mut used, mut total, mut util) = (0.0f32, 0.0f32, 0.0f32);
+    let mut sensors = GpuSensors::default();
+    let mut devices = Vec::new();
+    for line in stdout.lines() {
+        let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+        if let [index, uuid, name, u, t, busy, rest @ ..] = &parts[..] {
+            let (card_used, card_total) = (u.parse::<f32>().ok()?, t.parse::<f32>().ok()?);
+            used += card_used;
+            total += card_total;
+            // "[N/A]" on some boards
+            let busy = busy.parse::<f32>().ok();
+            util = util.max(busy.unwrap_or(0.0));
+            devices.push(GpuDevice {
+                index: index.parse().ok()?,
+                uuid: uuid.to_string(),
+                name: name.to_string(),
+                util_percent: busy,
+                vram_used_mb: card_used as f64,
+                vram_total_mb: card_total as f64,
+            });
+            let field = |i: usize| rest.get(i).and_then(|v| v.parse::<f32>().ok());
+            sensors.combine(GpuSensors {
+                temperature: field(0),
+                power_watts: field(1),
+                power_limit_watts: field(2),
+                clock_mhz: field(3),
+            });
+        }
+    }
+    (total > 0.0).then(|| GpuSample {
+        vram_percent: Some(used / total * 100.0),
+        util_percent: Some(util),
+        sensors,
+        devices,
+        ..Default::default()
+    })
+}
 
-    // Get GPU processes from nvidia-smi
-    let gpu_processes = match get_gpu_processes() {
-        Some(procs) => procs,
-        None => return container_vram,
+/// `cardN` directories of /sys/class/drm with their PCI vendor ID
+fn drm_cards() -> Vec<(PathBuf, String)> {
+    let Ok(entries) = fs::read_dir("/sys/class/drm") else {
+        return Vec::new();
     };
+    let mut cards: Vec<(PathBuf, String)> = entries
+        .flatten()
+        .filter(|e| e.file_name().to_str().and_then(|n| n.strip_prefix("card")).is_some_and(|n| n.parse::<u32>().is_ok()))
+        .filter_map(|e| {
+            let vendor = fs::read_to_string(e.path().join("device/vendor")).ok()?;
+            Some((e.path(), vendor.trim().to_string()))
+        })
+        .collect();
+    cards.sort();
+    cards
+}
+
+fn read_number(path: &Path) -> Option<f64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
 
-    if gpu_processes.is_empty() {
-        return container_vram;
+/// Temperature and power of a card from its hwmon directory (m°C and µW)
+fn hwmon_sensors(device: &Path) -> GpuSensors {
+    let Some(hwmon) = fs::read_dir(device.join("hwmon")).ok().and_then(|mut d| d.next()).and_then(|e| e.ok()) else {
+        return GpuSensors::default();
+    };
+    let read = |file: &str, scale: f64| read_number(&hwmon.path().join(file)).map(|v| (v / scale) as f32);
+    GpuSensors {
+        temperature: read("temp1_input", 1000.0),
+        power_watts: read("power1_average", 1e6).or_else(|| read("power1_input", 1e6)),
+        power_limit_watts: read("power1_cap", 1e6),
+        clock_mhz: None,
     }
+}
 
-    // Map each PID to its container
-    for proc in gpu_processes {
-        if let Some(container_id) = pid_to_container_id(proc.pid) {
-            *container_vram.entry(container_id).or_insert(0.0) += proc.memory_mb;
+/// Active level of an amdgpu `pp_dpm_sclk` table ("1: 1800Mhz *")
+fn parse_dpm_clock(table: &str) -> Option<f32> {
+    let active = table.lines().find(|l| l.trim_end().ends_with('*'))?;
+    let mhz = active.split_whitespace().nth(1)?;
+    mhz.trim_end_matches(|c: char| c.is_ascii_alphabetic()).parse().ok()
+}
+
+/// VRAM percent, busiest GPU and sensors from the amdgpu sysfs files
+fn amd_usage() -> GpuSample {
+    let (mut used, mut total, mut util) = (0.0, 0.0, None::<f32>);
+    let mut sensors = GpuSensors::default();
+    let mut devices = Vec::new();
+    for (index, (card, _)) in drm_cards().into_iter().filter(|(_, vendor)| vendor == PCI_AMD).enumerate() {
+        let device = card.join("device");
+        let mut card_sensors = hwmon_sensors(&device);
+        card_sensors.clock_mhz = fs::read_to_string(device.join("pp_dpm_sclk")).ok().as_deref().and_then(parse_dpm_clock);
+        sensors.combine(card_sensors);
+        let (card_used, card_total) = (read_number(&device.join("mem_info_vram_used")), read_number(&device.join("mem_info_vram_total")));
+        if let (Some(u), Some(t)) = (card_used, card_total) {
+            used += u;
+            total += t;
         }
+        let busy = read_number(&device.join("gpu_busy_percent")).map(|b| b as f32);
+        if let Some(busy) = busy {
+            util = Some(util.unwrap_or(0.0).max(busy));
+        }
+        let name = fs::read_to_string(device.join("product_name")).ok().map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+        let card_name = card.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        devices.push(GpuDevice {
+            index: index as u32,
+            uuid: String::new(),
+            name: name.unwrap_or_else(|| format!("AMD GPU ({})", card_name)),
+            util_percent: busy,
+            vram_used_mb: card_used.unwrap_or(0.0) / (1024.0 * 1024.0),
+            vram_total_mb: card_total.unwrap_or(0.0) / (1024.0 * 1024.0),
+        });
     }
+    let vram_percent = (total > 0.0).then(|| (used / total * 100.0) as f32);
+    GpuSample { vram_percent, util_percent: util, sensors, devices, ..Default::default() }
+}
 
-    container_vram
+/// Get GPU processes via `rocm-smi --showpids`
+fn get_gpu_processes_rocm_smi() -> Option<Vec<GpuProcess>> {
+    let output = Command::new("rocm-smi").arg("--showpids").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(parse_rocm_pids(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Rows of the KFD process table: PID, name, GPU(s), VRAM bytes, SDMA, CU
+/// occupancy. Names may hold spaces, so the numbers are read from the end
+fn parse_rocm_pids(stdout: &str) -> Vec<GpuProcess> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let pid = fields.first()?.parse::<u32>().ok()?;
+            let vram_bytes = fields.len().checked_sub(3).and_then(|i| fields.get(i))?.parse::<f64>().ok()?;
+            (fields.len() >= 6).then(|| GpuProcess { pid, memory_mb: vram_bytes / (1024.0 * 1024.0), gpu_uuid: None })
+        })
+        .collect()
+}
+
+/// Latest intel_gpu_top reading
+#[derive(Debug, Clone, Default, PartialEq)]
+struct IntelSample {
+    util_percent: Option<f32>,
+    /// Power and actual frequency
+    sensors: GpuSensors,
+    processes: Vec<GpuProcess>,
+}
+
+/// `intel_gpu_top -J` running in the background; a thread keeps the latest
+/// sample. The process is killed when this is dropped
+struct IntelGpuTop {
+    child: Child,
+    latest: Arc<Mutex<Option<IntelSample>>>,
+}
+
+impl IntelGpuTop {
+    fn spawn() -> Option<Self> {
+        let mut child = Command::new("intel_gpu_top")
+            .args(["-J", "-s", "1000"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .ok()?;
+        let mut stdout = child.stdout.take()?;
+        let latest = Arc::new(Mutex::new(None));
+        let shared = Arc::clone(&latest);
+        std::thread::spawn(move || {
+            let mut pending = String::new();
+            let mut chunk = [0u8; 8192];
+            while let Ok(n) = stdout.read(&mut chunk) {
+                if n == 0 {
+                    break;
+                }
+                pending.push_str(&String::from_utf8_lossy(&chunk[..n]));
+                let (objects, consumed) = split_json_objects(&pending);
+                if let Some(sample) = objects.last().and_then(|o| serde_json::from_str::<Value>(o).ok()) {
+                    if let Ok(mut latest) = shared.lock() {
+                        *latest = Some(parse_intel_sample(&sample));
+                    }
+                }
+                pending.drain(..consumed);
+            }
+        });
+        Some(Self { child, latest })
+    }
+
+    fn latest(&self) -> Option<IntelSample> {
+        self.latest.lock().ok()?.clone()
+    }
+}
+
+impl Drop for IntelGpuTop {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Complete top-level `{...}` objects in a streamed JSON array, and how many
+/// bytes they (and what came before them) take up
+fn split_json_objects(text: &str) -> (Vec<&str>, usize) {
+    let mut objects = Vec::new();
+    let (mut depth, mut start, mut consumed) = (0usize, 0usize, 0usize);
+    let (mut in_string, mut escaped) = (false, false);
+    for (i, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => {
+                if depth == 0 {
+                    start = i;
+                }
+                depth += 1;
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    objects.push(&text[start..=i]);
+                    consumed = i + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    (objects, consumed)
+}
+
+/// Busiest engine, and per-client memory (device-local when the card has
+/// its own, else system memory) from one intel_gpu_top sample
+fn parse_intel_sample(sample: &Value) -> IntelSample {
+    let util_percent = sample["engines"]
+        .as_object()
+        .map(|engines| engines.values().filter_map(|e| e["busy"].as_f64()).fold(0.0, f64::max) as f32);
+    let processes = sample["clients"]
+        .as_object()
+        .map(|clients| {
+            clients
+                .values()
+                .filter_map(|client| {
+                    let pid = client["pid"].as_str().and_then(|p| p.parse().ok()).or(client["pid"].as_u64().map(|p| p as u32))?;
+                    let memory = &client["memory"];
+                    let resident = |region: &str| memory[region]["resident"].as_f64().filter(|b| *b > 0.0);
+                    let bytes = resident("local").or_else(|| resident("system"))?;
+                    Some(GpuProcess { pid, memory_mb: bytes / (1024.0 * 1024.0), gpu_uuid: None })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let sensors = GpuSensors {
+        power_watts: sample["power"]["GPU"].as_f64().map(|w| w as f32),
+        clock_mhz: sample["frequency"]["actual"].as_f64().map(|mhz| mhz as f32),
+        ..Default::default()
+    };
+    IntelSample { util_percent, sensors, processes }
 }
 
 /// Query nvidia-smi for GPU compute processes
@@ -68,7 +451,7 @@ fn get_gpu_processes_dcgm() -> Option<Vec<GpuProcess>> {
 fn get_gpu_processes_nvidia_smi() -> Option<Vec<GpuProcess>> {
     let output = Command::new("nvidia-smi")
         .args([
-            "--query-compute-apps=pid,used_memory",
+            "--query-compute-apps=pid,used_memory,gpu_uuid",
             "--format=csv,noheader,nounits"
         ])
         .output()
@@ -85,7 +468,8 @@ fn get_gpu_processes_nvidia_smi() -> Option<Vec<GpuProcess>> {
         let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
         if parts.len() >= 2 {
             if let (Ok(pid), Ok(memory_mb)) = (parts[0].parse::<u32>(), parts[1].parse::<f64>()) {
-                processes.push(GpuProcess { pid, memory_mb });
+                let gpu_uuid = parts.get(2).map(|uuid| uuid.to_string());
+                processes.push(GpuProcess { pid, memory_mb, gpu_uuid });
             }
         }
     }
@@ -93,107 +477,67 @@ fn get_gpu_processes_nvidia_smi() -> Option<Vec<GpuProcess>> {
     Some(processes)
 }
 
-/// Map a PID to its container ID by reading cgroup info
-fn pid_to_container_id(pid: u32) -> Option<String> {
-    // Try cgroup v2 first (unified hierarchy)
-    if let Some(id) = pid_to_container_id_cgroupv2(pid) {
-        return Some(id);
-    }
-
-    // Fall back to cgroup v1
-    pid_to_container_id_cgroupv1(pid)
-}
-
-/// Get container ID from cgroup v2
-fn pid_to_container_id_cgroupv2(pid: u32) -> Option<String> {
-    let cgroup_path = format!("/proc/{}/cgroup", pid);
-    let content = fs::read_to_string(&cgroup_path).ok()?;
-
-    // cgroup v2 format: "0::/path/to/cgroup"
-    // Docker containers: "0::/docker/<container_id>"
-    // or "0::/system.slice/docker-<container_id>.scope"
-    for line in content.lines() {
-        if line.starts_with("0::") {
-            let path = &line[3..];
-
-            // Check for docker container patterns
-            if let Some(id) = extract_container_id_from_path(path) {
-                return Some(id);
-            }
-        }
-    }
-
-    None
-}
-
-/// Get container ID from cgroup v1
-fn pid_to_container_id_cgroupv1(pid: u32) -> Option<String> {
-    let cgroup_path = format!("/proc/{}/cgroup", pid);
-    let content = fs::read_to_string(&cgroup_path).ok()?;
-
-    // cgroup v1 format: "N:controller:/path"
-    // Look for docker in any controller
-    for line in content.lines() {
-        let parts: Vec<&str> = line.splitn(3, ':').collect();
-        if parts.len() >= 3 {
-            if let Some(id) = extract_container_id_from_path(parts[2]) {
-                return Some(id);
-            }
-        }
-    }
-
-    None
-}
-
-/// Extract container ID from a cgroup path
-fn extract_container_id_from_path(path: &str) -> Option<String> {
-    // Pattern 1: /docker/<container_id>
-    if let Some(pos) = path.find("/docker/") {
-        let id_start = pos + 8;
-        let id = &path[id_start..];
-        // Container IDs are 64 hex chars, but we only need first 12 for matching
-        if id.len() >= 12 && id.chars().take(12).all(|c| c.is_ascii_hexdigit()) {
-            return Some(id.chars().take(64).collect());
-        }
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Pattern 2: docker-<container_id>.scope
-    if let Some(pos) = path.find("docker-") {
-        let id_start = pos + 7;
-        let remaining = &path[id_start..];
-        if let Some(end) = remaining.find('.') {
-            let id = &remaining[..end];
-            if id.len() >= 12 && id.chars().all(|c| c.is_ascii_hexdigit()) {
-                return Some(id.to_string());
-            }
-        }
+    #[test]
+    fn test_parse_nvidia_usage() {
+        let sample = parse_nvidia_usage(
+            "0, GPU-aa, NVIDIA GeForce RTX 3090, 1024, 4096, 30, 71, 250.5, 350.00, 1800\n\
+             1, GPU-bb, NVIDIA GeForce RTX 3090, 3072, 4096, [N/A], 64, [N/A], 300.00, 2100\n",
+        )
+        .unwrap();
+        assert_eq!((sample.vram_percent, sample.util_percent), (Some(50.0), Some(30.0)));
+        assert_eq!(sample.devices.iter().map(|d| (d.index, d.uuid.as_str(), d.util_percent)).collect::<Vec<_>>(), [
+            (0, "GPU-aa", Some(30.0)),
+            (1, "GPU-bb", None)
+        ]);
+        assert_eq!(
+            sample.sensors,
+            GpuSensors { temperature: Some(71.0), power_watts: Some(250.5), power_limit_watts: Some(650.0), clock_mhz: Some(2100.0) }
+        );
+        assert_eq!(parse_nvidia_usage(""), None);
     }
 
-    // Pattern 3: /containerd/<container_id>
-    if let Some(pos) = path.find("/containerd/") {
-        let id_start = pos + 12;
-        let id = &path[id_start..];
-        if id.len() >= 12 {
-            return Some(id.chars().take(64).collect());
-        }
+    #[test]
+    fn test_parse_dpm_clock() {
+        assert_eq!(parse_dpm_clock("0: 500Mhz\n1: 1800Mhz *\n2: 2500Mhz\n"), Some(1800.0));
+        assert_eq!(parse_dpm_clock("0: 500Mhz\n"), None);
     }
 
-    None
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
     #[test]
-    fn test_extract_container_id_docker() {
-        let path = "/docker/abc123def456789012345678901234567890123456789012345678901234";
-        assert!(extract_container_id_from_path(path).is_some());
+    fn test_parse_rocm_pids() {
+        let stdout = "============ ROCm System Management Interface ============
+KFD process information:
+PID\tPROCESS NAME\tGPU(s)\tVRAM USED\tSDMA USED\tCU OCCUPANCY
+4242\tpython3\t1\t1073741824\t0\t0
+4343\tollama runner\t1\t524288\t0\tUNKNOWN
+==========================================================
+";
+        assert_eq!(
+            parse_rocm_pids(stdout),
+            [
+                GpuProcess { pid: 4242, memory_mb: 1024.0, gpu_uuid: None },
+                GpuProcess { pid: 4343, memory_mb: 0.5, gpu_uuid: None }
+            ]
+        );
     }
 
     #[test]
-    fn test_extract_container_id_scope() {
-        let path = "/system.slice/docker-abc123def456.scope";
-        assert!(extract_container_id_from_path(path).is_some());
+    fn test_intel_stream() {
+        let stream = r#"[
+{"frequency": {"requested": 1300.0, "actual": 1250.0}, "power": {"GPU": 8.5, "Package": 20.1, "unit": "W"},
+ "engines": {"Render/3D": {"busy": 12.5, "unit": "%"}, "Video": {"busy": 40.0}},
+ "clients": {"7": {"name": "ffmpeg {x}", "pid": "900", "memory": {"system": {"resident": 2097152}, "local": {"resident": 0}}}}},
+{"engines": {"Render/3D": {"busy": 1.0"#;
+        let (objects, consumed) = split_json_objects(stream);
+        assert_eq!(objects.len(), 1);
+        assert!(stream[consumed..].starts_with(",\n{\"engines\""));
+
+        let sample = parse_intel_sample(&serde_json::from_str(objects[0]).unwrap());
+        assert_eq!(sample.util_percent, Some(40.0));
+        assert_eq!((sample.sensors.power_watts, sample.sensors.clock_mhz), (Some(8.5), Some(1250.0)));
+        assert_eq!(sample.processes, [GpuProcess { pid: 900, memory_mb: 2.0, gpu_uuid: None }]);
     }
 }
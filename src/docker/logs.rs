@@ -1,18 +1,215 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use bollard::container::LogsOptions;
 use bollard::Docker;
+use chrono::{DateTime, NaiveDateTime, NaiveTime, Utc};
 use futures_util::StreamExt;
+use regex::Regex;
+use std::ops::Deref;
 
-/// Get logs from a container
+/// A regex over the message part of log lines (after the timestamp): lines
+/// must match it, or with `exclude` must not. Filters stack
+#[derive(Debug, Clone)]
+pub struct LogFilter {
+    pub regex: Regex,
+    pub exclude: bool,
+    pub enabled: bool,
+}
+
+impl LogFilter {
+    /// "error", or "!GET /health" to hide what matches
+    pub fn parse(input: &str) -> Result<Self> {
+        let (exclude, pattern) = match input.strip_prefix('!') {
+            Some(pattern) => (true, pattern),
+            None => (false, input),
+        };
+        if pattern.is_empty() {
+            anyhow::bail!("Empty filter");
+        }
+        let regex = Regex::new(pattern).context(format!("Invalid regex: {}", pattern))?;
+        Ok(Self { regex, exclude, enabled: true })
+    }
+
+    /// "+error" / "-GET /health", for the title
+    pub fn label(&self) -> String {
+        format!("{}{}", if self.exclude { '-' } else { '+' }, self.regex.as_str())
+    }
+
+    fn passes(&self, line: &str) -> bool {
+        !self.enabled || self.regex.is_match(message(line)) != self.exclude
+    }
+}
+
+/// A log line without its leading timestamp
+fn message(line: &str) -> &str {
+    match line.split_once(' ') {
+        Some((token, rest)) if DateTime::parse_from_rfc3339(token).is_ok() => rest,
+        _ => line,
+    }
+}
+
+/// Log lines held for the logs view. New lines go on the end and the oldest
+/// are dropped once either cap is passed
+#[derive(Debug, Clone)]
+pub struct LogBuffer {
+    lines: Vec<String>,
+    /// Whether each line passes the filters, checked as it arrives
+    shown: Vec<bool>,
+    filters: Vec<LogFilter>,
+    bytes: usize,
+    max_lines: usize,
+    max_bytes: usize,
+    /// Older lines dropped to stay under the caps
+    pub dropped: usize,
+}
+
+impl LogBuffer {
+    pub fn new(max_lines: usize, max_bytes: usize) -> Self {
+        Self {
+            lines: Vec::new(),
+            shown: Vec::new(),
+            filters: Vec::new(),
+            bytes: 0,
+            max_lines: max_lines.max(1),
+            max_bytes: max_bytes.max(1),
+            dropped: 0,
+        }
+    }
+
+    /// Empty it with new caps for another container, keeping the filters
+    pub fn reset(&mut self, max_lines: usize, max_bytes: usize) {
+        *self = Self { filters: std::mem::take(&mut self.filters), ..Self::new(max_lines, max_bytes) };
+    }
+
+    /// Start over with a freshly fetched tail
+    pub fn replace(&mut self, lines: Vec<String>) {
+        self.bytes = lines.iter().map(String::len).sum();
+        self.shown = lines.iter().map(|line| self.passes(line)).collect();
+        self.lines = lines;
+        self.dropped = 0;
+        self.trim();
+    }
+
+    /// Append the lines of a fetch that come after the last line held.
+    /// Lines carry nanosecond timestamps, so the last one is found again
+    /// by value, or else everything newer than it is taken
+    pub fn extend_new(&mut self, fetched: Vec<String>) {
+        let Some(last) = self.lines.last() else {
+            return self.replace(fetched);
+        };
+        let start = match fetched.iter().rposition(|line| line == last) {
+            Some(i) => i + 1,
+            None => match line_timestamp(last) {
+                Some(at) => fetched.iter().position(|l| line_timestamp(l).is_some_and(|t| t > at)).unwrap_or(fetched.len()),
+                None => return self.replace(fetched),
+            },
+        };
+        for line in fetched.into_iter().skip(start) {
+            self.bytes += line.len();
+            self.shown.push(self.passes(&line));
+            self.lines.push(line);
+        }
+        self.trim();
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.shown.clear();
+        self.bytes = 0;
+        self.dropped = 0;
+    }
+
+    pub fn filters(&self) -> &[LogFilter] {
+        &self.filters
+    }
+
+    pub fn add_filter(&mut self, filter: LogFilter) {
+        self.filters.push(filter);
+        self.refilter();
+    }
+
+    pub fn toggle_filter(&mut self, index: usize) {
+        if let Some(filter) = self.filters.get_mut(index) {
+            filter.enabled = !filter.enabled;
+            self.refilter();
+        }
+    }
+
+    pub fn remove_filter(&mut self, index: usize) {
+        if index < self.filters.len() {
+            self.filters.remove(index);
+            self.refilter();
+        }
+    }
+
+    /// Whether line `index` passes the filters
+    pub fn is_shown(&self, index: usize) -> bool {
+        self.shown.get(index).copied().unwrap_or(true)
+    }
+
+    /// Lines held but filtered out
+    pub fn hidden(&self) -> usize {
+        self.shown.iter().filter(|shown| !**shown).count()
+    }
+
+    fn passes(&self, line: &str) -> bool {
+        self.filters.iter().all(|filter| filter.passes(line))
+    }
+
+    fn refilter(&mut self) {
+        self.shown = self.lines.iter().map(|line| self.passes(line)).collect();
+    }
+
+    /// Raise the caps to hold at least `lines` lines, keeping their ratio
+    pub fn reserve_lines(&mut self, lines: usize) {
+        if lines > self.max_lines {
+            self.max_bytes = (self.max_bytes as f64 * lines as f64 / self.max_lines as f64) as usize;
+            self.max_lines = lines;
+        }
+    }
+
+    /// Timestamp of the newest line
+    pub fn last_timestamp(&self) -> Option<DateTime<Utc>> {
+        self.lines.iter().rev().find_map(|line| line_timestamp(line))
+    }
+
+    fn trim(&mut self) {
+        let mut excess = self.lines.len().saturating_sub(self.max_lines);
+        let mut bytes = self.bytes - self.lines[..excess].iter().map(String::len).sum::<usize>();
+        // Always keep the newest line, however long
+        while bytes > self.max_bytes && excess + 1 < self.lines.len() {
+            bytes -= self.lines[excess].len();
+            excess += 1;
+        }
+        if excess > 0 {
+            self.lines.drain(..excess);
+            self.shown.drain(..excess);
+            self.bytes = bytes;
+            self.dropped += excess;
+        }
+    }
+}
+
+impl Deref for LogBuffer {
+    type Target = [String];
+
+    fn deref(&self) -> &[String] {
+        &self.lines
+    }
+}
+
+/// Get logs from a container: the last `tail` lines, or with `since` (unix
+/// seconds) everything from then on
 pub async fn get_container_logs(
     docker: &Docker,
     container_name: &str,
     tail: usize,
+    since: Option<i64>,
 ) -> Result<Vec<String>> {
     let options = LogsOptions::<String> {
         stdout: true,
         stderr: true,
-        tail: tail.to_string(),
+        tail: if since.is_some() { "all".to_string() } else { tail.to_string() },
+        since: since.unwrap_or(0),
         timestamps: true,
         ..Default::default()
     };
@@ -41,3 +238,178 @@ pub async fn get_container_logs(
 
     Ok(logs)
 }
+
+/// Logs of several containers as one stream, like `docker compose logs`:
+/// each container's last `tail` lines (or everything since `since`),
+/// merged by timestamp and prefixed with the container's name
+pub async fn get_project_logs(
+    docker: &Docker,
+    containers: &[String],
+    tail: usize,
+    since: Option<i64>,
+) -> Result<Vec<String>> {
+    let fetches = containers.iter().map(|name| get_container_logs(docker, name, tail, since));
+    let streams = futures_util::future::join_all(fetches).await;
+    let streams = containers
+        .iter()
+        .zip(streams)
+        .map(|(name, lines)| Ok((name.clone(), lines?)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(merge_prefixed(streams))
+}
+
+/// Interleave per-container lines by timestamp (stable, so ties keep the
+/// containers' order), putting "name |" after each line's timestamp with
+/// the names padded to line up
+fn merge_prefixed(streams: Vec<(String, Vec<String>)>) -> Vec<String> {
+    let width = streams.iter().map(|(name, _)| name.chars().count()).max().unwrap_or(0);
+    let mut merged = Vec::new();
+    for (name, lines) in streams {
+        // Lines without a timestamp stay after the line before them
+        let mut at = None;
+        for line in lines {
+            let prefixed = match line_timestamp(&line) {
+                Some(stamp) => {
+                    at = Some(stamp);
+                    let (timestamp, rest) = line.split_once(' ').unwrap_or((&line, ""));
+                    format!("{} {:<width$} | {}", timestamp, name, rest)
+                }
+                None => format!("{:<width$} | {}", name, line),
+            };
+            merged.push((at, prefixed));
+        }
+    }
+    merged.sort_by_key(|(at, _)| *at);
+    merged.into_iter().map(|(_, line)| line).collect()
+}
+
+/// Timestamp docker/kubectl put at the start of each line (`--timestamps`)
+pub fn line_timestamp(line: &str) -> Option<DateTime<Utc>> {
+    let token = line.split_whitespace().next()?;
+    DateTime::parse_from_rfc3339(token).ok().map(|t| t.with_timezone(&Utc))
+}
+
+/// Index of the first line at or after `target` (else the last timestamped line)
+pub fn closest_line(logs: &[String], target: DateTime<Utc>) -> Option<usize> {
+    let mut last = None;
+    for (i, line) in logs.iter().enumerate() {
+        if let Some(at) = line_timestamp(line) {
+            if at >= target {
+                return Some(i);
+            }
+            last = Some(i);
+        }
+    }
+    last
+}
+
+/// Parse a go-to-time input in UTC, like the log timestamps: a full RFC 3339
+/// timestamp, "YYYY-MM-DD HH:MM[:SS]", or "HH:MM[:SS]" on the day of
+/// `latest` (the day before if that would be later than `latest`)
+pub fn parse_goto_time(input: &str, latest: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let input = input.trim();
+    if let Ok(at) = DateTime::parse_from_rfc3339(input) {
+        return Some(at.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(at) = NaiveDateTime::parse_from_str(input, format) {
+            return Some(at.and_utc());
+        }
+    }
+    let time = ["%H:%M:%S", "%H:%M"]
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(input, format).ok())?;
+    let at = latest.date_naive().and_time(time).and_utc();
+    Some(if at > latest { at - chrono::Duration::days(1) } else { at })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_buffer() {
+        let line = |s: u32| format!("2024-05-01T10:00:{:02}.000000000Z line {}", s, s);
+        let mut logs = LogBuffer::new(3, 1 << 20);
+        logs.replace((0..2).map(line).collect());
+        // A refetch overlapping what is held only adds the new lines
+        logs.extend_new((1..4).map(line).collect());
+        assert_eq!(logs.len(), 3);
+        assert_eq!(logs.dropped, 1);
+        assert_eq!(logs[0], line(1));
+        // Without overlap, lines newer than the last held one are taken
+        logs.extend_new(vec![line(2), line(5)]);
+        assert_eq!(logs.last(), Some(&line(5)));
+        assert_eq!(logs.dropped, 2);
+
+        // The byte cap drops old lines too, but never the newest
+        let mut logs = LogBuffer::new(100, line(0).len() * 2);
+        logs.replace((0..5).map(line).collect());
+        assert_eq!((logs.len(), logs.dropped), (2, 3));
+        logs.reserve_lines(200);
+        logs.extend_new((5..9).map(line).collect());
+        assert_eq!((logs.len(), logs.dropped), (4, 5));
+    }
+
+    #[test]
+    fn test_merge_prefixed() {
+        let streams = vec![
+            ("web-1".to_string(), vec!["2024-05-01T10:00:01.000000000Z GET /".to_string(), "  continued".to_string()]),
+            ("db".to_string(), vec!["2024-05-01T10:00:00.000000000Z ready".to_string(), "2024-05-01T10:00:01.000000000Z query".to_string()]),
+        ];
+        assert_eq!(
+            merge_prefixed(streams),
+            vec![
+                "2024-05-01T10:00:00.000000000Z db    | ready",
+                "2024-05-01T10:00:01.000000000Z web-1 | GET /",
+                "web-1 |   continued",
+                "2024-05-01T10:00:01.000000000Z db    | query",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_log_filters() {
+        let mut logs = LogBuffer::new(100, 1 << 20);
+        logs.add_filter(LogFilter::parse("!GET /health").unwrap());
+        logs.replace(vec![
+            "2024-05-01T10:00:00.000000000Z GET /health 200".to_string(),
+            "2024-05-01T10:00:01.000000000Z POST /login 500".to_string(),
+        ]);
+        logs.extend_new(vec!["2024-05-01T10:00:02.000000000Z GET /health 200".to_string()]);
+        assert_eq!((0..3).map(|i| logs.is_shown(i)).collect::<Vec<_>>(), [false, true, false]);
+
+        // Anchors apply to the message, not the timestamp
+        logs.add_filter(LogFilter::parse("^POST").unwrap());
+        assert_eq!(logs.hidden(), 2);
+        logs.toggle_filter(0);
+        assert_eq!((0..3).map(|i| logs.is_shown(i)).collect::<Vec<_>>(), [false, true, false]);
+        logs.remove_filter(1);
+        assert_eq!(logs.hidden(), 0);
+        assert_eq!(logs.filters()[0].label(), "-GET /health");
+
+        assert!(LogFilter::parse("!").is_err());
+        assert!(LogFilter::parse("(unclosed").is_err());
+    }
+
+    #[test]
+    fn test_goto_time() {
+        let logs = vec![
+            "2024-05-01T23:50:00.000000000Z starting".to_string(),
+            "no timestamp".to_string(),
+            "2024-05-02T00:10:30.500000000Z ready".to_string(),
+        ];
+        let latest = line_timestamp(&logs[2]).unwrap();
+
+        let target = parse_goto_time("23:55", latest).unwrap();
+        assert_eq!(target.to_rfc3339(), "2024-05-01T23:55:00+00:00");
+        assert_eq!(closest_line(&logs, target), Some(2));
+
+        let target = parse_goto_time("2024-05-01 12:00", latest).unwrap();
+        assert_eq!(closest_line(&logs, target), Some(0));
+
+        let target = parse_goto_time("00:11", latest).unwrap();
+        assert_eq!(target.to_rfc3339(), "2024-05-01T00:11:00+00:00");
+        assert_eq!(parse_goto_time("noon", latest), None);
+    }
+}
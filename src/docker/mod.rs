@@ -1,4 +1,9 @@
 pub mod client;
+pub mod events;
+pub mod exec_session;
 pub mod gpu;
 pub mod logs;
+pub mod pids;
+pub mod registry;
+pub mod startup;
 pub mod stats;
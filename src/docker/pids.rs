@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::fs;
+
+/// Remembers the container of each PID it was asked about, so refreshes only
+/// read /proc/<pid>/cgroup for processes that are new since the last one
+#[derive(Debug, Default)]
+pub struct PidMapper {
+    known: HashMap<u32, Option<String>>,
+}
+
+impl PidMapper {
+    /// Container ID of a process, `None` for host processes
+    pub fn container_id(&mut self, pid: u32) -> Option<&str> {
+        self.known.entry(pid).or_insert_with(|| pid_to_container_id(pid)).as_deref()
+    }
+
+    /// Forget PIDs that are gone, before they're reused by other processes
+    pub fn retain(&mut self, mut alive: impl FnMut(u32) -> bool) {
+        self.known.retain(|pid, _| alive(*pid));
+    }
+}
+
+/// Map a PID to its container ID by reading cgroup info
+pub fn pid_to_container_id(pid: u32) -> Option<String> {
+    // Try cgroup v2 first (unified hierarchy)
+    if let Some(id) = pid_to_container_id_cgroupv2(pid) {
+        return Some(id);
+    }
+
+    // Fall back to cgroup v1
+    pid_to_container_id_cgroupv1(pid)
+}
+
+/// Get container ID from cgroup v2
+fn pid_to_container_id_cgroupv2(pid: u32) -> Option<String> {
+    let cgroup_path = format!("/proc/{}/cgroup", pid);
+    let content = fs::read_to_string(&cgroup_path).ok()?;
+
+    // cgroup v2 format: "0::/path/to/cgroup"
+    // Docker containers: "0::/docker/<container_id>"
+    // or "0::/system.slice/docker-<container_id>.scope"
+    for line in content.lines() {
+        if let Some(path) = line.strip_prefix("0::") {
+            // Check for docker container patterns
+            if let Some(id) = extract_container_id_from_path(path) {
+                return Some(id);
+            }
+        }
+    }
+
+    None
+}
+
+/// Get container ID from cgroup v1
+fn pid_to_container_id_cgroupv1(pid: u32) -> Option<String> {
+    let cgroup_path = format!("/proc/{}/cgroup", pid);
+    let content = fs::read_to_string(&cgroup_path).ok()?;
+
+    // cgroup v1 format: "N:controller:/path"
+    // Look for docker in any controller
+    for line in content.lines() {
+        let parts: Vec<&str> = line.splitn(3, ':').collect();
+        if parts.len() >= 3 {
+            if let Some(id) = extract_container_id_from_path(parts[2]) {
+                return Some(id);
+            }
+        }
+    }
+
+    None
+}
+
+/// Extract container ID from a cgroup path
+fn extract_container_id_from_path(path: &str) -> Option<String> {
+    // Pattern 1: /docker/<container_id>
+    if let Some(pos) = path.find("/docker/") {
+        let id_start = pos + 8;
+        let id = &path[id_start..];
+        // Container IDs are 64 hex chars, but we only need first 12 for matching
+        if id.len() >= 12 && id.chars().take(12).all(|c| c.is_ascii_hexdigit()) {
+            return Some(id.chars().take(64).collect());
+        }
+    }
+
+    // Pattern 2: docker-<container_id>.scope
+    if let Some(pos) = path.find("docker-") {
+        let id_start = pos + 7;
+        let remaining = &path[id_start..];
+        if let Some(end) = remaining.find('.') {
+            let id = &remaining[..end];
+            if id.len() >= 12 && id.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Some(id.to_string());
+            }
+        }
+    }
+
+    // Pattern 3: /containerd/<container_id>
+    if let Some(pos) = path.find("/containerd/") {
+        let id_start = pos + 12;
+        let id = &path[id_start..];
+        if id.len() >= 12 {
+            return Some(id.chars().take(64).collect());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_container_id_docker() {
+        let path = "/docker/abc123def456789012345678901234567890123456789012345678901234";
+        assert!(extract_container_id_from_path(path).is_some());
+    }
+
+    #[test]
+    fn test_extract_container_id_scope() {
+        let path = "/system.slice/docker-abc123def456.scope";
+        assert!(extract_container_id_from_path(path).is_some());
+    }
+}
@@ -0,0 +1,377 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::config::RegistryAuth;
+
+/// Docker Hub's registry host
+pub const DOCKER_HUB: &str = "docker.io";
+
+/// Most Docker Hub tag pages (100 tags each) fetched per lookup
+const MAX_HUB_PAGES: usize = 5;
+
+/// Give up on a registry request after this long
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Manifest types to ask for, so multi-arch images report their index digest
+const MANIFEST_ACCEPT: &str = "application/vnd.oci.image.index.v1+json, \
+application/vnd.docker.distribution.manifest.list.v2+json, \
+application/vnd.oci.image.manifest.v1+json, \
+application/vnd.docker.distribution.manifest.v2+json";
+
+/// A repository split into its registry host and path
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepoRef {
+    pub registry: String,
+    /// Path within the registry (Docker Hub official images get "library/")
+    pub path: String,
+}
+
+impl RepoRef {
+    /// Parse "nginx", "user/app:1.0" or "ghcr.io/org/app"; any tag or digest is dropped
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let input = input.split('@').next().unwrap_or(input);
+        // A colon after the last slash is a tag; before it, a registry port
+        let name = match input.rfind(':') {
+            Some(i) if !input[i..].contains('/') => &input[..i],
+            _ => input,
+        };
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return None;
+        }
+
+        let (registry, path) = match name.split_once('/') {
+            Some((host, rest)) if host.contains('.') || host.contains(':') || host == "localhost" => {
+                (host.to_string(), rest.to_string())
+            }
+            _ => (DOCKER_HUB.to_string(), name.to_string()),
+        };
+        let registry = match registry.as_str() {
+            "index.docker.io" | "registry-1.docker.io" => DOCKER_HUB.to_string(),
+            _ => registry,
+        };
+        let path = if registry == DOCKER_HUB && !path.contains('/') {
+            format!("library/{}", path)
+        } else {
+            path
+        };
+        (!path.is_empty()).then_some(Self { registry, path })
+    }
+
+    pub fn is_docker_hub(&self) -> bool {
+        self.registry == DOCKER_HUB
+    }
+
+    /// Image name as `docker pull` expects it, e.g. "nginx" or "ghcr.io/org/app"
+    pub fn image(&self) -> String {
+        if self.is_docker_hub() {
+            self.path.strip_prefix("library/").unwrap_or(&self.path).to_string()
+        } else {
+            format!("{}/{}", self.registry, self.path)
+        }
+    }
+}
+
+/// Tag of an image reference ("latest" when none is given, None when pinned by digest)
+pub fn image_tag(image: &str) -> Option<&str> {
+    if image.contains('@') {
+        return None;
+    }
+    match image.rfind(':') {
+        Some(i) if !image[i..].contains('/') => Some(&image[i + 1..]),
+        _ => Some("latest"),
+    }
+}
+
+/// A tag published for a repository
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegistryTag {
+    pub name: String,
+    /// Only Docker Hub reports these
+    pub updated: Option<DateTime<Utc>>,
+    pub size: Option<u64>,
+}
+
+/// List the tags of a repository, newest first where the registry says
+pub async fn fetch_tags(repo: &RepoRef, auth: Option<&RegistryAuth>) -> Result<Vec<RegistryTag>> {
+    // Hub's own API has dates and sizes but only serves public repositories
+    if repo.is_docker_hub() && auth.is_none() {
+        return fetch_hub_tags(repo).await;
+    }
+    let host = api_host(&repo.registry);
+    let url = format!("{}://{}/v2/{}/tags/list", scheme(host), host, repo.path);
+    let body = get_with_auth(&url, auth).await?;
+
+    #[derive(Deserialize)]
+    struct TagList {
+        #[serde(default)]
+        tags: Option<Vec<String>>,
+    }
+    let list: TagList = serde_json::from_str(&body).context(format!("Unexpected tag list from {}", host))?;
+    let mut tags: Vec<RegistryTag> = list
+        .tags
+        .unwrap_or_default()
+        .into_iter()
+        .map(|name| RegistryTag { name, updated: None, size: None })
+        .collect();
+    // The v2 API lists tags lexically; show newer-looking versions first
+    tags.reverse();
+    Ok(tags)
+}
+
+/// Digest the registry currently serves for a tag (a HEAD request, which
+/// doesn't count against Docker Hub's pull limit)
+pub async fn manifest_digest(repo: &RepoRef, tag: &str, auth: Option<&RegistryAuth>) -> Result<String> {
+    let host = api_host(&repo.registry);
+    let url = format!("{}://{}/v2/{}/manifests/{}", scheme(host), host, repo.path, tag);
+    let response = request_with_auth(&url, auth, Some(MANIFEST_ACCEPT), true).await?;
+    response
+        .header("docker-content-digest")
+        .map(str::to_string)
+        .context(format!("{} sent no digest for {}:{}", host, repo.image(), tag))
+}
+
+/// Check credentials against a registry's v2 base endpoint
+pub async fn check_login(registry: &str, auth: &RegistryAuth) -> Result<()> {
+    let host = api_host(registry);
+    get_with_auth(&format!("{}://{}/v2/", scheme(host), host), Some(auth))
+        .await
+        .map(|_| ())
+        .context(format!("Login to {} failed", registry))
+}
+
+/// Tags from the Docker Hub API, most recently pushed first
+async fn fetch_hub_tags(repo: &RepoRef) -> Result<Vec<RegistryTag>> {
+    #[derive(Deserialize)]
+    struct Page {
+        next: Option<String>,
+        results: Vec<HubTag>,
+    }
+    #[derive(Deserialize)]
+    struct HubTag {
+        name: String,
+        last_updated: Option<String>,
+        full_size: Option<u64>,
+    }
+
+    let mut tags = Vec::new();
+    let mut url = Some(format!(
+        "https://hub.docker.com/v2/repositories/{}/tags?page_size=100&ordering=last_updated",
+        repo.path
+    ));
+    for _ in 0..MAX_HUB_PAGES {
+        let Some(page_url) = url.take() else { break };
+        let response = http_request(&page_url, &[], false).await?;
+        if response.status == 404 {
+            anyhow::bail!("Repository not found on Docker Hub: {}", repo.image());
+        }
+        if response.status != 200 {
+            anyhow::bail!("Docker Hub returned HTTP {} for {}", response.status, repo.image());
+        }
+        let page: Page = serde_json::from_str(&response.body).context("Unexpected response from Docker Hub")?;
+        tags.extend(page.results.into_iter().map(|t| RegistryTag {
+            name: t.name,
+            updated: t
+                .last_updated
+                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                .map(|d| d.with_timezone(&Utc)),
+            size: t.full_size,
+        }));
+        url = page.next;
+    }
+    Ok(tags)
+}
+
+/// GET a registry URL, answering a Basic or Bearer token challenge if one comes back
+async fn get_with_auth(url: &str, auth: Option<&RegistryAuth>) -> Result<String> {
+    request_with_auth(url, auth, None, false).await.map(|response| response.body)
+}
+
+/// Request a registry URL (GET, or HEAD when `head`) with an optional
+/// Accept header, answering an auth challenge; only a 200 response is Ok
+async fn request_with_auth(url: &str, auth: Option<&RegistryAuth>, accept: Option<&str>, head: bool) -> Result<Response> {
+    let basic = auth.map(|a| format!("Basic {}", crate::clipboard::base64_encode(format!("{}:{}", a.username, a.password).as_bytes())));
+    let headers = |authorization: Option<&str>| -> Vec<(&'static str, String)> {
+        accept
+            .map(|a| ("Accept", a.to_string()))
+            .into_iter()
+            .chain(authorization.map(|a| ("Authorization", a.to_string())))
+            .collect()
+    };
+
+    let mut response = http_request(url, &headers(None), head).await?;
+    if response.status == 401 {
+        let challenge = response.header("www-authenticate").and_then(parse_challenge);
+        let authorization = match challenge {
+            Some((scheme, params)) if scheme.eq_ignore_ascii_case("bearer") => {
+                Some(format!("Bearer {}", fetch_token(&params, basic.as_deref()).await?))
+            }
+            _ => basic.clone(),
+        };
+        if let Some(authorization) = authorization {
+            response = http_request(url, &headers(Some(&authorization)), head).await?;
+        }
+    }
+
+    match response.status {
+        200 => Ok(response),
+        401 | 403 => anyhow::bail!("Registry denied access (log in with L or add credentials under [registries] in config.toml)"),
+        404 => anyhow::bail!("Repository not found"),
+        status => anyhow::bail!("Registry returned HTTP {}", status),
+    }
+}
+
+/// Get a bearer token from the realm named in a challenge
+async fn fetch_token(params: &HashMap<String, String>, basic: Option<&str>) -> Result<String> {
+    let realm = params.get("realm").context("Registry auth challenge has no realm")?;
+    let query: Vec<String> = ["service", "scope"]
+        .iter()
+        .filter_map(|key| params.get(*key).map(|value| format!("{}={}", key, value)))
+        .collect();
+    let url = if query.is_empty() { realm.clone() } else { format!("{}?{}", realm, query.join("&")) };
+
+    let authorization: Vec<(&str, String)> = basic.map(|b| ("Authorization", b.to_string())).into_iter().collect();
+    let response = http_request(&url, &authorization, false).await?;
+    if response.status != 200 {
+        anyhow::bail!("Registry token request failed with HTTP {}", response.status);
+    }
+
+    #[derive(Deserialize)]
+    struct Token {
+        token: Option<String>,
+        access_token: Option<String>,
+    }
+    let token: Token = serde_json::from_str(&response.body).context("Unexpected token response")?;
+    token.token.or(token.access_token).context("Registry returned no token")
+}
+
+/// Parse `Bearer realm="...",service="...",scope="..."` into the scheme and its parameters
+fn parse_challenge(header: &str) -> Option<(String, HashMap<String, String>)> {
+    let (scheme, rest) = header.trim().split_once(' ').unwrap_or((header.trim(), ""));
+    let mut params = HashMap::new();
+    let mut rest = rest.trim();
+    while let Some((key, after)) = rest.split_once('=') {
+        let key = key.trim().trim_start_matches(',').trim().to_lowercase();
+        let (value, remaining) = match after.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"')?;
+                (&quoted[..end], &quoted[end + 1..])
+            }
+            None => after.split_once(',').map_or((after, ""), |(v, r)| (v, r)),
+        };
+        params.insert(key, value.to_string());
+        rest = remaining.trim_start_matches(',').trim();
+    }
+    (!scheme.is_empty()).then(|| (scheme.to_string(), params))
+}
+
+/// Host serving the v2 API for a registry (Docker Hub's differs from its name)
+fn api_host(registry: &str) -> &str {
+    if registry == DOCKER_HUB {
+        "registry-1.docker.io"
+    } else {
+        registry
+    }
+}
+
+/// Local registries usually don't have TLS set up
+fn scheme(host: &str) -> &'static str {
+    if host.starts_with("localhost") || host.starts_with("127.0.0.1") {
+        "http"
+    } else {
+        "https"
+    }
+}
+
+/// Status, headers and body of an HTTP response
+struct Response {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Response {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// HTTP client shared by every registry request, so connections are reused
+fn client() -> Result<&'static reqwest::Client> {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    if let Some(client) = CLIENT.get() {
+        return Ok(client);
+    }
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .user_agent(concat!("backplane-tui/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .context("Failed to set up the HTTP client")?;
+    Ok(CLIENT.get_or_init(|| client))
+}
+
+/// GET (or HEAD) a URL, following redirects
+async fn http_request(url: &str, headers: &[(&str, String)], head: bool) -> Result<Response> {
+    let client = client()?;
+    let mut request = if head { client.head(url) } else { client.get(url) };
+    for (name, value) in headers {
+        request = request.header(*name, value);
+    }
+    let response = request.send().await.context(format!("Request to {} failed", url))?;
+
+    let status = response.status().as_u16();
+    let headers = response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect();
+    let body = response.text().await.context(format!("Failed to read the response from {}", url))?;
+    Ok(Response { status, headers, body })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_tag() {
+        assert_eq!(image_tag("nginx"), Some("latest"));
+        assert_eq!(image_tag("localhost:5000/app:1.2"), Some("1.2"));
+        assert_eq!(image_tag("localhost:5000/app"), Some("latest"));
+        assert_eq!(image_tag("nginx@sha256:abc"), None);
+    }
+
+    #[test]
+    fn test_parse_repo_ref() {
+        let hub = RepoRef::parse("nginx:1.25").unwrap();
+        assert_eq!(hub.registry, DOCKER_HUB);
+        assert_eq!(hub.path, "library/nginx");
+        assert_eq!(hub.image(), "nginx");
+
+        let local = RepoRef::parse("localhost:5000/team/app@sha256:abc").unwrap();
+        assert_eq!(local.registry, "localhost:5000");
+        assert_eq!(local.path, "team/app");
+        assert_eq!(local.image(), "localhost:5000/team/app");
+
+        assert_eq!(RepoRef::parse("grafana/grafana").unwrap().path, "grafana/grafana");
+        assert!(RepoRef::parse("  ").is_none());
+    }
+
+    #[test]
+    fn test_parse_challenge() {
+        let (scheme, params) = parse_challenge(
+            r#"Bearer realm="https://auth.docker.io/token",service="registry.docker.io",scope="repository:library/nginx:pull""#,
+        )
+        .unwrap();
+        assert_eq!(scheme, "Bearer");
+        assert_eq!(params["realm"], "https://auth.docker.io/token");
+        assert_eq!(params["scope"], "repository:library/nginx:pull");
+    }
+}
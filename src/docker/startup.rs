@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::docker::client::DockerClient;
+use crate::models::{ContainerInfo, ContainerStatus};
+
+/// How long to wait for a dependency to become healthy
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(120);
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Progress of one container in an ordered start
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepState {
+    Pending,
+    Starting,
+    /// Started; waiting for its healthcheck before starting dependents
+    WaitingHealthy,
+    Done,
+    Failed(String),
+    /// Not started because a dependency failed
+    Skipped,
+}
+
+#[derive(Debug, Clone)]
+pub struct StartupStep {
+    pub name: String,
+    /// Container names (within this start) that must be up first
+    pub depends_on: Vec<String>,
+    pub state: StepState,
+}
+
+/// An ordered start of several containers, shared with the task running it
+#[derive(Debug, Clone)]
+pub struct Startup {
+    pub title: String,
+    pub steps: Vec<StartupStep>,
+    pub finished: bool,
+}
+
+impl Startup {
+    /// Plan the start of `names`, ordering them by their compose dependencies
+    /// on each other. Returns None if none of them depend on another.
+    pub fn plan(title: String, names: &[String], containers: &[ContainerInfo]) -> Option<Self> {
+        let selected: Vec<&ContainerInfo> = names
+            .iter()
+            .filter_map(|name| containers.iter().find(|c| &c.name == name))
+            .collect();
+        let container_of = |project: &Option<String>, service: &str| {
+            selected
+                .iter()
+                .find(|c| &c.compose_project == project && c.compose_service.as_deref() == Some(service))
+                .map(|c| c.name.clone())
+        };
+        let steps: Vec<StartupStep> = selected
+            .iter()
+            .map(|c| StartupStep {
+                name: c.name.clone(),
+                depends_on: c.depends_on.iter().filter_map(|s| container_of(&c.compose_project, s)).collect(),
+                state: StepState::Pending,
+            })
+            .collect();
+        if steps.iter().all(|s| s.depends_on.is_empty()) {
+            return None;
+        }
+        Some(Self {
+            title,
+            steps: start_order(steps),
+            finished: false,
+        })
+    }
+
+    fn set_state(shared: &Mutex<Self>, index: usize, state: StepState) {
+        if let Ok(mut startup) = shared.lock() {
+            startup.steps[index].state = state;
+        }
+    }
+
+    /// Start the steps in order on a background task
+    pub fn spawn(self, docker: DockerClient) -> Arc<Mutex<Self>> {
+        let steps = self.steps.clone();
+        let shared = Arc::new(Mutex::new(self));
+        let progress = shared.clone();
+        tokio::spawn(async move {
+            let has_dependents = |name: &str| steps.iter().any(|s| s.depends_on.iter().any(|d| d == name));
+            let mut failed: HashSet<String> = HashSet::new();
+            for (i, step) in steps.iter().enumerate() {
+                if step.depends_on.iter().any(|d| failed.contains(d)) {
+                    failed.insert(step.name.clone());
+                    Self::set_state(&progress, i, StepState::Skipped);
+                    continue;
+                }
+                Self::set_state(&progress, i, StepState::Starting);
+                let result = match docker.start_container(&step.name).await {
+                    Ok(()) if has_dependents(&step.name) => {
+                        Self::set_state(&progress, i, StepState::WaitingHealthy);
+                        wait_until_ready(&docker, &step.name).await
+                    }
+                    Ok(()) => Ok(()),
+                    Err(e) => Err(format!("{:#}", e)),
+                };
+                match result {
+                    Ok(()) => Self::set_state(&progress, i, StepState::Done),
+                    Err(e) => {
+                        failed.insert(step.name.clone());
+                        Self::set_state(&progress, i, StepState::Failed(e));
+                    }
+                }
+            }
+            if let Ok(mut startup) = progress.lock() {
+                startup.finished = true;
+            }
+        });
+        shared
+    }
+}
+
+/// Wait for a healthcheck to pass, or just for the container to be running
+/// if it has none
+async fn wait_until_ready(docker: &DockerClient, name: &str) -> Result<(), String> {
+    let deadline = Instant::now() + HEALTH_TIMEOUT;
+    loop {
+        let state = docker.inspect_state(name).await.map_err(|e| format!("{:#}", e))?;
+        match (state.status, state.health.as_deref()) {
+            (ContainerStatus::Running, None | Some("healthy")) => return Ok(()),
+            (_, Some("unhealthy")) => return Err("unhealthy".to_string()),
+            (ContainerStatus::Exited | ContainerStatus::Dead, _) => return Err("exited".to_string()),
+            _ if Instant::now() >= deadline => return Err("timed out waiting for healthy".to_string()),
+            _ => tokio::time::sleep(POLL_INTERVAL).await,
+        }
+    }
+}
+
+/// Order steps so dependencies come first (stable by input order).
+/// Steps caught in a dependency cycle are appended as they are.
+fn start_order(mut pending: Vec<StartupStep>) -> Vec<StartupStep> {
+    let mut ordered: Vec<StartupStep> = Vec::with_capacity(pending.len());
+    loop {
+        let ready = pending
+            .iter()
+            .position(|step| step.depends_on.iter().all(|d| ordered.iter().any(|o| &o.name == d)));
+        match ready {
+            Some(i) => ordered.push(pending.remove(i)),
+            None => break,
+        }
+    }
+    ordered.extend(pending);
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(name: &str, depends_on: &[&str]) -> StartupStep {
+        StartupStep {
+            name: name.to_string(),
+            depends_on: depends_on.iter().map(|d| d.to_string()).collect(),
+            state: StepState::Pending,
+        }
+    }
+
+    #[test]
+    fn test_start_order() {
+        let steps = vec![step("web", &["api"]), step("api", &["db", "cache"]), step("db", &[]), step("cache", &[])];
+        let names: Vec<String> = start_order(steps).into_iter().map(|s| s.name).collect();
+        assert_eq!(names, ["db", "cache", "api", "web"]);
+
+        let cycle = vec![step("a", &["b"]), step("b", &["a"]), step("c", &[])];
+        let names: Vec<String> = start_order(cycle).into_iter().map(|s| s.name).collect();
+        assert_eq!(names, ["c", "a", "b"]);
+    }
+}
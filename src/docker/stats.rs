@@ -1,10 +1,16 @@
 use anyhow::Result;
-use bollard::container::StatsOptions;
+use bollard::container::{BlkioStats, MemoryStats, MemoryStatsStats, StatsOptions, StorageStats};
 use bollard::Docker;
+use futures_util::stream::FuturesUnordered;
 use futures_util::StreamExt;
+use std::collections::HashMap;
+use std::time::Duration;
 
 use crate::models::ContainerStats;
 
+/// Most stats requests in flight at once
+const STATS_CONCURRENCY: usize = 16;
+
 /// Get stats for a container (single snapshot)
 pub async fn get_container_stats(docker: &Docker, container_name: &str) -> Result<ContainerStats> {
     let options = StatsOptions {
@@ -20,11 +26,9 @@ pub async fn get_container_stats(docker: &Docker, container_name: &str) -> Resul
         // Calculate CPU percentage
         let cpu_percent = calculate_cpu_percent(&stats);
 
-        // Calculate memory usage
-        let memory_usage = stats
-            .memory_stats
-            .usage
-            .unwrap_or(0) as f64;
+        // Calculate memory usage: the working set, without reclaimable page cache
+        let memory = memory_breakdown(&stats.memory_stats);
+        let memory_usage = memory.working_set as f64;
         let memory_limit = stats
             .memory_stats
             .limit
@@ -51,15 +55,25 @@ pub async fn get_container_stats(docker: &Docker, container_name: &str) -> Resul
             (0, 0)
         };
 
+        let (blk_read_bytes, blk_write_bytes) = block_io_bytes(&stats.blkio_stats, &stats.storage_stats);
+
         Ok(ContainerStats {
             cpu_percent,
             memory_usage_mb,
             memory_limit_mb,
             memory_percent,
+            memory_cache_mb: memory.cache.map(|bytes| bytes as f64 / 1024.0 / 1024.0),
+            memory_rss_mb: memory.rss.map(|bytes| bytes as f64 / 1024.0 / 1024.0),
             net_rx_bytes,
             net_tx_bytes,
             net_rx_rate: 0.0, // Rate calculated separately
             net_tx_rate: 0.0,
+            blk_read_bytes,
+            blk_write_bytes,
+            blk_read_rate: 0.0, // Rate calculated separately
+            blk_write_rate: 0.0,
+            pids: stats.pids_stats.current,
+            pids_limit: stats.pids_stats.limit,
             vram_usage_mb: None, // Set by app from GPU metrics
         })
     } else {
@@ -67,6 +81,72 @@ pub async fn get_container_stats(docker: &Docker, container_name: &str) -> Resul
     }
 }
 
+/// Stats for several containers, fetched concurrently (at most
+/// `STATS_CONCURRENCY` at a time). Containers that fail, or haven't
+/// answered when `budget` runs out, are left out so one slow daemon
+/// call doesn't hold up the rest of the refresh
+pub async fn get_containers_stats(docker: &Docker, names: &[String], budget: Duration) -> HashMap<String, ContainerStats> {
+    let deadline = tokio::time::Instant::now() + budget;
+    let fetch = |name: &String| {
+        let name = name.clone();
+        async move {
+            let stats = get_container_stats(docker, &name).await;
+            (name, stats)
+        }
+    };
+
+    let mut pending = names.iter();
+    let mut in_flight: FuturesUnordered<_> = pending.by_ref().take(STATS_CONCURRENCY).map(fetch).collect();
+    let mut collected = HashMap::new();
+    while let Ok(Some((name, stats))) = tokio::time::timeout_at(deadline, in_flight.next()).await {
+        if let Ok(stats) = stats {
+            collected.insert(name, stats);
+        }
+        if let Some(next) = pending.next() {
+            in_flight.push(fetch(next));
+        }
+    }
+    collected
+}
+
+/// A container's memory split as `docker stats` reports it
+struct MemoryBreakdown {
+    /// Usage minus inactive page cache, which the kernel can reclaim at will
+    working_set: u64,
+    /// Page cache (file-backed memory)
+    cache: Option<u64>,
+    /// Anonymous memory: heap, stacks and other process memory
+    rss: Option<u64>,
+}
+
+/// Split memory usage into working set, cache and RSS. cgroup v1 counts page
+/// cache in `usage`, which would otherwise inflate the memory percentage
+fn memory_breakdown(memory: &MemoryStats) -> MemoryBreakdown {
+    let usage = memory.usage.unwrap_or(0);
+    let (inactive_file, cache, rss) = match memory.stats {
+        Some(MemoryStatsStats::V1(ref v1)) => (v1.total_inactive_file, Some(v1.total_cache), Some(v1.total_rss)),
+        Some(MemoryStatsStats::V2(ref v2)) => (v2.inactive_file, Some(v2.file), Some(v2.anon)),
+        None => (0, None, None),
+    };
+    MemoryBreakdown { working_set: usage.saturating_sub(inactive_file), cache, rss }
+}
+
+/// Bytes read and written by the container's block devices. Linux reports
+/// them per device and operation (`Read`/`Write` on cgroup v1, lowercase on
+/// v2); Windows reports storage_stats instead
+fn block_io_bytes(blkio: &BlkioStats, storage: &StorageStats) -> (u64, u64) {
+    match blkio.io_service_bytes_recursive {
+        Some(ref entries) if !entries.is_empty() => entries.iter().fold((0, 0), |(read, write), entry| {
+            match entry.op.to_ascii_lowercase().as_str() {
+                "read" => (read + entry.value, write),
+                "write" => (read, write + entry.value),
+                _ => (read, write),
+            }
+        }),
+        _ => (storage.read_size_bytes.unwrap_or(0), storage.write_size_bytes.unwrap_or(0)),
+    }
+}
+
 /// Calculate CPU percentage from Docker stats
 fn calculate_cpu_percent(stats: &bollard::container::Stats) -> f64 {
     let cpu_stats = &stats.cpu_stats;
@@ -89,3 +169,29 @@ fn calculate_cpu_percent(stats: &bollard::container::Stats) -> f64 {
         0.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::container::BlkioStatsEntry;
+
+    #[test]
+    fn test_block_io_bytes() {
+        let entry = |op: &str, value| BlkioStatsEntry { major: 8, minor: 0, op: op.to_string(), value };
+        let blkio = BlkioStats {
+            io_service_bytes_recursive: Some(vec![entry("Read", 100), entry("write", 40), entry("Total", 140), entry("read", 5)]),
+            io_serviced_recursive: None,
+            io_queue_recursive: None,
+            io_service_time_recursive: None,
+            io_wait_time_recursive: None,
+            io_merged_recursive: None,
+            io_time_recursive: None,
+            sectors_recursive: None,
+        };
+        let storage = StorageStats { read_count_normalized: None, read_size_bytes: Some(7), write_count_normalized: None, write_size_bytes: Some(3) };
+        assert_eq!(block_io_bytes(&blkio, &storage), (105, 40));
+
+        let empty = BlkioStats { io_service_bytes_recursive: None, ..blkio };
+        assert_eq!(block_io_bytes(&empty, &storage), (7, 3));
+    }
+}
@@ -8,6 +8,12 @@ const SELECTION_COLORS: &[(u8, u8, u8)] = &[
     (120, 80, 180),  // Dark purple
 ];
 
+/// Braille spinner frames for the loading indicator
+const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Time each spinner frame is shown
+const SPINNER_FRAME_MS: u128 = 80;
+
 /// Manages visual effects for the application
 pub struct EffectManager {
     /// Startup fade-in effect
@@ -18,24 +24,43 @@ pub struct EffectManager {
     status_fx: Option<Effect>,
     /// When selection highlighting started
     selection_start: Instant,
+    /// When the spinner ticker started
+    spinner_start: Instant,
+    /// false = reduced motion: no effects, a still spinner
+    enabled: bool,
 }
 
 impl Default for EffectManager {
     fn default() -> Self {
-        Self::new()
+        Self::new(true)
     }
 }
 
 impl EffectManager {
-    pub fn new() -> Self {
+    pub fn new(enabled: bool) -> Self {
         Self {
-            startup_fx: Some(Self::create_startup_effect()),
+            startup_fx: enabled.then(Self::create_startup_effect),
             loading_fx: Some(Self::create_loading_effect()),
             status_fx: None,
             selection_start: Instant::now(),
+            spinner_start: Instant::now(),
+            enabled,
+        }
+    }
+
+    /// Turn animations on or off; turning them off drops any in flight
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.startup_fx = None;
+            self.status_fx = None;
         }
     }
 
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
     /// Create the initial fade-in effect for app startup
     fn create_startup_effect() -> Effect {
         fx::fade_from(
@@ -70,6 +95,9 @@ impl EffectManager {
 
     /// Trigger status change effect
     pub fn trigger_status_change(&mut self, running: bool) {
+        if !self.enabled {
+            return;
+        }
         self.status_fx = Some(Self::create_status_sweep_effect(running));
     }
 
@@ -86,7 +114,7 @@ impl EffectManager {
 
     /// Process loading effects (call on loading indicator area)
     pub fn process_loading(&mut self, elapsed: Duration, buf: &mut Buffer, area: Rect, is_loading: bool) {
-        if is_loading {
+        if is_loading && self.enabled {
             if let Some(ref mut fx) = self.loading_fx {
                 fx.process(elapsed.into(), buf, area);
                 // Don't clear - it loops via ping_pong
@@ -104,6 +132,20 @@ impl EffectManager {
         }
     }
 
+    /// Current frame of the braille loading spinner
+    pub fn spinner_frame(&self) -> &'static str {
+        if !self.enabled {
+            return SPINNER_FRAMES[0];
+        }
+        let idx = (self.spinner_start.elapsed().as_millis() / SPINNER_FRAME_MS) as usize;
+        SPINNER_FRAMES[idx % SPINNER_FRAMES.len()]
+    }
+
+    /// A fade or flash is playing
+    pub fn is_active(&self) -> bool {
+        self.startup_fx.is_some() || self.status_fx.is_some()
+    }
+
     /// Check if startup animation is still playing
     pub fn is_starting_up(&self) -> bool {
         self.startup_fx.is_some()
@@ -112,6 +154,9 @@ impl EffectManager {
     /// Render color cycling border effect on the selected row
     /// Call this after rendering the container list
     pub fn render_selection_highlight(&self, buf: &mut Buffer, area: Rect) {
+        if !self.enabled {
+            return;
+        }
         let elapsed = self.selection_start.elapsed().as_secs_f32();
         let speed = 20.0; // cells per second
         let base_idx = (elapsed * speed) as usize;
@@ -156,6 +201,9 @@ impl EffectManager {
 
     /// Render a subtle glow effect on a selected row (single line, not box)
     pub fn render_row_highlight(&self, buf: &mut Buffer, area: Rect) {
+        if !self.enabled {
+            return;
+        }
         let elapsed = self.selection_start.elapsed().as_secs_f32();
         let speed = 30.0; // cells per second for faster animation
         let base_idx = (elapsed * speed) as usize;
@@ -0,0 +1,149 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::models::ContainerInfo;
+
+/// Output format for container list exports
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// One exported container with its latest stats
+#[derive(Debug, Serialize)]
+pub struct ExportRow {
+    pub name: String,
+    pub id: String,
+    pub image: String,
+    pub status: String,
+    pub compose_project: Option<String>,
+    pub ports: String,
+    pub cpu_percent: Option<f64>,
+    pub memory_usage_mb: Option<f64>,
+    pub memory_limit_mb: Option<f64>,
+    pub memory_percent: Option<f64>,
+    pub net_rx_bytes: Option<u64>,
+    pub net_tx_bytes: Option<u64>,
+    pub net_rx_rate: Option<f64>,
+    pub net_tx_rate: Option<f64>,
+    pub vram_usage_mb: Option<f64>,
+}
+
+impl ExportRow {
+    pub fn from_container(c: &ContainerInfo) -> Self {
+        let stats = c.stats.as_ref();
+        Self {
+            name: c.name.clone(),
+            id: c.id.chars().take(12).collect(),
+            image: c.image.clone(),
+            status: c.status.as_str().to_string(),
+            compose_project: c.compose_project.clone(),
+            ports: c.ports.iter().map(|p| p.display()).collect::<Vec<_>>().join(" "),
+            cpu_percent: stats.map(|s| s.cpu_percent),
+            memory_usage_mb: stats.map(|s| s.memory_usage_mb),
+            memory_limit_mb: stats.map(|s| s.memory_limit_mb),
+            memory_percent: stats.map(|s| s.memory_percent),
+            net_rx_bytes: stats.map(|s| s.net_rx_bytes),
+            net_tx_bytes: stats.map(|s| s.net_tx_bytes),
+            net_rx_rate: stats.map(|s| s.net_rx_rate),
+            net_tx_rate: stats.map(|s| s.net_tx_rate),
+            vram_usage_mb: stats.and_then(|s| s.vram_usage_mb),
+        }
+    }
+}
+
+/// Render containers in the given format
+pub fn render(containers: &[&ContainerInfo], format: ExportFormat) -> Result<String> {
+    let rows: Vec<ExportRow> = containers.iter().map(|c| ExportRow::from_container(c)).collect();
+
+    match format {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(&rows)?),
+        ExportFormat::Csv => Ok(render_csv(&rows)),
+    }
+}
+
+/// Write containers to a timestamped file in the current directory
+pub fn write_file(containers: &[&ContainerInfo], format: ExportFormat) -> Result<PathBuf> {
+    let content = render(containers, format)?;
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let path = PathBuf::from(format!("backplane-export-{}.{}", timestamp, format.extension()));
+    std::fs::write(&path, content)?;
+    Ok(path)
+}
+
+fn render_csv(rows: &[ExportRow]) -> String {
+    let mut out = String::from(
+        "name,id,image,status,compose_project,ports,cpu_percent,memory_usage_mb,memory_limit_mb,\
+         memory_percent,net_rx_bytes,net_tx_bytes,net_rx_rate,net_tx_rate,vram_usage_mb\n",
+    );
+
+    let opt = |v: Option<String>| v.unwrap_or_default();
+
+    for r in rows {
+        let fields = [
+            csv_field(&r.name),
+            csv_field(&r.id),
+            csv_field(&r.image),
+            csv_field(&r.status),
+            csv_field(r.compose_project.as_deref().unwrap_or("")),
+            csv_field(&r.ports),
+            opt(r.cpu_percent.map(|v| format!("{:.2}", v))),
+            opt(r.memory_usage_mb.map(|v| format!("{:.1}", v))),
+            opt(r.memory_limit_mb.map(|v| format!("{:.1}", v))),
+            opt(r.memory_percent.map(|v| format!("{:.2}", v))),
+            opt(r.net_rx_bytes.map(|v| v.to_string())),
+            opt(r.net_tx_bytes.map(|v| v.to_string())),
+            opt(r.net_rx_rate.map(|v| format!("{:.0}", v))),
+            opt(r.net_tx_rate.map(|v| format!("{:.0}", v))),
+            opt(r.vram_usage_mb.map(|v| format!("{:.0}", v))),
+        ];
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Quote a CSV field if it contains separators, quotes or newlines
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_plain() {
+        assert_eq!(csv_field("nginx:latest"), "nginx:latest");
+    }
+
+    #[test]
+    fn test_csv_field_quotes_separators() {
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::{bail, Context, Result};
+use std::process::Stdio;
+use tokio::process::{Child, Command};
+
+/// A local port tunnelled to a container's internal port by a child process
+/// (socat for a local daemon, `ssh -L` for an ssh:// host)
+pub struct PortForward {
+    pub container: String,
+    pub local_port: u16,
+    /// Container address as `ip:port`
+    pub target: String,
+    pub via: &'static str,
+    /// Set once the process has exited, with how it exited
+    pub exited: Option<String>,
+    child: Child,
+}
+
+/// Port forwards started from the TUI. Their processes are killed when
+/// stopped, and when the TUI exits (dropping a forward kills its process)
+#[derive(Default)]
+pub struct PortForwards {
+    pub entries: Vec<PortForward>,
+}
+
+impl PortForwards {
+    /// Forward `local_port` on 127.0.0.1 to `ip:port` of a container
+    pub fn start(&mut self, container: &str, ip: &str, port: u16, local_port: u16, docker_host: Option<&str>) -> Result<()> {
+        if self.entries.iter().any(|f| f.local_port == local_port && f.exited.is_none()) {
+            bail!("Local port {} is already forwarded", local_port);
+        }
+        let target = format!("{}:{}", ip, port);
+        let via = forward_tool(docker_host)?;
+        let mut command = Command::new(via);
+        match via {
+            "ssh" => {
+                let host = docker_host.unwrap_or_default();
+                command.args(["-N", "-o", "ExitOnForwardFailure=yes", "-L"]);
+                command.arg(format!("127.0.0.1:{}:{}", local_port, target)).arg(host);
+            }
+            _ => {
+                command.arg(format!("TCP-LISTEN:{},bind=127.0.0.1,fork,reuseaddr", local_port));
+                command.arg(format!("TCP:{}", target));
+            }
+        }
+        let child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .context(format!("Failed to run {} (is it installed?)", via))?;
+
+        // Forget earlier forwards of the same port that have died
+        self.entries.retain(|f| !(f.local_port == local_port && f.exited.is_some()));
+        self.entries.push(PortForward {
+            container: container.to_string(),
+            local_port,
+            target,
+            via,
+            exited: None,
+            child,
+        });
+        Ok(())
+    }
+
+    /// Stop a forward and forget it
+    pub fn stop(&mut self, index: usize) -> Option<PortForward> {
+        if index >= self.entries.len() {
+            return None;
+        }
+        let mut forward = self.entries.remove(index);
+        let _ = forward.child.start_kill();
+        Some(forward)
+    }
+
+    /// Note forwards whose process exited (port taken, tunnel dropped),
+    /// returning a message for each one that just did
+    pub fn poll(&mut self) -> Vec<String> {
+        let mut messages = Vec::new();
+        for forward in self.entries.iter_mut().filter(|f| f.exited.is_none()) {
+            if let Ok(Some(status)) = forward.child.try_wait() {
+                let exited = match status.code() {
+                    Some(code) => format!("exited with status {}", code),
+                    None => "killed".to_string(),
+                };
+                messages.push(format!("Forward of port {} to {} {}", forward.local_port, forward.container, exited));
+                forward.exited = Some(exited);
+            }
+        }
+        messages
+    }
+
+    pub fn active(&self) -> usize {
+        self.entries.iter().filter(|f| f.exited.is_none()).count()
+    }
+}
+
+/// The tool that can reach container IPs for this Docker host: container
+/// IPs are only routable on the daemon's machine
+pub fn forward_tool(docker_host: Option<&str>) -> Result<&'static str> {
+    match docker_host {
+        None => Ok("socat"),
+        Some(host) if host.starts_with("unix://") => Ok("socat"),
+        Some(host) if host.starts_with("ssh://") => Ok("ssh"),
+        Some(host) => bail!("Can't forward ports through {}: container IPs are only reachable over ssh://", host),
+    }
+}
+
+/// A local port for a container port: the same one when unprivileged,
+/// else shifted above 1024
+pub fn default_local_port(container_port: u16) -> u16 {
+    if container_port > 1024 {
+        container_port
+    } else {
+        container_port + 8000
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tool_follows_docker_host() {
+        assert_eq!(forward_tool(None).unwrap(), "socat");
+        assert_eq!(forward_tool(Some("unix:///var/run/docker.sock")).unwrap(), "socat");
+        assert_eq!(forward_tool(Some("ssh://ops@build1")).unwrap(), "ssh");
+        assert!(forward_tool(Some("tcp://10.0.0.2:2375")).is_err());
+        assert_eq!(default_local_port(80), 8080);
+        assert_eq!(default_local_port(5432), 5432);
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+/// User-defined container groups ("ai-stack", "media"), persisted as
+/// groups.toml in the config dir: `media = ["jellyfin", "sonarr"]`
+#[derive(Debug, Default)]
+pub struct ContainerGroups {
+    path: Option<PathBuf>,
+    /// Group name -> container names (names survive recreation, ids don't)
+    groups: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl ContainerGroups {
+    /// Load groups.toml from the config directory, starting empty if it's missing
+    pub fn load_default() -> Result<Self> {
+        let path = crate::config::config_dir().map(|dir| dir.join("groups.toml"));
+        let groups = match path {
+            Some(ref path) if path.exists() => {
+                let content = fs::read_to_string(path).context(format!("Failed to read groups: {:?}", path))?;
+                toml::from_str(&content).context(format!("Failed to parse groups: {:?}", path))?
+            }
+            _ => BTreeMap::new(),
+        };
+        Ok(Self { path, groups })
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(ref path) = self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).context(format!("Failed to create {:?}", dir))?;
+        }
+        let content = toml::to_string(&self.groups).context("Failed to serialize groups")?;
+        fs::write(path, content).context(format!("Failed to write groups: {:?}", path))
+    }
+
+    /// Group names in sorted order
+    pub fn names(&self) -> Vec<String> {
+        self.groups.keys().cloned().collect()
+    }
+
+    pub fn contains(&self, group: &str, container: &str) -> bool {
+        self.groups.get(group).is_some_and(|members| members.contains(container))
+    }
+
+    /// Members of a group, sorted
+    pub fn members(&self, group: &str) -> Vec<String> {
+        self.groups.get(group).map(|m| m.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Add the container to the group or take it out; empty groups are dropped.
+    /// Returns whether it is now a member
+    pub fn toggle(&mut self, group: &str, container: &str) -> Result<bool> {
+        let members = self.groups.entry(group.to_string()).or_default();
+        let added = members.insert(container.to_string());
+        if !added {
+            members.remove(container);
+            if members.is_empty() {
+                self.groups.remove(group);
+            }
+        }
+        self.save()?;
+        Ok(added)
+    }
+
+    /// Group after `current` in name order, None after the last one
+    pub fn next_after(&self, current: Option<&str>) -> Option<String> {
+        let mut names = self.groups.keys();
+        match current {
+            None => names.next().cloned(),
+            Some(current) => names.find(|name| name.as_str() > current).cloned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_and_cycle() {
+        let mut groups = ContainerGroups::default();
+        assert!(groups.toggle("media", "jellyfin").unwrap());
+        assert!(groups.toggle("ai-stack", "ollama").unwrap());
+        assert!(groups.contains("media", "jellyfin"));
+
+        assert_eq!(groups.next_after(None).as_deref(), Some("ai-stack"));
+        assert_eq!(groups.next_after(Some("ai-stack")).as_deref(), Some("media"));
+        assert_eq!(groups.next_after(Some("media")), None);
+
+        // Removing the last member drops the group
+        assert!(!groups.toggle("media", "jellyfin").unwrap());
+        assert_eq!(groups.names(), ["ai-stack"]);
+    }
+}
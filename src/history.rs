@@ -0,0 +1,266 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+
+/// How long samples are retained
+pub const RETENTION_SECS: i64 = 24 * 3600;
+
+/// Minimum spacing between stored samples per container
+const SAMPLE_INTERVAL_SECS: i64 = 10;
+
+/// How often buffered samples are written out (and on drop)
+const FLUSH_INTERVAL_SECS: i64 = 60;
+
+/// A single downsampled stats sample
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Sample {
+    /// Unix timestamp (seconds)
+    pub t: i64,
+    pub cpu: f64,
+    pub mem: f64,
+    #[serde(default)]
+    pub rx: f64,
+    #[serde(default)]
+    pub tx: f64,
+}
+
+/// On-disk line format: one sample per line
+#[derive(Serialize, Deserialize)]
+struct Record {
+    name: String,
+    #[serde(flatten)]
+    sample: Sample,
+}
+
+/// Selectable time range for history charts
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TimeRange {
+    #[default]
+    FiveMinutes,
+    OneHour,
+    SixHours,
+    Day,
+}
+
+impl TimeRange {
+    pub fn secs(&self) -> i64 {
+        match self {
+            Self::FiveMinutes => 5 * 60,
+            Self::OneHour => 3600,
+            Self::SixHours => 6 * 3600,
+            Self::Day => 24 * 3600,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FiveMinutes => "5m",
+            Self::OneHour => "1h",
+            Self::SixHours => "6h",
+            Self::Day => "24h",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Self::FiveMinutes => Self::OneHour,
+            Self::OneHour => Self::SixHours,
+            Self::SixHours => Self::Day,
+            Self::Day => Self::FiveMinutes,
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            Self::FiveMinutes => Self::Day,
+            Self::OneHour => Self::FiveMinutes,
+            Self::SixHours => Self::OneHour,
+            Self::Day => Self::SixHours,
+        }
+    }
+}
+
+/// Long-range per-container stats (24h ring buffer), optionally persisted
+/// to an append-only JSON lines file that is compacted as it grows
+#[derive(Default)]
+pub struct HistoryStore {
+    samples: HashMap<String, VecDeque<Sample>>,
+    path: Option<PathBuf>,
+    writer: Option<BufWriter<File>>,
+    /// Lines appended since the file was last compacted
+    appended: usize,
+    /// When buffered lines were last flushed to the file
+    flushed_at: i64,
+}
+
+impl HistoryStore {
+    /// In-memory only store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store backed by a file; loads samples from the last 24 hours
+    pub fn with_file(path: PathBuf) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .context(format!("Failed to create directory: {:?}", parent))?;
+        }
+
+        let mut store = Self {
+            path: Some(path.clone()),
+            ..Self::default()
+        };
+
+        if let Ok(file) = File::open(&path) {
+            let cutoff = now() - RETENTION_SECS;
+            for line in BufReader::new(file).lines().map_while(|l| l.ok()) {
+                if let Ok(record) = serde_json::from_str::<Record>(&line) {
+                    if record.sample.t >= cutoff {
+                        store.samples.entry(record.name).or_default().push_back(record.sample);
+                    }
+                }
+            }
+        }
+
+        store.compact()?;
+        Ok(store)
+    }
+
+    /// Record a sample, skipping it if the previous one is too recent
+    pub fn record(&mut self, name: &str, cpu: f64, mem: f64, rx: f64, tx: f64) {
+        self.record_at(now(), name, cpu, mem, rx, tx);
+    }
+
+    fn record_at(&mut self, t: i64, name: &str, cpu: f64, mem: f64, rx: f64, tx: f64) {
+        let history = self.samples.entry(name.to_string()).or_default();
+
+        if history.back().is_some_and(|last| t - last.t < SAMPLE_INTERVAL_SECS) {
+            return;
+        }
+
+        let sample = Sample { t, cpu, mem, rx, tx };
+        history.push_back(sample);
+        while history.front().is_some_and(|s| s.t < t - RETENTION_SECS) {
+            history.pop_front();
+        }
+
+        if let Some(ref mut writer) = self.writer {
+            let record = Record { name: name.to_string(), sample };
+            if let Ok(line) = serde_json::to_string(&record) {
+                let _ = writeln!(writer, "{}", line);
+                self.appended += 1;
+            }
+            // Buffered between flushes: this runs for every container on every stats refresh
+            if t - self.flushed_at >= FLUSH_INTERVAL_SECS {
+                let _ = writer.flush();
+                self.flushed_at = t;
+            }
+        }
+
+        // Rewrite the file once it holds as many stale lines as live ones
+        if self.writer.is_some() && self.appended > self.len().max(1000) {
+            let _ = self.compact();
+        }
+    }
+
+    /// Samples for a container within the last `range`
+    pub fn range(&self, name: &str, range: TimeRange) -> Vec<Sample> {
+        self.range_at(now(), name, range)
+    }
+
+    fn range_at(&self, now: i64, name: &str, range: TimeRange) -> Vec<Sample> {
+        let cutoff = now - range.secs();
+        self.samples
+            .get(name)
+            .map(|h| h.iter().filter(|s| s.t >= cutoff).copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Total retained samples
+    fn len(&self) -> usize {
+        self.samples.values().map(|h| h.len()).sum()
+    }
+
+    /// Rewrite the backing file with only retained samples
+    fn compact(&mut self) -> Result<()> {
+        let Some(ref path) = self.path else {
+            return Ok(());
+        };
+
+        let tmp = path.with_extension("jsonl.tmp");
+        {
+            let mut writer = BufWriter::new(File::create(&tmp)?);
+            for (name, history) in &self.samples {
+                for sample in history {
+                    let record = Record { name: name.clone(), sample: *sample };
+                    writeln!(writer, "{}", serde_json::to_string(&record)?)?;
+                }
+            }
+            writer.flush()?;
+        }
+        fs::rename(&tmp, path).context(format!("Failed to write history: {:?}", path))?;
+
+        let file = OpenOptions::new().append(true).open(path)?;
+        self.writer = Some(BufWriter::new(file));
+        self.appended = 0;
+        Ok(())
+    }
+}
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("backplane-history-{}-{}.jsonl", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_samples_survive_a_restart() {
+        let path = temp_path("roundtrip");
+        let _ = fs::remove_file(&path);
+        {
+            let mut store = HistoryStore::with_file(path.clone()).unwrap();
+            store.record("web", 12.5, 30.0, 1.0, 2.0);
+            store.record("db", 3.0, 60.0, 0.0, 0.0);
+            // Too soon after the last one: not stored
+            store.record("web", 99.0, 99.0, 0.0, 0.0);
+        }
+        let store = HistoryStore::with_file(path.clone()).unwrap();
+        let web = store.range("web", TimeRange::FiveMinutes);
+        assert_eq!(web.len(), 1);
+        assert_eq!((web[0].cpu, web[0].mem, web[0].rx, web[0].tx), (12.5, 30.0, 1.0, 2.0));
+        assert_eq!(store.range("db", TimeRange::FiveMinutes).len(), 1);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_retention() {
+        let path = temp_path("retention");
+        let t = now();
+        let lines = [(t - RETENTION_SECS - 60, 1.0), (t - 2 * 3600, 2.0), (t - 60, 3.0)].map(|(t, cpu)| {
+            let sample = Sample { t, cpu, mem: 0.0, rx: 0.0, tx: 0.0 };
+            serde_json::to_string(&Record { name: "web".into(), sample }).unwrap()
+        });
+        fs::write(&path, lines.join("\n")).unwrap();
+
+        // Older than a day is dropped on load, and from the file by compaction
+        let mut store = HistoryStore::with_file(path.clone()).unwrap();
+        assert_eq!(store.range_at(t, "web", TimeRange::Day).len(), 2);
+        assert_eq!(store.range_at(t, "web", TimeRange::OneHour).len(), 1);
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 2);
+
+        // and as new samples push the window on
+        store.record_at(t + RETENTION_SECS - 3600, "web", 4.0, 0.0, 0.0, 0.0);
+        let cpu: Vec<f64> = store.range_at(t + RETENTION_SECS - 3600, "web", TimeRange::Day).iter().map(|s| s.cpu).collect();
+        assert_eq!(cpu, [3.0, 4.0]);
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,69 @@
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+/// Image sizes by image ID, persisted as image_sizes.json in the data dir.
+/// An ID names immutable content, so a cached size never goes stale
+#[derive(Debug, Default)]
+pub struct ImageSizeCache {
+    path: Option<PathBuf>,
+    sizes: BTreeMap<String, u64>,
+}
+
+impl ImageSizeCache {
+    /// Load the cache from the data directory, starting empty if it's missing
+    pub fn load_default() -> Result<Self> {
+        let path = crate::config::data_dir().map(|dir| dir.join("image_sizes.json"));
+        let sizes = match path {
+            Some(ref path) if path.exists() => {
+                let content = fs::read_to_string(path).context(format!("Failed to read image sizes: {:?}", path))?;
+                serde_json::from_str(&content).context(format!("Failed to parse image sizes: {:?}", path))?
+            }
+            _ => BTreeMap::new(),
+        };
+        Ok(Self { path, sizes })
+    }
+
+    pub fn sizes(&self) -> &BTreeMap<String, u64> {
+        &self.sizes
+    }
+
+    /// IDs among `ids` with no cached size, without duplicates
+    pub fn missing<'a>(&self, ids: impl Iterator<Item = &'a str>) -> Vec<String> {
+        let mut seen = HashSet::new();
+        ids.filter(|id| !id.is_empty() && !self.sizes.contains_key(*id) && seen.insert(*id))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Add fetched sizes, drop the ones of images no longer in use, and save
+    pub fn insert(&mut self, fetched: Vec<(String, u64)>, in_use: &HashSet<&str>) -> Result<()> {
+        self.sizes.extend(fetched);
+        self.sizes.retain(|id, _| in_use.contains(id.as_str()));
+        let Some(ref path) = self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).context(format!("Failed to create {:?}", dir))?;
+        }
+        let content = serde_json::to_string(&self.sizes).context("Failed to serialize image sizes")?;
+        fs::write(path, content).context(format!("Failed to write image sizes: {:?}", path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_skips_cached_and_duplicates() {
+        let mut cache = ImageSizeCache::default();
+        let in_use: HashSet<&str> = ["sha256:a", "sha256:b"].into_iter().collect();
+        cache.insert(vec![("sha256:a".to_string(), 10), ("sha256:gone".to_string(), 5)], &in_use).unwrap();
+        assert_eq!(cache.sizes().len(), 1);
+
+        let ids = ["sha256:a", "sha256:b", "", "sha256:b"];
+        assert_eq!(cache.missing(ids.into_iter()), ["sha256:b"]);
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::models::{ContainerInfo, ContainerStatus};
+
+/// Delay before the first restart; doubles with each consecutive crash
+const BASE_BACKOFF: Duration = Duration::from_secs(2);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// A run at least this long resets the backoff
+const STABLE_AFTER: Duration = Duration::from_secs(30);
+
+/// Keep-alive state for one watched container
+#[derive(Debug, Clone, Default)]
+pub struct Watch {
+    /// Restarts done by the TUI since the watch was enabled
+    pub restarts: u32,
+    /// Consecutive exits without a stable run in between
+    failures: u32,
+    retry_at: Option<Instant>,
+    running_since: Option<Instant>,
+}
+
+/// Containers the TUI restarts when they exit, independent of Docker's restart policy
+#[derive(Debug, Default)]
+pub struct KeepAlive {
+    watched: HashMap<String, Watch>,
+}
+
+impl KeepAlive {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Toggle the watch on a container; returns whether it is now watched
+    pub fn toggle(&mut self, name: &str) -> bool {
+        if self.watched.remove(name).is_some() {
+            false
+        } else {
+            self.watched.insert(name.to_string(), Watch::default());
+            true
+        }
+    }
+
+    /// Stop watching (e.g. the user stopped the container on purpose)
+    pub fn unwatch(&mut self, name: &str) -> bool {
+        self.watched.remove(name).is_some()
+    }
+
+    /// Restart counts of watched containers, for display
+    pub fn restarts(&self) -> HashMap<String, u32> {
+        self.watched.iter().map(|(name, w)| (name.clone(), w.restarts)).collect()
+    }
+
+    /// Update watches from the latest listing and return containers due for a restart
+    pub fn due(&mut self, containers: &[ContainerInfo], now: Instant) -> Vec<String> {
+        // Forget containers that no longer exist
+        self.watched.retain(|name, _| containers.iter().any(|c| &c.name == name));
+
+        let mut due = Vec::new();
+        for (name, watch) in self.watched.iter_mut() {
+            let Some(c) = containers.iter().find(|c| &c.name == name) else {
+                continue;
+            };
+            if c.status.is_active() {
+                watch.retry_at = None;
+                let since = *watch.running_since.get_or_insert(now);
+                if now.duration_since(since) >= STABLE_AFTER {
+                    watch.failures = 0;
+                }
+            } else if matches!(c.status, ContainerStatus::Exited | ContainerStatus::Dead) {
+                watch.running_since = None;
+                match watch.retry_at {
+                    None => watch.retry_at = Some(now + backoff(watch.failures)),
+                    Some(at) if now >= at => {
+                        watch.retry_at = None;
+                        watch.failures += 1;
+                        watch.restarts += 1;
+                        due.push(name.clone());
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        due
+    }
+}
+
+/// Backoff after `failures` consecutive crashes (2s, 4s, 8s, ... up to 60s)
+fn backoff(failures: u32) -> Duration {
+    BASE_BACKOFF.saturating_mul(2u32.saturating_pow(failures)).min(MAX_BACKOFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container(name: &str, status: ContainerStatus) -> ContainerInfo {
+        let mut c = ContainerInfo::new(name.to_string());
+        c.status = status;
+        c
+    }
+
+    #[test]
+    fn test_restart_after_backoff() {
+        let mut keep_alive = KeepAlive::new();
+        keep_alive.toggle("api");
+        let exited = vec![container("api", ContainerStatus::Exited)];
+        let start = Instant::now();
+
+        // First exit schedules a restart, which becomes due after the base backoff
+        assert!(keep_alive.due(&exited, start).is_empty());
+        assert!(keep_alive.due(&exited, start + Duration::from_secs(1)).is_empty());
+        assert_eq!(keep_alive.due(&exited, start + BASE_BACKOFF), vec!["api".to_string()]);
+
+        // Crashing again right away doubles the wait
+        let later = start + BASE_BACKOFF;
+        assert!(keep_alive.due(&exited, later).is_empty());
+        assert!(keep_alive.due(&exited, later + BASE_BACKOFF).is_empty());
+        assert_eq!(keep_alive.due(&exited, later + BASE_BACKOFF * 2).len(), 1);
+        assert_eq!(keep_alive.restarts()["api"], 2);
+
+        // Removed containers are forgotten
+        assert!(keep_alive.due(&[], later).is_empty());
+        assert!(keep_alive.restarts().is_empty());
+    }
+}
@@ -0,0 +1,456 @@
+use crossterm::event::KeyCode;
+use crossterm::event::KeyCode::{Char, Down, Enter, Esc, Left, Right, Up};
+
+use KeyContext::{Charts, Compare, Events, Global, Host, List, Logs, Pods, Services};
+
+/// Where a key binding is active
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyContext {
+    Global,
+    List,
+    Logs,
+    Charts,
+    Compare,
+    Services,
+    Pods,
+    Host,
+    Events,
+}
+
+impl KeyContext {
+    pub const ALL: [KeyContext; 9] = [
+        Self::List,
+        Self::Logs,
+        Self::Charts,
+        Self::Compare,
+        Self::Services,
+        Self::Pods,
+        Self::Host,
+        Self::Events,
+        Self::Global,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Global => "Global",
+            Self::List => "Container List",
+            Self::Logs => "Logs",
+            Self::Charts => "History Charts",
+            Self::Compare => "Compare",
+            Self::Services => "Swarm Services",
+            Self::Pods => "Kubernetes Pods",
+            Self::Host => "Host Processes",
+            Self::Events => "Docker Events",
+        }
+    }
+}
+
+/// Command triggered by a key, resolved into an `Action` against app state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    // Navigation
+    Up,
+    Down,
+    Top,
+    Bottom,
+    ScreenTop,
+    ScreenMiddle,
+    ScreenBottom,
+    Left,
+    Right,
+    Back,
+
+    // Views and modals
+    ViewLogs,
+    ViewProjectLogs,
+    ViewCharts,
+    ViewCompare,
+    ViewServices,
+    ViewPods,
+    ViewHostProcesses,
+    ViewGpus,
+    ViewEvents,
+    Mark,
+    Pin,
+    Groups,
+    Info,
+    Exec,
+    FocusExecPane,
+    NewContainer,
+    Filter,
+    ToggleAlerts,
+    ToggleRecycleBin,
+    ToggleAuditLog,
+    ToggleDaemon,
+    ToggleSchedule,
+    WatchExit,
+    ForwardPort,
+    ToggleExpandedHeader,
+    ToggleSplitView,
+    Help,
+
+    // Container operations
+    Start,
+    Stop,
+    Restart,
+    Delete,
+    Pause,
+    Unpause,
+    Rename,
+    Labels,
+    Processes,
+    ImageHistory,
+    ScanImage,
+    Diff,
+    ComposeFile,
+    ScaffoldProject,
+    Checkpoints,
+    CopyFiles,
+    RecreateDeleted,
+    KeepAlive,
+    Yank,
+    ActionMenu,
+    CheckUpdates,
+    UpdateImage,
+
+    // Project and bulk operations
+    ComposeDown,
+    StartProject,
+    StopProject,
+    Prune,
+    Cleanup,
+    Pull,
+    Login,
+
+    // Swarm services
+    ScaleUp,
+    ScaleDown,
+    RestartService,
+
+    // Kubernetes pods
+    PodLogs,
+    NextNamespace,
+
+    // Host processes
+    ToggleProcessSort,
+    CycleProjectSort,
+    ToggleProcessGrouping,
+
+    // Docker events
+    FilterEvents,
+
+    // Logs
+    SelectText,
+    GoToTime,
+    LoadMoreLogs,
+    FilterLogs,
+    SendStdin,
+
+    // List control
+    Refresh,
+    RefreshFaster,
+    RefreshSlower,
+    ExportJson,
+    ExportCsv,
+    CycleStatusFilter,
+    FilterAll,
+    FilterGroups,
+    FilterRunning,
+    FilterStopped,
+    CycleGroupFilter,
+    Quit,
+}
+
+impl Command {
+    /// Every command, in declaration order (the palette lists them all)
+    pub const ALL: [Command; 92] = [
+        Self::Up, Self::Down, Self::Top, Self::Bottom, Self::ScreenTop, Self::ScreenMiddle,
+        Self::ScreenBottom, Self::Left, Self::Right, Self::Back,
+        Self::ViewLogs, Self::ViewProjectLogs, Self::ViewCharts, Self::ViewCompare, Self::ViewServices,
+        Self::ViewPods, Self::ViewHostProcesses, Self::ViewGpus, Self::ViewEvents, Self::Mark, Self::Pin,
+        Self::Groups, Self::Info, Self::Exec, Self::FocusExecPane, Self::NewContainer, Self::Filter,
+        Self::ToggleAlerts, Self::ToggleRecycleBin, Self::ToggleAuditLog, Self::ToggleDaemon,
+        Self::ToggleSchedule, Self::WatchExit, Self::ForwardPort, Self::ToggleExpandedHeader,
+        Self::ToggleSplitView, Self::Help,
+        Self::Start, Self::Stop, Self::Restart, Self::Delete, Self::Pause, Self::Unpause, Self::Rename,
+        Self::Labels, Self::Processes, Self::ImageHistory, Self::ScanImage, Self::Diff, Self::ComposeFile,
+        Self::ScaffoldProject, Self::Checkpoints, Self::CopyFiles, Self::RecreateDeleted, Self::KeepAlive,
+        Self::Yank, Self::ActionMenu, Self::CheckUpdates, Self::UpdateImage,
+        Self::ComposeDown, Self::StartProject, Self::StopProject, Self::Prune, Self::Cleanup, Self::Pull,
+        Self::Login,
+        Self::ScaleUp, Self::ScaleDown, Self::RestartService,
+        Self::PodLogs, Self::NextNamespace,
+        Self::ToggleProcessSort, Self::CycleProjectSort, Self::ToggleProcessGrouping,
+        Self::FilterEvents,
+        Self::SelectText, Self::GoToTime, Self::LoadMoreLogs, Self::FilterLogs, Self::SendStdin,
+        Self::Refresh, Self::RefreshFaster, Self::RefreshSlower, Self::ExportJson, Self::ExportCsv,
+        Self::CycleStatusFilter, Self::FilterAll, Self::FilterGroups, Self::FilterRunning,
+        Self::FilterStopped, Self::CycleGroupFilter, Self::Quit,
+    ];
+
+    /// Whether the command changes containers, images or the daemon; these are
+    /// disabled in read-only mode
+    pub fn is_mutating(&self) -> bool {
+        matches!(
+            self,
+            Command::Exec
+                | Command::FocusExecPane
+                | Command::NewContainer
+                | Command::Start
+                | Command::Stop
+                | Command::Restart
+                | Command::Delete
+                | Command::Pause
+                | Command::Unpause
+                | Command::Rename
+                | Command::Labels
+                | Command::Checkpoints
+                | Command::CopyFiles
+                | Command::RecreateDeleted
+                | Command::KeepAlive
+                | Command::UpdateImage
+                | Command::ComposeDown
+                | Command::StartProject
+                | Command::StopProject
+                | Command::Prune
+                | Command::Cleanup
+                | Command::Pull
+                | Command::Login
+                | Command::ScaleUp
+                | Command::ScaleDown
+                | Command::RestartService
+                | Command::SendStdin
+        )
+    }
+
+    /// What read-only mode refuses the command as, if it does
+    pub fn read_only_refusal(&self, read_only: bool) -> Option<&'static str> {
+        (read_only && self.is_mutating()).then(|| describe(*self))
+    }
+}
+
+/// A key binding: the keys, what they do, and where they apply
+#[derive(Debug)]
+pub struct Binding {
+    pub context: KeyContext,
+    pub keys: &'static [KeyCode],
+    pub command: Command,
+    pub desc: &'static str,
+}
+
+impl Binding {
+    /// Display label for the keys, e.g. "j/↓"
+    pub fn label(&self) -> String {
+        self.keys.iter().map(key_label).collect::<Vec<_>>().join("/")
+    }
+}
+
+const fn bind(context: KeyContext, keys: &'static [KeyCode], command: Command, desc: &'static str) -> Binding {
+    Binding { context, keys, command, desc }
+}
+
+/// The keymap (in help display order)
+pub const BINDINGS: &[Binding] = &[
+    // Container list
+    bind(List, &[Char('j'), Down], Command::Down, "Move down"),
+    bind(List, &[Char('k'), Up], Command::Up, "Move up"),
+    bind(List, &[Char('g')], Command::Top, "Go to top"),
+    bind(List, &[Char('G')], Command::Bottom, "Go to bottom"),
+    // Vim's H/M/L, but H and L are the history charts and registry login here
+    bind(List, &[Char('(')], Command::ScreenTop, "Top of the screen"),
+    bind(List, &[Char('M')], Command::ScreenMiddle, "Middle of the screen"),
+    bind(List, &[Char(')')], Command::ScreenBottom, "Bottom of the screen"),
+    bind(List, &[Left, Char('h')], Command::Left, "Previous view (Stats/Network/Details)"),
+    bind(List, &[Right], Command::Right, "Next view"),
+    bind(List, &[Char('f')], Command::CycleStatusFilter, "Cycle filter (All/Groups/Running/Stopped)"),
+    bind(List, &[Char('1')], Command::FilterAll, "Show all containers"),
+    bind(List, &[Char('2')], Command::FilterGroups, "Group by compose project"),
+    bind(List, &[Char('3')], Command::FilterRunning, "Show running only"),
+    bind(List, &[Char('4')], Command::FilterStopped, "Show stopped only"),
+    bind(List, &[Char('$')], Command::CycleProjectSort, "Sort projects by name/CPU/memory/network (Groups)"),
+    bind(List, &[Char('b')], Command::CycleGroupFilter, "Cycle group filter (S/X then act on the group)"),
+    bind(List, &[Char('/')], Command::Filter, "Filter (name, image:, project:, id:)"),
+    bind(List, &[Enter, Char('l')], Command::ViewLogs, "View logs"),
+    bind(List, &[Char('|')], Command::ViewProjectLogs, "Logs of every container in the compose project, interleaved"),
+    bind(List, &[Char('H')], Command::ViewCharts, "History charts (5m/1h/6h/24h)"),
+    bind(List, &[Char(' ')], Command::Mark, "Mark/unmark for compare"),
+    bind(List, &[Char('m')], Command::Groups, "Add to/remove from groups"),
+    bind(List, &[Char('z')], Command::Pin, "Pin/unpin to the top of the list"),
+    bind(List, &[Char('c')], Command::ViewCompare, "Compare marked containers"),
+    bind(List, &[Char('v')], Command::ViewServices, "Swarm services (managers only)"),
+    bind(List, &[KeyCode::Tab], Command::ViewPods, "Kubernetes pods tab (kubernetes feature)"),
+    bind(List, &[Char('O')], Command::ViewHostProcesses, "Host processes (top by CPU/memory)"),
+    bind(List, &[Char('*')], Command::ViewGpus, "GPUs: which containers may use and are using each"),
+    bind(List, &[Char('~')], Command::ViewEvents, "Docker events (start, die, health, ...) since launch"),
+    bind(List, &[Char('.')], Command::ActionMenu, "Actions menu for the container"),
+    bind(List, &[Char('i')], Command::Info, "Container info"),
+    bind(List, &[Char('A')], Command::ToggleAlerts, "Toggle recent alerts panel"),
+    bind(List, &[Char(']')], Command::ToggleSplitView, "Toggle details pane (stats and log tail of the selection)"),
+    bind(List, &[Char('u')], Command::ToggleRecycleBin, "Toggle recently deleted panel"),
+    bind(List, &[Char('J')], Command::ToggleAuditLog, "Toggle audit log panel"),
+    bind(List, &[Char('!')], Command::ToggleDaemon, "Toggle Docker daemon status panel"),
+    bind(List, &[Char('%')], Command::ToggleSchedule, "Toggle scheduled actions panel"),
+    bind(List, &[Char('y')], Command::Yank, "Copy id/name/image/port/exec command"),
+    bind(List, &[Char('e')], Command::Exec, "Exec shell into container"),
+    bind(List, &[KeyCode::F(2)], Command::FocusExecPane, "Focus the embedded shell (F2 again returns)"),
+    bind(List, &[Char('n')], Command::NewContainer, "New container"),
+    bind(List, &[Char('s')], Command::Start, "Start container"),
+    bind(List, &[Char('x')], Command::Stop, "Stop container"),
+    bind(List, &[Char('R')], Command::Restart, "Restart container"),
+    bind(List, &[Char('d')], Command::Delete, "Delete container"),
+    bind(List, &[Char('U')], Command::RecreateDeleted, "Recreate last deleted container"),
+    bind(List, &[Char('p')], Command::Pause, "Pause container"),
+    bind(List, &[Char('P')], Command::Unpause, "Unpause container"),
+    bind(List, &[Char('w')], Command::KeepAlive, "Toggle keep-alive (restart on exit)"),
+    bind(List, &[Char('&')], Command::WatchExit, "Notify when the container exits (with its exit code)"),
+    bind(List, &[Char('N')], Command::Rename, "Rename container"),
+    bind(List, &[Char('#')], Command::Labels, "Edit labels (marked containers, or this one) and recreate"),
+    bind(List, &[Char('>')], Command::ForwardPort, "Forward a local port to the container (socat/ssh)"),
+    bind(List, &[Char('t')], Command::Processes, "Processes (docker top)"),
+    bind(List, &[Char('I')], Command::ImageHistory, "Image history (layer sizes)"),
+    bind(List, &[Char('Y')], Command::ScanImage, "Scan image for vulnerabilities (trivy); again for the report"),
+    bind(List, &[Char('F')], Command::Diff, "Filesystem changes (docker diff)"),
+    bind(List, &[Char('{')], Command::ComposeFile, "Compose file of the project, at the service"),
+    bind(List, &[Char('}')], Command::ScaffoldProject, "Write a project.yaml for the container into projects_dir"),
+    bind(List, &[Char('K')], Command::Checkpoints, "Checkpoints (create/restore, experimental)"),
+    bind(List, &[Char('C')], Command::CopyFiles, "Copy files to/from container"),
+    bind(List, &[Char('S')], Command::StartProject, "Start all containers in project or group"),
+    bind(List, &[Char('X')], Command::StopProject, "Stop all containers in project or group"),
+    bind(List, &[Char('D')], Command::ComposeDown, "Compose down (remove project)"),
+    bind(List, &[Char('Z')], Command::Prune, "Prune stopped containers"),
+    bind(List, &[Char('o')], Command::Pull, "Pull image (browse registry tags)"),
+    bind(List, &[Char('L')], Command::Login, "Log in to a registry"),
+    bind(List, &[Char('a')], Command::CheckUpdates, "Check registries for newer images"),
+    bind(List, &[Char('B')], Command::UpdateImage, "Update: pull latest image and recreate"),
+    bind(List, &[Char('W')], Command::Cleanup, "Cleanup wizard (images, containers, networks, volumes)"),
+    bind(List, &[Char('r')], Command::Refresh, "Refresh list"),
+    bind(List, &[Char('+'), Char('=')], Command::RefreshFaster, "Refresh faster (halve intervals)"),
+    bind(List, &[Char('-')], Command::RefreshSlower, "Refresh slower (double intervals)"),
+    bind(List, &[Char('E')], Command::ExportJson, "Export list as JSON"),
+    bind(List, &[Char('V')], Command::ExportCsv, "Export list as CSV"),
+    // Logs
+    bind(Logs, &[Char('j'), Down], Command::Down, "Scroll down"),
+    bind(Logs, &[Char('k'), Up], Command::Up, "Scroll up"),
+    bind(Logs, &[Char('g')], Command::Top, "Go to top"),
+    bind(Logs, &[Char('G')], Command::Bottom, "Go to bottom"),
+    bind(Logs, &[Char('H')], Command::ScreenTop, "Start of the buffer"),
+    bind(Logs, &[Char('M')], Command::ScreenMiddle, "Middle of the buffer"),
+    bind(Logs, &[Char('L')], Command::ScreenBottom, "End of the buffer (follow)"),
+    bind(Logs, &[Char('t')], Command::GoToTime, "Go to time (UTC HH:MM[:SS])"),
+    bind(Logs, &[Char('m')], Command::LoadMoreLogs, "Load more history (twice the lines)"),
+    bind(Logs, &[Char('/')], Command::FilterLogs, "Filters: add regex (!regex hides), toggle or remove"),
+    bind(Logs, &[Char('i')], Command::SendStdin, "Send a line to stdin (containers run with -i)"),
+    bind(Logs, &[Char('s')], Command::SelectText, "Plain text for native selection (Enter returns)"),
+    bind(Logs, &[Esc], Command::Back, "Back to list"),
+    // History charts
+    bind(Charts, &[Left, Char('h')], Command::Left, "Shorter time range"),
+    bind(Charts, &[Right, Char('l')], Command::Right, "Longer time range"),
+    bind(Charts, &[Esc, Char('H')], Command::Back, "Back to list"),
+    // Compare
+    bind(Compare, &[Left, Char('h')], Command::Left, "Shorter time range"),
+    bind(Compare, &[Right, Char('l')], Command::Right, "Longer time range"),
+    bind(Compare, &[Esc, Char('c')], Command::Back, "Back to list"),
+    // Swarm services
+    bind(Services, &[Char('j'), Down], Command::Down, "Move down"),
+    bind(Services, &[Char('k'), Up], Command::Up, "Move up"),
+    bind(Services, &[Char('+'), Char('=')], Command::ScaleUp, "Scale up by one replica"),
+    bind(Services, &[Char('-')], Command::ScaleDown, "Scale down by one replica"),
+    bind(Services, &[Char('R')], Command::RestartService, "Rolling restart (force update)"),
+    bind(Services, &[Char('r')], Command::Refresh, "Refresh services"),
+    bind(Services, &[Esc, Char('v')], Command::Back, "Back to list"),
+    // Kubernetes pods
+    bind(Pods, &[Char('j'), Down], Command::Down, "Move down"),
+    bind(Pods, &[Char('k'), Up], Command::Up, "Move up"),
+    bind(Pods, &[Enter, Char('l')], Command::PodLogs, "View pod logs"),
+    bind(Pods, &[Char('n')], Command::NextNamespace, "Next namespace"),
+    bind(Pods, &[Char('r')], Command::Refresh, "Refresh pods"),
+    bind(Pods, &[KeyCode::Tab, Esc], Command::Back, "Back to containers"),
+    // Host processes
+    bind(Host, &[Char('j'), Down], Command::Down, "Move down"),
+    bind(Host, &[Char('k'), Up], Command::Up, "Move up"),
+    bind(Host, &[Char('s')], Command::ToggleProcessSort, "Sort by CPU/memory"),
+    bind(Host, &[Char('g')], Command::ToggleProcessGrouping, "Group processes by container"),
+    bind(Host, &[Char('r')], Command::Refresh, "Refresh processes"),
+    bind(Host, &[Esc, Char('O')], Command::Back, "Back to list"),
+    // Docker events
+    bind(Events, &[Char('j'), Down], Command::Down, "Move down (older)"),
+    bind(Events, &[Char('k'), Up], Command::Up, "Move up (newer)"),
+    bind(Events, &[Char('g')], Command::Top, "Newest"),
+    bind(Events, &[Char('G')], Command::Bottom, "Oldest"),
+    bind(Events, &[Char('/')], Command::FilterEvents, "Filter by container name"),
+    bind(Events, &[Esc, Char('~')], Command::Back, "Back to list"),
+    // Everywhere
+    bind(Global, &[Char('?')], Command::Help, "Help"),
+    bind(Global, &[Char('T')], Command::ToggleExpandedHeader, "Toggle per-core CPU, load and temperature"),
+    bind(Global, &[Char('q')], Command::Quit, "Quit"),
+];
+
+/// Find the command bound to a key in a context
+pub fn lookup(context: KeyContext, code: KeyCode) -> Option<Command> {
+    BINDINGS
+        .iter()
+        .find(|b| b.context == context && b.keys.contains(&code))
+        .map(|b| b.command)
+}
+
+/// Description of the first binding for a command
+pub fn describe(command: Command) -> &'static str {
+    BINDINGS
+        .iter()
+        .find(|b| b.command == command)
+        .map_or("This action", |b| b.desc)
+}
+
+/// First key bound to a command in a context
+pub fn key_in(context: KeyContext, command: Command) -> Option<KeyCode> {
+    BINDINGS
+        .iter()
+        .find(|b| b.context == context && b.command == command)
+        .map(|b| b.keys[0])
+}
+
+/// Bindings active in a context, in display order
+pub fn bindings_for(context: KeyContext) -> impl Iterator<Item = &'static Binding> {
+    BINDINGS.iter().filter(move |b| b.context == context)
+}
+
+/// Short display name for a key
+fn key_label(code: &KeyCode) -> String {
+    match code {
+        Char(' ') => "Space".to_string(),
+        Char(c) => c.to_string(),
+        Up => "↑".to_string(),
+        Down => "↓".to_string(),
+        Left => "←".to_string(),
+        Right => "→".to_string(),
+        Enter => "Enter".to_string(),
+        Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        other => format!("{:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_only_refuses_create_and_exec() {
+        assert_eq!(Command::NewContainer.read_only_refusal(true), Some(describe(Command::NewContainer)));
+        assert!(Command::Exec.read_only_refusal(true).is_some());
+        assert!(Command::FocusExecPane.read_only_refusal(true).is_some());
+        assert_eq!(Command::Exec.read_only_refusal(false), None);
+        assert_eq!(Command::Info.read_only_refusal(true), None);
+    }
+
+    #[test]
+    fn test_all_lists_every_bound_command_once() {
+        for binding in BINDINGS {
+            assert!(Command::ALL.contains(&binding.command), "{:?} missing from Command::ALL", binding.command);
+        }
+        for (i, command) in Command::ALL.iter().enumerate() {
+            assert!(!Command::ALL[..i].contains(command), "{:?} listed twice", command);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+
+use crate::config::RegistryAuth;
+
+/// Keyring service name the credentials are filed under
+const SERVICE: &str = "backplane-tui";
+
+/// Save registry credentials in the system keyring: `security` on macOS,
+/// libsecret's `secret-tool` elsewhere
+pub fn store(host: &str, auth: &RegistryAuth) -> Result<()> {
+    let secret = serde_json::to_string(auth)?;
+    if cfg!(target_os = "macos") {
+        // A trailing -w without a value makes security prompt for the
+        // password (and once more to confirm), read from stdin
+        let mut command = Command::new("security");
+        command.args(["add-generic-password", "-U", "-s", SERVICE, "-a", host, "-w"]);
+        let input = format!("{}\n{}\n", secret, secret);
+        if !store_from_stdin(command, &input, "security")? {
+            anyhow::bail!("Keychain refused to store credentials for {}", host);
+        }
+        return Ok(());
+    }
+
+    let mut command = Command::new("secret-tool");
+    command.args(["store", "--label", &format!("{} registry {}", SERVICE, host), "service", SERVICE, "registry", host]);
+    if !store_from_stdin(command, &secret, "secret-tool (install libsecret-tools)")? {
+        anyhow::bail!("Keyring refused to store credentials for {}", host);
+    }
+    Ok(())
+}
+
+/// Run a keyring tool with the secret on its stdin, so it never appears in
+/// the process list; whether the tool succeeded
+fn store_from_stdin(mut command: Command, input: &str, tool: &str) -> Result<bool> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .context(format!("Failed to run {}", tool))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes()).context(format!("Failed to write to {}", tool))?;
+    }
+    Ok(child.wait().context(format!("Failed to run {}", tool))?.success())
+}
+
+/// Credentials previously stored for a registry host, if any
+pub fn load(host: &str) -> Option<RegistryAuth> {
+    let output = if cfg!(target_os = "macos") {
+        Command::new("security")
+            .args(["find-generic-password", "-s", SERVICE, "-a", host, "-w"])
+            .stderr(Stdio::null())
+            .output()
+    } else {
+        Command::new("secret-tool")
+            .args(["lookup", "service", SERVICE, "registry", host])
+            .stderr(Stdio::null())
+            .output()
+    }
+    .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+    serde_json::from_slice(String::from_utf8_lossy(&output.stdout).trim().as_bytes()).ok()
+}
@@ -0,0 +1,144 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::path::PathBuf;
+use tokio::process::Command;
+
+use crate::models::PodInfo;
+
+/// The kubeconfig kubectl would use ($KUBECONFIG or ~/.kube/config), if it exists
+pub fn kubeconfig() -> Option<PathBuf> {
+    let path = match std::env::var_os("KUBECONFIG") {
+        // KUBECONFIG may list several files; the first is enough to know one is set up
+        Some(paths) if !paths.is_empty() => std::env::split_paths(&paths).next()?,
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".kube").join("config"),
+    };
+    path.exists().then_some(path)
+}
+
+/// Run kubectl, returning stdout
+async fn kubectl(args: &[&str]) -> Result<String> {
+    let output = Command::new("kubectl")
+        .args(args)
+        .output()
+        .await
+        .context("Failed to run kubectl (is it installed?)")?;
+
+    if !output.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Namespace of the current context ("default" when unset)
+pub async fn current_namespace() -> String {
+    kubectl(&["config", "view", "--minify", "-o", "jsonpath={..namespace}"])
+        .await
+        .ok()
+        .map(|ns| ns.trim().to_string())
+        .filter(|ns| !ns.is_empty())
+        .unwrap_or_else(|| "default".to_string())
+}
+
+/// All namespace names, sorted
+pub async fn list_namespaces() -> Result<Vec<String>> {
+    let output = kubectl(&["get", "namespaces", "-o", "jsonpath={.items[*].metadata.name}"])
+        .await
+        .context("Failed to list namespaces")?;
+    let mut namespaces: Vec<String> = output.split_whitespace().map(String::from).collect();
+    namespaces.sort();
+    Ok(namespaces)
+}
+
+/// Pods in a namespace
+pub async fn list_pods(namespace: &str) -> Result<Vec<PodInfo>> {
+    let output = kubectl(&["get", "pods", "-n", namespace, "-o", "json"])
+        .await
+        .context(format!("Failed to list pods in {}", namespace))?;
+    parse_pods(&output)
+}
+
+/// The last `tail` log lines of a pod (all containers), with timestamps
+pub async fn pod_logs(namespace: &str, pod: &str, tail: usize) -> Result<Vec<String>> {
+    let tail = tail.to_string();
+    let output = kubectl(&[
+        "logs", "-n", namespace, pod, "--all-containers", "--timestamps", "--tail", &tail,
+    ])
+    .await
+    .context(format!("Failed to get logs of {}", pod))?;
+    Ok(output.lines().map(String::from).collect())
+}
+
+/// Parse `kubectl get pods -o json` output
+fn parse_pods(json: &str) -> Result<Vec<PodInfo>> {
+    let list: Value = serde_json::from_str(json).context("Unexpected kubectl output")?;
+    let items = list["items"].as_array().cloned().unwrap_or_default();
+
+    let mut pods: Vec<PodInfo> = items
+        .iter()
+        .map(|pod| {
+            let statuses = pod["status"]["containerStatuses"].as_array().cloned().unwrap_or_default();
+            // A waiting or terminated reason says more than the phase (e.g. CrashLoopBackOff)
+            let reason = statuses.iter().find_map(|s| {
+                s["state"]["waiting"]["reason"]
+                    .as_str()
+                    .or_else(|| s["state"]["terminated"]["reason"].as_str().filter(|r| *r != "Completed"))
+            });
+            let status = if pod["metadata"]["deletionTimestamp"].is_string() {
+                "Terminating"
+            } else {
+                reason.or(pod["status"]["phase"].as_str()).unwrap_or("Unknown")
+            };
+
+            PodInfo {
+                name: pod["metadata"]["name"].as_str().unwrap_or_default().to_string(),
+                namespace: pod["metadata"]["namespace"].as_str().unwrap_or_default().to_string(),
+                status: status.to_string(),
+                ready: statuses.iter().filter(|s| s["ready"].as_bool() == Some(true)).count(),
+                containers: pod["spec"]["containers"].as_array().map_or(statuses.len(), |c| c.len()),
+                restarts: statuses.iter().filter_map(|s| s["restartCount"].as_u64()).sum(),
+                node: pod["spec"]["nodeName"].as_str().map(String::from),
+                created: pod["metadata"]["creationTimestamp"]
+                    .as_str()
+                    .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+                    .map(|t| t.with_timezone(&Utc)),
+            }
+        })
+        .collect();
+
+    pods.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(pods)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pods() {
+        let json = r#"{"items": [
+            {"metadata": {"name": "web-1", "namespace": "apps", "creationTimestamp": "2024-05-01T10:00:00Z"},
+             "spec": {"nodeName": "node-a", "containers": [{}, {}]},
+             "status": {"phase": "Running", "containerStatuses": [
+                {"ready": true, "restartCount": 2, "state": {"running": {}}},
+                {"ready": false, "restartCount": 5, "state": {"waiting": {"reason": "CrashLoopBackOff"}}}
+             ]}},
+            {"metadata": {"name": "job-1", "namespace": "apps"},
+             "spec": {"containers": [{}]},
+             "status": {"phase": "Succeeded", "containerStatuses": [
+                {"ready": false, "restartCount": 0, "state": {"terminated": {"reason": "Completed"}}}
+             ]}}
+        ]}"#;
+        let pods = parse_pods(json).unwrap();
+
+        assert_eq!(pods[0].name, "job-1");
+        assert_eq!(pods[0].status, "Succeeded");
+        assert!(pods[0].is_healthy());
+
+        let web = &pods[1];
+        assert_eq!(web.status, "CrashLoopBackOff");
+        assert_eq!((web.ready, web.containers, web.restarts), (1, 2, 7));
+        assert_eq!(web.node.as_deref(), Some("node-a"));
+        assert!(!web.is_healthy());
+    }
+}
@@ -1,48 +1,193 @@
 #![allow(dead_code)]
 
 mod action;
+mod alerts;
 mod app;
+mod audit;
+mod cli;
+mod clipboard;
+mod columns;
+mod commands;
 mod components;
 mod config;
 mod docker;
 mod effects;
+mod export;
+mod forwards;
+mod groups;
+mod history;
+mod image_sizes;
+mod keepalive;
+mod keyring;
+#[cfg(feature = "kubernetes")]
+mod kube;
+mod keymap;
+#[cfg(feature = "metrics")]
+mod metrics;
 mod models;
+mod pins;
+mod recording;
+mod recycle;
+mod scaffold;
+mod schedule;
+mod template;
+mod trivy;
 mod tui;
 mod ui;
 
-use std::process::Command;
 use std::time::{Duration, Instant};
 
+/// Shortest time between frames, however often input or data arrives
+const MIN_FRAME_INTERVAL: Duration = Duration::from_millis(1000 / 30);
+
+/// Longest time between frames, for clocks and background results that
+/// don't mark the app for a redraw
+const MAX_FRAME_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long a bound digit waits to turn out to be a count (12j) before it
+/// takes its own binding, like vim's timeoutlen
+const COUNT_TIMEOUT: Duration = Duration::from_millis(500);
+
 use anyhow::Result;
+use clap::Parser;
 use crossterm::event::{self, Event, KeyCode, KeyModifiers};
 
 use crate::action::Action;
-use crate::app::{App, ModalState, ViewMode};
+use crate::app::{App, AppOptions, ModalState, StatusFilter, ViewMode};
+use crate::cli::{Cli, Commands};
+use crate::components::confirm_modal::{BatchOp, ConfirmAction};
+use crate::components::cleanup_modal::CleanupPhase;
+use crate::components::exec_modal::ExecFocus;
+use crate::components::pull_modal::PullFocus;
+use crate::components::yank_menu::YankTarget;
 use crate::components::CreateMode;
+use crate::export::ExportFormat;
+use crate::keymap::{Command, KeyContext};
+use crate::models::ContainerInfo;
+use crate::template::ContainerTemplate;
+use crate::ui::ThemeName;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    // Non-TUI subcommands
+    match cli.command {
+        Some(Commands::Sessions) => return commands::sessions(),
+        Some(Commands::Ps { json }) => return commands::ps(cli.host.as_deref(), json).await,
+        Some(Commands::CheckConfig { projects }) => return commands::check_config(projects.as_deref()),
+        Some(Commands::ExportTemplate { manifest }) => return commands::export_template(&manifest),
+        None => {}
+    }
+
+    if let Some(format) = cli.snapshot.as_deref() {
+        let format = ExportFormat::parse(format)
+            .ok_or_else(|| anyhow::anyhow!("Unknown snapshot format: {}", format))?;
+        return commands::snapshot(cli.host.as_deref(), cli.filter.as_deref(), format).await;
+    }
+
+    let theme = match cli.theme.as_deref() {
+        Some(name) => Some(ThemeName::parse(name).ok_or_else(|| anyhow::anyhow!("Unknown theme: {}", name))?),
+        None => None,
+    };
+    // Read before the terminal is taken over, since it may come from stdin
+    let template = cli.template.as_deref().map(ContainerTemplate::read).transpose()?;
+    let options = AppOptions {
+        host: cli.host,
+        refresh_interval: cli.refresh_ms.map(Duration::from_millis),
+        theme,
+        filter: cli.filter,
+        history_file: if cli.history {
+            config::data_dir().map(|dir| dir.join("history.jsonl"))
+        } else {
+            None
+        },
+        read_only: cli.read_only,
+        accessible: cli.accessible,
+        template,
+        select: cli.select,
+        logs: cli.logs,
+        #[cfg(feature = "metrics")]
+        metrics_addr: cli.metrics_port.map(|port| std::net::SocketAddr::new(cli.metrics_addr, port)),
+    };
+
     // Initialize terminal
     let mut terminal = tui::init()?;
 
     // Create app
-    let mut app = App::new().await?;
+    let mut app = match App::new(options).await {
+        Ok(app) => app,
+        Err(e) => {
+            tui::restore()?;
+            return Err(e);
+        }
+    };
 
-    // Main event loop - use faster tick for smooth animations
-    let tick_rate = Duration::from_millis(32); // ~30 FPS for animations
+    // Main event loop - fast ticks for smooth animations unless motion is reduced
     let mut last_frame = Instant::now();
+    // Key picked in the actions menu, handled as if it had been pressed
+    let mut replay: Option<event::KeyEvent> = None;
 
     loop {
-        // Calculate elapsed time for animations
+        // Redraw only for a change or a running animation, at a capped rate
         let elapsed = last_frame.elapsed();
-        last_frame = Instant::now();
+        let wanted = app.needs_redraw || app.animating();
+        if wanted && elapsed >= MIN_FRAME_INTERVAL || elapsed >= MAX_FRAME_INTERVAL {
+            last_frame = Instant::now();
+            app.needs_redraw = false;
+            // Render with elapsed time for effects; one that started while
+            // nothing was drawn begins at its first frame, not the idle gap in
+            let step = elapsed.min(MIN_FRAME_INTERVAL);
+            terminal.draw(|frame| app.render_with_effects(frame, step))?;
+        }
+
+        // Handle events with timeout for tick; a redraw held back by the
+        // frame cap is due sooner than that
+        let mut timeout = if app.needs_redraw {
+            app.tick_rate().min(MIN_FRAME_INTERVAL.saturating_sub(last_frame.elapsed()))
+        } else {
+            app.tick_rate()
+        };
+        if let Some((_, at)) = app.count_key {
+            timeout = timeout.min(COUNT_TIMEOUT.saturating_sub(at.elapsed()));
+        }
+        let event = match replay.take() {
+            Some(key) => Some(Event::Key(key)),
+            None if event::poll(timeout)? => Some(event::read()?),
+            None => None,
+        };
+        if let Some(event) = event {
+            app.needs_redraw = true;
+            if let Event::Resize(..) = event {
+                // Repaint everything at the new size so no stale cells survive
+                terminal.autoresize()?;
+                terminal.clear()?;
+            }
+            if let Event::Key(key) = event {
+                app.note_input();
+
+                // A bound digit that didn't turn out to be a count acts on its own
+                if app.count_key.is_some() && !is_count_digit(&app, key) {
+                    match handle_key_event(&app, key) {
+                        Action::Down | Action::Up => {}
+                        _ if key.code == KeyCode::Esc => {
+                            app.count_key = None;
+                            app.pending_count = None;
+                            continue;
+                        }
+                        _ => flush_count_key(&mut app).await?,
+                    }
+                }
 
-        // Render with elapsed time for effects
-        terminal.draw(|frame| app.render_with_effects(frame, elapsed))?;
+                // The embedded shell gets every key but the one that leaves it
+                if app.exec_focused {
+                    match app.exec_pane {
+                        Some(ref session) if key.code != KeyCode::F(2) => session.send_key(key),
+                        _ => app.exec_focused = false,
+                    }
+                    continue;
+                }
 
-        // Handle events with timeout for tick
-        if event::poll(tick_rate)? {
-            if let Event::Key(key) = event::read()? {
                 // Handle modes that need text input separately
                 match app.view_mode {
                     ViewMode::Create => {
@@ -51,12 +196,24 @@ async fn main() -> Result<()> {
                     ViewMode::Filter => {
                         handle_filter_mode(&mut app, key)?;
                     }
+                    ViewMode::Exec if key.code == KeyCode::F(2) => {
+                        // Run the shell in the embedded pane instead of suspending the TUI
+                        if let Some(modal) = app.exec_modal.take() {
+                            app.view_mode = ViewMode::List;
+                            app.open_exec_pane(&modal).await;
+                        }
+                    }
                     ViewMode::Exec => {
-                        if let Some((container, shell)) = handle_exec_mode(&mut app, key) {
+                        if let Some((container, args)) = handle_exec_mode(&mut app, key) {
+                            app.audit("exec", &container, "ok".to_string());
+                            let cast = app.full_screen_cast(&container);
                             // Exec into container and get new terminal
-                            terminal = exec_into_container(&container, &shell)?;
+                            terminal = exec_into_container(&args, cast.as_deref())?;
                             // Force full redraw
                             terminal.clear()?;
+                            if let Some(ref path) = cast {
+                                app.recorded(path);
+                            }
                         }
                     }
                     ViewMode::Info => {
@@ -71,35 +228,134 @@ async fn main() -> Result<()> {
                     ViewMode::Processes => {
                         handle_processes_mode(&mut app, key);
                     }
+                    ViewMode::ImageHistory => {
+                        handle_image_history_mode(&mut app, key);
+                    }
+                    ViewMode::Vulns => {
+                        handle_vulns_mode(&mut app, key);
+                    }
+                    ViewMode::Gpus => {
+                        handle_gpus_mode(&mut app, key);
+                    }
+                    ViewMode::Cleanup => {
+                        handle_cleanup_mode(&mut app, key);
+                    }
+                    ViewMode::Diff => {
+                        handle_diff_mode(&mut app, key);
+                    }
+                    ViewMode::Compose => {
+                        handle_compose_mode(&mut app, key);
+                    }
+                    ViewMode::ConfigErrors => {
+                        handle_config_errors_mode(&mut app, key);
+                    }
+                    ViewMode::Checkpoints => {
+                        handle_checkpoint_mode(&mut app, key).await?;
+                    }
+                    ViewMode::Groups => {
+                        handle_group_mode(&mut app, key).await?;
+                    }
+                    ViewMode::Labels => {
+                        handle_label_mode(&mut app, key).await?;
+                    }
+                    ViewMode::Forward => {
+                        handle_forward_mode(&mut app, key);
+                    }
+                    ViewMode::Pull => {
+                        handle_pull_mode(&mut app, key).await?;
+                    }
+                    ViewMode::Login => {
+                        handle_login_mode(&mut app, key).await?;
+                    }
                     ViewMode::CopyFiles => {
                         handle_copy_mode(&mut app, key).await?;
                     }
+                    ViewMode::Logs if app.logs_view.goto.is_some() => {
+                        handle_goto_mode(&mut app, key);
+                    }
+                    ViewMode::Logs if app.logs_view.stdin.is_some() => {
+                        handle_stdin_mode(&mut app, key).await?;
+                    }
+                    ViewMode::Logs if app.logs_view.filter_input.is_some() => {
+                        handle_log_filter_mode(&mut app, key);
+                    }
+                    ViewMode::Events if app.events_view.input.is_some() => {
+                        handle_event_filter_mode(&mut app, key);
+                    }
+                    _ if matches!(app.modal, ModalState::Help) => {
+                        handle_help_mode(&mut app, key);
+                    }
+                    _ if matches!(app.modal, ModalState::Yank) => {
+                        handle_yank_mode(&mut app, key);
+                    }
+                    _ if matches!(app.modal, ModalState::Actions) => {
+                        replay = handle_action_menu(&mut app, key);
+                    }
+                    _ if matches!(app.modal, ModalState::Palette) => {
+                        replay = handle_palette(&mut app, key).await?;
+                    }
+                    _ if is_count_digit(&app, key) => {
+                        if let KeyCode::Char(c) = key.code {
+                            let bound = keymap::lookup(app.key_context(), key.code).is_some();
+                            app.count_key = (app.pending_count.is_none() && bound).then(|| (key, Instant::now()));
+                            let digit = c.to_digit(10).unwrap_or(0) as usize;
+                            app.pending_count = Some((app.pending_count.unwrap_or(0) * 10 + digit).min(MAX_COUNT));
+                        }
+                    }
                     _ => {
-                        // Special handling for 'n' to open create form
-                        if key.code == KeyCode::Char('n') && app.view_mode == ViewMode::List && matches!(app.modal, ModalState::None) {
+                        let count = app.pending_count.take();
+                        app.count_key = None;
+                        // List commands that need mutable access are handled here
+                        let list_command = if app.view_mode == ViewMode::List && matches!(app.modal, ModalState::None) {
+                            keymap::lookup(KeyContext::List, key.code)
+                        } else {
+                            None
+                        };
+
+                        let logs_command = if app.view_mode == ViewMode::Logs && matches!(app.modal, ModalState::None) {
+                            keymap::lookup(KeyContext::Logs, key.code)
+                        } else {
+                            None
+                        };
+
+                        // Read-only mode refuses before the commands handled here
+                        if let Some(desc) = list_command.and_then(|c| c.read_only_refusal(app.read_only)) {
+                            app.handle_action(Action::ReadOnlyBlocked(desc)).await?;
+                        } else if logs_command == Some(Command::SelectText) {
+                            // Hand the screen to the terminal so its own selection works
+                            terminal = show_plain_text(&app.logs_container, &app.logs)?;
+                            terminal.clear()?;
+                        } else if list_command == Some(Command::NewContainer) {
                             app.open_create_form().await?;
-                        } else if key.code == KeyCode::Char('/') && app.view_mode == ViewMode::List && matches!(app.modal, ModalState::None) {
+                        } else if list_command == Some(Command::Filter) {
                             // Enter filter mode
                             app.filter.activate();
                             app.view_mode = ViewMode::Filter;
-                        } else if key.code == KeyCode::Char('e') && app.view_mode == ViewMode::List && matches!(app.modal, ModalState::None) {
+                        } else if list_command == Some(Command::Exec) {
                             // Open exec modal for running containers
                             if let Some(container) = app.selected_container() {
                                 if container.status.is_running() {
-                                    app.open_exec_modal(container.name.clone());
+                                    app.open_exec_modal(container.name.clone()).await;
                                 }
                             }
-                        } else if key.code == KeyCode::Char('i') && app.view_mode == ViewMode::List && matches!(app.modal, ModalState::None) {
-                            // Open info modal (network I/O)
-                            app.view_mode = ViewMode::Info;
+                        } else if list_command == Some(Command::Info) {
+                            // Open info modal (details, network I/O)
+                            app.open_info().await;
                         } else {
-                            let action = handle_key_event(&app, key);
+                            let action = match (handle_key_event(&app, key), count) {
+                                (Action::Down, Some(n)) => Action::MoveBy(n as isize),
+                                (Action::Up, Some(n)) => Action::MoveBy(-(n as isize)),
+                                (action, _) => action,
+                            };
                             app.handle_action(action).await?;
                         }
                     }
                 }
             }
         } else {
+            if app.count_key.is_some_and(|(_, at)| at.elapsed() >= COUNT_TIMEOUT) {
+                flush_count_key(&mut app).await?;
+            }
             // Tick for periodic updates
             app.handle_action(Action::Tick).await?;
         }
@@ -136,19 +392,23 @@ fn handle_filter_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
             app.update_filtered_indices();
         }
         KeyCode::Up => {
-            app.container_list.previous(app.filtered_indices.len());
+            let count = app.nav_item_count();
+            app.container_list.previous(count);
         }
         KeyCode::Down => {
-            app.container_list.next(app.filtered_indices.len());
+            let count = app.nav_item_count();
+            app.container_list.next(count);
         }
         _ => {}
     }
     Ok(())
 }
 
-/// Handle key events in exec mode (shell selection)
-/// Returns Some((container, shell)) if exec should be performed
-fn handle_exec_mode(app: &mut App, key: event::KeyEvent) -> Option<(String, String)> {
+/// Handle key events in exec mode (shell, user and working directory)
+/// Returns the container and docker arguments if exec should be performed
+fn handle_exec_mode(app: &mut App, key: event::KeyEvent) -> Option<(String, Vec<String>)> {
+    let modal = app.exec_modal.as_mut()?;
+    let on_shells = modal.focus == ExecFocus::Shell;
     match key.code {
         KeyCode::Esc => {
             app.exec_modal = None;
@@ -156,45 +416,66 @@ fn handle_exec_mode(app: &mut App, key: event::KeyEvent) -> Option<(String, Stri
             None
         }
         KeyCode::Enter => {
-            if let Some(ref modal) = app.exec_modal {
-                let shell = modal.selected_shell().to_string();
-                let container = modal.container_name.clone();
+            let target = (modal.container_name.clone(), modal.exec_args());
 
-                // Close modal
-                app.exec_modal = None;
-                app.view_mode = ViewMode::List;
+            // Close modal
+            app.exec_modal = None;
+            app.view_mode = ViewMode::List;
 
-                Some((container, shell))
-            } else {
-                None
-            }
+            Some(target)
         }
-        KeyCode::Up | KeyCode::Char('k') => {
-            if let Some(ref mut modal) = app.exec_modal {
-                modal.previous();
-            }
+        KeyCode::Tab => {
+            modal.next_focus();
             None
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if let Some(ref mut modal) = app.exec_modal {
-                modal.next();
-            }
+        KeyCode::BackTab => {
+            modal.prev_focus();
+            None
+        }
+        KeyCode::Up => {
+            modal.previous();
+            None
+        }
+        KeyCode::Down => {
+            modal.next();
+            None
+        }
+        KeyCode::Char('k') if on_shells => {
+            modal.previous();
+            None
+        }
+        KeyCode::Char('j') if on_shells => {
+            modal.next();
+            None
+        }
+        KeyCode::Char(c) => {
+            modal.handle_char(c);
+            None
+        }
+        KeyCode::Backspace => {
+            modal.handle_backspace();
             None
         }
         _ => None,
     }
 }
 
-/// Execute docker exec into container
+/// Execute docker exec into container, under `asciinema rec` when a cast
+/// path is given
 /// Returns a new terminal after reinitializing
-fn exec_into_container(container: &str, shell: &str) -> Result<ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>> {
+fn exec_into_container(args: &[String], cast: Option<&std::path::Path>) -> Result<ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>> {
     // Restore terminal for interactive docker exec
     tui::restore()?;
 
     // Run docker exec interactively
-    let status = Command::new("docker")
-        .args(["exec", "-it", container, shell])
-        .status();
+    let status = match cast {
+        Some(path) => std::process::Command::new("asciinema")
+            .args(["rec", "--quiet", "-c"])
+            .arg(format!("docker {}", recording::shell_join(args)))
+            .arg(path)
+            .status(),
+        None => std::process::Command::new("docker").args(args).status(),
+    };
 
     if let Err(e) = status {
         eprintln!("Failed to exec into container: {}", e);
@@ -206,30 +487,76 @@ fn exec_into_container(container: &str, shell: &str) -> Result<ratatui::Terminal
     Ok(tui::init()?)
 }
 
+/// Leave the TUI and print lines as plain text (selectable and in the
+/// terminal's scrollback) until Enter is pressed
+fn show_plain_text(title: &str, lines: &[String]) -> Result<ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>> {
+    use std::io::Write;
+
+    tui::restore()?;
+
+    let mut out = std::io::stdout().lock();
+    writeln!(out, "── {} ──", title)?;
+    for line in lines {
+        writeln!(out, "{}", line)?;
+    }
+    writeln!(out, "── select with the mouse, press Enter to return ──")?;
+    out.flush()?;
+    drop(out);
+
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+
+    Ok(tui::init()?)
+}
+
 /// Handle key events in create mode (text input)
 async fn handle_create_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
+    if app.create_form.mode == CreateMode::EnvEdit {
+        handle_env_editor(app, key);
+        return Ok(());
+    }
+    if let Some(ref mut path) = app.create_form.template_file {
+        match key.code {
+            KeyCode::Esc => app.create_form.template_file = None,
+            KeyCode::Enter => {
+                let path = path.clone();
+                app.create_form.template_file = None;
+                app.import_template(&path);
+            }
+            KeyCode::Backspace => {
+                path.pop();
+            }
+            KeyCode::Char(c) => path.push(c),
+            _ => {}
+        }
+        return Ok(());
+    }
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
     match key.code {
+        KeyCode::Char('s') if ctrl && app.create_form.mode == CreateMode::Form => app.export_template(),
+        KeyCode::Char('o') if ctrl && app.create_form.mode == CreateMode::Form => {
+            app.create_form.template_file = Some(String::new());
+        }
         KeyCode::Esc => {
-            if app.create_form.mode == CreateMode::ImageSelect {
+            if app.create_form.mode != CreateMode::Form {
                 app.create_form.mode = CreateMode::Form;
             } else {
                 app.view_mode = ViewMode::List;
             }
         }
-        KeyCode::Enter => {
-            if app.create_form.mode == CreateMode::ImageSelect {
-                app.create_form.select_image();
-            } else if app.create_form.is_valid() {
-                app.create_container_from_form().await?;
-            }
-        }
+        KeyCode::Enter => match app.create_form.mode {
+            CreateMode::ImageSelect => app.create_form.select_image(),
+            CreateMode::NetworkSelect => app.create_form.select_network(),
+            CreateMode::Form if app.create_form.is_valid() => app.create_container_from_form().await?,
+            CreateMode::Form | CreateMode::EnvEdit => {}
+        },
         KeyCode::Tab => {
             if key.modifiers.contains(KeyModifiers::SHIFT) {
                 app.create_form.prev_field();
             } else {
-                // If on image field, open image selector
-                if app.create_form.selected_field == 1 && app.create_form.mode == CreateMode::Form {
-                    app.create_form.mode = CreateMode::ImageSelect;
+                // On the image or network field, open its selector
+                if let Some(picker) = app.create_form.picker_for_field().filter(|_| app.create_form.mode == CreateMode::Form) {
+                    app.create_form.mode = picker;
                 } else {
                     app.create_form.next_field();
                 }
@@ -239,13 +566,13 @@ async fn handle_create_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
             app.create_form.prev_field();
         }
         KeyCode::Up => {
-            if app.create_form.mode == CreateMode::ImageSelect {
-                app.create_form.prev_image();
+            if app.create_form.mode != CreateMode::Form {
+                app.create_form.up();
             }
         }
         KeyCode::Down => {
-            if app.create_form.mode == CreateMode::ImageSelect {
-                app.create_form.next_image();
+            if app.create_form.mode != CreateMode::Form {
+                app.create_form.down();
             }
         }
         KeyCode::Backspace => {
@@ -263,6 +590,72 @@ async fn handle_create_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
     Ok(())
 }
 
+/// Handle key events in the create form's env editor
+fn handle_env_editor(app: &mut App, key: event::KeyEvent) {
+    let form = &mut app.create_form;
+    if let Some(ref mut path) = form.env_file {
+        match key.code {
+            KeyCode::Esc => form.env_file = None,
+            KeyCode::Enter => {
+                let path = path.clone();
+                form.env_file = None;
+                app.load_env_file(&path);
+            }
+            KeyCode::Backspace => {
+                path.pop();
+            }
+            KeyCode::Char(c) => path.push(c),
+            _ => {}
+        }
+        return;
+    }
+    match key.code {
+        KeyCode::Esc => {
+            form.mode = CreateMode::Form;
+            form.next_field();
+        }
+        KeyCode::Enter => form.env_add_row(),
+        KeyCode::Tab | KeyCode::BackTab => form.env_toggle_column(),
+        KeyCode::Up => form.up(),
+        KeyCode::Down => form.down(),
+        KeyCode::Delete => form.env_remove_row(),
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => form.env_remove_row(),
+        KeyCode::Char('o') if key.modifiers.contains(KeyModifiers::CONTROL) => form.env_file = Some(".env".to_string()),
+        KeyCode::Backspace => form.backspace(),
+        KeyCode::Char(c) => form.type_char(c),
+        _ => {}
+    }
+}
+
+/// Largest count prefix kept (99999j is a typo, not a request)
+const MAX_COUNT: usize = 9999;
+
+/// Whether a digit starts or extends a count prefix (5j). One the view binds
+/// (1-4 in the list) is held in `count_key` until it's clear which it is
+fn is_count_digit(app: &App, key: event::KeyEvent) -> bool {
+    let KeyCode::Char(c @ '0'..='9') = key.code else {
+        return false;
+    };
+    // Alt+digit switches tabs
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        return false;
+    }
+    if !matches!(app.view_mode, ViewMode::List | ViewMode::Logs) || !matches!(app.modal, ModalState::None) {
+        return false;
+    }
+    app.pending_count.is_some() || c != '0'
+}
+
+/// Run the binding of a digit held as a possible count, dropping the count
+async fn flush_count_key(app: &mut App) -> Result<()> {
+    if let Some((key, _)) = app.count_key.take() {
+        app.pending_count = None;
+        let action = handle_key_event(app, key);
+        app.handle_action(action).await?;
+    }
+    Ok(())
+}
+
 /// Convert key events to actions based on current state
 fn handle_key_event(app: &App, key: event::KeyEvent) -> Action {
     // Handle modal keys first
@@ -270,189 +663,820 @@ fn handle_key_event(app: &App, key: event::KeyEvent) -> Action {
         return match key.code {
             KeyCode::Esc | KeyCode::Char('n') => Action::CloseModal,
             KeyCode::Enter | KeyCode::Char('y') => Action::ConfirmAction,
+            KeyCode::Char(c) if matches!(&app.modal, ModalState::Confirm(action)
+                if action.alternative().is_some_and(|(key, ..)| key == c)) => Action::ConfirmAlternative,
             _ => Action::None,
         };
     }
 
     // Global keys
-    match key.code {
-        KeyCode::Char('q') => return Action::Quit,
-        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            return Action::Quit
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        return Action::Quit;
+    }
+    if key.code == KeyCode::Char('p') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        return Action::ShowPalette;
+    }
+    if key.modifiers.contains(KeyModifiers::ALT) && app.can_switch_tabs() {
+        match key.code {
+            KeyCode::Char(c @ '1'..='9') => return Action::SwitchTab(c as usize - '1' as usize),
+            KeyCode::Char('t') => return Action::NewTab,
+            KeyCode::Char('w') => return Action::CloseTab,
+            _ => {}
+        }
+    }
+    if matches!(app.view_mode, ViewMode::List | ViewMode::Logs) && key.modifiers.contains(KeyModifiers::CONTROL) {
+        match key.code {
+            KeyCode::Char('d') => return Action::HalfPageDown,
+            KeyCode::Char('u') => return Action::HalfPageUp,
+            _ => {}
         }
-        KeyCode::Char('?') => return Action::ShowHelp,
-        _ => {}
+    }
+    if let Some(command) = keymap::lookup(KeyContext::Global, key.code) {
+        return command_action(app, command);
     }
 
     // View-specific keys
-    match app.view_mode {
-        ViewMode::List => handle_list_key(app, key),
-        ViewMode::Logs => handle_logs_key(key),
+    let context = match app.view_mode {
+        ViewMode::List => KeyContext::List,
+        ViewMode::Logs => KeyContext::Logs,
+        ViewMode::Charts => KeyContext::Charts,
+        ViewMode::Compare => KeyContext::Compare,
+        ViewMode::Services => KeyContext::Services,
+        ViewMode::Pods => KeyContext::Pods,
+        ViewMode::Host => KeyContext::Host,
+        ViewMode::Events => KeyContext::Events,
         ViewMode::Create | ViewMode::Filter | ViewMode::Exec | ViewMode::Info
-        | ViewMode::Rename | ViewMode::Processes | ViewMode::CopyFiles => Action::None, // Handled separately
-    }
+        | ViewMode::Rename | ViewMode::Processes | ViewMode::CopyFiles
+        | ViewMode::ImageHistory | ViewMode::Cleanup | ViewMode::Pull
+        | ViewMode::Login | ViewMode::Diff | ViewMode::Checkpoints
+        | ViewMode::Groups | ViewMode::Labels | ViewMode::Forward | ViewMode::Vulns | ViewMode::Gpus
+        | ViewMode::Compose | ViewMode::ConfigErrors => return Action::None, // Handled separately
+    };
+    keymap::lookup(context, key.code)
+        .map(|command| command_action(app, command))
+        .unwrap_or(Action::None)
 }
 
-/// Handle keys in list view
-fn handle_list_key(app: &App, key: event::KeyEvent) -> Action {
-    match key.code {
-        KeyCode::Char('j') | KeyCode::Down => Action::Down,
-        KeyCode::Char('k') | KeyCode::Up => Action::Up,
-        KeyCode::Left | KeyCode::Char('h') => Action::Left,
-        KeyCode::Right => Action::Right,
-        KeyCode::Char('g') => Action::Top,
-        KeyCode::Char('G') => Action::Bottom,
-
-        KeyCode::Enter | KeyCode::Char('l') => {
-            if let Some(name) = app.selected_container_name() {
-                Action::ViewLogs(name)
+/// Resolve a keymap command into an action for the selected container
+fn command_action(app: &App, command: Command) -> Action {
+    if let Some(desc) = command.read_only_refusal(app.read_only) {
+        return Action::ReadOnlyBlocked(desc);
+    }
+    let selected = app.selected_container();
+    let name = || selected.map(|c| c.name.clone());
+    let project = || selected.and_then(|c| c.compose_project.clone());
+    let service = app.services_view.selected().and_then(|i| app.services.get(i));
+    let pod = app.pods_view.selected().and_then(|i| app.pods.get(i));
+    // Containers of the active group, or else of the selected compose project,
+    // that the batch op applies to
+    let project_batch = |op: BatchOp, applies: fn(&ContainerInfo) -> bool| {
+        let group = app.group_filter.as_deref();
+        let project = if group.is_some() { None } else { Some(project()?) };
+        let names: Vec<String> = app
+            .containers
+            .iter()
+            .filter(|c| {
+                let in_scope = match group {
+                    Some(group) => app.groups.contains(group, &c.name),
+                    None => c.compose_project == project,
+                };
+                in_scope && applies(c)
+            })
+            .map(|c| c.name.clone())
+            .collect();
+        (!names.is_empty()).then_some(Action::ShowConfirm(ConfirmAction::Batch(op, names)))
+    };
+
+    let action = match command {
+        Command::Up => Some(Action::Up),
+        Command::Down => Some(Action::Down),
+        Command::Top => Some(Action::Top),
+        Command::Bottom => Some(Action::Bottom),
+        Command::ScreenTop => Some(Action::ScreenTop),
+        Command::ScreenMiddle => Some(Action::ScreenMiddle),
+        Command::ScreenBottom => Some(Action::ScreenBottom),
+        Command::Left => Some(Action::Left),
+        Command::Right => Some(Action::Right),
+        Command::Back => Some(Action::BackToList),
+        Command::Help => Some(Action::ShowHelp),
+        Command::Quit => Some(Action::Quit),
+
+        Command::ViewLogs => name().map(Action::ViewLogs),
+        Command::ViewProjectLogs => project().map(Action::ViewProjectLogs),
+        Command::ViewCharts => name().map(Action::ViewCharts),
+        Command::ViewCompare => Some(Action::ViewCompare),
+        Command::ViewServices => Some(Action::ViewServices),
+        Command::ViewHostProcesses => Some(Action::ViewHostProcesses),
+        Command::ViewGpus => Some(Action::ViewGpus),
+        Command::ViewEvents => Some(Action::ViewEvents),
+        Command::FilterEvents => Some(Action::StartEventFilter),
+        Command::ToggleProcessSort => Some(Action::ToggleProcessSort),
+        Command::CycleProjectSort => Some(Action::CycleProjectSort),
+        Command::ToggleProcessGrouping => Some(Action::ToggleProcessGrouping),
+        Command::ViewPods => Some(Action::ViewPods),
+        Command::PodLogs => pod.map(|p| Action::ViewPodLogs(p.namespace.clone(), p.name.clone())),
+        Command::NextNamespace => Some(Action::NextNamespace),
+        Command::Mark => name().map(Action::ToggleMark),
+        Command::Groups => name().map(Action::ShowGroups),
+        Command::Pin => name().map(Action::TogglePin),
+        Command::Labels => {
+            // Marked containers in list order, else the selected one
+            let marked: Vec<String> = app
+                .containers
+                .iter()
+                .filter(|c| app.container_list.marked.contains(&c.name))
+                .map(|c| c.name.clone())
+                .collect();
+            if marked.is_empty() {
+                name().map(|name| Action::ShowLabels(vec![name]))
             } else {
-                Action::None
+                Some(Action::ShowLabels(marked))
             }
         }
+        Command::CycleGroupFilter => Some(Action::CycleGroupFilter),
+        Command::ToggleAlerts => Some(Action::ToggleAlerts),
+        Command::ToggleRecycleBin => Some(Action::ToggleRecycleBin),
+        Command::ToggleAuditLog => Some(Action::ToggleAuditLog),
+        Command::ToggleDaemon => Some(Action::ToggleDaemon),
+        Command::ToggleSchedule => Some(Action::ToggleSchedule),
+        Command::WatchExit => name().map(Action::ToggleExitWatch),
+        Command::ForwardPort => name().map(Action::ShowForward),
+        Command::ToggleExpandedHeader => Some(Action::ToggleExpandedHeader),
+        Command::ToggleSplitView => Some(Action::ToggleSplitView),
+        Command::FocusExecPane => app.exec_pane.as_ref().map(|_| Action::FocusExecPane),
+        Command::GoToTime => Some(Action::StartGoToTime),
+        Command::LoadMoreLogs => Some(Action::LoadMoreLogs),
+        Command::FilterLogs => Some(Action::StartLogFilter),
+        Command::SendStdin => (app.logs_pod().is_none() && app.logs_project().is_none()).then_some(Action::StartStdin),
+        Command::RefreshFaster => Some(Action::ChangeRefreshSpeed(true)),
+        Command::RefreshSlower => Some(Action::ChangeRefreshSpeed(false)),
+        Command::KeepAlive => name().map(Action::ToggleKeepAlive),
+        Command::Yank => selected.map(|_| Action::ShowYank),
+        Command::ActionMenu => selected.map(|_| Action::ShowActionMenu),
+        Command::RecreateDeleted => (!app.recycle.is_empty()).then_some(Action::RecreateDeleted),
 
-        KeyCode::Char('s') => {
-            if let Some(name) = app.selected_container_name() {
-                Action::StartContainer(name)
-            } else {
-                Action::None
-            }
+        Command::Start => name().map(Action::StartContainer),
+        Command::Stop => name().map(|n| Action::ShowConfirm(ConfirmAction::Stop(n))),
+        Command::Restart => name().map(|n| Action::ShowConfirm(ConfirmAction::Restart(n))),
+        Command::Delete => name().map(|n| Action::ShowConfirm(ConfirmAction::Delete(n))),
+        Command::Rename => name().map(Action::ShowRename),
+        Command::CopyFiles => name().map(Action::ShowCopyFiles),
+        Command::Pause => selected
+            .filter(|c| c.status.is_running())
+            .map(|c| Action::PauseContainer(c.name.clone())),
+        Command::Unpause => selected
+            .filter(|c| c.status == crate::models::ContainerStatus::Paused)
+            .map(|c| Action::UnpauseContainer(c.name.clone())),
+        Command::ImageHistory => selected
+            .filter(|c| !c.image.is_empty())
+            .map(|c| Action::ShowImageHistory(c.image.clone())),
+        Command::ScanImage => selected
+            .filter(|c| !c.image.is_empty())
+            .map(|c| Action::ShowVulns(c.name.clone())),
+        Command::Diff => name().map(Action::ShowDiff),
+        Command::ComposeFile => name().map(Action::ShowCompose),
+        Command::ScaffoldProject => name().map(Action::ScaffoldProject),
+        Command::Checkpoints => name().map(Action::ShowCheckpoints),
+        Command::Processes => selected
+            .filter(|c| c.status.is_running())
+            .map(|c| Action::ShowProcesses(c.name.clone())),
+
+        Command::ComposeDown => project().map(|p| Action::ShowConfirm(ConfirmAction::ComposeDown(p))),
+        Command::StartProject => project_batch(BatchOp::Start, |c| !c.status.is_active()),
+        Command::StopProject => project_batch(BatchOp::Stop, |c| c.status.is_active()),
+        Command::Prune => Some(Action::ShowConfirm(ConfirmAction::PruneContainers)),
+        Command::Cleanup => Some(Action::ShowCleanup),
+        Command::Pull => Some(Action::ShowPull),
+        Command::Login => Some(Action::ShowLogin),
+        Command::CheckUpdates => Some(Action::CheckImageUpdates),
+        Command::UpdateImage => selected
+            .filter(|c| !c.image.is_empty() && crate::docker::registry::image_tag(&c.image).is_some())
+            .map(|c| Action::ShowConfirm(ConfirmAction::UpdateImage(c.name.clone()))),
+
+        Command::ScaleUp => service
+            .filter(|s| s.is_scalable())
+            .map(|s| Action::ScaleService(s.name.clone(), s.desired + 1)),
+        Command::ScaleDown => service
+            .filter(|s| s.is_scalable() && s.desired > 0)
+            .map(|s| Action::ScaleService(s.name.clone(), s.desired - 1)),
+        Command::RestartService => service.map(|s| Action::ShowConfirm(ConfirmAction::RestartService(s.name.clone()))),
+
+        Command::Refresh => Some(Action::Refresh),
+        Command::ExportJson => Some(Action::Export(ExportFormat::Json)),
+        Command::ExportCsv => Some(Action::Export(ExportFormat::Csv)),
+        Command::CycleStatusFilter => Some(Action::CycleStatusFilter),
+        Command::FilterAll => Some(Action::SetStatusFilter(StatusFilter::All)),
+        Command::FilterGroups => Some(Action::SetStatusFilter(StatusFilter::Groups)),
+        Command::FilterRunning => Some(Action::SetStatusFilter(StatusFilter::Running)),
+        Command::FilterStopped => Some(Action::SetStatusFilter(StatusFilter::Stopped)),
+
+        // Handled in the main loop (need mutable app / async setup)
+        Command::Info | Command::Exec | Command::NewContainer | Command::Filter | Command::SelectText => None,
+    };
+
+    action.unwrap_or(Action::None)
+}
+
+/// Handle keys while the help modal is open
+fn handle_help_mode(app: &mut App, key: event::KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => app.modal = ModalState::None,
+        KeyCode::Down | KeyCode::Char('j') => app.help.scroll_down(1),
+        KeyCode::Up | KeyCode::Char('k') => app.help.scroll_up(1),
+        KeyCode::PageDown | KeyCode::Char(' ') => app.help.scroll_down(10),
+        KeyCode::PageUp => app.help.scroll_up(10),
+        KeyCode::Char('g') => app.help.scroll = 0,
+        KeyCode::Tab | KeyCode::Char('a') => app.help.toggle_scope(),
+        _ => {}
+    }
+}
+
+/// Handle keys while the copy-to-clipboard menu is open
+fn handle_yank_mode(app: &mut App, key: event::KeyEvent) {
+    // Any other key just closes the menu
+    if let Some(target) = key.code.as_char().and_then(YankTarget::from_key) {
+        app.yank(target);
+    }
+    app.modal = ModalState::None;
+}
+
+/// Handle keys in the actions menu; returns the key of the picked entry to replay
+fn handle_action_menu(app: &mut App, key: event::KeyEvent) -> Option<event::KeyEvent> {
+    let picked = match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.action_menu.previous();
+            return None;
         }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.action_menu.next();
+            return None;
+        }
+        KeyCode::Enter => app.action_menu.selected_key(),
+        code => app.action_menu.shortcut(code),
+    };
+    // Any other key just closes the menu
+    app.modal = ModalState::None;
+    picked.map(|code| event::KeyEvent::new(code, KeyModifiers::NONE))
+}
 
-        KeyCode::Char('x') => {
-            if let Some(name) = app.selected_container_name() {
-                Action::ShowConfirmStop(name)
-            } else {
-                Action::None
+/// Handle keys in the command palette; a command bound in the view comes back
+/// as its key to replay, any other runs directly
+async fn handle_palette(app: &mut App, key: event::KeyEvent) -> Result<Option<event::KeyEvent>> {
+    let Some(ref mut palette) = app.palette else {
+        app.modal = ModalState::None;
+        return Ok(None);
+    };
+    match key.code {
+        KeyCode::Esc => {}
+        KeyCode::Enter => {
+            let picked = palette.selected();
+            app.palette = None;
+            app.modal = ModalState::None;
+            return match picked {
+                Some((_, Some(code))) => Ok(Some(event::KeyEvent::new(code, KeyModifiers::NONE))),
+                Some((command, None)) => match (command_action(app, command), keymap::key_in(KeyContext::List, command)) {
+                    // Commands the main loop handles (info, exec, ...) run from the list
+                    (Action::None, Some(code)) => {
+                        app.handle_action(Action::BackToList).await?;
+                        Ok((app.view_mode == ViewMode::List).then(|| event::KeyEvent::new(code, KeyModifiers::NONE)))
+                    }
+                    (action, _) => {
+                        app.handle_action(action).await?;
+                        Ok(None)
+                    }
+                },
+                None => Ok(None),
+            };
+        }
+        KeyCode::Up => {
+            palette.previous();
+            return Ok(None);
+        }
+        KeyCode::Down | KeyCode::Tab => {
+            palette.next();
+            return Ok(None);
+        }
+        KeyCode::Backspace => {
+            palette.handle_backspace();
+            return Ok(None);
+        }
+        KeyCode::Char(c) => {
+            palette.handle_char(c);
+            return Ok(None);
+        }
+        _ => return Ok(None),
+    }
+    app.palette = None;
+    app.modal = ModalState::None;
+    Ok(None)
+}
+
+/// Handle keys in rename mode
+async fn handle_rename_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc => {
+            app.rename_modal = None;
+            app.view_mode = ViewMode::List;
+        }
+        KeyCode::Enter => {
+            if let Some(ref modal) = app.rename_modal {
+                if modal.is_valid() {
+                    let old_name = modal.container_name.clone();
+                    let new_name = modal.new_name.clone();
+                    app.handle_action(Action::RenameContainer(old_name, new_name)).await?;
+                    app.rename_modal = None;
+                    app.view_mode = ViewMode::List;
+                }
             }
         }
+        KeyCode::Backspace => {
+            if let Some(ref mut modal) = app.rename_modal {
+                modal.handle_backspace();
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(ref mut modal) = app.rename_modal {
+                modal.handle_char(c);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keys in the pull dialog (repository input and tag search)
+async fn handle_pull_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
+    let Some(ref mut modal) = app.pull_modal else {
+        app.view_mode = ViewMode::List;
+        return Ok(());
+    };
+    match key.code {
+        KeyCode::Esc => {
+            app.pull_modal = None;
+            app.view_mode = ViewMode::List;
+        }
+        KeyCode::Tab | KeyCode::BackTab => modal.toggle_focus(),
+        KeyCode::Up => modal.previous(),
+        KeyCode::Down => modal.next(),
+        KeyCode::Backspace => modal.handle_backspace(),
+        KeyCode::Char(c) => modal.handle_char(c),
+        KeyCode::Enter => match modal.focus {
+            PullFocus::Repository => app.handle_action(Action::FetchTags).await?,
+            PullFocus::Tags => {
+                if let Some((image, tag)) = modal.selected_pull() {
+                    app.pull_modal = None;
+                    app.view_mode = ViewMode::List;
+                    app.handle_action(Action::PullImage(image, tag)).await?;
+                }
+            }
+        },
+        _ => {}
+    }
+    Ok(())
+}
 
-        KeyCode::Char('R') => {
-            if let Some(name) = app.selected_container_name() {
-                Action::RestartContainer(name)
+/// Handle keys in the registry login modal
+async fn handle_login_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
+    let Some(ref mut modal) = app.login_modal else {
+        app.view_mode = ViewMode::List;
+        return Ok(());
+    };
+    match key.code {
+        KeyCode::Esc => {
+            app.login_modal = None;
+            app.view_mode = ViewMode::List;
+        }
+        KeyCode::Tab | KeyCode::Down => modal.next_field(),
+        KeyCode::BackTab | KeyCode::Up => modal.prev_field(),
+        KeyCode::Backspace => modal.handle_backspace(),
+        KeyCode::Char(c) => modal.handle_char(c),
+        KeyCode::Enter => {
+            if modal.is_valid() {
+                app.handle_action(Action::Login).await?;
             } else {
-                Action::None
+                modal.next_field();
             }
         }
+        _ => {}
+    }
+    Ok(())
+}
 
-        KeyCode::Char('d') => {
-            if let Some(name) = app.selected_container_name() {
-                Action::ShowConfirmDelete(name)
-            } else {
-                Action::None
+/// Handle keys in the cleanup wizard
+fn handle_cleanup_mode(app: &mut App, key: event::KeyEvent) {
+    let Some(ref mut modal) = app.cleanup_modal else {
+        app.view_mode = ViewMode::List;
+        return;
+    };
+    match modal.phase {
+        CleanupPhase::Select => match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                app.cleanup_modal = None;
+                app.view_mode = ViewMode::List;
+            }
+            KeyCode::Up | KeyCode::Char('k') => modal.previous(),
+            KeyCode::Down | KeyCode::Char('j') => modal.next(),
+            KeyCode::Char(' ') => modal.toggle(),
+            KeyCode::Char('a') => modal.toggle_all(),
+            KeyCode::Enter => modal.review(),
+            _ => {}
+        },
+        CleanupPhase::Confirm => match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => modal.start(),
+            KeyCode::Char('n') | KeyCode::Esc | KeyCode::Char('q') => modal.back(),
+            _ => {}
+        },
+        // Removal runs on ticks; Esc stops after the item in flight
+        CleanupPhase::Running => {
+            if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+                modal.stop();
             }
         }
+        CleanupPhase::Done => {
+            if matches!(key.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')) {
+                app.cleanup_modal = None;
+                app.view_mode = ViewMode::List;
+            }
+        }
+    }
+}
 
-        KeyCode::Char('r') => Action::Refresh,
+/// Handle keys in image history mode
+fn handle_image_history_mode(app: &mut App, key: event::KeyEvent) {
+    let Some(ref mut modal) = app.image_history_modal else {
+        app.view_mode = ViewMode::List;
+        return;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('I') | KeyCode::Char('q') => {
+            app.image_history_modal = None;
+            app.view_mode = ViewMode::List;
+        }
+        KeyCode::Up | KeyCode::Char('k') => modal.scroll_up(1),
+        KeyCode::Down | KeyCode::Char('j') => modal.scroll_down(1),
+        KeyCode::PageUp => modal.scroll_up(10),
+        KeyCode::PageDown | KeyCode::Char(' ') => modal.scroll_down(10),
+        KeyCode::Char('g') => modal.scroll = 0,
+        KeyCode::Char('G') => modal.scroll_to_bottom(),
+        _ => {}
+    }
+}
 
-        // Pause container
-        KeyCode::Char('p') => {
-            if let Some(container) = app.selected_container() {
-                if container.status.is_running() {
-                    Action::PauseContainer(container.name.clone())
-                } else {
-                    Action::None
-                }
-            } else {
-                Action::None
+/// Handle keys in the vulnerability report
+fn handle_vulns_mode(app: &mut App, key: event::KeyEvent) {
+    let Some(ref mut modal) = app.vuln_modal else {
+        app.view_mode = ViewMode::List;
+        return;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('Y') | KeyCode::Char('q') => {
+            app.vuln_modal = None;
+            app.view_mode = ViewMode::List;
+        }
+        KeyCode::Up | KeyCode::Char('k') => modal.scroll_up(1),
+        KeyCode::Down | KeyCode::Char('j') => modal.scroll_down(1),
+        KeyCode::PageUp => modal.scroll_up(10),
+        KeyCode::PageDown | KeyCode::Char(' ') => modal.scroll_down(10),
+        KeyCode::Char('g') => modal.scroll = 0,
+        KeyCode::Char('G') => modal.scroll_to_bottom(),
+        KeyCode::Char('r') => {
+            let image = modal.report.image.clone();
+            let id = app.containers.iter().find(|c| c.image == image).map(|c| c.image_id.clone());
+            if let Some(id) = id {
+                app.start_scan(id, image);
             }
         }
+        _ => {}
+    }
+}
 
-        // Unpause container
-        KeyCode::Char('P') => {
-            if let Some(container) = app.selected_container() {
-                if container.status == crate::models::ContainerStatus::Paused {
-                    Action::UnpauseContainer(container.name.clone())
-                } else {
-                    Action::None
-                }
-            } else {
-                Action::None
+/// Handle keys in the GPU view
+fn handle_gpus_mode(app: &mut App, key: event::KeyEvent) {
+    let Some(ref mut modal) = app.gpu_modal else {
+        app.view_mode = ViewMode::List;
+        return;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('*') | KeyCode::Char('q') => {
+            app.gpu_modal = None;
+            app.view_mode = ViewMode::List;
+        }
+        KeyCode::Up | KeyCode::Char('k') => modal.scroll_up(1),
+        KeyCode::Down | KeyCode::Char('j') => modal.scroll_down(1),
+        KeyCode::PageUp => modal.scroll_up(10),
+        KeyCode::PageDown | KeyCode::Char(' ') => modal.scroll_down(10),
+        KeyCode::Char('g') => modal.scroll = 0,
+        _ => {}
+    }
+}
+
+/// Handle key events in the logs go-to-time input
+fn handle_goto_mode(app: &mut App, key: event::KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.logs_view.goto = None,
+        KeyCode::Enter => {
+            if let Some(input) = app.logs_view.goto.take() {
+                app.go_to_time(&input);
+            }
+        }
+        KeyCode::Backspace => {
+            if let Some(ref mut input) = app.logs_view.goto {
+                input.pop();
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(ref mut input) = app.logs_view.goto {
+                input.push(c);
             }
         }
+        _ => {}
+    }
+}
 
-        // Rename container
-        KeyCode::Char('N') => {
-            if let Some(name) = app.selected_container_name() {
-                Action::ShowRename(name)
-            } else {
-                Action::None
+/// Handle key events in the logs stdin input (stays open for the next line)
+async fn handle_stdin_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc => app.logs_view.stdin = None,
+        KeyCode::Enter => {
+            let line = app.logs_view.stdin.replace(String::new()).unwrap_or_default();
+            app.send_stdin(&line).await;
+        }
+        KeyCode::Backspace => {
+            if let Some(ref mut input) = app.logs_view.stdin {
+                input.pop();
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(ref mut input) = app.logs_view.stdin {
+                input.push(c);
             }
         }
+        _ => {}
+    }
+    Ok(())
+}
 
-        // View processes (docker top)
-        KeyCode::Char('t') => {
-            if let Some(container) = app.selected_container() {
-                if container.status.is_running() {
-                    Action::ShowProcesses(container.name.clone())
-                } else {
-                    Action::None
-                }
-            } else {
-                Action::None
+/// Handle key events in the logs filter input: type a regex and Enter to
+/// add it; ↑↓ pick an existing filter to toggle (Tab) or remove (Delete)
+fn handle_log_filter_mode(app: &mut App, key: event::KeyEvent) {
+    let selected = app.logs_view.filter_selected;
+    match key.code {
+        KeyCode::Esc => app.logs_view.filter_input = None,
+        // Enter on an empty input closes it
+        KeyCode::Enter => match app.logs_view.filter_input.replace(String::new()) {
+            Some(input) if !input.trim().is_empty() => app.add_log_filter(&input),
+            _ => app.logs_view.filter_input = None,
+        },
+        KeyCode::Up => app.logs_view.filter_selected = selected.saturating_sub(1),
+        KeyCode::Down => {
+            app.logs_view.filter_selected = (selected + 1).min(app.logs.filters().len().saturating_sub(1));
+        }
+        KeyCode::Tab => app.logs.toggle_filter(selected),
+        KeyCode::Delete => {
+            app.logs.remove_filter(selected);
+            app.logs_view.filter_selected = selected.min(app.logs.filters().len().saturating_sub(1));
+        }
+        KeyCode::Backspace => {
+            if let Some(ref mut input) = app.logs_view.filter_input {
+                input.pop();
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(ref mut input) = app.logs_view.filter_input {
+                input.push(c);
             }
         }
+        _ => {}
+    }
+}
 
-        // Copy files
-        KeyCode::Char('C') => {
-            if let Some(name) = app.selected_container_name() {
-                Action::ShowCopyFiles(name)
-            } else {
-                Action::None
+fn handle_event_filter_mode(app: &mut App, key: event::KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.events_view.input = None,
+        // An empty filter shows every event again
+        KeyCode::Enter => {
+            if let Some(input) = app.events_view.input.take() {
+                app.events_view.set_filter(&input);
+            }
+        }
+        KeyCode::Backspace => {
+            if let Some(ref mut input) = app.events_view.input {
+                input.pop();
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(ref mut input) = app.events_view.input {
+                input.push(c);
             }
         }
+        _ => {}
+    }
+}
 
-        // 'n' for new container - handled specially
-        KeyCode::Char('n') => Action::None, // Will be handled in main loop
+/// Handle keys in the diff view (scrolling, path search)
+fn handle_diff_mode(app: &mut App, key: event::KeyEvent) {
+    let Some(ref mut modal) = app.diff_modal else {
+        app.view_mode = ViewMode::List;
+        return;
+    };
+    if modal.searching {
+        match key.code {
+            KeyCode::Esc => modal.stop_search(true),
+            KeyCode::Enter => modal.stop_search(false),
+            KeyCode::Backspace => modal.handle_backspace(),
+            KeyCode::Char(c) => modal.handle_char(c),
+            KeyCode::Up => modal.scroll_up(1),
+            KeyCode::Down => modal.scroll_down(1),
+            _ => {}
+        }
+        return;
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('F') | KeyCode::Char('q') => {
+            app.diff_modal = None;
+            app.view_mode = ViewMode::List;
+        }
+        KeyCode::Char('/') => modal.start_search(),
+        KeyCode::Up | KeyCode::Char('k') => modal.scroll_up(1),
+        KeyCode::Down | KeyCode::Char('j') => modal.scroll_down(1),
+        KeyCode::PageUp => modal.scroll_up(10),
+        KeyCode::PageDown | KeyCode::Char(' ') => modal.scroll_down(10),
+        KeyCode::Char('g') => modal.scroll = 0,
+        KeyCode::Char('G') => modal.scroll_to_bottom(),
+        _ => {}
+    }
+}
 
-        // 'f' to cycle status filter (All -> Running -> Stopped)
-        KeyCode::Char('f') => Action::CycleStatusFilter,
+fn handle_config_errors_mode(app: &mut App, key: event::KeyEvent) {
+    let Some(ref mut modal) = app.config_errors else {
+        app.view_mode = ViewMode::List;
+        return;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+            app.config_errors = None;
+            app.view_mode = ViewMode::List;
+        }
+        KeyCode::Up | KeyCode::Char('k') => modal.scroll_up(1),
+        KeyCode::Down | KeyCode::Char('j') => modal.scroll_down(1),
+        KeyCode::PageUp => modal.scroll_up(10),
+        KeyCode::PageDown | KeyCode::Char(' ') => modal.scroll_down(10),
+        _ => {}
+    }
+}
 
-        _ => Action::None,
+fn handle_compose_mode(app: &mut App, key: event::KeyEvent) {
+    let Some(ref mut modal) = app.compose_modal else {
+        app.view_mode = ViewMode::List;
+        return;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('{') | KeyCode::Char('q') => {
+            app.compose_modal = None;
+            app.view_mode = ViewMode::List;
+        }
+        KeyCode::Char('s') => modal.jump_to_service(),
+        KeyCode::Tab => modal.next_file(),
+        KeyCode::Up | KeyCode::Char('k') => modal.scroll_up(1),
+        KeyCode::Down | KeyCode::Char('j') => modal.scroll_down(1),
+        KeyCode::PageUp => modal.scroll_up(10),
+        KeyCode::PageDown | KeyCode::Char(' ') => modal.scroll_down(10),
+        KeyCode::Char('g') => modal.scroll = 0,
+        KeyCode::Char('G') => modal.scroll_to_bottom(),
+        _ => {}
     }
 }
 
-/// Handle keys in logs view
-fn handle_logs_key(key: event::KeyEvent) -> Action {
+/// Handle keys in the group assignment modal
+/// Handle keys in the label editor: edits, then the dry run
+async fn handle_label_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
+    let Some(ref mut modal) = app.label_modal else {
+        app.view_mode = ViewMode::List;
+        return Ok(());
+    };
+    if modal.review.is_some() {
+        match key.code {
+            KeyCode::Esc => modal.review = None,
+            KeyCode::Enter => app.handle_action(Action::ApplyLabels).await?,
+            KeyCode::Up | KeyCode::Char('k') => modal.scroll_up(),
+            KeyCode::Down | KeyCode::Char('j') => modal.scroll_down(),
+            _ => {}
+        }
+        return Ok(());
+    }
     match key.code {
-        KeyCode::Esc => Action::BackToList,
-        KeyCode::Char('j') | KeyCode::Down => Action::Down,
-        KeyCode::Char('k') | KeyCode::Up => Action::Up,
-        KeyCode::Char('g') => Action::Top,
-        KeyCode::Char('G') => Action::Bottom,
-        _ => Action::None,
+        KeyCode::Esc => {
+            app.label_modal = None;
+            app.view_mode = ViewMode::List;
+        }
+        KeyCode::Enter => app.preview_labels().await,
+        KeyCode::Backspace => modal.handle_backspace(),
+        KeyCode::Char(c) => modal.handle_char(c),
+        _ => {}
     }
+    Ok(())
 }
 
-/// Handle keys in rename mode
-async fn handle_rename_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
+/// Handle keys in the port forward dialog
+fn handle_forward_mode(app: &mut App, key: event::KeyEvent) {
+    let Some(ref mut modal) = app.forward_modal else {
+        app.view_mode = ViewMode::List;
+        return;
+    };
     match key.code {
         KeyCode::Esc => {
-            app.rename_modal = None;
+            app.forward_modal = None;
             app.view_mode = ViewMode::List;
         }
-        KeyCode::Enter => {
-            if let Some(ref modal) = app.rename_modal {
-                if modal.is_valid() {
-                    let old_name = modal.container_name.clone();
-                    let new_name = modal.new_name.clone();
-                    app.handle_action(Action::RenameContainer(old_name, new_name)).await?;
-                    app.rename_modal = None;
-                    app.view_mode = ViewMode::List;
+        KeyCode::Enter => app.start_forward(),
+        KeyCode::Tab | KeyCode::BackTab => modal.next_field(),
+        KeyCode::Up => modal.selected = modal.selected.saturating_sub(1),
+        KeyCode::Down => modal.selected += 1,
+        KeyCode::Char('d') => {
+            let index = modal.selected;
+            app.stop_forward(index);
+        }
+        KeyCode::Backspace => modal.handle_backspace(),
+        KeyCode::Char(c) => modal.handle_char(c),
+        _ => {}
+    }
+}
+
+async fn handle_group_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
+    let Some(ref mut modal) = app.group_modal else {
+        app.view_mode = ViewMode::List;
+        return Ok(());
+    };
+    if modal.new_group.is_some() {
+        match key.code {
+            KeyCode::Esc => modal.new_group = None,
+            KeyCode::Enter => {
+                let name = modal.new_group.take().unwrap_or_default();
+                if !name.is_empty() {
+                    app.handle_action(Action::ToggleGroup(name)).await?;
                 }
             }
+            KeyCode::Backspace => modal.handle_backspace(),
+            KeyCode::Char(c) => modal.handle_char(c),
+            _ => {}
         }
-        KeyCode::Backspace => {
-            if let Some(ref mut modal) = app.rename_modal {
-                modal.handle_backspace();
-            }
+        return Ok(());
+    }
+    let action = match key.code {
+        KeyCode::Esc | KeyCode::Char('m') | KeyCode::Char('q') => {
+            app.group_modal = None;
+            app.view_mode = ViewMode::List;
+            None
         }
-        KeyCode::Char(c) => {
-            if let Some(ref mut modal) = app.rename_modal {
-                modal.handle_char(c);
-            }
+        KeyCode::Up | KeyCode::Char('k') => {
+            modal.previous();
+            None
         }
-        _ => {}
+        KeyCode::Down | KeyCode::Char('j') => {
+            modal.next();
+            None
+        }
+        KeyCode::Char('n') => {
+            modal.new_group = Some(String::new());
+            None
+        }
+        KeyCode::Char(' ') | KeyCode::Enter => modal.selected().cloned().map(Action::ToggleGroup),
+        _ => None,
+    };
+    if let Some(action) = action {
+        app.handle_action(action).await?;
+    }
+    Ok(())
+}
+
+/// Handle keys in the checkpoint manager
+async fn handle_checkpoint_mode(app: &mut App, key: event::KeyEvent) -> Result<()> {
+    let Some(ref mut modal) = app.checkpoint_modal else {
+        app.view_mode = ViewMode::List;
+        return Ok(());
+    };
+    let action = match key.code {
+        KeyCode::Esc | KeyCode::Char('K') | KeyCode::Char('q') => {
+            app.checkpoint_modal = None;
+            app.view_mode = ViewMode::List;
+            None
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            modal.previous();
+            None
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            modal.next();
+            None
+        }
+        KeyCode::Char('c') => Some(Action::CreateCheckpoint(false)),
+        KeyCode::Char('l') => Some(Action::CreateCheckpoint(true)),
+        KeyCode::Enter => modal.selected().cloned().map(Action::RestoreCheckpoint),
+        KeyCode::Char('d') => modal.selected().cloned().map(Action::DeleteCheckpoint),
+        _ => None,
+    };
+    if let Some(action) = action {
+        app.handle_action(action).await?;
     }
     Ok(())
 }
@@ -0,0 +1,226 @@
+use anyhow::{Context, Result};
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::models::{ContainerInfo, SystemStats};
+
+/// Latest data collected by the TUI, shared with the HTTP server
+#[derive(Debug, Default)]
+pub struct MetricsSnapshot {
+    pub containers: Vec<ContainerInfo>,
+    pub system: SystemStats,
+}
+
+/// Handle used by the app to publish new samples
+#[derive(Clone, Default)]
+pub struct MetricsHandle {
+    snapshot: Arc<Mutex<MetricsSnapshot>>,
+}
+
+impl MetricsHandle {
+    /// Replace the published snapshot
+    pub fn publish(&self, containers: &[ContainerInfo], system: &SystemStats) {
+        if let Ok(mut snapshot) = self.snapshot.lock() {
+            snapshot.containers = containers.to_vec();
+            snapshot.system = system.clone();
+        }
+    }
+
+    fn render(&self) -> String {
+        match self.snapshot.lock() {
+            Ok(snapshot) => render_prometheus(&snapshot),
+            Err(_) => String::new(),
+        }
+    }
+}
+
+/// Longest request head read; the path is all that's needed
+const MAX_REQUEST_HEAD: usize = 8192;
+
+/// Start the metrics server on `addr`, serving `/metrics`
+pub async fn serve(addr: SocketAddr) -> Result<MetricsHandle> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .context(format!("Failed to bind metrics address {}", addr))?;
+    let handle = MetricsHandle::default();
+    let server_handle = handle.clone();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let handle = server_handle.clone();
+            tokio::spawn(async move {
+                let request = read_request_head(&mut stream).await;
+                let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+                let response = if path == "/metrics" || path.starts_with("/metrics?") {
+                    let body = handle.render();
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    )
+                } else {
+                    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+                };
+                let _ = stream.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+
+    Ok(handle)
+}
+
+/// The request line and headers, up to the blank line that ends them.
+/// Reading all of it lets the connection close cleanly after the response
+async fn read_request_head(stream: &mut (impl AsyncRead + Unpin)) -> String {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") && head.len() < MAX_REQUEST_HEAD {
+        match stream.read(&mut buf).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => head.extend_from_slice(&buf[..n]),
+        }
+    }
+    String::from_utf8_lossy(&head).into_owned()
+}
+
+/// Render a snapshot in the Prometheus text exposition format
+fn render_prometheus(snapshot: &MetricsSnapshot) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    let mut out = String::new();
+
+    // Host metrics
+    let sys = &snapshot.system;
+    gauge_header(&mut out, "backplane_host_cpu_percent", "Host CPU usage percent");
+    let _ = writeln!(out, "backplane_host_cpu_percent {}", sys.cpu_percent);
+    gauge_header(&mut out, "backplane_host_memory_percent", "Host memory usage percent");
+    let _ = writeln!(out, "backplane_host_memory_percent {}", sys.memory_percent);
+    gauge_header(&mut out, "backplane_host_disk_percent", "Host disk usage percent");
+    let _ = writeln!(out, "backplane_host_disk_percent {}", sys.disk_percent);
+    if let Some(vram) = sys.vram_percent {
+        gauge_header(&mut out, "backplane_host_vram_percent", "Host GPU memory usage percent");
+        let _ = writeln!(out, "backplane_host_vram_percent {}", vram);
+    }
+    if let Some(util) = sys.gpu_util_percent {
+        gauge_header(&mut out, "backplane_host_gpu_utilization_percent", "Host GPU utilization percent");
+        let _ = writeln!(out, "backplane_host_gpu_utilization_percent {}", util);
+    }
+    if let Some(temp) = sys.gpu_sensors.temperature {
+        gauge_header(&mut out, "backplane_host_gpu_temperature_celsius", "Hottest GPU temperature");
+        let _ = writeln!(out, "backplane_host_gpu_temperature_celsius {}", temp);
+    }
+    if let Some(watts) = sys.gpu_sensors.power_watts {
+        gauge_header(&mut out, "backplane_host_gpu_power_watts", "Total GPU power draw");
+        let _ = writeln!(out, "backplane_host_gpu_power_watts {}", watts);
+    }
+
+    // Per-container metrics
+    gauge_header(&mut out, "backplane_container_running", "1 if the container is running");
+    for c in &snapshot.containers {
+        let _ = writeln!(out, "backplane_container_running{{{}}} {}", labels(c), c.status.is_running() as u8);
+    }
+
+    let with_stats: Vec<_> = snapshot.containers
+        .iter()
+        .filter_map(|c| c.stats.as_ref().map(|s| (c, s)))
+        .collect();
+
+    gauge_header(&mut out, "backplane_container_cpu_percent", "Container CPU usage percent");
+    for (c, s) in &with_stats {
+        let _ = writeln!(out, "backplane_container_cpu_percent{{{}}} {}", labels(c), s.cpu_percent);
+    }
+    gauge_header(&mut out, "backplane_container_memory_usage_bytes", "Container memory usage in bytes");
+    for (c, s) in &with_stats {
+        let _ = writeln!(out, "backplane_container_memory_usage_bytes{{{}}} {}", labels(c), (s.memory_usage_mb * MB) as u64);
+    }
+    gauge_header(&mut out, "backplane_container_memory_limit_bytes", "Container memory limit in bytes");
+    for (c, s) in &with_stats {
+        let _ = writeln!(out, "backplane_container_memory_limit_bytes{{{}}} {}", labels(c), (s.memory_limit_mb * MB) as u64);
+    }
+    counter_header(&mut out, "backplane_container_network_rx_bytes_total", "Container network bytes received");
+    for (c, s) in &with_stats {
+        let _ = writeln!(out, "backplane_container_network_rx_bytes_total{{{}}} {}", labels(c), s.net_rx_bytes);
+    }
+    counter_header(&mut out, "backplane_container_network_tx_bytes_total", "Container network bytes sent");
+    for (c, s) in &with_stats {
+        let _ = writeln!(out, "backplane_container_network_tx_bytes_total{{{}}} {}", labels(c), s.net_tx_bytes);
+    }
+    gauge_header(&mut out, "backplane_container_gpu_memory_bytes", "Container GPU memory usage in bytes");
+    for (c, s) in &with_stats {
+        if let Some(vram) = s.vram_usage_mb {
+            let _ = writeln!(out, "backplane_container_gpu_memory_bytes{{{}}} {}", labels(c), (vram * MB) as u64);
+        }
+    }
+
+    out
+}
+
+fn gauge_header(out: &mut String, name: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}\n# TYPE {} gauge", name, help, name);
+}
+
+fn counter_header(out: &mut String, name: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}\n# TYPE {} counter", name, help, name);
+}
+
+/// Label set identifying a container
+fn labels(c: &ContainerInfo) -> String {
+    let short_id: String = c.id.chars().take(12).collect();
+    format!(
+        "name=\"{}\",id=\"{}\",project=\"{}\"",
+        escape_label(&c.name),
+        short_id,
+        escape_label(c.compose_project.as_deref().unwrap_or("")),
+    )
+}
+
+/// Escape a label value per the exposition format
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ContainerStats, ContainerStatus};
+
+    #[tokio::test]
+    async fn test_read_request_head_across_reads() {
+        let (mut client, mut server) = tokio::io::duplex(64);
+        tokio::spawn(async move {
+            client.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n").await.unwrap();
+            client.write_all(b"Accept: text/plain\r\n\r\n").await.unwrap();
+        });
+        let head = read_request_head(&mut server).await;
+        assert!(head.starts_with("GET /metrics "));
+        assert!(head.ends_with("Accept: text/plain\r\n\r\n"));
+    }
+
+    #[test]
+    fn test_render_prometheus_escapes_labels() {
+        let mut c = ContainerInfo::new("we\"b\\app".to_string());
+        c.id = "0123456789abcdef".to_string();
+        c.compose_project = Some("line\nbreak".to_string());
+        c.status = ContainerStatus::Running;
+        c.stats = Some(ContainerStats { cpu_percent: 12.5, net_rx_bytes: 2048, ..Default::default() });
+        let snapshot = MetricsSnapshot { containers: vec![c], system: SystemStats::default() };
+
+        let text = render_prometheus(&snapshot);
+        let labels = r#"{name="we\"b\\app",id="0123456789ab",project="line\nbreak"}"#;
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines.contains(&format!("backplane_container_running{} 1", labels).as_str()));
+        assert!(lines.contains(&format!("backplane_container_cpu_percent{} 12.5", labels).as_str()));
+        assert!(lines.contains(&format!("backplane_container_network_rx_bytes_total{} 2048", labels).as_str()));
+        assert!(lines.contains(&"# TYPE backplane_container_network_rx_bytes_total counter"));
+        // No VRAM sample, so no series (only its header)
+        assert!(!text.contains("backplane_container_gpu_memory_bytes{"));
+        // Every sample line ends in a number
+        for line in lines.iter().filter(|l| !l.starts_with('#')) {
+            let value = line.rsplit_once(' ').map(|(_, value)| value).unwrap_or_default();
+            assert!(value.parse::<f64>().is_ok(), "{}", line);
+        }
+    }
+}
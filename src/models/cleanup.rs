@@ -0,0 +1,69 @@
+/// Kind of resource the cleanup wizard can remove
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CleanupKind {
+    Image,
+    Container,
+    Network,
+    Volume,
+}
+
+impl CleanupKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Image => "image",
+            Self::Container => "container",
+            Self::Network => "network",
+            Self::Volume => "volume",
+        }
+    }
+}
+
+/// A removable resource found by the cleanup scan
+#[derive(Debug, Clone)]
+pub struct CleanupItem {
+    pub kind: CleanupKind,
+    /// ID or name used to remove it
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Extra info, e.g. "exited 12 days ago"
+    pub detail: String,
+    /// Reclaimable bytes, when Docker reports them
+    pub size: Option<u64>,
+}
+
+/// What compose down or a prune would remove, shown before confirming
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemovalPreview {
+    /// Name and size of the writable layer of each container
+    pub containers: Vec<(String, u64)>,
+    pub networks: Vec<String>,
+    /// Anonymous volumes of those containers; Docker leaves them behind
+    pub volumes: Vec<String>,
+}
+
+impl RemovalPreview {
+    /// Estimated space freed: the containers' writable layers
+    pub fn reclaimed(&self) -> u64 {
+        self.containers.iter().map(|(_, size)| size).sum()
+    }
+}
+
+/// Anonymous volumes are named by a random 64-digit hex ID
+pub fn is_anonymous_volume(name: &str) -> bool {
+    name.len() == 64 && name.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anonymous_volume() {
+        assert!(is_anonymous_volume(&"3f".repeat(32)));
+        assert!(!is_anonymous_volume("media_config"));
+        assert!(!is_anonymous_volume(&"zz".repeat(32)));
+        let preview = RemovalPreview { containers: vec![("a".to_string(), 10), ("b".to_string(), 5)], ..Default::default() };
+        assert_eq!(preview.reclaimed(), 15);
+    }
+}
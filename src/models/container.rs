@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use super::ContainerStats;
 
 /// Status of a Docker container
@@ -52,6 +54,50 @@ impl ContainerStatus {
     }
 }
 
+/// A lifecycle operation sent to Docker and not finished yet; the row shows
+/// it until the operation completes or a refresh shows where it leads
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transition {
+    Starting,
+    Stopping,
+    Restarting,
+    Pausing,
+    Unpausing,
+}
+
+impl Transition {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Starting => "starting…",
+            Self::Stopping => "stopping…",
+            Self::Restarting => "restarting…",
+            Self::Pausing => "pausing…",
+            Self::Unpausing => "unpausing…",
+        }
+    }
+
+    /// Whether `status` is already the state this leads to (a restart ends
+    /// where it began, so only its completion counts)
+    pub fn reached(&self, status: &ContainerStatus) -> bool {
+        match self {
+            Self::Starting | Self::Unpausing => status.is_running(),
+            Self::Stopping => !status.is_active(),
+            Self::Pausing => *status == ContainerStatus::Paused,
+            Self::Restarting => false,
+        }
+    }
+}
+
+/// A container's address on one network
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkAddress {
+    pub network: String,
+    /// Empty while the container isn't running
+    pub ip: String,
+    /// DNS names other containers on the network can use (besides the container name)
+    pub aliases: Vec<String>,
+}
+
 /// Port mapping info
 #[derive(Debug, Clone)]
 pub struct PortMapping {
@@ -76,6 +122,8 @@ pub struct ContainerInfo {
     pub id: String,
     pub name: String,
     pub image: String,
+    /// ID of the image the container runs (sha256:...)
+    pub image_id: String,
     pub status: ContainerStatus,
     pub is_cli: bool,
     pub port: Option<u16>,
@@ -84,6 +132,61 @@ pub struct ContainerInfo {
     pub created: Option<i64>,
     /// Compose project name (from com.docker.compose.project label)
     pub compose_project: Option<String>,
+    /// Compose service name (from com.docker.compose.service label)
+    pub compose_service: Option<String>,
+    /// Services this one depends on (from com.docker.compose.depends_on label)
+    pub depends_on: Vec<String>,
+    /// Compose files of the project, as absolute paths on the Docker host
+    pub compose_files: Vec<String>,
+    /// Healthcheck state ("healthy", "unhealthy", "starting") if defined
+    pub health: Option<String>,
+    /// Exit code of the last run (exited containers only)
+    pub exit_code: Option<i64>,
+    /// Whether the last run was killed by the OOM killer
+    pub oom_killed: bool,
+    /// When the last run finished (unix timestamp)
+    pub finished_at: Option<i64>,
+    /// When the current/last run started (unix timestamp)
+    pub started_at: Option<i64>,
+    /// Longest it can have been up, in seconds, from the listing's rounded
+    /// "Up 5 seconds" (None when not running or too coarse to tell)
+    pub max_uptime: Option<i64>,
+    /// Restarts by the restart policy since the container was created
+    pub restart_count: i64,
+    /// Addresses per network, by network name
+    pub networks: Vec<NetworkAddress>,
+    /// GPUs it was given (from inspect)
+    pub gpus: GpuAccess,
+}
+
+/// GPUs a container may use, from its device requests (`--gpus`) or
+/// NVIDIA_VISIBLE_DEVICES under the nvidia runtime
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum GpuAccess {
+    #[default]
+    None,
+    All,
+    /// Indices or UUIDs
+    Devices(Vec<String>),
+}
+
+impl GpuAccess {
+    pub fn allows(&self, index: u32, uuid: &str) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Devices(ids) => ids.iter().any(|id| *id == index.to_string() || (!uuid.is_empty() && id == uuid)),
+        }
+    }
+
+    /// "all", "0,2"
+    pub fn describe(&self) -> String {
+        match self {
+            Self::None => "none".to_string(),
+            Self::All => "all".to_string(),
+            Self::Devices(ids) => ids.join(","),
+        }
+    }
 }
 
 impl ContainerInfo {
@@ -92,6 +195,7 @@ impl ContainerInfo {
             id: String::new(),
             name,
             image: String::new(),
+            image_id: String::new(),
             status: ContainerStatus::NotDeployed,
             is_cli: false,
             port: None,
@@ -99,6 +203,93 @@ impl ContainerInfo {
             stats: None,
             created: None,
             compose_project: None,
+            compose_service: None,
+            depends_on: Vec::new(),
+            compose_files: Vec::new(),
+            health: None,
+            exit_code: None,
+            oom_killed: false,
+            finished_at: None,
+            started_at: None,
+            max_uptime: None,
+            restart_count: 0,
+            networks: Vec::new(),
+            gpus: GpuAccess::None,
         }
     }
+
+    /// First IP address on any network
+    pub fn ip(&self) -> Option<&str> {
+        self.networks.iter().map(|n| n.ip.as_str()).find(|ip| !ip.is_empty())
+    }
+}
+
+/// Environment details shown in the info view: clock, locale, OS and main process
+#[derive(Debug, Clone, Default)]
+pub struct RuntimeInfo {
+    /// TZ, /etc/timezone or the /etc/localtime link, with the current offset when running
+    pub timezone: Option<String>,
+    /// LC_ALL or LANG from the environment
+    pub locale: Option<String>,
+    /// PRETTY_NAME from /etc/os-release (running containers only)
+    pub distro: Option<String>,
+    /// Path and arguments of the main process
+    pub command: String,
+}
+
+/// Run state details from `docker inspect` (not included in container listings)
+#[derive(Debug, Clone, Default)]
+pub struct RunState {
+    /// Status at the time of the inspect
+    pub status: ContainerStatus,
+    /// Healthcheck status, if the container has one
+    pub health: Option<String>,
+    pub started_at: Option<i64>,
+    pub exit_code: Option<i64>,
+    pub oom_killed: bool,
+    pub finished_at: Option<i64>,
+    pub restart_count: i64,
+    /// Network name -> aliases (the listing has the IPs but not these)
+    pub aliases: HashMap<String, Vec<String>>,
+    pub gpus: GpuAccess,
+}
+
+impl RunState {
+    /// Whether a listing shows a change this inspect doesn't have: a new
+    /// status or exit code, a restart in progress, or a run that started
+    /// after this one (a crash loop can go running to running between
+    /// refreshes, bumping only the start time and restart count)
+    pub fn outdated_by(&self, listed: &ContainerInfo, now: i64) -> bool {
+        let restarted = match (self.started_at, listed.max_uptime) {
+            (Some(started_at), Some(max_uptime)) => now - started_at > max_uptime,
+            _ => false,
+        };
+        self.status != listed.status
+            || listed.status == ContainerStatus::Restarting
+            || (listed.exit_code.is_some() && self.exit_code != listed.exit_code)
+            || restarted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outdated_by_fast_restart() {
+        let now = 1_000_000;
+        let state = RunState { status: ContainerStatus::Running, started_at: Some(now - 60), ..Default::default() };
+        let mut listed = ContainerInfo::new("web".to_string());
+        listed.status = ContainerStatus::Running;
+        // "Up About a minute": still the run that was inspected
+        listed.max_uptime = Some(120);
+        assert!(!state.outdated_by(&listed, now));
+        // "Up 3 seconds": restarted since, though it's running both times
+        listed.max_uptime = Some(4);
+        assert!(state.outdated_by(&listed, now));
+        listed.max_uptime = None;
+        assert!(!state.outdated_by(&listed, now));
+        listed.status = ContainerStatus::Exited;
+        assert!(state.outdated_by(&listed, now));
+    }
 }
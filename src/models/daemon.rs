@@ -0,0 +1,17 @@
+/// Docker engine details and recent daemon errors, for the daemon panel
+#[derive(Debug, Clone, Default)]
+pub struct DaemonInfo {
+    pub version: String,
+    pub api_version: String,
+    pub storage_driver: String,
+    /// Version and driver, e.g. "v2 (systemd)"
+    pub cgroup: String,
+    pub os: String,
+    pub kernel: String,
+    /// Problems the daemon reports with its own setup
+    pub warnings: Vec<String>,
+    /// Recent error lines from the dockerd journal, oldest first
+    pub journal: Vec<String>,
+    /// Why the journal couldn't be read, if it couldn't
+    pub journal_note: Option<String>,
+}
@@ -0,0 +1,82 @@
+/// Kind of filesystem change reported by `docker diff`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChangeKind {
+    Added,
+    Changed,
+    Deleted,
+}
+
+impl ChangeKind {
+    /// Letter `docker diff` prints for the change
+    pub fn symbol(&self) -> char {
+        match self {
+            ChangeKind::Added => 'A',
+            ChangeKind::Changed => 'C',
+            ChangeKind::Deleted => 'D',
+        }
+    }
+}
+
+/// A path changed in the container's writable layer
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileChange {
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+impl FileChange {
+    /// Parent directory ("/" for top-level entries)
+    pub fn dir(&self) -> &str {
+        match self.path.rfind('/') {
+            Some(0) | None => "/",
+            Some(i) => &self.path[..i],
+        }
+    }
+
+    /// Final path component
+    pub fn file_name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+}
+
+/// Changes grouped by parent directory, directories and entries sorted by path
+pub fn group_by_dir<'a>(changes: &[&'a FileChange]) -> Vec<(&'a str, Vec<&'a FileChange>)> {
+    let mut sorted = changes.to_vec();
+    sorted.sort_by(|a, b| a.dir().cmp(b.dir()).then_with(|| a.path.cmp(&b.path)));
+
+    let mut groups: Vec<(&str, Vec<&FileChange>)> = Vec::new();
+    for change in sorted {
+        match groups.last_mut() {
+            Some((dir, entries)) if *dir == change.dir() => entries.push(change),
+            _ => groups.push((change.dir(), vec![change])),
+        }
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(path: &str, kind: ChangeKind) -> FileChange {
+        FileChange { path: path.to_string(), kind }
+    }
+
+    #[test]
+    fn test_group_by_dir() {
+        let changes = [
+            change("/var/log/app.log", ChangeKind::Added),
+            change("/etc", ChangeKind::Changed),
+            change("/var/log", ChangeKind::Changed),
+            change("/etc/hosts", ChangeKind::Changed),
+            change("/var/log/old.log", ChangeKind::Deleted),
+        ];
+        let refs: Vec<&FileChange> = changes.iter().collect();
+        let groups = group_by_dir(&refs);
+
+        let dirs: Vec<&str> = groups.iter().map(|(dir, _)| *dir).collect();
+        assert_eq!(dirs, ["/", "/etc", "/var", "/var/log"]);
+        let names: Vec<&str> = groups[3].1.iter().map(|c| c.file_name()).collect();
+        assert_eq!(names, ["app.log", "old.log"]);
+    }
+}
@@ -0,0 +1,51 @@
+/// One layer from `docker history`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageLayer {
+    /// Layer image ID ("<missing>" for intermediate layers not present locally)
+    pub id: String,
+    /// Unix timestamp
+    pub created: i64,
+    pub created_by: String,
+    pub size: u64,
+}
+
+/// Size of each layer plus everything below it; `layers` are newest first, as Docker returns them
+pub fn cumulative_sizes(layers: &[ImageLayer]) -> Vec<u64> {
+    let mut total = 0;
+    let mut sizes: Vec<u64> = layers
+        .iter()
+        .rev()
+        .map(|layer| {
+            total += layer.size;
+            total
+        })
+        .collect();
+    sizes.reverse();
+    sizes
+}
+
+/// Strip the shell wrapper Docker records around build steps
+pub fn clean_created_by(created_by: &str) -> &str {
+    let s = created_by.trim();
+    let s = s.strip_prefix("/bin/sh -c ").unwrap_or(s);
+    let s = s.strip_prefix("#(nop) ").unwrap_or(s);
+    s.trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(size: u64, created_by: &str) -> ImageLayer {
+        ImageLayer { id: "<missing>".to_string(), created: 0, created_by: created_by.to_string(), size }
+    }
+
+    #[test]
+    fn test_cumulative_sizes_from_base() {
+        let layers = [layer(5, "CMD"), layer(100, "RUN apt-get"), layer(20, "ADD rootfs")];
+        assert_eq!(cumulative_sizes(&layers), vec![125, 120, 20]);
+        assert_eq!(clean_created_by("/bin/sh -c #(nop)  CMD [\"bash\"]"), "CMD [\"bash\"]");
+        assert_eq!(clean_created_by("/bin/sh -c apt-get update"), "apt-get update");
+        assert_eq!(clean_created_by("RUN /bin/sh -c make # buildkit"), "RUN /bin/sh -c make # buildkit");
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+
+/// A label edit typed in the relabel dialog: `key=value` sets, `-key` removes
+#[derive(Debug, Clone, PartialEq)]
+pub enum LabelEdit {
+    Set(String, String),
+    Remove(String),
+}
+
+/// What an edit does to one container's labels (the dry run)
+#[derive(Debug, Clone, PartialEq)]
+pub enum LabelChange {
+    Added(String, String),
+    /// (key, old value, new value)
+    Changed(String, String, String),
+    Removed(String, String),
+}
+
+/// Parse whitespace-separated edits, e.g. `traefik.enable=true -com.example.old`
+pub fn parse_label_edits(input: &str) -> Result<Vec<LabelEdit>> {
+    let mut edits = Vec::new();
+    for token in input.split_whitespace() {
+        let edit = match (token.strip_prefix('-'), token.split_once('=')) {
+            (Some(key), None) if !key.is_empty() => LabelEdit::Remove(key.to_string()),
+            (None, Some((key, value))) if !key.is_empty() => LabelEdit::Set(key.to_string(), value.to_string()),
+            _ => bail!("Expected key=value or -key, got '{}'", token),
+        };
+        edits.push(edit);
+    }
+    if edits.is_empty() {
+        bail!("No label edits given");
+    }
+    Ok(edits)
+}
+
+/// Changes the edits make to a set of labels; edits that change nothing are left out
+pub fn label_changes(labels: &HashMap<String, String>, edits: &[LabelEdit]) -> Vec<LabelChange> {
+    let mut updated = labels.clone();
+    apply_label_edits(&mut updated, edits);
+
+    let mut keys: Vec<&String> = labels.keys().chain(updated.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter_map(|key| match (labels.get(key), updated.get(key)) {
+            (None, Some(new)) => Some(LabelChange::Added(key.clone(), new.clone())),
+            (Some(old), None) => Some(LabelChange::Removed(key.clone(), old.clone())),
+            (Some(old), Some(new)) if old != new => Some(LabelChange::Changed(key.clone(), old.clone(), new.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Apply edits in order (a later edit of the same key wins)
+pub fn apply_label_edits(labels: &mut HashMap<String, String>, edits: &[LabelEdit]) {
+    for edit in edits {
+        match edit {
+            LabelEdit::Set(key, value) => {
+                labels.insert(key.clone(), value.clone());
+            }
+            LabelEdit::Remove(key) => {
+                labels.remove(key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_diff_edits() {
+        let edits = parse_label_edits("traefik.enable=true  -old tier=web").unwrap();
+        assert_eq!(edits.len(), 3);
+        assert!(parse_label_edits("novalue").is_err());
+        assert!(parse_label_edits("  ").is_err());
+
+        let labels: HashMap<String, String> =
+            [("old", "1"), ("tier", "db"), ("keep", "x")].into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        assert_eq!(
+            label_changes(&labels, &edits),
+            [
+                LabelChange::Removed("old".to_string(), "1".to_string()),
+                LabelChange::Changed("tier".to_string(), "db".to_string(), "web".to_string()),
+                LabelChange::Added("traefik.enable".to_string(), "true".to_string()),
+            ]
+        );
+    }
+}
@@ -1,5 +1,21 @@
+pub mod cleanup;
 pub mod container;
+pub mod daemon;
+pub mod diff;
+pub mod image;
+pub mod label;
+pub mod pod;
+pub mod process;
+pub mod service;
 pub mod stats;
 
-pub use container::{ContainerInfo, ContainerStatus, PortMapping};
-pub use stats::{ContainerStats, SystemStats};
+pub use cleanup::{CleanupItem, CleanupKind, RemovalPreview};
+pub use container::{ContainerInfo, ContainerStatus, GpuAccess, NetworkAddress, PortMapping, RunState, RuntimeInfo, Transition};
+pub use daemon::DaemonInfo;
+pub use diff::{ChangeKind, FileChange};
+pub use image::ImageLayer;
+pub use label::{LabelChange, LabelEdit};
+pub use pod::PodInfo;
+pub use process::{group_by_container, HostProcess, ProcessSort};
+pub use service::ServiceInfo;
+pub use stats::{ContainerStats, ContainerTotals, GpuSensors, ProjectSort, SystemStats};
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+
+/// A Kubernetes pod as listed in the pods view
+#[derive(Debug, Clone, PartialEq)]
+pub struct PodInfo {
+    pub name: String,
+    pub namespace: String,
+    /// Phase, or a more telling reason like "CrashLoopBackOff" or "Terminating"
+    pub status: String,
+    pub ready: usize,
+    pub containers: usize,
+    pub restarts: u64,
+    pub node: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+}
+
+impl PodInfo {
+    /// Running with every container ready, or finished successfully
+    pub fn is_healthy(&self) -> bool {
+        (self.status == "Running" && self.ready == self.containers) || self.status == "Succeeded"
+    }
+}
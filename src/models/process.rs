@@ -0,0 +1,107 @@
+/// A host process as listed in the host process view
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostProcess {
+    pub pid: u32,
+    pub name: String,
+    pub command: String,
+    /// CPU usage, 100% per core
+    pub cpu_percent: f32,
+    pub memory_mb: f64,
+    /// Name of the container whose cgroup the process runs in
+    pub container: Option<String>,
+}
+
+/// Column the host process view is ordered by
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ProcessSort {
+    #[default]
+    Cpu,
+    Memory,
+}
+
+impl ProcessSort {
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Cpu => Self::Memory,
+            Self::Memory => Self::Cpu,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cpu => "CPU",
+            Self::Memory => "MEM",
+        }
+    }
+
+    /// Order heaviest first
+    pub fn sort(self, processes: &mut [HostProcess]) {
+        match self {
+            Self::Cpu => processes.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent).then(a.pid.cmp(&b.pid))),
+            Self::Memory => processes.sort_by(|a, b| b.memory_mb.total_cmp(&a.memory_mb).then(a.pid.cmp(&b.pid))),
+        }
+    }
+}
+
+/// A container's processes with their summed usage
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessGroup<'a> {
+    pub container: &'a str,
+    pub cpu_percent: f32,
+    pub memory_mb: f64,
+    pub processes: Vec<&'a HostProcess>,
+}
+
+/// Group processes by container, heaviest container first by `sort`.
+/// Processes keep their order within a group; those outside containers are left out
+pub fn group_by_container(processes: &[HostProcess], sort: ProcessSort) -> Vec<ProcessGroup<'_>> {
+    let mut groups: Vec<ProcessGroup> = Vec::new();
+    for process in processes {
+        let Some(ref container) = process.container else {
+            continue;
+        };
+        let group = match groups.iter().position(|g| g.container == container) {
+            Some(i) => &mut groups[i],
+            None => {
+                groups.push(ProcessGroup { container, cpu_percent: 0.0, memory_mb: 0.0, processes: Vec::new() });
+                groups.last_mut().expect("just pushed")
+            }
+        };
+        group.cpu_percent += process.cpu_percent;
+        group.memory_mb += process.memory_mb;
+        group.processes.push(process);
+    }
+    match sort {
+        ProcessSort::Cpu => groups.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent).then(a.container.cmp(b.container))),
+        ProcessSort::Memory => groups.sort_by(|a, b| b.memory_mb.total_cmp(&a.memory_mb).then(a.container.cmp(b.container))),
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, cpu_percent: f32, memory_mb: f64) -> HostProcess {
+        HostProcess { pid, name: String::new(), command: String::new(), cpu_percent, memory_mb, container: None }
+    }
+
+    #[test]
+    fn test_process_sort() {
+        let mut processes = vec![process(1, 5.0, 900.0), process(2, 80.0, 10.0), process(3, 5.0, 50.0)];
+        ProcessSort::Cpu.sort(&mut processes);
+        assert_eq!(processes.iter().map(|p| p.pid).collect::<Vec<_>>(), [2, 1, 3]);
+        ProcessSort::Memory.sort(&mut processes);
+        assert_eq!(processes.iter().map(|p| p.pid).collect::<Vec<_>>(), [1, 3, 2]);
+    }
+
+    #[test]
+    fn test_group_by_container() {
+        let in_container = |pid, cpu, name: &str| HostProcess { container: Some(name.to_string()), ..process(pid, cpu, 10.0) };
+        let processes = vec![in_container(1, 30.0, "web"), process(2, 20.0, 10.0), in_container(3, 15.0, "db"), in_container(4, 20.0, "db")];
+        let groups = group_by_container(&processes, ProcessSort::Cpu);
+        assert_eq!(groups.iter().map(|g| g.container).collect::<Vec<_>>(), ["db", "web"]);
+        assert_eq!(groups[0].processes.iter().map(|p| p.pid).collect::<Vec<_>>(), [3, 4]);
+        assert_eq!(groups[0].memory_mb, 20.0);
+    }
+}
@@ -0,0 +1,27 @@
+/// A Swarm service with its task counts
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceInfo {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    /// "replicated", "global", or a job mode
+    pub mode: String,
+    /// Desired replicas (tasks, for global services)
+    pub desired: u64,
+    pub running: u64,
+    /// Rolling update state, e.g. "updating" or "completed"
+    pub update_state: Option<String>,
+    pub update_message: Option<String>,
+}
+
+impl ServiceInfo {
+    /// Only replicated services have a replica count to change
+    pub fn is_scalable(&self) -> bool {
+        self.mode == "replicated"
+    }
+
+    /// Fewer tasks running than wanted
+    pub fn is_degraded(&self) -> bool {
+        self.running < self.desired
+    }
+}
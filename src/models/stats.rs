@@ -1,4 +1,6 @@
-use std::process::Command;
+use std::collections::HashMap;
+
+use super::ContainerInfo;
 
 /// Statistics for a single container
 #[derive(Debug, Clone, Default)]
@@ -7,16 +9,39 @@ pub struct ContainerStats {
     pub memory_usage_mb: f64,
     pub memory_limit_mb: f64,
     pub memory_percent: f64,
+    // Page cache and anonymous (RSS) memory, when the daemon reports the breakdown
+    pub memory_cache_mb: Option<f64>,
+    pub memory_rss_mb: Option<f64>,
     // Network I/O (cumulative bytes)
     pub net_rx_bytes: u64,
     pub net_tx_bytes: u64,
     // Network I/O rates (bytes per second, calculated from delta)
     pub net_rx_rate: f64,
     pub net_tx_rate: f64,
+    // Block I/O (cumulative bytes read from and written to disk)
+    pub blk_read_bytes: u64,
+    pub blk_write_bytes: u64,
+    // Block I/O rates (bytes per second, calculated from delta)
+    pub blk_read_rate: f64,
+    pub blk_write_rate: f64,
+    // Processes and threads in the container, and the pids limit when one is set
+    pub pids: Option<u64>,
+    pub pids_limit: Option<u64>,
     // GPU VRAM usage (if container is using GPU)
     pub vram_usage_mb: Option<f64>,
 }
 
+impl ContainerStats {
+    /// PIDs in use as a percentage of the pids limit, if the container has one
+    pub fn pids_percent(&self) -> Option<f64> {
+        match (self.pids, self.pids_limit) {
+            // An unlimited cgroup can report "max" as the largest u64
+            (Some(pids), Some(limit)) if limit > 0 && limit < u64::MAX => Some(pids as f64 / limit as f64 * 100.0),
+            _ => None,
+        }
+    }
+}
+
 /// System-wide statistics
 #[derive(Debug, Clone, Default)]
 pub struct SystemStats {
@@ -28,32 +53,212 @@ pub struct SystemStats {
     pub disk_used_gb: f32,
     pub disk_total_gb: f32,
     pub vram_percent: Option<f32>,
+    /// Busiest GPU (or Intel engine) utilization
+    pub gpu_util_percent: Option<f32>,
+    /// Logical CPUs, to put container CPU% (100% per core) on the host scale
+    pub cpu_count: usize,
+    /// Usage per logical CPU (0-100)
+    pub cpu_cores: Vec<f32>,
+    /// 1, 5 and 15 minute load averages
+    pub load_average: [f64; 3],
+    /// Hottest CPU sensor in °C, when the host exposes one
+    pub temperature: Option<f32>,
+    pub gpu_sensors: GpuSensors,
 }
 
-impl SystemStats {
-    /// Get VRAM usage from nvidia-smi if available
-    pub fn get_vram_percent() -> Option<f32> {
-        let output = Command::new("nvidia-smi")
-            .args(["--query-gpu=memory.used,memory.total", "--format=csv,noheader,nounits"])
-            .output()
-            .ok()?;
-
-        if !output.status.success() {
-            return None;
+/// GPU temperature, power and clock, combined over all cards
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct GpuSensors {
+    /// Hottest card in °C
+    pub temperature: Option<f32>,
+    /// Total draw in watts
+    pub power_watts: Option<f32>,
+    /// Total power cap in watts
+    pub power_limit_watts: Option<f32>,
+    /// Fastest shader/graphics clock in MHz
+    pub clock_mhz: Option<f32>,
+}
+
+impl GpuSensors {
+    /// Fold in another card's readings
+    pub fn combine(&mut self, other: GpuSensors) {
+        let max = |a: Option<f32>, b: Option<f32>| a.into_iter().chain(b).reduce(f32::max);
+        let sum = |a: Option<f32>, b: Option<f32>| a.into_iter().chain(b).reduce(|x, y| x + y);
+        self.temperature = max(self.temperature, other.temperature);
+        self.power_watts = sum(self.power_watts, other.power_watts);
+        self.power_limit_watts = sum(self.power_limit_watts, other.power_limit_watts);
+        self.clock_mhz = max(self.clock_mhz, other.clock_mhz);
+    }
+}
+
+/// Combined usage of all containers with stats
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerTotals {
+    pub containers: usize,
+    pub cpu_percent: f64,
+    pub memory_usage_mb: f64,
+    pub net_rx_rate: f64,
+    pub net_tx_rate: f64,
+}
+
+impl ContainerTotals {
+    pub fn sum<'a>(stats: impl IntoIterator<Item = &'a ContainerStats>) -> Self {
+        stats.into_iter().fold(Self::default(), |mut totals, s| {
+            totals.containers += 1;
+            totals.cpu_percent += s.cpu_percent;
+            totals.memory_usage_mb += s.memory_usage_mb;
+            totals.net_rx_rate += s.net_rx_rate;
+            totals.net_tx_rate += s.net_tx_rate;
+            totals
+        })
+    }
+
+    /// Container CPU as a share of the whole host (0-100)
+    pub fn host_cpu_share(&self, cpu_count: usize) -> f64 {
+        if cpu_count == 0 {
+            return 0.0;
+        }
+        self.cpu_percent / cpu_count as f64
+    }
+}
+
+/// How compose projects are ordered in Groups mode
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ProjectSort {
+    #[default]
+    Name,
+    Cpu,
+    Memory,
+    Network,
+}
+
+impl ProjectSort {
+    pub fn cycle(self) -> Self {
+        match self {
+            Self::Name => Self::Cpu,
+            Self::Cpu => Self::Memory,
+            Self::Memory => Self::Network,
+            Self::Network => Self::Name,
         }
+    }
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let line = stdout.lines().next()?;
-        let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Cpu => "CPU",
+            Self::Memory => "MEM",
+            Self::Network => "NET",
+        }
+    }
 
-        if parts.len() >= 2 {
-            let used: f32 = parts[0].parse().ok()?;
-            let total: f32 = parts[1].parse().ok()?;
-            if total > 0.0 {
-                return Some((used / total) * 100.0);
-            }
+    fn weight(self, totals: &ContainerTotals) -> f64 {
+        match self {
+            Self::Name => 0.0,
+            Self::Cpu => totals.cpu_percent,
+            Self::Memory => totals.memory_usage_mb,
+            Self::Network => totals.net_rx_rate + totals.net_tx_rate,
         }
+    }
+
+    /// Reorder containers (as indices into `containers`) heaviest project
+    /// first, keeping each project together and in its order. Containers
+    /// outside a project stay last
+    pub fn order(self, indices: &mut [usize], containers: &[ContainerInfo]) {
+        let mut stats: HashMap<Option<&str>, Vec<&ContainerStats>> = HashMap::new();
+        for &i in indices.iter() {
+            let c = &containers[i];
+            stats.entry(c.compose_project.as_deref()).or_default().extend(c.stats.as_ref());
+        }
+        let weights: HashMap<Option<&str>, f64> = stats
+            .into_iter()
+            .map(|(project, stats)| (project, self.weight(&ContainerTotals::sum(stats))))
+            .collect();
+        let weight = |i: usize| weights[&containers[i].compose_project.as_deref()];
+        indices.sort_by(|&a, &b| {
+            let (pa, pb) = (&containers[a].compose_project, &containers[b].compose_project);
+            pa.is_none()
+                .cmp(&pb.is_none())
+                .then(weight(b).total_cmp(&weight(a)))
+                .then(pa.cmp(pb))
+        });
+    }
+}
+
+impl SystemStats {
+    /// Pick the CPU temperature from (label, °C) sensor readings: the
+    /// hottest CPU-looking sensor, else the hottest sensor overall
+    pub fn cpu_temperature<'a>(sensors: impl IntoIterator<Item = (&'a str, f32)>) -> Option<f32> {
+        let readings: Vec<(String, f32)> = sensors
+            .into_iter()
+            .filter(|(_, temp)| temp.is_finite() && *temp > 0.0)
+            .map(|(label, temp)| (label.to_lowercase(), temp))
+            .collect();
+        let is_cpu = |label: &str| ["cpu", "core", "package", "tctl", "tdie"].iter().any(|k| label.contains(k));
+        let hottest = |cpu_only: bool| {
+            readings
+                .iter()
+                .filter(|(label, _)| !cpu_only || is_cpu(label))
+                .map(|(_, temp)| *temp)
+                .reduce(f32::max)
+        };
+        hottest(true).or_else(|| hottest(false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_sort() {
+        let container = |name: &str, project: Option<&str>, cpu_percent: f64, memory_usage_mb: f64| {
+            let mut c = ContainerInfo::new(name.to_string());
+            c.compose_project = project.map(str::to_string);
+            c.stats = Some(ContainerStats { cpu_percent, memory_usage_mb, ..Default::default() });
+            c
+        };
+        let containers = [
+            container("api", Some("app"), 10.0, 100.0),
+            container("db", Some("app"), 20.0, 900.0),
+            container("plex", Some("media"), 90.0, 200.0),
+            container("tool", None, 300.0, 5000.0),
+        ];
+        let order = |sort: ProjectSort| {
+            let mut indices = vec![0, 1, 2, 3];
+            sort.order(&mut indices, &containers);
+            indices
+        };
+        assert_eq!(order(ProjectSort::Name), [0, 1, 2, 3]);
+        assert_eq!(order(ProjectSort::Cpu), [2, 0, 1, 3]);
+        assert_eq!(order(ProjectSort::Memory), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_container_totals() {
+        let a = ContainerStats { cpu_percent: 150.0, memory_usage_mb: 512.0, net_rx_rate: 10.0, ..Default::default() };
+        let b = ContainerStats { cpu_percent: 50.0, memory_usage_mb: 256.0, net_tx_rate: 5.0, ..Default::default() };
+        let totals = ContainerTotals::sum([&a, &b]);
+        assert_eq!(totals.containers, 2);
+        assert_eq!(totals.memory_usage_mb, 768.0);
+        assert_eq!((totals.net_rx_rate, totals.net_tx_rate), (10.0, 5.0));
+        assert_eq!(totals.host_cpu_share(8), 25.0);
+        assert_eq!(totals.host_cpu_share(0), 0.0);
+    }
+
+    #[test]
+    fn test_pids_percent() {
+        let stats = |pids, pids_limit| ContainerStats { pids, pids_limit, ..Default::default() };
+        assert_eq!(stats(Some(90), Some(100)).pids_percent(), Some(90.0));
+        assert_eq!(stats(Some(90), None).pids_percent(), None);
+        assert_eq!(stats(Some(90), Some(u64::MAX)).pids_percent(), None);
+        assert_eq!(stats(Some(90), Some(0)).pids_percent(), None);
+    }
 
-        None
+    #[test]
+    fn test_cpu_temperature() {
+        let sensors = [("nvme Composite", 61.0), ("coretemp Package id 0", 48.0), ("coretemp Core 1", 52.0)];
+        assert_eq!(SystemStats::cpu_temperature(sensors), Some(52.0));
+        assert_eq!(SystemStats::cpu_temperature([("acpitz", 40.0), ("gpu", f32::NAN)]), Some(40.0));
+        assert_eq!(SystemStats::cpu_temperature([]), None);
     }
 }
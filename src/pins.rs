@@ -0,0 +1,58 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+/// pins.toml contents
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct PinsFile {
+    pinned: BTreeSet<String>,
+}
+
+/// Containers kept at the top of the list, persisted by name in pins.toml
+#[derive(Debug, Default)]
+pub struct Pins {
+    path: Option<PathBuf>,
+    file: PinsFile,
+}
+
+impl Pins {
+    /// Load pins.toml from the config directory, starting empty if it's missing
+    pub fn load_default() -> Result<Self> {
+        let path = crate::config::config_dir().map(|dir| dir.join("pins.toml"));
+        let file = match path {
+            Some(ref path) if path.exists() => {
+                let content = fs::read_to_string(path).context(format!("Failed to read pins: {:?}", path))?;
+                toml::from_str(&content).context(format!("Failed to parse pins: {:?}", path))?
+            }
+            _ => PinsFile::default(),
+        };
+        Ok(Self { path, file })
+    }
+
+    pub fn contains(&self, container: &str) -> bool {
+        self.file.pinned.contains(container)
+    }
+
+    pub fn names(&self) -> &BTreeSet<String> {
+        &self.file.pinned
+    }
+
+    /// Pin or unpin a container and save; returns whether it is now pinned
+    pub fn toggle(&mut self, container: &str) -> Result<bool> {
+        let pinned = self.file.pinned.insert(container.to_string());
+        if !pinned {
+            self.file.pinned.remove(container);
+        }
+        if let Some(ref path) = self.path {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).context(format!("Failed to create {:?}", dir))?;
+            }
+            let content = toml::to_string(&self.file).context("Failed to serialize pins")?;
+            fs::write(path, content).context(format!("Failed to write pins: {:?}", path))?;
+        }
+        Ok(pinned)
+    }
+}
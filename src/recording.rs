@@ -0,0 +1,117 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+
+/// Records terminal output as an asciinema v2 cast file
+pub struct CastRecorder {
+    pub path: PathBuf,
+    file: BufWriter<File>,
+    start: Instant,
+    /// Trailing bytes of a UTF-8 character split across chunks
+    pending: Vec<u8>,
+}
+
+impl CastRecorder {
+    /// Create `<dir>/<container>-<timestamp>.cast` and write the header
+    pub fn create(dir: &Path, container: &str, size: (u16, u16)) -> Result<Self> {
+        let path = cast_path(dir, container)?;
+        let now = chrono::Local::now();
+        let file = File::create(&path).context(format!("Failed to create recording: {:?}", path))?;
+
+        let mut recorder = Self {
+            path,
+            file: BufWriter::new(file),
+            start: Instant::now(),
+            pending: Vec::new(),
+        };
+        let header = serde_json::json!({
+            "version": 2,
+            "width": size.1,
+            "height": size.0,
+            "timestamp": now.timestamp(),
+            "title": format!("docker exec {}", container),
+        });
+        writeln!(recorder.file, "{}", header)?;
+        Ok(recorder)
+    }
+
+    /// Append an output event
+    pub fn output(&mut self, bytes: &[u8]) -> Result<()> {
+        self.pending.extend_from_slice(bytes);
+        let (text, rest) = split_utf8(&self.pending);
+        if text.is_empty() {
+            return Ok(());
+        }
+        let event = serde_json::json!([self.start.elapsed().as_secs_f64(), "o", text]);
+        self.pending = rest.to_vec();
+        writeln!(self.file, "{}", event)?;
+        self.file.flush()?;
+        Ok(())
+    }
+}
+
+/// New cast file path in `dir`, creating the directory if needed
+pub fn cast_path(dir: &Path, container: &str) -> Result<PathBuf> {
+    fs::create_dir_all(dir).context(format!("Failed to create recordings directory: {:?}", dir))?;
+    Ok(dir.join(format!("{}-{}.cast", container, chrono::Local::now().format("%Y%m%d-%H%M%S"))))
+}
+
+/// Whether the asciinema CLI is installed (needed to record full-screen exec)
+pub fn asciinema_available() -> bool {
+    std::process::Command::new("asciinema")
+        .arg("--version")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+/// Decode as much as possible, keeping an incomplete trailing character
+/// for the next chunk (invalid bytes become U+FFFD)
+fn split_utf8(bytes: &[u8]) -> (String, &[u8]) {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => (text.to_string(), &[]),
+        Err(e) if e.error_len().is_none() => {
+            let (valid, rest) = bytes.split_at(e.valid_up_to());
+            (String::from_utf8_lossy(valid).into_owned(), rest)
+        }
+        Err(_) => (String::from_utf8_lossy(bytes).into_owned(), &[]),
+    }
+}
+
+/// Quote arguments for `sh -c` (asciinema runs its command through a shell)
+pub fn shell_join(args: &[String]) -> String {
+    args.iter()
+        .map(|arg| {
+            if !arg.is_empty() && arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_./:=@".contains(c)) {
+                arg.clone()
+            } else {
+                format!("'{}'", arg.replace('\'', "'\\''"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_utf8_keeps_partial_char() {
+        let bytes = "ab€".as_bytes();
+        let (text, rest) = split_utf8(&bytes[..4]);
+        assert_eq!(text, "ab");
+        assert_eq!(rest, &bytes[2..4]);
+        assert_eq!(split_utf8(bytes).0, "ab€");
+    }
+
+    #[test]
+    fn test_shell_join() {
+        let args = ["exec", "-it", "-w", "/my dir", "web", "it's"].map(String::from);
+        assert_eq!(shell_join(&args), "exec -it -w '/my dir' web 'it'\\''s'");
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::VecDeque;
+
+use bollard::container::Config;
+
+/// Number of deleted containers remembered for recreation
+const MAX_DELETED: usize = 20;
+
+/// Config of a deleted container, captured via inspect just before removal
+#[derive(Debug, Clone)]
+pub struct DeletedContainer {
+    pub name: String,
+    pub image: String,
+    /// Create config including host and network settings
+    pub config: Config<String>,
+    /// Start the container again after recreating it
+    pub was_running: bool,
+    pub deleted_at: chrono::DateTime<chrono::Local>,
+}
+
+/// Recently deleted containers (newest first)
+#[derive(Debug, Default)]
+pub struct RecycleBin {
+    pub entries: VecDeque<DeletedContainer>,
+}
+
+impl RecycleBin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, deleted: DeletedContainer) {
+        // Only the latest snapshot per name can be recreated
+        self.entries.retain(|d| d.name != deleted.name);
+        self.entries.push_front(deleted);
+        self.entries.truncate(MAX_DELETED);
+    }
+
+    /// Take the most recently deleted container
+    pub fn pop(&mut self) -> Option<DeletedContainer> {
+        self.entries.pop_front()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// What a container adds on top of its image, from inspect: the parts of a
+/// project.yaml that can be recovered from a running container
+#[derive(Debug, Clone, Default)]
+pub struct ProjectSource {
+    pub name: String,
+    pub image: String,
+    /// KEY=value entries the image doesn't set itself
+    pub env: Vec<String>,
+    /// Command, when it differs from the image's
+    pub command: Option<Vec<String>>,
+    /// Published container ports, lowest first
+    pub ports: Vec<u16>,
+    /// Binds and named volumes, `source:target[:ro]`
+    pub volumes: Vec<String>,
+    pub gpu: bool,
+    /// `host[:container[:permissions]]`
+    pub devices: Vec<String>,
+}
+
+/// A project.yaml skeleton for the container, with the parts the manifest
+/// can't hold left as comments to review
+pub fn render(source: &ProjectSource) -> String {
+    let mut lines = vec![
+        format!("# Generated by backplane-tui from the container {}; review before deploying", source.name),
+        format!("project: {}", quote(&source.name)),
+        format!("image: {}", quote(&source.image)),
+    ];
+    if let Some((port, others)) = source.ports.split_first() {
+        lines.push(format!("port: {}", port));
+        if !others.is_empty() {
+            let others: Vec<String> = others.iter().map(u16::to_string).collect();
+            lines.push(format!("# Also published (one port per project): {}", others.join(", ")));
+        }
+        lines.push("# route:".to_string());
+        lines.push(format!("#   path_prefix: /{}", source.name));
+    }
+    if source.gpu {
+        lines.push("gpu: true".to_string());
+    }
+    if !source.env.is_empty() {
+        lines.push("env:".to_string());
+        for entry in &source.env {
+            let (key, value) = entry.split_once('=').unwrap_or((entry, ""));
+            lines.push(format!("  {}: {}", quote(key), quote(value)));
+        }
+    }
+    for (key, items) in [("volumes", &source.volumes), ("devices", &source.devices)] {
+        if !items.is_empty() {
+            lines.push(format!("{}:", key));
+            lines.extend(items.iter().map(|item| format!("  - {}", quote(item))));
+        }
+    }
+    if let Some(ref command) = source.command {
+        lines.push(format!("command: {}", quote(&command.join(" "))));
+    }
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Write the skeleton to `<projects_dir>/<name>/project.yaml`, leaving an
+/// existing manifest alone
+pub fn write_file(projects_dir: &Path, source: &ProjectSource) -> Result<PathBuf> {
+    let dir = projects_dir.join(&source.name);
+    let path = dir.join("project.yaml");
+    if path.exists() {
+        anyhow::bail!("{} already exists", path.display());
+    }
+    std::fs::create_dir_all(&dir).context(format!("Failed to create directory: {:?}", dir))?;
+    std::fs::write(&path, render(source)).context(format!("Failed to write manifest: {:?}", path))?;
+    Ok(path)
+}
+
+/// A YAML double-quoted scalar (JSON strings are valid YAML)
+fn quote(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ProjectManifest;
+    use std::path::Path;
+
+    #[test]
+    fn test_render_round_trips() {
+        let source = ProjectSource {
+            name: "web".to_string(),
+            image: "nginx:1.25".to_string(),
+            env: vec!["MODE=prod".to_string(), "QUOTE=say \"hi\": now".to_string()],
+            command: Some(vec!["nginx".to_string(), "-g".to_string(), "daemon off;".to_string()]),
+            ports: vec![80, 443],
+            volumes: vec!["/srv/web:/usr/share/nginx/html:ro".to_string()],
+            gpu: true,
+            devices: vec!["/dev/dri".to_string()],
+        };
+        let yaml = render(&source);
+        assert!(yaml.contains("# Also published (one port per project): 443"));
+
+        let manifest = ProjectManifest::check(Path::new("project.yaml"), &yaml).unwrap();
+        assert_eq!(manifest.project, "web");
+        assert_eq!(manifest.port, Some(80));
+        assert_eq!(manifest.env.get("QUOTE").map(String::as_str), Some("say \"hi\": now"));
+        assert_eq!(manifest.volumes, source.volumes);
+        assert_eq!(manifest.command.as_deref(), Some("nginx -g daemon off;"));
+        assert!(manifest.gpu);
+    }
+}
@@ -0,0 +1,173 @@
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Datelike, Days, Local, NaiveDateTime, NaiveTime, Weekday};
+use serde::Deserialize;
+use std::collections::VecDeque;
+
+/// Runs kept for the schedule panel
+const MAX_HISTORY: usize = 20;
+
+/// What a scheduled rule does to its containers
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduledAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl ScheduledAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Stop => "stop",
+            Self::Restart => "restart",
+        }
+    }
+}
+
+/// A `[[schedule]]` entry of config.toml: an action on a container or a
+/// group, daily at a local time, optionally only on some weekdays
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScheduleRule {
+    pub action: ScheduledAction,
+    pub container: Option<String>,
+    pub group: Option<String>,
+    /// "HH:MM", local time
+    pub at: String,
+    /// Weekdays it runs on ("mon", "tue", ...); every day when empty
+    #[serde(default)]
+    pub days: Vec<String>,
+}
+
+/// A checked rule with its next run
+#[derive(Debug, Clone)]
+pub struct Scheduled {
+    pub action: ScheduledAction,
+    pub target: ScheduleTarget,
+    pub at: NaiveTime,
+    pub days: Vec<Weekday>,
+    pub next: DateTime<Local>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduleTarget {
+    Container(String),
+    Group(String),
+}
+
+impl Scheduled {
+    /// "restart jellyfin", "stop group media"
+    pub fn describe(&self) -> String {
+        match self.target {
+            ScheduleTarget::Container(ref name) => format!("{} {}", self.action.as_str(), name),
+            ScheduleTarget::Group(ref name) => format!("{} group {}", self.action.as_str(), name),
+        }
+    }
+}
+
+/// A finished scheduled run, for the panel
+#[derive(Debug, Clone)]
+pub struct ScheduleRun {
+    pub at: DateTime<Local>,
+    pub description: String,
+    pub ok: bool,
+    /// "ok", or what went wrong
+    pub result: String,
+}
+
+/// Rules from the config with their next run times, and recent runs
+#[derive(Debug, Default)]
+pub struct Scheduler {
+    pub rules: Vec<Scheduled>,
+    /// Most recent first
+    pub history: VecDeque<ScheduleRun>,
+}
+
+impl Scheduler {
+    pub fn new(rules: &[ScheduleRule], now: DateTime<Local>) -> Result<Self> {
+        let rules = rules
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| check_rule(rule, now).context(format!("Invalid schedule entry {}", i + 1)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { rules, history: VecDeque::new() })
+    }
+
+    /// Rules whose time has come, each moved on to its following run
+    pub fn take_due(&mut self, now: DateTime<Local>) -> Vec<Scheduled> {
+        let mut due = Vec::new();
+        for rule in self.rules.iter_mut().filter(|r| r.next <= now) {
+            due.push(rule.clone());
+            rule.next = next_run(rule.at, &rule.days, now.naive_local());
+        }
+        due
+    }
+
+    pub fn record(&mut self, description: String, result: Result<(), String>) {
+        let (ok, result) = match result {
+            Ok(()) => (true, "ok".to_string()),
+            Err(e) => (false, e),
+        };
+        self.history.push_front(ScheduleRun { at: Local::now(), description, ok, result });
+        self.history.truncate(MAX_HISTORY);
+    }
+}
+
+fn check_rule(rule: &ScheduleRule, now: DateTime<Local>) -> Result<Scheduled> {
+    let target = match (&rule.container, &rule.group) {
+        (Some(name), None) => ScheduleTarget::Container(name.clone()),
+        (None, Some(name)) => ScheduleTarget::Group(name.clone()),
+        _ => bail!("Set either container or group"),
+    };
+    let at = NaiveTime::parse_from_str(&rule.at, "%H:%M").context(format!("Expected HH:MM, got '{}'", rule.at))?;
+    let days = rule
+        .days
+        .iter()
+        .map(|day| day.parse::<Weekday>().map_err(|_| anyhow::anyhow!("Unknown weekday '{}'", day)))
+        .collect::<Result<Vec<_>>>()?;
+    let next = next_run(at, &days, now.naive_local());
+    Ok(Scheduled { action: rule.action, target, at, days, next })
+}
+
+/// First time after `now` at `at` on one of `days` (any day when empty)
+fn next_run(at: NaiveTime, days: &[Weekday], now: NaiveDateTime) -> DateTime<Local> {
+    (0..=7u64)
+        .filter_map(|offset| now.date().checked_add_days(Days::new(offset)))
+        .map(|date| date.and_time(at))
+        .filter(|candidate| *candidate > now)
+        .filter(|candidate| days.is_empty() || days.contains(&candidate.weekday()))
+        // A time skipped by a DST change waits for the next matching day
+        .find_map(|candidate| candidate.and_local_timezone(Local).earliest())
+        .unwrap_or_else(|| Local::now() + chrono::Duration::days(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_run_respects_time_and_days() {
+        let at = NaiveTime::from_hms_opt(3, 0, 0).unwrap();
+        // Wednesday 2024-05-01, 10:00
+        let now = NaiveDateTime::parse_from_str("2024-05-01 10:00", "%Y-%m-%d %H:%M").unwrap();
+        assert_eq!(next_run(at, &[], now).naive_local().to_string(), "2024-05-02 03:00:00");
+        assert_eq!(next_run(at, &[Weekday::Mon], now).naive_local().to_string(), "2024-05-06 03:00:00");
+        let early = NaiveDateTime::parse_from_str("2024-05-01 02:59", "%Y-%m-%d %H:%M").unwrap();
+        assert_eq!(next_run(at, &[], early).naive_local().to_string(), "2024-05-01 03:00:00");
+    }
+
+    #[test]
+    fn rules_need_one_target_and_a_time() {
+        let rule = |container: Option<&str>, group: Option<&str>, at: &str| ScheduleRule {
+            action: ScheduledAction::Stop,
+            container: container.map(str::to_string),
+            group: group.map(str::to_string),
+            at: at.to_string(),
+            days: vec!["fri".to_string()],
+        };
+        let now = Local::now();
+        assert!(Scheduler::new(&[rule(Some("web"), None, "22:00")], now).is_ok());
+        assert!(Scheduler::new(&[rule(Some("web"), Some("media"), "22:00")], now).is_err());
+        assert!(Scheduler::new(&[rule(None, Some("media"), "10pm")], now).is_err());
+    }
+}
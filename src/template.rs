@@ -0,0 +1,175 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::PathBuf;
+
+use crate::components::CreateContainerForm;
+use crate::components::create_modal::EnvRow;
+
+/// A container's settings as one YAML document to pass around: what the
+/// create form holds. A project.yaml reads as one too (`project` is the
+/// name, `port` the container port; route and build are ignored)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContainerTemplate {
+    #[serde(alias = "project")]
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Container port
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_port: Option<u16>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub volumes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub gpu: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub devices: Vec<String>,
+    /// With a unit suffix (512m, 2g)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+    /// In cores (0.5, 2)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dns: Vec<String>,
+}
+
+impl ContainerTemplate {
+    /// The form's current settings, or the first invalid env row
+    pub fn from_form(form: &CreateContainerForm) -> Result<Self, String> {
+        let env = form
+            .env_vars()?
+            .iter()
+            .filter_map(|var| var.split_once('='))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let text = |value: &str| Some(value.trim().to_string()).filter(|v| !v.is_empty());
+        let list = |value: &str| value.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+        Ok(Self {
+            name: form.name.trim().to_string(),
+            image: text(&form.image),
+            port: form.port_container.trim().parse().ok(),
+            host_port: form.port_host.trim().parse().ok(),
+            env,
+            volumes: list(&form.volumes),
+            command: text(&form.command),
+            gpu: form.gpu,
+            devices: list(&form.devices),
+            memory: text(&form.memory_limit),
+            cpus: text(&form.cpu_limit),
+            network: text(&form.network),
+            hostname: text(&form.hostname),
+            dns: form.dns_servers(),
+        })
+    }
+
+    /// Fill the form's fields from the template, keeping its pickers
+    pub fn apply(&self, form: &mut CreateContainerForm) {
+        let port = |port: Option<u16>| port.map(|p| p.to_string()).unwrap_or_default();
+        form.name = self.name.clone();
+        form.image = self.image.clone().unwrap_or_default();
+        form.port_container = port(self.port);
+        // A template with only the container port publishes it on the same host port
+        form.port_host = port(self.host_port.or(self.port));
+        form.env_rows = self.env.iter().map(|(key, value)| EnvRow { key: key.clone(), value: value.clone() }).collect();
+        if form.env_rows.is_empty() {
+            form.env_rows.push(EnvRow::default());
+        }
+        form.env_row = 0;
+        form.volumes = self.volumes.join(",");
+        form.command = self.command.clone().unwrap_or_default();
+        form.gpu = self.gpu;
+        form.devices = self.devices.join(",");
+        form.memory_limit = self.memory.clone().unwrap_or_default();
+        form.cpu_limit = self.cpus.clone().unwrap_or_default();
+        form.network = self.network.clone().unwrap_or_default();
+        form.hostname = self.hostname.clone().unwrap_or_default();
+        form.dns = self.dns.join(",");
+    }
+
+    /// Parse a template (or project.yaml); it needs an image to be created from
+    pub fn parse(content: &str) -> Result<Self> {
+        let template: Self = serde_yaml::from_str(content).context("Failed to parse template YAML")?;
+        if template.image.is_none() {
+            anyhow::bail!("Template has no image (a project.yaml that only builds can't be created from)");
+        }
+        Ok(template)
+    }
+
+    /// Read a template from a file, or from stdin for `-`
+    pub fn read(source: &str) -> Result<Self> {
+        let content = if source == "-" {
+            let mut content = String::new();
+            std::io::stdin().read_to_string(&mut content).context("Failed to read template from stdin")?;
+            content
+        } else {
+            let path = match source.trim().strip_prefix("~/") {
+                Some(rest) => std::env::var_os("HOME").map(PathBuf::from).unwrap_or_default().join(rest),
+                None => PathBuf::from(source.trim()),
+            };
+            std::fs::read_to_string(&path).context(format!("Failed to read template: {:?}", path))?
+        };
+        Self::parse(&content)
+    }
+
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml::to_string(self).context("Failed to serialize template")
+    }
+
+    /// Write the template to `<name>.template.yaml` in the current directory
+    pub fn write_file(&self) -> Result<PathBuf> {
+        let path = PathBuf::from(format!("{}.template.yaml", self.name));
+        std::fs::write(&path, self.to_yaml()?).context(format!("Failed to write template: {:?}", path))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_round_trips_through_form() {
+        let mut form = CreateContainerForm::new();
+        form.name = "dev".to_string();
+        form.image = "rust:1.80".to_string();
+        form.port_host = "8081".to_string();
+        form.port_container = "8080".to_string();
+        form.env_rows = vec![EnvRow { key: "MODE".to_string(), value: "a=b".to_string() }];
+        form.volumes = "/src:/work, cache:/cache".to_string();
+        form.gpu = true;
+        form.dns = "1.1.1.1".to_string();
+
+        let template = ContainerTemplate::from_form(&form).unwrap();
+        let parsed = ContainerTemplate::parse(&template.to_yaml().unwrap()).unwrap();
+        assert_eq!(parsed, template);
+        assert_eq!(parsed.volumes, vec!["/src:/work", "cache:/cache"]);
+
+        let mut imported = CreateContainerForm::new();
+        parsed.apply(&mut imported);
+        assert_eq!((imported.port_host.as_str(), imported.port_container.as_str()), ("8081", "8080"));
+        assert_eq!(imported.env_vars().unwrap(), vec!["MODE=a=b"]);
+        assert_eq!(imported.volumes, "/src:/work,cache:/cache");
+    }
+
+    #[test]
+    fn test_template_from_manifest() {
+        let manifest = "project: web\nimage: nginx\nport: 80\nroute:\n  path_prefix: /web\nenv:\n  MODE: prod\n";
+        let template = ContainerTemplate::parse(manifest).unwrap();
+        assert_eq!((template.name.as_str(), template.port), ("web", Some(80)));
+        assert_eq!(template.env.get("MODE").map(String::as_str), Some("prod"));
+        assert!(ContainerTemplate::parse("project: app\nbuild:\n  context: .\n").is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::io::ErrorKind;
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Vulnerability severity as reported by trivy, most severe first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Critical,
+    High,
+    Medium,
+    Low,
+    Unknown,
+}
+
+impl Severity {
+    fn parse(s: &str) -> Self {
+        match s {
+            "CRITICAL" => Self::Critical,
+            "HIGH" => Self::High,
+            "MEDIUM" => Self::Medium,
+            "LOW" => Self::Low,
+            _ => Self::Unknown,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Critical => "CRITICAL",
+            Self::High => "HIGH",
+            Self::Medium => "MEDIUM",
+            Self::Low => "LOW",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// One vulnerable package of an image
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vulnerability {
+    pub id: String,
+    pub package: String,
+    pub installed: String,
+    /// Version that fixes it, when there is one
+    pub fixed: Option<String>,
+    pub severity: Severity,
+    pub title: String,
+}
+
+/// CRITICAL/HIGH/MEDIUM counts, for the list badge
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SeverityCounts {
+    pub critical: usize,
+    pub high: usize,
+    pub medium: usize,
+}
+
+/// Result of a trivy scan of one image
+#[derive(Debug, Clone)]
+pub struct ScanReport {
+    pub image: String,
+    /// Most severe first
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+impl ScanReport {
+    pub fn counts(&self) -> SeverityCounts {
+        let count = |severity| self.vulnerabilities.iter().filter(|v| v.severity == severity).count();
+        SeverityCounts {
+            critical: count(Severity::Critical),
+            high: count(Severity::High),
+            medium: count(Severity::Medium),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrivyOutput {
+    #[serde(default)]
+    results: Vec<TrivyResult>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrivyResult {
+    #[serde(default)]
+    vulnerabilities: Option<Vec<TrivyVulnerability>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrivyVulnerability {
+    #[serde(rename = "VulnerabilityID")]
+    vulnerability_id: String,
+    pkg_name: String,
+    #[serde(default)]
+    installed_version: String,
+    fixed_version: Option<String>,
+    #[serde(default)]
+    severity: String,
+    #[serde(default)]
+    title: String,
+}
+
+/// Vulnerabilities in `trivy image --format json` output, most severe first
+/// (then by ID, so the order is stable between scans)
+pub fn parse_report(json: &str) -> Result<Vec<Vulnerability>> {
+    let output: TrivyOutput = serde_json::from_str(json).context("Failed to parse trivy output")?;
+    let mut vulnerabilities: Vec<Vulnerability> = output
+        .results
+        .into_iter()
+        .flat_map(|r| r.vulnerabilities.unwrap_or_default())
+        .map(|v| Vulnerability {
+            id: v.vulnerability_id,
+            package: v.pkg_name,
+            installed: v.installed_version,
+            fixed: v.fixed_version.filter(|f| !f.is_empty()),
+            severity: Severity::parse(&v.severity),
+            title: v.title,
+        })
+        .collect();
+    vulnerabilities.sort_by(|a, b| a.severity.cmp(&b.severity).then_with(|| a.id.cmp(&b.id)).then_with(|| a.package.cmp(&b.package)));
+    // The same CVE shows up once per target (e.g. two binaries of one package)
+    vulnerabilities.dedup_by(|a, b| a.id == b.id && a.package == b.package);
+    Ok(vulnerabilities)
+}
+
+/// Scan an image with trivy, using the same Docker daemon as the TUI
+pub async fn scan(image: &str, docker_host: Option<&str>) -> Result<ScanReport> {
+    let mut command = Command::new("trivy");
+    command.args(["image", "--quiet", "--format", "json", "--scanners", "vuln"]);
+    if let Some(host) = docker_host {
+        command.args(["--docker-host", host]);
+    }
+    let output = command
+        .arg(image)
+        .stdin(Stdio::null())
+        .kill_on_drop(true)
+        .output()
+        .await
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => anyhow::anyhow!("trivy is not installed (https://trivy.dev)"),
+            _ => anyhow::Error::new(e).context("Failed to run trivy"),
+        })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().rev().find(|l| !l.trim().is_empty()).unwrap_or("no output");
+        bail!("trivy failed on {}: {}", image, reason.trim());
+    }
+    let vulnerabilities = parse_report(&String::from_utf8_lossy(&output.stdout)).context(format!("Failed to read trivy report for {}", image))?;
+    Ok(ScanReport { image: image.to_string(), vulnerabilities })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_is_sorted_and_counted() {
+        let json = r#"{"Results": [
+            {"Target": "debian", "Vulnerabilities": [
+                {"VulnerabilityID": "CVE-2", "PkgName": "zlib", "InstalledVersion": "1.2", "FixedVersion": "", "Severity": "MEDIUM"},
+                {"VulnerabilityID": "CVE-1", "PkgName": "openssl", "InstalledVersion": "3.0", "FixedVersion": "3.1", "Severity": "CRITICAL", "Title": "Overflow"}
+            ]},
+            {"Target": "app", "Vulnerabilities": null},
+            {"Target": "jar", "Vulnerabilities": [
+                {"VulnerabilityID": "CVE-1", "PkgName": "openssl", "InstalledVersion": "3.0", "Severity": "CRITICAL"}
+            ]}
+        ]}"#;
+        let vulnerabilities = parse_report(json).unwrap();
+        assert_eq!(vulnerabilities.iter().map(|v| v.id.as_str()).collect::<Vec<_>>(), ["CVE-1", "CVE-2"]);
+        assert_eq!(vulnerabilities[0].fixed.as_deref(), Some("3.1"));
+        assert_eq!(vulnerabilities[1].fixed, None);
+
+        let report = ScanReport { image: "app".to_string(), vulnerabilities };
+        assert_eq!(report.counts(), SeverityCounts { critical: 1, high: 0, medium: 1 });
+        assert!(parse_report(r#"{"SchemaVersion": 2}"#).unwrap().is_empty());
+    }
+}
@@ -1,11 +1,11 @@
 use ratatui::prelude::*;
 
 /// Create the main layout with header, body (split pane), and footer
-pub fn main_layout(area: Rect) -> (Rect, Rect, Rect) {
+pub fn main_layout(area: Rect, header_height: u16) -> (Rect, Rect, Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1),  // Header
+            Constraint::Length(header_height), // Header (2 lines when expanded)
             Constraint::Min(0),     // Body
             Constraint::Length(1),  // Footer/status bar
         ])
@@ -45,7 +45,7 @@ pub fn details_layout(area: Rect) -> (Rect, Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(10), // Container info + stats
+            Constraint::Length(12), // Container info + stats
             Constraint::Min(0),     // Logs
         ])
         .split(area);
@@ -53,25 +53,43 @@ pub fn details_layout(area: Rect) -> (Rect, Rect) {
     (chunks[0], chunks[1])
 }
 
-/// Create a centered modal area
+/// Smallest terminal the full UI is laid out for
+pub const MIN_WIDTH: u16 = 60;
+pub const MIN_HEIGHT: u16 = 10;
+
+/// Modals never get narrower than this (unless the frame is)
+const MIN_MODAL_WIDTH: u16 = 40;
+
+/// Whether the terminal is below the minimum size
+pub fn is_too_small(area: Rect) -> bool {
+    area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+}
+
+/// Create a centered modal area, clamped so it never exceeds `area`
 pub fn centered_modal(area: Rect, width_percent: u16, height: u16) -> Rect {
-    let vertical = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - height.min(80)) / 2),
-            Constraint::Length(height),
-            Constraint::Percentage((100 - height.min(80)) / 2),
-        ])
-        .split(area);
+    let width = (area.width as u32 * width_percent.min(100) as u32 / 100) as u16;
+    let width = width.max(MIN_MODAL_WIDTH.min(area.width));
+    let height = height.min(area.height);
 
-    let horizontal = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - width_percent) / 2),
-            Constraint::Percentage(width_percent),
-            Constraint::Percentage((100 - width_percent) / 2),
-        ])
-        .split(vertical[1]);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_centered_modal_fits_tiny_frame() {
+        let area = Rect::new(0, 0, 30, 8);
+        let modal = centered_modal(area, 50, 22);
+        assert_eq!(modal, Rect::new(0, 0, 30, 8));
 
-    horizontal[1]
+        let modal = centered_modal(Rect::new(0, 0, 100, 40), 50, 10);
+        assert_eq!(modal, Rect::new(25, 15, 50, 10));
+    }
 }
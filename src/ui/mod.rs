@@ -1,8 +1,10 @@
 pub mod layout;
 pub mod styles;
+pub mod text;
 
 pub use layout::*;
+pub use text::{display_width, fit_width, format_bytes, pad_width, truncate_width};
 pub use styles::{
     border_style, key_desc_span, key_span, selected_style, status_color,
-    status_icon, title_style, Theme,
+    status_icon, title_style, apply_ascii, apply_theme, dim, Theme, ThemeName,
 };
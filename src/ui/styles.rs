@@ -1,4 +1,5 @@
 use ratatui::prelude::*;
+use serde::Deserialize;
 
 use crate::models::ContainerStatus;
 
@@ -58,6 +59,9 @@ impl Theme {
     pub const CREATED: Color = Self::PEACH;
     pub const NOT_DEPLOYED: Color = Self::OVERLAY;
 
+    // Alerts
+    pub const ALERT_BG: Color = Color::Rgb(58, 30, 42);       // Red-tinted row background
+
     // Progress bars
     pub const PROGRESS_FG: Color = Self::SAPPHIRE;
     pub const PROGRESS_BG: Color = Self::SURFACE0;
@@ -72,6 +76,217 @@ impl Theme {
     pub const KEY_DESC_FG: Color = Self::FG_DARK;
 }
 
+/// Selectable color themes (Catppuccin flavors).
+/// Components always render with the Mocha palette; other flavors are
+/// applied afterwards by remapping the frame buffer with `apply_theme`.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+#[serde(try_from = "String")]
+pub enum ThemeName {
+    #[default]
+    Mocha,
+    Macchiato,
+    Frappe,
+    Latte,
+    /// Black background, white text and saturated accents
+    HighContrast,
+}
+
+impl ThemeName {
+    /// Parse a theme name (case-insensitive)
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "mocha" => Some(Self::Mocha),
+            "macchiato" => Some(Self::Macchiato),
+            "frappe" | "frappé" => Some(Self::Frappe),
+            "latte" => Some(Self::Latte),
+            "high-contrast" | "highcontrast" | "contrast" => Some(Self::HighContrast),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mocha => "mocha",
+            Self::Macchiato => "macchiato",
+            Self::Frappe => "frappe",
+            Self::Latte => "latte",
+            Self::HighContrast => "high-contrast",
+        }
+    }
+
+    /// Replacement colors in `MOCHA_PALETTE` order (None = no remapping)
+    fn palette(&self) -> Option<&'static [Color; 25]> {
+        match self {
+            Self::Mocha => None,
+            Self::Macchiato => Some(&MACCHIATO_PALETTE),
+            Self::Frappe => Some(&FRAPPE_PALETTE),
+            Self::Latte => Some(&LATTE_PALETTE),
+            Self::HighContrast => Some(&HIGH_CONTRAST_PALETTE),
+        }
+    }
+}
+
+impl TryFrom<String> for ThemeName {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        Self::parse(&name).ok_or_else(|| format!("unknown theme '{}' (mocha, macchiato, frappe, latte or high-contrast)", name))
+    }
+}
+
+/// Theme colors as rendered: crust, mantle, base, bg dark, bg highlight,
+/// surface0-2, text, subtext, overlay, then the 14 accents
+const MOCHA_PALETTE: [Color; 25] = [
+    Theme::CRUST, Theme::MANTLE, Theme::BASE, Theme::BG_DARK, Theme::BG_HIGHLIGHT,
+    Theme::SURFACE0, Theme::SURFACE1, Theme::SURFACE2, Theme::FG, Theme::FG_DARK, Theme::OVERLAY,
+    Theme::ROSEWATER, Theme::FLAMINGO, Theme::PINK, Theme::MAUVE, Theme::RED, Theme::MAROON,
+    Theme::PEACH, Theme::YELLOW, Theme::GREEN, Theme::TEAL, Theme::SKY, Theme::SAPPHIRE,
+    Theme::BLUE, Theme::LAVENDER,
+];
+
+const MACCHIATO_PALETTE: [Color; 25] = [
+    Color::Rgb(24, 25, 38), Color::Rgb(30, 32, 48), Color::Rgb(36, 39, 58),
+    Color::Rgb(19, 20, 31), Color::Rgb(46, 49, 68),
+    Color::Rgb(54, 58, 79), Color::Rgb(73, 77, 100), Color::Rgb(91, 96, 120),
+    Color::Rgb(202, 211, 245), Color::Rgb(184, 192, 224), Color::Rgb(128, 135, 162),
+    Color::Rgb(244, 219, 214), Color::Rgb(240, 198, 198), Color::Rgb(245, 189, 230),
+    Color::Rgb(198, 160, 246), Color::Rgb(237, 135, 150), Color::Rgb(238, 153, 160),
+    Color::Rgb(245, 169, 127), Color::Rgb(238, 212, 159), Color::Rgb(166, 218, 149),
+    Color::Rgb(139, 213, 202), Color::Rgb(145, 215, 227), Color::Rgb(125, 196, 228),
+    Color::Rgb(138, 173, 244), Color::Rgb(183, 189, 248),
+];
+
+const FRAPPE_PALETTE: [Color; 25] = [
+    Color::Rgb(35, 38, 52), Color::Rgb(41, 44, 60), Color::Rgb(48, 52, 70),
+    Color::Rgb(29, 31, 43), Color::Rgb(58, 62, 82),
+    Color::Rgb(65, 69, 89), Color::Rgb(81, 87, 109), Color::Rgb(98, 104, 128),
+    Color::Rgb(198, 208, 245), Color::Rgb(181, 191, 226), Color::Rgb(131, 139, 167),
+    Color::Rgb(242, 213, 207), Color::Rgb(238, 190, 190), Color::Rgb(244, 184, 228),
+    Color::Rgb(202, 158, 230), Color::Rgb(231, 130, 132), Color::Rgb(234, 153, 156),
+    Color::Rgb(239, 159, 118), Color::Rgb(229, 200, 144), Color::Rgb(166, 209, 137),
+    Color::Rgb(129, 200, 190), Color::Rgb(153, 209, 219), Color::Rgb(133, 193, 220),
+    Color::Rgb(140, 170, 238), Color::Rgb(186, 187, 241),
+];
+
+const LATTE_PALETTE: [Color; 25] = [
+    Color::Rgb(220, 224, 232), Color::Rgb(230, 233, 239), Color::Rgb(239, 241, 245),
+    Color::Rgb(230, 233, 239), Color::Rgb(204, 208, 218),
+    Color::Rgb(204, 208, 218), Color::Rgb(188, 192, 204), Color::Rgb(172, 176, 190),
+    Color::Rgb(76, 79, 105), Color::Rgb(92, 95, 119), Color::Rgb(140, 143, 161),
+    Color::Rgb(220, 138, 120), Color::Rgb(221, 120, 120), Color::Rgb(234, 118, 203),
+    Color::Rgb(136, 57, 239), Color::Rgb(210, 15, 57), Color::Rgb(230, 69, 83),
+    Color::Rgb(254, 100, 11), Color::Rgb(223, 142, 29), Color::Rgb(64, 160, 43),
+    Color::Rgb(23, 146, 153), Color::Rgb(4, 165, 229), Color::Rgb(32, 159, 181),
+    Color::Rgb(30, 102, 245), Color::Rgb(114, 135, 253),
+];
+
+const HIGH_CONTRAST_PALETTE: [Color; 25] = [
+    Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 0),
+    Color::Rgb(0, 0, 0), Color::Rgb(40, 40, 40),
+    Color::Rgb(70, 70, 70), Color::Rgb(120, 120, 120), Color::Rgb(170, 170, 170),
+    Color::Rgb(255, 255, 255), Color::Rgb(235, 235, 235), Color::Rgb(210, 210, 210),
+    Color::Rgb(255, 210, 210), Color::Rgb(255, 170, 170), Color::Rgb(255, 120, 230),
+    Color::Rgb(220, 150, 255), Color::Rgb(255, 85, 85), Color::Rgb(255, 120, 120),
+    Color::Rgb(255, 170, 50), Color::Rgb(255, 255, 0), Color::Rgb(0, 255, 0),
+    Color::Rgb(0, 255, 210), Color::Rgb(0, 230, 255), Color::Rgb(90, 200, 255),
+    Color::Rgb(120, 170, 255), Color::Rgb(200, 200, 255),
+];
+
+/// Remap Mocha colors in the buffer to the selected theme's palette
+pub fn apply_theme(buf: &mut Buffer, area: Rect, theme: ThemeName) {
+    let Some(palette) = theme.palette() else {
+        return;
+    };
+    let remap = |color: Color| -> Color {
+        MOCHA_PALETTE
+            .iter()
+            .position(|&c| c == color)
+            .map(|i| palette[i])
+            .unwrap_or(color)
+    };
+
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.fg = remap(cell.fg);
+                cell.bg = remap(cell.bg);
+            }
+        }
+    }
+}
+
+/// ASCII stand-in for a glyph that needs a good Unicode font (box drawing,
+/// block bars, braille, icons and arrows); None when it's already plain
+pub fn ascii_symbol(symbol: &str) -> Option<&'static str> {
+    let mut chars = symbol.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+    if c.is_ascii() {
+        return None;
+    }
+    let ascii = match c {
+        // Box drawing: lines and every kind of corner or junction
+        '─' | '━' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '═' | '╴' | '╶' | '╸' | '╺' | '╼' | '╾' => "-",
+        '│' | '┃' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '║' | '╵' | '╷' | '╹' | '╻' | '╽' | '╿' => "|",
+        '\u{2500}'..='\u{257F}' => "+",
+        // Sparkline and bar heights, gauge fills
+        '▁' | '▂' => "_",
+        '▃' | '▄' => "-",
+        '▅' | '▆' => "=",
+        '▇' | '█' | '▉' | '▊' | '▋' | '▌' | '▓' => "#",
+        '▍' | '▎' | '▏' => "|",
+        '▒' => ":",
+        '░' | '·' | '…' => ".",
+        // Braille chart dots and spinner frames
+        '\u{2800}' => " ",
+        '\u{2801}'..='\u{28FF}' => "*",
+        // Status icons and markers
+        '●' | '•' | '◆' | '★' | '♥' => "*",
+        '○' => "o",
+        '◯' => "O",
+        '◐' | '⏸' => "=",
+        '◌' => ".",
+        '↻' => "@",
+        '✕' | '✗' => "x",
+        '✖' => "X",
+        '✓' => "+",
+        '⚠' | '⚑' => "!",
+        '⌛' => "~",
+        '↑' | '▲' | '⬆' => "^",
+        '↓' | '▼' => "v",
+        '←' => "<",
+        '→' | '▶' => ">",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+/// Replace glyphs with their ASCII stand-ins, for terminals and fonts that
+/// draw them poorly and for screen readers
+pub fn apply_ascii(buf: &mut Buffer, area: Rect) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                if let Some(ascii) = ascii_symbol(cell.symbol()) {
+                    cell.set_symbol(ascii);
+                }
+            }
+        }
+    }
+}
+
+/// Dim every cell, e.g. while the app is idle
+pub fn dim(buf: &mut Buffer, area: Rect) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.modifier.insert(Modifier::DIM);
+            }
+        }
+    }
+}
+
 /// Status icons for containers
 pub struct StatusIcons;
 
@@ -165,3 +380,19 @@ pub fn key_desc_span(desc: &str) -> Span<'_> {
         Style::default().fg(Theme::FG_DARK),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_symbol() {
+        assert_eq!(ascii_symbol("─"), Some("-"));
+        assert_eq!(ascii_symbol("╭"), Some("+"));
+        assert_eq!(ascii_symbol("▇"), Some("#"));
+        assert_eq!(ascii_symbol("⠋"), Some("*"));
+        assert_eq!(ascii_symbol(StatusIcons::EXITED), Some("o"));
+        assert_eq!(ascii_symbol("a"), None);
+        assert_eq!(ascii_symbol("é"), None);
+    }
+}
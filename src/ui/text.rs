@@ -0,0 +1,95 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Terminal columns a string takes (wide characters count as two)
+pub fn display_width(s: &str) -> usize {
+    s.width()
+}
+
+/// Pad with spaces to `width` columns without cutting anything: `{:<w$}`
+/// counted in columns instead of chars
+pub fn pad_width(s: &str, width: usize) -> String {
+    let mut out = s.to_string();
+    out.extend(std::iter::repeat_n(' ', width.saturating_sub(s.width())));
+    out
+}
+
+/// Cut a string to at most `width` terminal columns, ending in "…" when
+/// anything was dropped. Wide characters (CJK, emoji) count as two
+pub fn truncate_width(s: &str, width: usize) -> String {
+    if s.width() <= width {
+        return s.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for ch in s.chars() {
+        let w = ch.width().unwrap_or(0);
+        if used + w > width - 1 {
+            break;
+        }
+        out.push(ch);
+        used += w;
+    }
+    out.push('…');
+    out
+}
+
+/// Truncate and pad with spaces to exactly `width` columns
+pub fn fit_width(s: &str, width: usize) -> String {
+    pad_width(&truncate_width(s, width), width)
+}
+
+/// Format bytes as human readable (KB, MB, GB)
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes == 0 {
+        return "0 B".to_string();
+    }
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1}GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1}MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1}KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{}B", bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_width() {
+        assert_eq!(truncate_width("web", 8), "web");
+        assert_eq!(truncate_width("backplane", 5), "back…");
+        assert_eq!(truncate_width("café-frontend", 5), "café…");
+        // Two columns per character: "日本" plus the ellipsis fills 5
+        assert_eq!(truncate_width("日本語コンテナ", 5), "日本…");
+        assert_eq!(truncate_width("日本語", 4), "日…");
+        assert_eq!(truncate_width("abc", 0), "");
+        assert_eq!(fit_width("日本", 6), "日本  ");
+        assert_eq!(fit_width("日本語コンテナ", 6).width(), 6);
+    }
+
+    #[test]
+    fn test_pad_width() {
+        assert_eq!(pad_width("web", 5), "web  ");
+        assert_eq!(pad_width("日本", 5), "日本 ");
+        assert_eq!(display_width(&pad_width("🐳 app", 10)), 10);
+        assert_eq!(pad_width("backplane", 4), "backplane");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.5KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0GB");
+    }
+}